@@ -0,0 +1,24 @@
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use rand::{thread_rng, Rng};
+use shamir::algos::{gf256, gf64};
+
+fn split(c: &mut Criterion) {
+    let mut group = c.benchmark_group("split_bytes");
+    group.sample_size(10);
+    let mut rng = thread_rng();
+    for len in [4096usize, 1 << 20] {
+        let mut secret = vec![0u8; len];
+        rng.fill(&mut secret[..]);
+        group.throughput(Throughput::Bytes(len as u64));
+        group.bench_with_input(BenchmarkId::new("gf256", len), &len, |b, _| {
+            b.iter(|| gf256::split(&secret, 3, 5, &mut thread_rng()))
+        });
+        group.bench_with_input(BenchmarkId::new("gf64", len), &len, |b, _| {
+            b.iter(|| gf64::split(&secret, 3, 5, &mut thread_rng()))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, split);
+criterion_main!(benches);
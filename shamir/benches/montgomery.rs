@@ -0,0 +1,42 @@
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use num_bigint::{BigUint, RandBigInt};
+use rand::thread_rng;
+use shamir::algos::bigint::BigPrimeField;
+use shamir::algos::montgomery::Montgomery;
+use shamir::algos::polynomial;
+
+// Share evaluation and reconstruction over the 521-bit Mersenne prime, with the generic field
+// (BigUint multiply then `%`) against Montgomery form.
+fn arithmetic(c: &mut Criterion) {
+    let field = BigPrimeField::mersenne_521();
+    let mont = Montgomery::new(field.modulus()).expect("Failed to set up Montgomery form");
+    let mut rng = thread_rng();
+    let mut group = c.benchmark_group("mersenne_521");
+    group.sample_size(10);
+    for t in [3usize, 16, 64] {
+        let coeffs: Vec<BigUint> = (0..t).map(|_| rng.gen_biguint_below(field.modulus())).collect();
+        let xs: Vec<BigUint> = (1..=t as u64).map(BigUint::from).collect();
+        group.bench_with_input(BenchmarkId::new("evaluate_plain", t), &t, |b, _| {
+            b.iter(|| xs.iter().map(|x| polynomial::evaluate(&field, &coeffs, x)).collect::<Vec<_>>())
+        });
+        group.bench_with_input(BenchmarkId::new("evaluate_montgomery", t), &t, |b, _| {
+            b.iter(|| {
+                let residues = mont.to_montgomery_all(&coeffs);
+                xs.iter().map(|x| mont.evaluate(&residues, x)).collect::<Vec<_>>()
+            })
+        });
+
+        let points: Vec<(BigUint, BigUint)> =
+            xs.iter().map(|x| (x.clone(), polynomial::evaluate(&field, &coeffs, x))).collect();
+        group.bench_with_input(BenchmarkId::new("interpolate_plain", t), &t, |b, _| {
+            b.iter(|| polynomial::interpolate_constant(&field, &points))
+        });
+        group.bench_with_input(BenchmarkId::new("interpolate_montgomery", t), &t, |b, _| {
+            b.iter(|| mont.interpolate_at_zero(&points))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, arithmetic);
+criterion_main!(benches);
@@ -0,0 +1,24 @@
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use rand::{thread_rng, Rng};
+use shamir::algos::multipoint;
+use shamir::algos::sss::{self, PRIME};
+
+fn evaluation(c: &mut Criterion) {
+    let mut group = c.benchmark_group("evaluate_all_shares");
+    group.sample_size(10);
+    let mut rng = thread_rng();
+    for n in [512usize, 2048, 8192] {
+        let coeffs: Vec<u64> = (0..n).map(|_| rng.gen_range(0..PRIME)).collect();
+        let xs: Vec<u64> = (1..=n as u64).collect();
+        group.bench_with_input(BenchmarkId::new("horner", n), &n, |b, _| {
+            b.iter(|| xs.iter().map(|&x| sss::evaluate_polynomial(&coeffs, x)).collect::<Vec<_>>())
+        });
+        group.bench_with_input(BenchmarkId::new("subproduct_tree", n), &n, |b, _| {
+            b.iter(|| multipoint::evaluate_many(&coeffs, &xs))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, evaluation);
+criterion_main!(benches);
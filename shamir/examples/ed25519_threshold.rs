@@ -0,0 +1,50 @@
+// Splits an Ed25519 key 3-of-5, signs a message with three of the shares without ever
+// rebuilding the key, then reassembles the key from a different three and signs again.
+//
+//     cargo run --example ed25519_threshold --features ed25519
+
+use rand::{thread_rng, Rng};
+use shamir::algos::ed25519::{self, SigningKey};
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+fn main() {
+    let mut rng = thread_rng();
+    let seed: [u8; 32] = rng.r#gen();
+    let key = SigningKey::from_seed(&seed);
+    let public_key = key.public_key();
+    println!("public key: {}", hex(&public_key));
+
+    let (shares, commitments) = ed25519::split_key(&key, 3, 5, &mut rng).expect("Failed to split key");
+    drop(key);
+    for share in &shares {
+        assert!(ed25519::verify_share(share, &commitments), "share {} failed verification", share.index);
+    }
+
+    // Round one: each signer commits to a pair of nonces.
+    let message = b"release the funds";
+    let signers = [&shares[0], &shares[2], &shares[4]];
+    let (nonces, nonce_commitments): (Vec<_>, Vec<_>) =
+        signers.iter().map(|share| ed25519::commit(share.index, &mut rng)).unzip();
+
+    // Round two: each signer signs once it has seen every commitment.
+    let partials: Vec<_> = signers
+        .iter()
+        .zip(nonces)
+        .map(|(share, nonces)| {
+            ed25519::sign(message, share, nonces, &nonce_commitments, &public_key).expect("Failed to sign")
+        })
+        .collect();
+    let signature =
+        ed25519::aggregate(message, &partials, &nonce_commitments, &commitments).expect("Failed to aggregate");
+    assert!(ed25519::verify(&public_key, message, &signature));
+    println!("threshold signature: {}", hex(&signature));
+
+    let key = ed25519::reassemble(&shares[1..4]).expect("Failed to reassemble key");
+    assert_eq!(key.public_key(), public_key);
+    let signature = key.sign(message);
+    assert!(ed25519::verify(&public_key, message, &signature));
+    println!("reassembled key signature: {}", hex(&signature));
+}
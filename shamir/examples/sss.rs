@@ -0,0 +1,22 @@
+// Splits a random secret 3-of-5 over the prime field and reconstructs it from the first three
+// shares, printing each step.
+//
+//     cargo run --example sss
+
+use rand::{thread_rng, Rng};
+use shamir::algos::sss::{self, PRIME};
+
+fn main() {
+    let mut rng = thread_rng();
+    let secret = rng.gen_range(0..PRIME);
+    println!("secret: {}", secret);
+
+    let shares = sss::generate_shares(secret, 3, 5, &mut rng).expect("Failed to split");
+    for share in &shares {
+        println!("share: {}", share);
+    }
+
+    let reconstructed = sss::reconstruct_secret(&shares[..3]).expect("Failed to reconstruct");
+    println!("reconstructed from shares 1-3: {}", reconstructed);
+    assert_eq!(reconstructed, secret);
+}
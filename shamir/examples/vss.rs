@@ -0,0 +1,32 @@
+// Feldman VSS with the toy parameters: deals a random secret, checks every share against the
+// public commitments, and reconstructs from the first threshold shares.
+//
+//     cargo run --example vss
+
+use rand::{thread_rng, Rng};
+use shamir::vss::{self, VssConfig};
+
+fn main() {
+    let config = VssConfig::default();
+    let mut rng = thread_rng();
+    let secret = rng.gen_range(0..config.order());
+    println!("secret: {}", secret);
+
+    let coeffs = vss::generate_polynomial(secret, &config, &mut rng);
+    let shares = vss::generate_shares(&coeffs, &config, &mut rng);
+    let commitments = vss::generate_commitments(&coeffs, &config);
+    drop(coeffs);
+    for share in &shares {
+        println!("share: {}", share);
+    }
+    println!("commitments: {:?}", commitments);
+
+    for share in &shares {
+        println!("share {} valid: {}", share.index, vss::verify_share(share, &commitments, &config));
+    }
+
+    let threshold = config.threshold();
+    let reconstructed = vss::reconstruct_secret(&shares[..threshold], &config).expect("Failed to reconstruct");
+    println!("reconstructed from the first {} shares: {}", threshold, reconstructed);
+    assert_eq!(reconstructed, secret);
+}
@@ -0,0 +1,25 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use num_bigint::BigUint;
+use shamir::algos::share::Share;
+use shamir::algos::wire;
+
+fuzz_target!(|data: &[u8]| {
+    // Anything the decoder accepts must re-encode to the same bytes.
+    if let Ok(share) = wire::decode::<u64>(data) {
+        assert_eq!(wire::encode(&share), data);
+    }
+    if let Ok(share) = wire::decode::<Vec<u8>>(data) {
+        assert_eq!(wire::encode(&share), data);
+    }
+    let _ = wire::decode::<i128>(data);
+    let _ = wire::decode::<BigUint>(data);
+
+    if let Ok(text) = std::str::from_utf8(data) {
+        let _ = text.parse::<Share>();
+        let _ = text.parse::<Share<Vec<u8>>>();
+        let _ = Share::<Vec<u8>>::from_bech32(text, "shr");
+        let _ = shamir::algos::mnemonic::from_mnemonic(text);
+    }
+});
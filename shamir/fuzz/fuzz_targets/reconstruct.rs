@@ -0,0 +1,24 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use shamir::algos::robust;
+use shamir::algos::share::Share;
+use shamir::algos::sss;
+
+// Adversarial share sets: duplicate or zero x, values at or above the prime, mixed lengths.
+fuzz_target!(|input: (u8, Vec<(u64, u64)>, Vec<(u64, Vec<u8>)>)| {
+    let (threshold, points, byte_points) = input;
+    let threshold = threshold as usize;
+
+    let shares: Vec<Share> = points.iter().map(|&(x, y)| Share::new(x, y, threshold, 0)).collect();
+    if let Ok(secret) = sss::reconstruct_secret(&shares) {
+        assert!(secret < sss::PRIME);
+    }
+    if shares.len() <= 64 {
+        let _ = robust::reconstruct_robust(&shares);
+    }
+
+    let shares: Vec<Share<Vec<u8>>> =
+        byte_points.into_iter().map(|(x, y)| Share::new(x, y, threshold, 0)).collect();
+    let _ = sss::combine_bytes(&shares);
+});
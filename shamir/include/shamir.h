@@ -0,0 +1,72 @@
+#ifndef SHAMIR_H
+#define SHAMIR_H
+
+/* Generated by cbindgen from src/algos/ffi.rs; do not edit. Regenerate with `cbindgen --config cbindgen.toml --output include/shamir.h`. */
+
+#include <stddef.h>
+#include <stdint.h>
+
+#define SHAMIR_OK 0
+
+#define SHAMIR_ERR_NULL -1
+
+#define SHAMIR_ERR_BUFFER_TOO_SMALL -2
+
+#define SHAMIR_ERR_INVALID -3
+
+#define SHAMIR_ERR_MALFORMED -4
+
+/*
+ Size in bytes of each encoded share produced by `shamir_split` for a secret of `secret_len` bytes.
+ */
+size_t shamir_share_len(size_t secret_len);
+
+/*
+ Splits `secret` into `num_shares` shares, written back to back into `out`, each
+ `shamir_share_len(secret_len)` bytes long.
+
+ # Safety
+
+ `secret` must point to `secret_len` readable bytes and `out` to `out_len` writable bytes.
+ */
+int32_t shamir_split(const uint8_t *secret,
+                     size_t secret_len,
+                     size_t threshold,
+                     size_t num_shares,
+                     uint8_t *out,
+                     size_t out_len);
+
+/*
+ Combines `count` encoded shares of `share_len` bytes each, stored back to back in `shares`.
+ The secret is written to `out` and its length to `secret_len`.
+
+ # Safety
+
+ `shares` must point to `count * share_len` readable bytes, `out` to `out_len` writable bytes,
+ and `secret_len` to a writable `size_t`.
+ */
+int32_t shamir_combine(const uint8_t *shares,
+                       size_t share_len,
+                       size_t count,
+                       uint8_t *out,
+                       size_t out_len,
+                       size_t *secret_len);
+
+/*
+ Checks an encoded Feldman VSS share against the dealer's commitments. Returns 1 if the share
+ is valid, 0 if it is not, and a negative error code otherwise.
+
+ # Safety
+
+ `share` must point to `share_len` readable bytes and `commitments` to `count` readable values.
+ */
+int32_t vss_verify_share(const uint8_t *share,
+                         size_t share_len,
+                         const int64_t *commitments,
+                         size_t count,
+                         int64_t prime,
+                         int64_t generator,
+                         size_t threshold,
+                         size_t num_shares);
+
+#endif  /* SHAMIR_H */
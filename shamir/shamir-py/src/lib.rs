@@ -0,0 +1,72 @@
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::PyBytes;
+use shamir::algos::share::Share;
+use shamir::algos::{sss, vss, wire};
+
+// Shares cross into Python as bytes in the crate's versioned wire format, so they can be stored
+// or handed back to the Rust CLI and C API unchanged.
+
+fn value_error(err: impl std::fmt::Debug) -> PyErr {
+    PyValueError::new_err(format!("{:?}", err))
+}
+
+/// split(secret: bytes, threshold: int, shares: int) -> list[bytes]
+#[pyfunction]
+fn split<'py>(py: Python<'py>, secret: &[u8], threshold: usize, shares: usize) -> PyResult<Vec<Bound<'py, PyBytes>>> {
+    let shares = sss::split_bytes(secret, threshold, shares, &mut rand::thread_rng()).map_err(value_error)?;
+    Ok(shares.iter().map(|share| PyBytes::new(py, &wire::encode(share))).collect())
+}
+
+/// combine(shares: list[bytes]) -> bytes
+#[pyfunction]
+fn combine<'py>(py: Python<'py>, shares: Vec<Vec<u8>>) -> PyResult<Bound<'py, PyBytes>> {
+    let shares = shares
+        .iter()
+        .map(|bytes| wire::decode::<Vec<u8>>(bytes))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(value_error)?;
+    let secret = sss::combine_bytes(&shares).map_err(value_error)?;
+    Ok(PyBytes::new(py, &secret))
+}
+
+/// vss_split(secret: int, prime: int, generator: int, threshold: int, shares: int)
+///     -> tuple[list[bytes], list[int]]
+#[pyfunction]
+fn vss_split<'py>(
+    py: Python<'py>,
+    secret: i64,
+    prime: i64,
+    generator: i64,
+    threshold: usize,
+    shares: usize,
+) -> PyResult<(Vec<Bound<'py, PyBytes>>, Vec<i64>)> {
+    let config = vss::VssConfig::new(prime as i128, generator as i128, threshold, shares).map_err(value_error)?;
+    let mut rng = rand::thread_rng();
+    let coeffs = vss::generate_polynomial(secret as i128, &config, &mut rng);
+    let shares = vss::generate_shares(&coeffs, &config, &mut rng);
+    let commitments = vss::generate_commitments(&coeffs, &config);
+    Ok((
+        shares.iter().map(|share| PyBytes::new(py, &wire::encode(share))).collect(),
+        commitments.iter().map(|&c| c as i64).collect(),
+    ))
+}
+
+/// verify(share: bytes, commitments: list[int], prime: int, generator: int, threshold: int,
+///        shares: int) -> bool
+#[pyfunction]
+fn verify(share: &[u8], commitments: Vec<i64>, prime: i64, generator: i64, threshold: usize, shares: usize) -> PyResult<bool> {
+    let config = vss::VssConfig::new(prime as i128, generator as i128, threshold, shares).map_err(value_error)?;
+    let share: Share<i128> = wire::decode(share).map_err(value_error)?;
+    let commitments: Vec<i128> = commitments.into_iter().map(i128::from).collect();
+    Ok(vss::verify_share(&share, &commitments, &config))
+}
+
+#[pymodule]
+fn shamir_py(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(split, m)?)?;
+    m.add_function(wrap_pyfunction!(combine, m)?)?;
+    m.add_function(wrap_pyfunction!(vss_split, m)?)?;
+    m.add_function(wrap_pyfunction!(verify, m)?)?;
+    Ok(())
+}
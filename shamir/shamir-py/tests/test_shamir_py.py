@@ -0,0 +1,23 @@
+import pytest
+
+import shamir_py
+
+
+def test_split_and_combine():
+    shares = shamir_py.split(b"python secret", 3, 5)
+    assert len(shares) == 5
+    assert all(isinstance(share, bytes) for share in shares)
+    assert shamir_py.combine(shares[2:]) == b"python secret"
+
+
+def test_combine_below_threshold():
+    shares = shamir_py.split(b"python secret", 3, 5)
+    with pytest.raises(ValueError):
+        shamir_py.combine(shares[:2])
+
+
+def test_vss_verify():
+    shares, commitments = shamir_py.vss_split(42, 4007, 2, 3, 5)
+    assert all(shamir_py.verify(share, commitments, 4007, 2, 3, 5) for share in shares)
+    commitments[0] = commitments[0] * 2 % 4007
+    assert not shamir_py.verify(shares[0], commitments, 4007, 2, 3, 5)
@@ -0,0 +1,77 @@
+use alloc::vec::Vec;
+
+use rand::{CryptoRng, Rng, RngCore};
+
+use super::field::FiniteField;
+use super::share::{self, Share};
+use super::sss::{self, ShamirError, FIELD, PRIME};
+
+// Conversions between n-of-n additive sharing (values summing to the secret mod PRIME) and
+// t-of-n Shamir sharing, the usual glue when handing a dealing to MPC code and back.
+
+pub fn split_additive(secret: u64, parties: usize, rng: &mut (impl RngCore + CryptoRng)) -> Result<Vec<u64>, ShamirError> {
+    if secret >= PRIME {
+        return Err(ShamirError::SecretOutOfRange);
+    }
+    if parties == 0 {
+        return Err(ShamirError::InvalidShareCount);
+    }
+    let mut values: Vec<u64> = (1..parties).map(|_| rng.gen_range(0..PRIME)).collect();
+    let sum = combine_additive(&values);
+    values.push(FIELD.sub(&secret, &sum));
+    Ok(values)
+}
+
+pub fn combine_additive(values: &[u64]) -> u64 {
+    values.iter().fold(0, |acc, value| FIELD.add(&acc, value))
+}
+
+// One holder's additive value once the quorum is fixed: its share weighted by its Lagrange
+// coefficient at zero. Every member of `quorum` must do the same for the values to add up.
+pub fn to_additive(share: &Share, quorum: &[u64]) -> Result<u64, ShamirError> {
+    sss::check_indices(quorum, PRIME)?;
+    if !quorum.contains(&share.index) {
+        return Err(ShamirError::InconsistentShares);
+    }
+    let mut numerator = 1;
+    let mut denominator = 1;
+    for &x_j in quorum.iter().filter(|&&x_j| x_j != share.index) {
+        numerator = FIELD.mul(&numerator, &FIELD.neg(&x_j));
+        denominator = FIELD.mul(&denominator, &FIELD.sub(&share.index, &x_j));
+    }
+    Ok(FIELD.mul(&share.value, &FIELD.mul(&numerator, &sss::mod_inverse(denominator))))
+}
+
+pub fn shamir_to_additive(shares: &[Share]) -> Result<Vec<u64>, ShamirError> {
+    sss::quorum(shares)?;
+    if !share::check_same_dealing(shares) {
+        return Err(ShamirError::InconsistentShares);
+    }
+    let quorum: Vec<u64> = shares.iter().map(|share| share.index).collect();
+    shares.iter().map(|share| to_additive(share, &quorum)).collect()
+}
+
+// Step one of additive-to-Shamir: each party Shamir-shares its own additive value and sends
+// sub-share j to party j.
+pub fn deal_additive(
+    value: u64,
+    threshold: usize,
+    num_shares: usize,
+    rng: &mut (impl RngCore + CryptoRng),
+) -> Result<Vec<Share>, ShamirError> {
+    if value >= PRIME {
+        return Err(ShamirError::SecretOutOfRange);
+    }
+    sss::generate_shares(value, threshold, num_shares, rng)
+}
+
+// Step two: party `index` sums the sub-shares it received, one from every additive party, into
+// a Shamir share of the sum. All parties must agree on `scheme_id` beforehand.
+pub fn additive_to_shamir(index: u64, sub_shares: &[Share], scheme_id: u32) -> Result<Share, ShamirError> {
+    let threshold = sub_shares.first().ok_or(ShamirError::InsufficientShares { provided: 0, required: 1 })?.threshold;
+    if sub_shares.iter().any(|sub_share| sub_share.index != index || sub_share.threshold != threshold) {
+        return Err(ShamirError::InconsistentShares);
+    }
+    let value = sub_shares.iter().fold(0, |acc, sub_share| FIELD.add(&acc, &sub_share.value));
+    Ok(Share::new(index, value, threshold, scheme_id))
+}
@@ -0,0 +1,91 @@
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use curve25519_dalek::MontgomeryPoint;
+use rand::{CryptoRng, RngCore};
+use zeroize::Zeroizing;
+
+use super::bech32::{self, BECH32_CONST};
+use super::share::Share;
+use super::sss::{self, ShamirError};
+
+// age X25519 identities under a threshold policy: the 32-byte secret from an
+// `AGE-SECRET-KEY-1...` line is split with `sss::split_bytes`, and a quorum of shares gives back
+// the same identity, ready to write out as an identity file for `age -d -i`.
+
+const SECRET_HRP: &str = "age-secret-key-";
+const PUBLIC_HRP: &str = "age";
+
+pub struct Identity {
+    secret: Zeroizing<[u8; 32]>,
+}
+
+impl Identity {
+    // Reads an identity file as `age-keygen` writes it: comments, blank lines, and exactly one
+    // secret key. A `# public key:` comment, if present, has to match the key.
+    pub fn parse(file: &str) -> Result<Self, ShamirError> {
+        let mut keys = file.lines().map(str::trim).filter(|line| !line.is_empty() && !line.starts_with('#'));
+        let line = keys.next().ok_or(ShamirError::InvalidParameters("identity"))?;
+        if keys.next().is_some() {
+            return Err(ShamirError::InvalidParameters("identity"));
+        }
+        let identity = Identity::from_secret_key(line)?;
+        let expected = file.lines().find_map(|line| line.trim().strip_prefix("# public key:"));
+        if expected.is_some_and(|expected| expected.trim() != identity.recipient()) {
+            return Err(ShamirError::InvalidParameters("public key"));
+        }
+        Ok(identity)
+    }
+
+    // One `AGE-SECRET-KEY-1...` string.
+    pub fn from_secret_key(key: &str) -> Result<Self, ShamirError> {
+        if key.bytes().any(|b| b.is_ascii_lowercase()) {
+            return Err(ShamirError::InvalidParameters("identity"));
+        }
+        let (hrp, payload) = bech32::decode_with(key, BECH32_CONST).map_err(|_| ShamirError::InvalidChecksum)?;
+        let payload = Zeroizing::new(payload);
+        if hrp != SECRET_HRP {
+            return Err(ShamirError::InvalidParameters("identity"));
+        }
+        Identity::from_bytes(&payload)
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<Self, ShamirError> {
+        if bytes.len() != 32 {
+            return Err(ShamirError::InvalidParameters("identity"));
+        }
+        let mut identity = Identity { secret: Zeroizing::new([0; 32]) };
+        identity.secret.copy_from_slice(bytes);
+        Ok(identity)
+    }
+
+    pub fn to_secret_key(&self) -> Zeroizing<String> {
+        let key = bech32::encode_with(SECRET_HRP, &self.secret[..], BECH32_CONST).expect("Failed to encode identity");
+        Zeroizing::new(key.to_ascii_uppercase())
+    }
+
+    // The `age1...` recipient for this identity.
+    pub fn recipient(&self) -> String {
+        let public = MontgomeryPoint::mul_base_clamped(*self.secret);
+        bech32::encode_with(PUBLIC_HRP, public.as_bytes(), BECH32_CONST).expect("Failed to encode recipient")
+    }
+
+    pub fn to_file(&self) -> Zeroizing<String> {
+        Zeroizing::new(format!("# public key: {}\n{}\n", self.recipient(), self.to_secret_key().as_str()))
+    }
+}
+
+pub fn split_identity(
+    file: &str,
+    threshold: usize,
+    num_shares: usize,
+    rng: &mut (impl RngCore + CryptoRng),
+) -> Result<Vec<Share<Vec<u8>>>, ShamirError> {
+    let identity = Identity::parse(file)?;
+    sss::split_bytes(&identity.secret[..], threshold, num_shares, rng)
+}
+
+pub fn combine_identity(shares: &[Share<Vec<u8>>]) -> Result<Identity, ShamirError> {
+    Identity::from_bytes(&sss::combine_bytes(shares)?)
+}
@@ -0,0 +1,146 @@
+use alloc::vec::Vec;
+
+use rand::{CryptoRng, RngCore};
+use zeroize::Zeroizing;
+
+use super::share::{Share, ShareValue};
+use super::sss::{self, ShamirError};
+use super::vss::{self, VerifiedReconstruction, VssConfig, VssError};
+
+// A record of what happened to shares, for key-ceremony logs: the wrappers below do the usual
+// split, verify, and combine, and report each step to an `AuditSink`. Events name shares by
+// dealing and index only; no share value or secret ever reaches a sink.
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(tag = "event"))]
+pub enum AuditEvent {
+    ShareIssued { scheme_id: u32, index: u64, threshold: usize, epoch: u32 },
+    ShareVerified { scheme_id: u32, index: u64 },
+    VerificationFailed { scheme_id: u32, index: u64 },
+    ReconstructionAttempted { scheme_id: u32, indices: Vec<u64>, succeeded: bool },
+}
+
+pub trait AuditSink {
+    fn record(&mut self, event: AuditEvent);
+}
+
+impl AuditSink for Vec<AuditEvent> {
+    fn record(&mut self, event: AuditEvent) {
+        self.push(event);
+    }
+}
+
+impl<F: FnMut(AuditEvent)> AuditSink for F {
+    fn record(&mut self, event: AuditEvent) {
+        self(event)
+    }
+}
+
+// One JSON object per line, e.g. for appending to a file an audit system tails.
+#[cfg(feature = "json")]
+pub struct JsonLines<W: std::io::Write>(pub W);
+
+#[cfg(feature = "json")]
+impl<W: std::io::Write> AuditSink for JsonLines<W> {
+    fn record(&mut self, event: AuditEvent) {
+        // An audit trail that can't be written shouldn't take the ceremony down with it.
+        if let Ok(line) = serde_json::to_string(&event) {
+            let _ = writeln!(self.0, "{}", line);
+        }
+    }
+}
+
+fn issued<V: ShareValue>(shares: &[Share<V>], sink: &mut impl AuditSink) {
+    for share in shares {
+        sink.record(AuditEvent::ShareIssued {
+            scheme_id: share.scheme_id,
+            index: share.index,
+            threshold: share.threshold,
+            epoch: share.epoch,
+        });
+    }
+}
+
+fn attempted<V: ShareValue>(shares: &[Share<V>], succeeded: bool, sink: &mut impl AuditSink) {
+    sink.record(AuditEvent::ReconstructionAttempted {
+        scheme_id: shares.first().map_or(0, |share| share.scheme_id),
+        indices: shares.iter().map(|share| share.index).collect(),
+        succeeded,
+    });
+}
+
+pub fn split_bytes(
+    secret: &[u8],
+    threshold: usize,
+    num_shares: usize,
+    rng: &mut (impl RngCore + CryptoRng),
+    sink: &mut impl AuditSink,
+) -> Result<Vec<Share<Vec<u8>>>, ShamirError> {
+    let shares = sss::split_bytes(secret, threshold, num_shares, rng)?;
+    issued(&shares, sink);
+    Ok(shares)
+}
+
+pub fn combine_bytes(shares: &[Share<Vec<u8>>], sink: &mut impl AuditSink) -> Result<Zeroizing<Vec<u8>>, ShamirError> {
+    let result = sss::combine_bytes(shares);
+    attempted(shares, result.is_ok(), sink);
+    result
+}
+
+// Deals a Feldman VSS sharing, returning the shares and the commitments to publish.
+pub fn deal(
+    secret: i128,
+    config: &VssConfig,
+    rng: &mut (impl RngCore + CryptoRng),
+    sink: &mut impl AuditSink,
+) -> (Vec<Share<i128>>, Vec<i128>) {
+    let coeffs = vss::generate_polynomial(secret, config, rng);
+    let shares = vss::generate_shares(&coeffs, config, rng);
+    issued(&shares, sink);
+    (shares, vss::generate_commitments(&coeffs, config))
+}
+
+// Checks every share, recording each outcome; fails on the first bad one as `vss::verify_shares`.
+pub fn verify_shares(
+    shares: &[Share<i128>],
+    commitments: &[i128],
+    config: &VssConfig,
+    sink: &mut impl AuditSink,
+) -> Result<(), VssError> {
+    let mut failed = None;
+    for share in shares {
+        let (scheme_id, index) = (share.scheme_id, share.index);
+        if vss::verify_share(share, commitments, config) {
+            sink.record(AuditEvent::ShareVerified { scheme_id, index });
+        } else {
+            sink.record(AuditEvent::VerificationFailed { scheme_id, index });
+            failed.get_or_insert(index);
+        }
+    }
+    match failed {
+        Some(index) => Err(VssError::VerificationFailed { index }),
+        None => Ok(()),
+    }
+}
+
+pub fn reconstruct_verified(
+    shares: &[Share<i128>],
+    commitments: &[i128],
+    config: &VssConfig,
+    sink: &mut impl AuditSink,
+) -> Result<VerifiedReconstruction, VssError> {
+    let result = vss::reconstruct_verified(shares, commitments, config);
+    if let Ok(reconstruction) = &result {
+        for share in shares {
+            let (scheme_id, index) = (share.scheme_id, share.index);
+            if reconstruction.rejected.iter().any(|rejection| rejection.index == index) {
+                sink.record(AuditEvent::VerificationFailed { scheme_id, index });
+            } else {
+                sink.record(AuditEvent::ShareVerified { scheme_id, index });
+            }
+        }
+    }
+    let succeeded = result.as_ref().is_ok_and(|reconstruction| reconstruction.secret.is_some());
+    attempted(shares, succeeded, sink);
+    result
+}
@@ -0,0 +1,88 @@
+use alloc::vec::Vec;
+
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use zeroize::Zeroize;
+
+use super::share::{Share, ShareValue};
+use super::sss::ShamirError;
+use super::wire::{self, WireValue};
+
+// Dealer-keyed share authentication. The dealer derives a per-dealing HMAC-SHA256 key from a
+// secret of its own and tags the wire encoding of every share. The key is published to
+// combiners (for VSS, inside the `CommitmentBundle`) but not to shareholders: anyone holding it
+// can mint tags, so it only protects shares in transit or at rest with untrusted holders.
+
+pub const TAG_LEN: usize = 32;
+const DOMAIN: &[u8] = b"shamir/share-mac/v1";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct VerificationKey {
+    pub scheme_id: u32,
+    pub(crate) key: [u8; 32],
+}
+
+impl Drop for VerificationKey {
+    fn drop(&mut self) {
+        self.key.zeroize();
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TaggedShare<V: ShareValue = u64> {
+    pub share: Share<V>,
+    pub tag: [u8; TAG_LEN],
+}
+
+fn hmac(key: &[u8]) -> Hmac<Sha256> {
+    Hmac::<Sha256>::new_from_slice(key).expect("HMAC accepts any key length")
+}
+
+pub fn derive_key(dealer_secret: &[u8], scheme_id: u32) -> VerificationKey {
+    let mut mac = hmac(dealer_secret);
+    mac.update(DOMAIN);
+    mac.update(&scheme_id.to_be_bytes());
+    VerificationKey { scheme_id, key: mac.finalize().into_bytes().into() }
+}
+
+pub fn tag<V: WireValue>(share: &Share<V>, key: &VerificationKey) -> [u8; TAG_LEN] {
+    let mut mac = hmac(&key.key);
+    mac.update(&wire::encode(share));
+    mac.finalize().into_bytes().into()
+}
+
+pub fn verify<V: WireValue>(tagged: &TaggedShare<V>, key: &VerificationKey) -> bool {
+    if tagged.share.scheme_id != key.scheme_id {
+        return false;
+    }
+    let mut mac = hmac(&key.key);
+    mac.update(&wire::encode(&tagged.share));
+    mac.verify_slice(&tagged.tag).is_ok()
+}
+
+// Tags one dealing; every share must carry the same scheme id.
+pub fn tag_shares<V: WireValue>(
+    shares: Vec<Share<V>>,
+    dealer_secret: &[u8],
+) -> Result<(Vec<TaggedShare<V>>, VerificationKey), ShamirError> {
+    let scheme_id = shares.first().ok_or(ShamirError::InsufficientShares { provided: 0, required: 1 })?.scheme_id;
+    if shares.iter().any(|share| share.scheme_id != scheme_id) {
+        return Err(ShamirError::InconsistentShares);
+    }
+    let key = derive_key(dealer_secret, scheme_id);
+    let tagged = shares
+        .into_iter()
+        .map(|share| {
+            let tag = tag(&share, &key);
+            TaggedShare { share, tag }
+        })
+        .collect();
+    Ok((tagged, key))
+}
+
+// The shares whose tags verify, ready for the usual reconstruction; forgeries are dropped.
+pub fn authentic_shares<V: WireValue + Clone>(tagged: &[TaggedShare<V>], key: &VerificationKey) -> Vec<Share<V>> {
+    tagged.iter().filter(|tagged| verify(tagged, key)).map(|tagged| tagged.share.clone()).collect()
+}
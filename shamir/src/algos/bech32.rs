@@ -0,0 +1,145 @@
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use super::share::Share;
+use super::wire::{self, DecodeError, WireValue};
+
+// Bech32m (BIP-350) over the binary wire encoding of a share.
+const CHARSET: &[u8; 32] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+const BECH32M_CONST: u32 = 0x2bc830a3;
+// The original BIP-173 checksum, for formats that predate bech32m (age keys).
+#[cfg(feature = "age")]
+pub(crate) const BECH32_CONST: u32 = 1;
+const GENERATOR: [u32; 5] = [0x3b6a57b2, 0x26508e6d, 0x1ea119fa, 0x3d4233dd, 0x2a1462b3];
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Bech32Error {
+    InvalidHrp,
+    HrpMismatch,
+    MissingSeparator,
+    MixedCase,
+    InvalidChar,
+    InvalidChecksum,
+    InvalidPadding,
+    Wire(DecodeError),
+}
+
+impl From<DecodeError> for Bech32Error {
+    fn from(err: DecodeError) -> Self {
+        Bech32Error::Wire(err)
+    }
+}
+
+fn polymod(values: impl Iterator<Item = u8>) -> u32 {
+    let mut chk: u32 = 1;
+    for value in values {
+        let top = chk >> 25;
+        chk = ((chk & 0x1ffffff) << 5) ^ value as u32;
+        for (i, &g) in GENERATOR.iter().enumerate() {
+            if (top >> i) & 1 == 1 {
+                chk ^= g;
+            }
+        }
+    }
+    chk
+}
+
+fn hrp_expand(hrp: &str) -> impl Iterator<Item = u8> + '_ {
+    hrp.bytes().map(|b| b >> 5).chain([0]).chain(hrp.bytes().map(|b| b & 31))
+}
+
+fn checksum(hrp: &str, data: &[u8], constant: u32) -> [u8; 6] {
+    let pm = polymod(hrp_expand(hrp).chain(data.iter().copied()).chain([0; 6])) ^ constant;
+    let mut out = [0; 6];
+    for (i, c) in out.iter_mut().enumerate() {
+        *c = ((pm >> (5 * (5 - i))) & 31) as u8;
+    }
+    out
+}
+
+fn check_hrp(hrp: &str) -> Result<(), Bech32Error> {
+    if hrp.is_empty() || hrp.len() > 83 || !hrp.bytes().all(|b| (33..=126).contains(&b)) {
+        return Err(Bech32Error::InvalidHrp);
+    }
+    Ok(())
+}
+
+fn convert_bits(data: &[u8], from: u32, to: u32, pad: bool) -> Result<Vec<u8>, Bech32Error> {
+    let mut acc: u32 = 0;
+    let mut bits = 0;
+    let max = (1 << to) - 1;
+    let mut out = Vec::with_capacity(data.len() * from as usize / to as usize + 1);
+    for &value in data {
+        acc = (acc << from) | value as u32;
+        bits += from;
+        while bits >= to {
+            bits -= to;
+            out.push(((acc >> bits) & max) as u8);
+        }
+    }
+    if pad {
+        if bits > 0 {
+            out.push(((acc << (to - bits)) & max) as u8);
+        }
+    } else if bits >= from || (acc << (to - bits)) & max != 0 {
+        return Err(Bech32Error::InvalidPadding);
+    }
+    Ok(out)
+}
+
+pub fn encode(hrp: &str, payload: &[u8]) -> Result<String, Bech32Error> {
+    encode_with(hrp, payload, BECH32M_CONST)
+}
+
+pub(crate) fn encode_with(hrp: &str, payload: &[u8], constant: u32) -> Result<String, Bech32Error> {
+    check_hrp(hrp)?;
+    let hrp = hrp.to_ascii_lowercase();
+    let data = convert_bits(payload, 8, 5, true)?;
+    let mut out = String::with_capacity(hrp.len() + 1 + data.len() + 6);
+    out.push_str(&hrp);
+    out.push('1');
+    for &d in data.iter().chain(checksum(&hrp, &data, constant).iter()) {
+        out.push(CHARSET[d as usize] as char);
+    }
+    Ok(out)
+}
+
+// Returns the (lowercased) human-readable part and the decoded payload.
+pub fn decode(s: &str) -> Result<(String, Vec<u8>), Bech32Error> {
+    decode_with(s, BECH32M_CONST)
+}
+
+pub(crate) fn decode_with(s: &str, constant: u32) -> Result<(String, Vec<u8>), Bech32Error> {
+    if s.bytes().any(|b| b.is_ascii_lowercase()) && s.bytes().any(|b| b.is_ascii_uppercase()) {
+        return Err(Bech32Error::MixedCase);
+    }
+    let s = s.to_ascii_lowercase();
+    let (hrp, data) = s.rsplit_once('1').ok_or(Bech32Error::MissingSeparator)?;
+    check_hrp(hrp)?;
+    if data.len() < 6 {
+        return Err(Bech32Error::InvalidChecksum);
+    }
+    let data = data
+        .bytes()
+        .map(|c| CHARSET.iter().position(|&x| x == c).map(|p| p as u8).ok_or(Bech32Error::InvalidChar))
+        .collect::<Result<Vec<u8>, _>>()?;
+    if polymod(hrp_expand(hrp).chain(data.iter().copied())) != constant {
+        return Err(Bech32Error::InvalidChecksum);
+    }
+    let payload = convert_bits(&data[..data.len() - 6], 5, 8, false)?;
+    Ok((hrp.to_string(), payload))
+}
+
+impl<V: WireValue> Share<V> {
+    pub fn to_bech32(&self, hrp: &str) -> Result<String, Bech32Error> {
+        encode(hrp, &wire::encode(self))
+    }
+
+    pub fn from_bech32(s: &str, hrp: &str) -> Result<Self, Bech32Error> {
+        let (found, payload) = decode(s.trim())?;
+        if found != hrp.to_ascii_lowercase() {
+            return Err(Bech32Error::HrpMismatch);
+        }
+        Ok(wire::decode(&payload)?)
+    }
+}
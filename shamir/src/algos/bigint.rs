@@ -0,0 +1,203 @@
+use alloc::vec::Vec;
+
+use num_bigint::{BigUint, RandBigInt};
+use num_traits::{One, Zero};
+use rand::{CryptoRng, Rng, RngCore};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use sha2::{Digest, Sha256};
+
+use super::field::FiniteField;
+use super::montgomery::Montgomery;
+use super::polynomial;
+use super::share::{self, Share, ShareValue};
+use super::sss::{self, ShamirError};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct BigPrimeField {
+    modulus: BigUint,
+    // For dealing and reconstruction below; single operations, `FiniteField`'s included, stay plain
+    // `%`, since they would spend more on converting than they save.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    montgomery: Montgomery,
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for BigPrimeField {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(serde::Deserialize)]
+        struct Params {
+            modulus: BigUint,
+        }
+
+        let params = Params::deserialize(deserializer)?;
+        BigPrimeField::new(params.modulus).map_err(|_| serde::de::Error::custom("invalid modulus"))
+    }
+}
+
+const MILLER_RABIN_ROUNDS: u32 = 40;
+
+// Miller–Rabin with bases drawn from SHA-256 of the candidate, so the check needs no RNG and
+// gives the same answer everywhere; a composite passes with probability at most 4^-rounds.
+pub fn is_probable_prime(n: &BigUint, rounds: u32) -> bool {
+    let two = BigUint::from(2u8);
+    if n < &two {
+        return false;
+    }
+    for p in [2u8, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37] {
+        if (n % p).is_zero() {
+            return n == &BigUint::from(p);
+        }
+    }
+    let n_minus_one = n - 1u8;
+    let shift = n_minus_one.trailing_zeros().unwrap_or(0);
+    let d = &n_minus_one >> shift;
+    let digest = Sha256::digest(n.to_bytes_be());
+    'witness: for round in 0..rounds {
+        let mut seed = Sha256::new();
+        seed.update(digest);
+        seed.update(round.to_be_bytes());
+        // A base in [2, n - 2].
+        let base = BigUint::from_bytes_be(&seed.finalize()) % (n - 3u8) + 2u8;
+        let mut x = base.modpow(&d, n);
+        if x.is_one() || x == n_minus_one {
+            continue;
+        }
+        for _ in 1..shift {
+            x = &x * &x % n;
+            if x == n_minus_one {
+                continue 'witness;
+            }
+        }
+        return false;
+    }
+    true
+}
+
+impl BigPrimeField {
+    // The modulus must pass `is_probable_prime`; inversion and interpolation depend on it.
+    pub fn new(modulus: BigUint) -> Result<Self, ShamirError> {
+        if modulus < BigUint::from(3u8) || !is_probable_prime(&modulus, MILLER_RABIN_ROUNDS) {
+            return Err(ShamirError::InvalidModulus);
+        }
+        Ok(Self::from_prime(modulus))
+    }
+
+    // For moduli known to be prime, skipping the primality test.
+    pub(crate) fn from_prime(modulus: BigUint) -> Self {
+        let montgomery = Montgomery::new(&modulus).expect("Odd primes have a Montgomery form");
+        BigPrimeField { modulus, montgomery }
+    }
+
+    // 2^521 - 1, large enough for any 512-bit secret.
+    pub fn mersenne_521() -> Self {
+        Self::from_prime((BigUint::one() << 521u32) - 1u8)
+    }
+
+    pub fn modulus(&self) -> &BigUint {
+        &self.modulus
+    }
+}
+
+impl FiniteField for BigPrimeField {
+    type Element = BigUint;
+
+    fn zero(&self) -> BigUint {
+        BigUint::zero()
+    }
+
+    fn one(&self) -> BigUint {
+        BigUint::one()
+    }
+
+    fn element(&self, x: u64) -> BigUint {
+        BigUint::from(x) % &self.modulus
+    }
+
+    fn add(&self, a: &BigUint, b: &BigUint) -> BigUint {
+        (a + b) % &self.modulus
+    }
+
+    fn sub(&self, a: &BigUint, b: &BigUint) -> BigUint {
+        (a % &self.modulus + &self.modulus - b % &self.modulus) % &self.modulus
+    }
+
+    fn mul(&self, a: &BigUint, b: &BigUint) -> BigUint {
+        a * b % &self.modulus
+    }
+
+    fn inv(&self, a: &BigUint) -> Option<BigUint> {
+        a.modinv(&self.modulus)
+    }
+
+    fn random(&self, rng: &mut (impl RngCore + CryptoRng)) -> BigUint {
+        rng.gen_biguint_below(&self.modulus)
+    }
+
+    fn pow(&self, base: &BigUint, exp: u64) -> BigUint {
+        base.modpow(&BigUint::from(exp), &self.modulus)
+    }
+}
+
+pub fn generate_polynomial(
+    secret: &BigUint,
+    threshold: usize,
+    field: &BigPrimeField,
+    rng: &mut (impl RngCore + CryptoRng),
+) -> Result<Vec<BigUint>, ShamirError> {
+    if threshold < 2 {
+        return Err(ShamirError::InvalidThreshold);
+    }
+    if secret >= &field.modulus {
+        return Err(ShamirError::SecretOutOfRange);
+    }
+
+    Ok(polynomial::generate(field, secret.clone(), threshold, rng))
+}
+
+pub fn evaluate_polynomial(coeffs: &[BigUint], x: &BigUint, field: &BigPrimeField) -> BigUint {
+    polynomial::evaluate(field, coeffs, x)
+}
+
+pub fn generate_shares(
+    secret: &BigUint,
+    threshold: usize,
+    num_shares: usize,
+    field: &BigPrimeField,
+    rng: &mut (impl RngCore + CryptoRng),
+) -> Result<Vec<Share<BigUint>>, ShamirError> {
+    if num_shares < threshold || BigUint::from(num_shares) >= field.modulus {
+        return Err(ShamirError::InvalidShareCount);
+    }
+
+    let mut coeffs = generate_polynomial(secret, threshold, field, rng)?;
+    let residues = field.montgomery.to_montgomery_all(&coeffs);
+    let scheme_id = rng.r#gen();
+    let xs = 1..=num_shares as u64;
+    #[cfg(feature = "parallel")]
+    let xs = xs.into_par_iter();
+    let shares = xs
+        .map(|x| {
+            let value = field.montgomery.evaluate(&residues, &BigUint::from(x));
+            Share::new(x, value, threshold, scheme_id)
+        })
+        .collect();
+    coeffs.iter_mut().for_each(ShareValue::wipe);
+    Ok(share::dealt(shares, rng))
+}
+
+pub fn reconstruct_secret(shares: &[Share<BigUint>], field: &BigPrimeField) -> Result<BigUint, ShamirError> {
+    let threshold = sss::quorum(shares)?;
+    if !share::check_same_dealing(shares) {
+        return Err(ShamirError::InconsistentShares);
+    }
+    sss::check_field_indices(field, shares)?;
+    sss::check_consistent(field, shares, threshold)?;
+
+    let points: Vec<(BigUint, BigUint)> =
+        shares[..threshold].iter().map(|share| (BigUint::from(share.index), share.value.clone())).collect();
+    let secret = field.montgomery.interpolate_at_zero(&points).ok_or(ShamirError::InconsistentShares);
+    points.into_iter().for_each(|(_, mut y)| y.wipe());
+    secret
+}
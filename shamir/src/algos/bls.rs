@@ -0,0 +1,144 @@
+use alloc::{vec, vec::Vec};
+
+use bls12_381::hash_to_curve::{ExpandMsgXmd, HashToCurve};
+use bls12_381::{G1Affine, G1Projective, G2Affine, G2Projective, Scalar, pairing};
+use rand::{CryptoRng, RngCore};
+
+use super::bls12::{self, Bls12};
+use super::group::{self, Group, ScalarField};
+use super::polynomial;
+use super::share::Share;
+use super::vss::VssError;
+
+// Threshold BLS signatures, minimal-public-key variant: keys and commitments in G1 as `bls12`
+// deals them, signatures in G2, messages hashed to G2 with the RFC 9380 suite named by DST. Each
+// share holder signs alone; any t partial signatures interpolate in the exponent to the signature
+// the group key would have made, which verifies with a single pairing check.
+pub const DST: &[u8] = b"BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_NUL_";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PartialSignature {
+    pub index: u64,
+    pub signature: G2Projective,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DkgOutput {
+    pub share: Share<Scalar>,
+    pub public_key: G1Projective,
+    pub commitments: Vec<G1Projective>,
+}
+
+pub fn hash_to_g2(message: &[u8]) -> G2Projective {
+    <G2Projective as HashToCurve<ExpandMsgXmd<sha2_09::Sha256>>>::hash_to_curve(message, DST)
+}
+
+pub fn public_key(secret: &Scalar) -> G1Projective {
+    G1Projective::generator() * secret
+}
+
+pub fn sign(secret: &Scalar, message: &[u8]) -> G2Projective {
+    hash_to_g2(message) * secret
+}
+
+// e(pk, H(m)) == e(G1, sig).
+pub fn verify(public_key: &G1Projective, message: &[u8], signature: &G2Projective) -> bool {
+    pairing(&G1Affine::from(public_key), &G2Affine::from(hash_to_g2(message)))
+        == pairing(&G1Affine::generator(), &G2Affine::from(signature))
+}
+
+pub fn sign_share(share: &Share<Scalar>, message: &[u8]) -> PartialSignature {
+    PartialSignature { index: share.index, signature: sign(&share.value, message) }
+}
+
+// Checks a partial against the share's public key, read off the dealing's commitments.
+pub fn verify_partial(partial: &PartialSignature, message: &[u8], commitments: &[G1Projective]) -> bool {
+    partial.index != 0
+        && verify(&group::commitment_at(&Bls12, partial.index, commitments), message, &partial.signature)
+}
+
+// The group signature from the first `threshold` partials.
+pub fn combine(partials: &[PartialSignature], threshold: usize) -> Result<G2Projective, VssError> {
+    if partials.len() < threshold {
+        return Err(VssError::InsufficientShares { provided: partials.len(), required: threshold });
+    }
+    let partials = &partials[..threshold];
+    if partials.iter().any(|partial| partial.index == 0) {
+        return Err(VssError::ZeroIndex);
+    }
+    let xs: Vec<Scalar> = partials.iter().map(|partial| Scalar::from(partial.index)).collect();
+    let field = ScalarField::<Bls12>::new();
+    let basis = polynomial::lagrange_basis_at_zero(&field, &xs).ok_or(VssError::DuplicateIndex)?;
+    let mut signature = G2Projective::identity();
+    for (partial, lambda) in partials.iter().zip(basis) {
+        signature += partial.signature * lambda;
+    }
+    Ok(signature)
+}
+
+// Like `combine`, but checks every partial first and names the first one that fails.
+pub fn combine_verified(
+    partials: &[PartialSignature],
+    message: &[u8],
+    commitments: &[G1Projective],
+) -> Result<G2Projective, VssError> {
+    if let Some(bad) = partials.iter().find(|partial| !verify_partial(partial, message, commitments)) {
+        return Err(VssError::VerificationFailed { index: bad.index });
+    }
+    combine(partials, commitments.len())
+}
+
+pub fn compress_signature(signature: &G2Projective) -> [u8; 96] {
+    G2Affine::from(signature).to_compressed()
+}
+
+pub fn decompress_signature(bytes: &[u8; 96]) -> Option<G2Projective> {
+    Option::<G2Affine>::from(G2Affine::from_compressed(bytes)).map(G2Projective::from)
+}
+
+// Every party derives the same scheme id from the joint commitments (FNV-1a).
+fn dealing_id(commitments: &[G1Projective]) -> u32 {
+    let mut hash: u32 = 0x811c9dc5;
+    for c in commitments {
+        for byte in bls12::compress(c) {
+            hash = (hash ^ byte as u32).wrapping_mul(0x01000193);
+        }
+    }
+    hash
+}
+
+// A joint-Feldman DKG in G1, run for all parties at once as `dkg::run_dkg` does: every party
+// deals a random polynomial, checks the shares it receives, and keeps their sum. No one learns
+// the group secret; the public key is the sum of the dealers' C_0.
+pub fn run_dkg(
+    threshold: usize,
+    num_parties: usize,
+    rng: &mut (impl RngCore + CryptoRng),
+) -> Result<Vec<DkgOutput>, VssError> {
+    let mut values = vec![Scalar::zero(); num_parties];
+    let mut commitments = vec![G1Projective::identity(); threshold];
+    for _ in 0..num_parties {
+        let coeffs = bls12::generate_polynomial(Bls12::random_scalar(rng), threshold, rng)?;
+        let dealer_commitments = bls12::generate_commitments(&coeffs);
+        for (value, share) in values.iter_mut().zip(bls12::generate_shares(&coeffs, num_parties, rng)?) {
+            if !bls12::verify_share(&share, &dealer_commitments) {
+                return Err(VssError::VerificationFailed { index: share.index });
+            }
+            *value += share.value;
+        }
+        for (c, d) in commitments.iter_mut().zip(&dealer_commitments) {
+            *c += d;
+        }
+    }
+
+    let scheme_id = dealing_id(&commitments);
+    Ok(values
+        .into_iter()
+        .enumerate()
+        .map(|(i, value)| DkgOutput {
+            share: Share::new(i as u64 + 1, value, threshold, scheme_id),
+            public_key: commitments[0],
+            commitments: commitments.clone(),
+        })
+        .collect())
+}
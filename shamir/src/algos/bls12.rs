@@ -0,0 +1,125 @@
+use alloc::vec::Vec;
+use core::fmt;
+
+use bls12_381::{G1Affine, G1Projective, Scalar};
+use rand::{CryptoRng, Rng, RngCore};
+use zeroize::Zeroizing;
+
+use super::group::{self, Coefficients, Group};
+use super::share::{Share, ShareValue};
+use super::vss::VssError;
+use super::wire::WireValue;
+
+// Feldman VSS with commitments in G1 of BLS12-381 and shares in its scalar field, so a dealing
+// can feed threshold BLS signing and pairing-based verifiers. Scalars use the curve crate's
+// 32-byte little-endian encoding; commitments use the standard 48-byte compressed G1 form.
+
+impl ShareValue for Scalar {
+    fn fmt_value(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for byte in self.to_bytes() {
+            write!(f, "{:02x}", byte)?;
+        }
+        Ok(())
+    }
+
+    fn parse_value(s: &str) -> Option<Self> {
+        let bytes: [u8; 32] = Vec::<u8>::parse_value(s)?.try_into().ok()?;
+        Option::from(Scalar::from_bytes(&bytes))
+    }
+
+    // bls12_381 has no Zeroize impl; overwrite the limbs with zero instead.
+    fn wipe(&mut self) {
+        *self = Scalar::zero();
+    }
+}
+
+impl WireValue for Scalar {
+    const FIELD_ID: u8 = 6;
+
+    fn to_payload(&self) -> Vec<u8> {
+        self.to_bytes().to_vec()
+    }
+
+    fn from_payload(payload: &[u8]) -> Option<Self> {
+        Option::from(Scalar::from_bytes(payload.try_into().ok()?))
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Bls12;
+
+impl Group for Bls12 {
+    type Scalar = Scalar;
+    type Point = G1Projective;
+
+    fn scalar_from_u64(x: u64) -> Scalar {
+        Scalar::from(x)
+    }
+
+    fn random_scalar(rng: &mut (impl RngCore + CryptoRng)) -> Scalar {
+        let mut wide = Zeroizing::new([0u8; 64]);
+        rng.fill(&mut wide[..]);
+        Scalar::from_bytes_wide(&wide)
+    }
+
+    fn invert(scalar: &Scalar) -> Option<Scalar> {
+        Option::from(scalar.invert())
+    }
+
+    fn generator() -> G1Projective {
+        G1Projective::generator()
+    }
+
+    fn identity() -> G1Projective {
+        G1Projective::identity()
+    }
+
+    fn encode_point(point: &G1Projective) -> Vec<u8> {
+        compress(point).to_vec()
+    }
+
+    fn scalar_from_wide(bytes: &[u8; 64]) -> Scalar {
+        Scalar::from_bytes_wide(bytes)
+    }
+
+    fn is_valid_point(point: &G1Projective) -> bool {
+        let affine = G1Affine::from(point);
+        !bool::from(affine.is_identity()) && bool::from(affine.is_on_curve() & affine.is_torsion_free())
+    }
+}
+
+pub fn compress(commitment: &G1Projective) -> [u8; 48] {
+    G1Affine::from(commitment).to_compressed()
+}
+
+pub fn decompress(bytes: &[u8; 48]) -> Option<G1Projective> {
+    Option::<G1Affine>::from(G1Affine::from_compressed(bytes)).map(G1Projective::from)
+}
+
+pub fn generate_polynomial(
+    secret: Scalar,
+    threshold: usize,
+    rng: &mut (impl RngCore + CryptoRng),
+) -> Result<Coefficients<Scalar>, VssError> {
+    group::generate_polynomial::<Bls12>(secret, threshold, rng)
+}
+
+pub fn generate_shares(
+    coeffs: &[Scalar],
+    num_shares: usize,
+    rng: &mut (impl RngCore + CryptoRng),
+) -> Result<Vec<Share<Scalar>>, VssError> {
+    group::generate_shares::<Bls12>(coeffs, num_shares, rng)
+}
+
+pub fn generate_commitments(coeffs: &[Scalar]) -> Vec<G1Projective> {
+    group::commit_polynomial(&Bls12, coeffs)
+}
+
+pub fn verify_share(share: &Share<Scalar>, commitments: &[G1Projective]) -> bool {
+    group::verify_commitment(&Bls12, share, commitments)
+}
+
+pub fn reconstruct_secret(shares: &[Share<Scalar>]) -> Result<Scalar, VssError> {
+    group::reconstruct_secret::<Bls12>(shares)
+}
@@ -0,0 +1,313 @@
+use alloc::vec::Vec;
+
+use num_bigint::BigUint;
+
+use super::auth::VerificationKey;
+use super::share::Share;
+use super::vss::CommitmentBundle;
+use super::wire::{DecodeError, WireValue};
+
+// A compact CBOR profile (RFC 8949) for shares and commitment bundles, for transports where JSON
+// is too large. Only deterministic encoding is written or accepted: definite lengths, shortest
+// integer heads, integers outside 64 bits as bignums (tags 2 and 3), arrays rather than maps.
+// Each item is wrapped in a tag from the first-come-first-served range (not IANA-registered):
+//   share:  SHARE_TAG([field id, scheme id, epoch, level, threshold, total, fingerprint, index, value])
+//           (early encoders left out the total and then the fingerprint; seven- and eight-element
+//           shares decode with those fields 0)
+//   bundle: BUNDLE_TAG([[commitment, ...], null / [scheme id, key bytes]])
+pub const SHARE_TAG: u64 = 0x5348_4d52;
+pub const BUNDLE_TAG: u64 = 0x5348_4d43;
+
+const UNSIGNED: u8 = 0;
+const NEGATIVE: u8 = 1;
+const BYTES: u8 = 2;
+const ARRAY: u8 = 4;
+const TAG: u8 = 6;
+const NULL: u8 = 0xf6;
+const POSITIVE_BIGNUM: u64 = 2;
+const NEGATIVE_BIGNUM: u64 = 3;
+
+fn head(out: &mut Vec<u8>, major: u8, n: u64) {
+    let major = major << 5;
+    match n {
+        0..=23 => out.push(major | n as u8),
+        24..=0xff => out.extend_from_slice(&[major | 24, n as u8]),
+        0x100..=0xffff => {
+            out.push(major | 25);
+            out.extend_from_slice(&(n as u16).to_be_bytes());
+        }
+        0x1_0000..=0xffff_ffff => {
+            out.push(major | 26);
+            out.extend_from_slice(&(n as u32).to_be_bytes());
+        }
+        _ => {
+            out.push(major | 27);
+            out.extend_from_slice(&n.to_be_bytes());
+        }
+    }
+}
+
+fn bytes(out: &mut Vec<u8>, value: &[u8]) {
+    head(out, BYTES, value.len() as u64);
+    out.extend_from_slice(value);
+}
+
+fn bignum(out: &mut Vec<u8>, tag: u64, magnitude: &[u8]) {
+    let start = magnitude.iter().position(|&b| b != 0).unwrap_or(magnitude.len());
+    head(out, TAG, tag);
+    bytes(out, &magnitude[start..]);
+}
+
+pub struct Reader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, n: usize) -> Result<&'a [u8], DecodeError> {
+        if self.bytes.len() < n {
+            return Err(DecodeError::Truncated);
+        }
+        let (head, tail) = self.bytes.split_at(n);
+        self.bytes = tail;
+        Ok(head)
+    }
+
+    fn peek(&self) -> Result<u8, DecodeError> {
+        self.bytes.first().copied().ok_or(DecodeError::Truncated)
+    }
+
+    // A head in shortest form; indefinite lengths and longer-than-needed heads are rejected.
+    fn head(&mut self) -> Result<(u8, u64), DecodeError> {
+        let initial = self.take(1)?[0];
+        let (major, info) = (initial >> 5, initial & 0x1f);
+        let (n, min) = match info {
+            0..=23 => return Ok((major, info as u64)),
+            24 => (self.take(1)?[0] as u64, 24),
+            25 => (u16::from_be_bytes(self.take(2)?.try_into().map_err(|_| DecodeError::Truncated)?) as u64, 0x100),
+            26 => (u32::from_be_bytes(self.take(4)?.try_into().map_err(|_| DecodeError::Truncated)?) as u64, 0x1_0000),
+            27 => (u64::from_be_bytes(self.take(8)?.try_into().map_err(|_| DecodeError::Truncated)?), 0x1_0000_0000),
+            _ => return Err(DecodeError::Malformed),
+        };
+        if n < min {
+            return Err(DecodeError::Malformed);
+        }
+        Ok((major, n))
+    }
+
+    fn expect(&mut self, major: u8) -> Result<u64, DecodeError> {
+        match self.head()? {
+            (found, n) if found == major => Ok(n),
+            _ => Err(DecodeError::Malformed),
+        }
+    }
+
+    fn uint(&mut self) -> Result<u64, DecodeError> {
+        self.expect(UNSIGNED)
+    }
+
+    fn u32(&mut self) -> Result<u32, DecodeError> {
+        u32::try_from(self.uint()?).map_err(|_| DecodeError::Malformed)
+    }
+
+    fn bytes(&mut self) -> Result<&'a [u8], DecodeError> {
+        let len = self.expect(BYTES)?;
+        self.take(usize::try_from(len).map_err(|_| DecodeError::Truncated)?)
+    }
+
+    fn array(&mut self, len: u64) -> Result<(), DecodeError> {
+        if self.expect(ARRAY)? != len {
+            return Err(DecodeError::Malformed);
+        }
+        Ok(())
+    }
+
+    fn tag(&mut self, tag: u64) -> Result<(), DecodeError> {
+        if self.expect(TAG)? != tag {
+            return Err(DecodeError::Malformed);
+        }
+        Ok(())
+    }
+
+    // Bignum magnitude, which must need more than 64 bits and carry no leading zeros.
+    fn bignum(&mut self) -> Result<&'a [u8], DecodeError> {
+        let magnitude = self.bytes()?;
+        if magnitude.len() <= 8 || magnitude[0] == 0 {
+            return Err(DecodeError::Malformed);
+        }
+        Ok(magnitude)
+    }
+
+    fn finish(&self) -> Result<(), DecodeError> {
+        if !self.bytes.is_empty() {
+            return Err(DecodeError::Malformed);
+        }
+        Ok(())
+    }
+}
+
+pub trait CborValue: WireValue {
+    fn encode_value(&self, out: &mut Vec<u8>);
+    fn decode_value(reader: &mut Reader<'_>) -> Result<Self, DecodeError>;
+}
+
+impl CborValue for u64 {
+    fn encode_value(&self, out: &mut Vec<u8>) {
+        head(out, UNSIGNED, *self);
+    }
+
+    fn decode_value(reader: &mut Reader<'_>) -> Result<Self, DecodeError> {
+        reader.uint()
+    }
+}
+
+impl CborValue for Vec<u8> {
+    fn encode_value(&self, out: &mut Vec<u8>) {
+        bytes(out, self);
+    }
+
+    fn decode_value(reader: &mut Reader<'_>) -> Result<Self, DecodeError> {
+        Ok(reader.bytes()?.to_vec())
+    }
+}
+
+impl CborValue for i128 {
+    fn encode_value(&self, out: &mut Vec<u8>) {
+        // CBOR stores a negative n as -1 - n.
+        let (major, magnitude) = if *self < 0 { (NEGATIVE, (-1 - *self) as u128) } else { (UNSIGNED, *self as u128) };
+        match u64::try_from(magnitude) {
+            Ok(n) => head(out, major, n),
+            Err(_) => {
+                let tag = if major == NEGATIVE { NEGATIVE_BIGNUM } else { POSITIVE_BIGNUM };
+                bignum(out, tag, &magnitude.to_be_bytes());
+            }
+        }
+    }
+
+    fn decode_value(reader: &mut Reader<'_>) -> Result<Self, DecodeError> {
+        let (major, n) = reader.head()?;
+        let (negative, magnitude) = match (major, n) {
+            (UNSIGNED, n) => (false, n as u128),
+            (NEGATIVE, n) => (true, n as u128),
+            (TAG, POSITIVE_BIGNUM | NEGATIVE_BIGNUM) => {
+                let magnitude = reader.bignum()?;
+                if magnitude.len() > 16 {
+                    return Err(DecodeError::Malformed);
+                }
+                let mut buf = [0u8; 16];
+                buf[16 - magnitude.len()..].copy_from_slice(magnitude);
+                (n == NEGATIVE_BIGNUM, u128::from_be_bytes(buf))
+            }
+            _ => return Err(DecodeError::Malformed),
+        };
+        let magnitude = i128::try_from(magnitude).map_err(|_| DecodeError::Malformed)?;
+        Ok(if negative { -1 - magnitude } else { magnitude })
+    }
+}
+
+impl CborValue for BigUint {
+    fn encode_value(&self, out: &mut Vec<u8>) {
+        match u64::try_from(self) {
+            Ok(n) => head(out, UNSIGNED, n),
+            Err(_) => bignum(out, POSITIVE_BIGNUM, &self.to_bytes_be()),
+        }
+    }
+
+    fn decode_value(reader: &mut Reader<'_>) -> Result<Self, DecodeError> {
+        match reader.head()? {
+            (UNSIGNED, n) => Ok(BigUint::from(n)),
+            (TAG, POSITIVE_BIGNUM) => Ok(BigUint::from_bytes_be(reader.bignum()?)),
+            _ => Err(DecodeError::Malformed),
+        }
+    }
+}
+
+pub fn encode_share<V: CborValue>(share: &Share<V>) -> Vec<u8> {
+    let mut out = Vec::new();
+    head(&mut out, TAG, SHARE_TAG);
+    head(&mut out, ARRAY, 9);
+    let header = [
+        V::FIELD_ID as u64,
+        share.scheme_id as u64,
+        share.epoch as u64,
+        share.level as u64,
+        share.threshold as u64,
+        share.total as u64,
+        share.fingerprint,
+        share.index,
+    ];
+    for n in header {
+        head(&mut out, UNSIGNED, n);
+    }
+    share.value.encode_value(&mut out);
+    out
+}
+
+pub fn decode_share<V: CborValue>(bytes: &[u8]) -> Result<Share<V>, DecodeError> {
+    let mut reader = Reader { bytes };
+    reader.tag(SHARE_TAG)?;
+    let len = reader.expect(ARRAY)?;
+    if !(7..=9).contains(&len) {
+        return Err(DecodeError::Malformed);
+    }
+    if reader.uint()? != V::FIELD_ID as u64 {
+        return Err(DecodeError::FieldMismatch);
+    }
+    let scheme_id = reader.u32()?;
+    let epoch = reader.u32()?;
+    let level = reader.u32()?;
+    let threshold = reader.u32()? as usize;
+    let total = if len >= 8 { reader.u32()? as usize } else { 0 };
+    let fingerprint = if len == 9 { reader.uint()? } else { 0 };
+    let index = reader.uint()?;
+    let value = V::decode_value(&mut reader)?;
+    reader.finish()?;
+
+    let mut share = Share::new(index, value, threshold, scheme_id);
+    share.epoch = epoch;
+    share.level = level;
+    share.total = total;
+    share.fingerprint = fingerprint;
+    Ok(share)
+}
+
+pub fn encode_bundle(bundle: &CommitmentBundle) -> Vec<u8> {
+    let mut out = Vec::new();
+    head(&mut out, TAG, BUNDLE_TAG);
+    head(&mut out, ARRAY, 2);
+    head(&mut out, ARRAY, bundle.commitments.len() as u64);
+    for commitment in &bundle.commitments {
+        commitment.encode_value(&mut out);
+    }
+    match &bundle.verification_key {
+        Some(key) => {
+            head(&mut out, ARRAY, 2);
+            head(&mut out, UNSIGNED, key.scheme_id as u64);
+            bytes(&mut out, &key.key);
+        }
+        None => out.push(NULL),
+    }
+    out
+}
+
+pub fn decode_bundle(bytes: &[u8]) -> Result<CommitmentBundle, DecodeError> {
+    let mut reader = Reader { bytes };
+    reader.tag(BUNDLE_TAG)?;
+    reader.array(2)?;
+    let len = reader.expect(ARRAY)?;
+    // Every commitment takes at least one byte, which bounds the allocation.
+    if len > reader.bytes.len() as u64 {
+        return Err(DecodeError::Truncated);
+    }
+    let commitments = (0..len).map(|_| i128::decode_value(&mut reader)).collect::<Result<Vec<_>, _>>()?;
+    let verification_key = if reader.peek()? == NULL {
+        reader.take(1)?;
+        None
+    } else {
+        reader.array(2)?;
+        let scheme_id = reader.u32()?;
+        let key = reader.bytes()?.try_into().map_err(|_| DecodeError::Malformed)?;
+        Some(VerificationKey { scheme_id, key })
+    };
+    reader.finish()?;
+    Ok(CommitmentBundle { commitments, verification_key })
+}
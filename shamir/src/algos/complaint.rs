@@ -0,0 +1,152 @@
+use alloc::collections::{BTreeMap, BTreeSet};
+use alloc::vec::Vec;
+
+use super::share::Share;
+use super::vss::{self, VssConfig, VssError};
+
+// The complaint round that follows a Feldman dealing. Holders whose share is missing or fails
+// verification broadcast a complaint; the dealer must answer each one by publishing that share;
+// everyone then runs the same `resolve` over the public messages and reaches the same verdict.
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Complaint {
+    pub complainant: u64,
+    pub dealer: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Response {
+    pub dealer: u64,
+    pub share: Share<i128>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DisqualifyReason {
+    // Answering threshold or more complaints in public would reveal the secret.
+    TooManyComplaints,
+    MissingResponse(u64),
+    InvalidResponse(u64),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Verdict {
+    // The dealing stands; complainants take their share from the published responses.
+    Qualified { revealed: Vec<Share<i128>> },
+    Disqualified(DisqualifyReason),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DisputeState {
+    Collecting,
+    AwaitingResponses,
+    Resolved,
+}
+
+// A holder's check of what it received, producing the complaint to broadcast if any.
+pub fn check_dealing(
+    index: u64,
+    dealer: u64,
+    share: Option<&Share<i128>>,
+    commitments: &[i128],
+    config: &VssConfig,
+) -> Option<Complaint> {
+    match share {
+        Some(share) if share.index == index && vss::verify_share(share, commitments, config) => None,
+        _ => Some(Complaint { complainant: index, dealer }),
+    }
+}
+
+pub struct Dispute {
+    dealer: u64,
+    commitments: Vec<i128>,
+    config: VssConfig,
+    state: DisputeState,
+    complaints: BTreeSet<u64>,
+    responses: BTreeMap<u64, Share<i128>>,
+}
+
+impl Dispute {
+    pub fn new(dealer: u64, commitments: Vec<i128>, config: VssConfig) -> Result<Self, VssError> {
+        if commitments.len() != config.threshold() {
+            return Err(VssError::InvalidParameters("commitments"));
+        }
+        Ok(Dispute {
+            dealer,
+            commitments,
+            config,
+            state: DisputeState::Collecting,
+            complaints: BTreeSet::new(),
+            responses: BTreeMap::new(),
+        })
+    }
+
+    pub fn state(&self) -> DisputeState {
+        self.state
+    }
+
+    pub fn complaints(&self) -> impl Iterator<Item = u64> + '_ {
+        self.complaints.iter().copied()
+    }
+
+    pub fn file(&mut self, complaint: &Complaint) -> Result<(), VssError> {
+        if self.state != DisputeState::Collecting {
+            return Err(VssError::InvalidState);
+        }
+        if complaint.dealer != self.dealer || complaint.complainant == 0 || complaint.complainant > self.config.shares() as u64 {
+            return Err(VssError::InvalidParameters("complaint"));
+        }
+        if !self.complaints.insert(complaint.complainant) {
+            return Err(VssError::DuplicateIndex);
+        }
+        Ok(())
+    }
+
+    // Ends the complaint window; with no complaints there is nothing to answer.
+    pub fn close(&mut self) -> Result<(), VssError> {
+        if self.state != DisputeState::Collecting {
+            return Err(VssError::InvalidState);
+        }
+        self.state = if self.complaints.is_empty() { DisputeState::Resolved } else { DisputeState::AwaitingResponses };
+        Ok(())
+    }
+
+    pub fn respond(&mut self, response: Response) -> Result<(), VssError> {
+        if self.state != DisputeState::AwaitingResponses {
+            return Err(VssError::InvalidState);
+        }
+        let index = response.share.index;
+        if response.dealer != self.dealer || !self.complaints.contains(&index) {
+            return Err(VssError::InvalidParameters("response"));
+        }
+        if self.responses.insert(index, response.share).is_some() {
+            return Err(VssError::DuplicateIndex);
+        }
+        Ok(())
+    }
+
+    // Call once the response window has passed; unanswered complaints count against the dealer.
+    pub fn resolve(&mut self) -> Result<Verdict, VssError> {
+        if self.state == DisputeState::Collecting {
+            return Err(VssError::InvalidState);
+        }
+        self.state = DisputeState::Resolved;
+        if self.complaints.len() >= self.config.threshold() {
+            return Ok(Verdict::Disqualified(DisqualifyReason::TooManyComplaints));
+        }
+        let mut revealed = Vec::with_capacity(self.complaints.len());
+        for &index in &self.complaints {
+            let Some(share) = self.responses.get(&index) else {
+                return Ok(Verdict::Disqualified(DisqualifyReason::MissingResponse(index)));
+            };
+            if !vss::verify_share(share, &self.commitments, &self.config) {
+                return Ok(Verdict::Disqualified(DisqualifyReason::InvalidResponse(index)));
+            }
+            revealed.push(share.clone());
+        }
+        Ok(Verdict::Qualified { revealed })
+    }
+}
@@ -0,0 +1,117 @@
+use core::hint::black_box;
+
+use super::gf256::POLY;
+
+// Returns 1 if a == b and 0 otherwise, without branching on either value.
+pub fn eq(a: u64, b: u64) -> u64 {
+    let x = a ^ b;
+    1 ^ ((x | x.wrapping_neg()) >> 63)
+}
+
+// Returns a when choice is 1 and b when choice is 0.
+pub fn select(choice: u64, a: u64, b: u64) -> u64 {
+    let mask = black_box(choice).wrapping_neg();
+    (a & mask) | (b & !mask)
+}
+
+// Barrett reduction modulo a fixed m. A u128 `%` lowers to a division routine whose running time
+// depends on the dividend; here mu = floor((2^128 - 1) / m) depends only on the public modulus,
+// and every reduction is the same four multiplications and two masked subtractions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Barrett {
+    m: u64,
+    mu: u128,
+}
+
+impl Barrett {
+    pub fn new(m: u64) -> Self {
+        assert!(m > 0, "modulus must be nonzero");
+        Barrett { m, mu: u128::MAX / m as u128 }
+    }
+
+    // x mod m for any x: the quotient estimate is at most two short, so r < 3m before the fixes.
+    pub fn reduce(&self, x: u128) -> u64 {
+        let q = mul_high(x, self.mu);
+        let m = self.m as u128;
+        let mut r = x.wrapping_sub(q.wrapping_mul(m));
+        for _ in 0..2 {
+            let (reduced, borrow) = r.overflowing_sub(m);
+            let mask = (borrow as u128).wrapping_sub(1);
+            r = (reduced & mask) | (r & !mask);
+        }
+        r as u64
+    }
+
+    pub fn mul(&self, a: u64, b: u64) -> u64 {
+        self.reduce(a as u128 * b as u128)
+    }
+}
+
+// The high 128 bits of the 256-bit product, from four 64 x 64 multiplications.
+fn mul_high(a: u128, b: u128) -> u128 {
+    let (a1, a0) = (a >> 64, a as u64 as u128);
+    let (b1, b0) = (b >> 64, b as u64 as u128);
+    let (low, cross1, cross2) = (a0 * b0, a0 * b1, a1 * b0);
+    let middle = (low >> 64) + (cross1 as u64 as u128) + (cross2 as u64 as u128);
+    a1 * b1 + (cross1 >> 64) + (cross2 >> 64) + (middle >> 64)
+}
+
+pub fn reduce(x: u128, m: u64) -> u64 {
+    Barrett::new(m).reduce(x)
+}
+
+pub fn mul_mod(a: u64, b: u64, m: u64) -> u64 {
+    Barrett::new(m).mul(a, b)
+}
+
+// Fixed 4-bit window exponentiation: always 64 squarings and 16 multiplications,
+// with the table entry picked by a full scan rather than an index.
+pub fn pow_mod(base: u64, exp: u64, m: u64) -> u64 {
+    let barrett = Barrett::new(m);
+    let (one, base) = (barrett.reduce(1), barrett.reduce(base as u128));
+    let mut table = [one; 16];
+    for i in 1..16 {
+        table[i] = barrett.mul(table[i - 1], base);
+    }
+
+    let mut result = one;
+    for window in (0..16).rev() {
+        for _ in 0..4 {
+            result = barrett.mul(result, result);
+        }
+        let digit = (exp >> (4 * window)) & 0xf;
+        let mut entry = 0;
+        for (j, &value) in table.iter().enumerate() {
+            entry = select(eq(j as u64, digit), value, entry);
+        }
+        result = barrett.mul(result, entry);
+    }
+    result
+}
+
+// Fermat inversion a^(p - 2); only valid for prime p. Returns 0 for a = 0.
+pub fn inv_mod(a: u64, p: u64) -> u64 {
+    pow_mod(a, p - 2, p)
+}
+
+pub fn gf256_mul(a: u8, b: u8) -> u8 {
+    let (mut a, mut b, mut product) = (a as u16, b as u16, 0u16);
+    for _ in 0..8 {
+        product ^= a & (b & 1).wrapping_neg();
+        b >>= 1;
+        a <<= 1;
+        a ^= POLY & ((a >> 8) & 1).wrapping_neg();
+    }
+    product as u8
+}
+
+// a^254 = a^-1 in GF(256); maps 0 to 0.
+pub fn gf256_inv(a: u8) -> u8 {
+    let mut square = a;
+    let mut result = 1;
+    for _ in 1..8 {
+        square = gf256_mul(square, square);
+        result = gf256_mul(result, square);
+    }
+    result
+}
@@ -0,0 +1,57 @@
+use alloc::collections::BTreeSet;
+use alloc::vec::Vec;
+
+use rand::{CryptoRng, Rng, RngCore};
+use zeroize::Zeroizing;
+
+use super::share::Share;
+use super::sss::{self, ShamirError, PRIME};
+
+// A dealing that stays open: the dealer keeps the polynomial so it can hand out shares at new
+// indices later (a custodian joining) without reconstructing and re-splitting. Whoever holds a
+// `Dealer` holds the secret; drop it once no more shares will be needed.
+pub struct Dealer {
+    coeffs: Zeroizing<Vec<u64>>,
+    scheme_id: u32,
+    issued: BTreeSet<u64>,
+}
+
+impl Dealer {
+    pub fn new(secret: u64, threshold: usize, rng: &mut (impl RngCore + CryptoRng)) -> Result<Self, ShamirError> {
+        if secret >= PRIME {
+            return Err(ShamirError::SecretOutOfRange);
+        }
+        let coeffs = sss::generate_polynomial(secret, threshold, rng)?;
+        Ok(Dealer { coeffs, scheme_id: rng.r#gen(), issued: BTreeSet::new() })
+    }
+
+    pub fn threshold(&self) -> usize {
+        self.coeffs.len()
+    }
+
+    pub fn scheme_id(&self) -> u32 {
+        self.scheme_id
+    }
+
+    pub fn issued(&self) -> impl Iterator<Item = u64> + '_ {
+        self.issued.iter().copied()
+    }
+
+    pub fn issue_at(&mut self, x: u64) -> Result<Share, ShamirError> {
+        sss::check_indices(&[x], PRIME)?;
+        if !self.issued.insert(x) {
+            return Err(ShamirError::DuplicateIndex);
+        }
+        Ok(Share::new(x, sss::evaluate_polynomial(&self.coeffs, x), self.threshold(), self.scheme_id))
+    }
+
+    // The share at the lowest index above every one issued so far.
+    pub fn issue(&mut self) -> Result<Share, ShamirError> {
+        let next = self.issued.last().map_or(1, |&x| x + 1);
+        self.issue_at(next)
+    }
+
+    pub fn deal(&mut self, num_shares: usize) -> Result<Vec<Share>, ShamirError> {
+        (0..num_shares).map(|_| self.issue()).collect()
+    }
+}
@@ -0,0 +1,145 @@
+use alloc::collections::BTreeMap;
+use alloc::{vec, vec::Vec};
+
+use rand::{CryptoRng, Rng, RngCore};
+
+use super::polynomial::Polynomial;
+use super::share::{self, Share};
+use super::vss::{self, VssConfig, VssError};
+
+// What one party broadcasts (commitments) and sends privately (one share per recipient).
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Dealing {
+    pub dealer: u64,
+    pub commitments: Vec<i128>,
+    pub shares: Vec<Share<i128>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DkgOutput {
+    pub share: Share<i128>,
+    pub public_key: i128,
+    pub commitments: Vec<i128>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DkgState {
+    Ready,
+    Dealt,
+    Complete,
+}
+
+pub struct Party {
+    index: u64,
+    config: VssConfig,
+    state: DkgState,
+    received: BTreeMap<u64, (Share<i128>, Vec<i128>)>,
+}
+
+impl Party {
+    pub fn new(index: u64, config: VssConfig) -> Result<Self, VssError> {
+        if index == 0 || index > config.shares() as u64 {
+            return Err(VssError::InvalidParameters("index"));
+        }
+        Ok(Party { index, config, state: DkgState::Ready, received: BTreeMap::new() })
+    }
+
+    pub fn index(&self) -> u64 {
+        self.index
+    }
+
+    pub fn state(&self) -> DkgState {
+        self.state
+    }
+
+    pub fn deal(&mut self, rng: &mut (impl RngCore + CryptoRng)) -> Result<Dealing, VssError> {
+        if self.state != DkgState::Ready {
+            return Err(VssError::InvalidState);
+        }
+        let secret = rng.gen_range(0..self.config.order());
+        let poly = Polynomial::random(self.config.scalar_field(), secret, self.config.threshold(), rng);
+        let commitments = vss::generate_commitments(poly.coeffs(), &self.config);
+        let shares = vss::generate_shares(poly.coeffs(), &self.config, rng);
+        // A party keeps its own share of its own dealing rather than receiving it.
+        self.received.insert(self.index, (shares[self.index as usize - 1].clone(), commitments.clone()));
+        self.state = DkgState::Dealt;
+        Ok(Dealing { dealer: self.index, commitments, shares })
+    }
+
+    pub fn receive(&mut self, dealer: u64, share: Share<i128>, commitments: Vec<i128>) -> Result<(), VssError> {
+        if self.state != DkgState::Dealt {
+            return Err(VssError::InvalidState);
+        }
+        let valid_dealer = dealer != 0 && dealer <= self.config.shares() as u64 && dealer != self.index;
+        if share.index != self.index || !valid_dealer || self.received.contains_key(&dealer) {
+            return Err(VssError::InvalidParameters("dealer"));
+        }
+        if !vss::verify_share(&share, &commitments, &self.config) {
+            return Err(VssError::VerificationFailed { index: dealer });
+        }
+        self.received.insert(dealer, (share, commitments));
+        Ok(())
+    }
+
+    pub fn finalize(&mut self) -> Result<DkgOutput, VssError> {
+        if self.state != DkgState::Dealt {
+            return Err(VssError::InvalidState);
+        }
+        if self.received.len() < self.config.shares() {
+            return Err(VssError::InsufficientShares { provided: self.received.len(), required: self.config.shares() });
+        }
+
+        let (p, q) = (self.config.prime(), self.config.order());
+        let mut value = 0;
+        let mut commitments = vec![1; self.config.threshold()];
+        // Dealer values pass `verify_share` up to a multiple of the modulus, so reduce before combining.
+        for (share, dealer_commitments) in self.received.values() {
+            value = vss::mod_norm(value + vss::mod_norm(share.value, q), q);
+            for (c, &d) in commitments.iter_mut().zip(dealer_commitments) {
+                *c = vss::mod_norm(*c * vss::mod_norm(d, p), p);
+            }
+        }
+
+        let mut share = Share::new(self.index, value, self.config.threshold(), dealing_id(&commitments));
+        share.total = self.config.shares();
+        share.fingerprint = share::joint_fingerprint(b"dkg", &commitments);
+        self.state = DkgState::Complete;
+        Ok(DkgOutput {
+            share,
+            public_key: commitments[0],
+            commitments,
+        })
+    }
+}
+
+// Every party derives the same scheme id from the joint commitments (FNV-1a).
+fn dealing_id(commitments: &[i128]) -> u32 {
+    let mut hash: u32 = 0x811c9dc5;
+    for c in commitments {
+        for byte in c.to_le_bytes() {
+            hash = (hash ^ byte as u32).wrapping_mul(0x01000193);
+        }
+    }
+    hash
+}
+
+pub fn run_dkg(config: &VssConfig, rng: &mut (impl RngCore + CryptoRng)) -> Result<Vec<DkgOutput>, VssError> {
+    let mut parties = (1..=config.shares() as u64)
+        .map(|index| Party::new(index, *config))
+        .collect::<Result<Vec<_>, _>>()?;
+    let dealings = parties
+        .iter_mut()
+        .map(|party| party.deal(rng))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    for dealing in &dealings {
+        for (party, share) in parties.iter_mut().zip(&dealing.shares) {
+            if party.index != dealing.dealer {
+                party.receive(dealing.dealer, share.clone(), dealing.commitments.clone())?;
+            }
+        }
+    }
+    parties.iter_mut().map(Party::finalize).collect()
+}
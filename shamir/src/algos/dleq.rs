@@ -0,0 +1,155 @@
+use alloc::vec::Vec;
+
+use rand::{CryptoRng, Rng, RngCore};
+use sha2::{Digest, Sha256};
+use zeroize::Zeroize;
+
+use super::group::Commitment;
+use super::share::Share;
+use super::vss::{self, VssConfig, VssError};
+
+// Chaum-Pedersen proofs that two group elements share a discrete log: log_g(A) = log_h(B), made
+// non-interactive with Fiat-Shamir. A VSS holder publishes B = h^s_i for some public base h (a
+// partial operation: one share of h^secret) with a proof against its verification share
+// A = g^s_i, so anyone can audit the partial without learning s_i; t checked partials combine
+// in the exponent into h^secret.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DleqProof {
+    pub challenge: i128,
+    pub response: i128,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PartialEvaluation {
+    pub index: u64,
+    // base^s_i
+    pub value: i128,
+    pub proof: DleqProof,
+}
+
+fn challenge(elements: &[i128], config: &VssConfig) -> i128 {
+    let mut hasher = Sha256::new();
+    hasher.update(b"shamir/dleq");
+    for element in elements {
+        hasher.update(element.to_be_bytes());
+    }
+    let digest = hasher.finalize();
+    let mut wide = [0u8; 16];
+    wide.copy_from_slice(&digest[..16]);
+    (u128::from_be_bytes(wide) % config.order() as u128) as i128
+}
+
+fn in_subgroup(x: i128, config: &VssConfig) -> bool {
+    (1..config.prime()).contains(&x) && config.scale(&x, &config.order()) == 1
+}
+
+// A generator of the order-q subgroup, i.e. any element but the identity.
+fn is_base(x: i128, config: &VssConfig) -> bool {
+    x != 1 && in_subgroup(x, config)
+}
+
+// A base for `evaluate_share` derived from `message`; nobody knows its log to g. With p = 2q + 1,
+// squaring any other residue lands in the order-q subgroup.
+pub fn hash_to_group(message: &[u8], config: &VssConfig) -> i128 {
+    let p = config.prime();
+    (0u32..)
+        .map(|counter| {
+            let mut hasher = Sha256::new();
+            hasher.update(b"shamir/dleq/base");
+            hasher.update(counter.to_be_bytes());
+            hasher.update(message);
+            let digest = hasher.finalize();
+            let mut wide = [0u8; 16];
+            wide.copy_from_slice(&digest[..16]);
+            let x = (u128::from_be_bytes(wide) % p as u128) as i128;
+            vss::mod_norm(x * x, p)
+        })
+        .find(|&h| is_base(h, config))
+        .expect("squares of residues other than 0 and +-1 have order q")
+}
+
+// Proves log_g(g^x) = log_base(base^x).
+pub fn prove(x: i128, base: i128, config: &VssConfig, rng: &mut (impl RngCore + CryptoRng)) -> DleqProof {
+    let q = config.order();
+    let mut k = rng.gen_range(1..q);
+    let a = config.commit(&x);
+    let b = config.scale(&base, &x);
+    let c = challenge(&[config.generator(), base, a, b, config.commit(&k), config.scale(&base, &k)], config);
+    let response = vss::mod_norm(k - c * x, q);
+    k.zeroize();
+    DleqProof { challenge: c, response }
+}
+
+// g^z * A^c and base^z * B^c reproduce the commitments the challenge was hashed from.
+pub fn verify(a: i128, b: i128, base: i128, proof: &DleqProof, config: &VssConfig) -> bool {
+    if !is_base(base, config) || !in_subgroup(a, config) || !in_subgroup(b, config) {
+        return false;
+    }
+    let t1 = config.combine(&config.commit(&proof.response), &config.scale(&a, &proof.challenge));
+    let t2 = config.combine(&config.scale(&base, &proof.response), &config.scale(&b, &proof.challenge));
+    challenge(&[config.generator(), base, a, b, t1, t2], config) == proof.challenge
+}
+
+// g^s_i for the holder at `index`, from the dealing's commitments. Auditors can compute these
+// once per dealing and check any number of partials against them with `verify_evaluation_with`.
+pub fn verification_share(index: u64, commitments: &[i128], config: &VssConfig) -> i128 {
+    vss::commitment_at(index, commitments, config)
+}
+
+pub fn evaluate_share(
+    share: &Share<i128>,
+    base: i128,
+    config: &VssConfig,
+    rng: &mut (impl RngCore + CryptoRng),
+) -> Result<PartialEvaluation, VssError> {
+    if !is_base(base, config) {
+        return Err(VssError::InvalidParameters("base"));
+    }
+    Ok(PartialEvaluation {
+        index: share.index,
+        value: config.scale(&base, &share.value),
+        proof: prove(share.value, base, config, rng),
+    })
+}
+
+pub fn verify_evaluation_with(
+    evaluation: &PartialEvaluation,
+    base: i128,
+    verification_share: i128,
+    config: &VssConfig,
+) -> bool {
+    verify(verification_share, evaluation.value, base, &evaluation.proof, config)
+}
+
+pub fn verify_evaluation(evaluation: &PartialEvaluation, base: i128, commitments: &[i128], config: &VssConfig) -> bool {
+    commitments.len() == config.threshold()
+        && verify_evaluation_with(evaluation, base, verification_share(evaluation.index, commitments, config), config)
+}
+
+// base^secret from the first `threshold` partials, after checking every one of them.
+pub fn combine_evaluations(
+    evaluations: &[PartialEvaluation],
+    base: i128,
+    commitments: &[i128],
+    config: &VssConfig,
+) -> Result<i128, VssError> {
+    if let Some(bad) = evaluations.iter().find(|e| !verify_evaluation(e, base, commitments, config)) {
+        return Err(VssError::VerificationFailed { index: bad.index });
+    }
+    let threshold = config.threshold();
+    if evaluations.len() < threshold {
+        return Err(VssError::InsufficientShares { provided: evaluations.len(), required: threshold });
+    }
+    let evaluations = &evaluations[..threshold];
+    let q = config.order();
+    let xs: Vec<i128> = evaluations.iter().map(|e| vss::mod_norm(e.index as i128, q)).collect();
+    let basis = vss::lagrange_basis_at_zero(&xs, q).map_err(|_| VssError::DuplicateIndex)?;
+    let mut result = 1;
+    for (evaluation, lambda) in evaluations.iter().zip(&basis) {
+        result = config.combine(&result, &config.scale(&evaluation.value, lambda));
+    }
+    Ok(result)
+}
@@ -0,0 +1,320 @@
+use alloc::vec::Vec;
+
+use curve25519_dalek::constants::ED25519_BASEPOINT_POINT;
+use curve25519_dalek::edwards::CompressedEdwardsY;
+use curve25519_dalek::{EdwardsPoint, Scalar};
+use rand::{CryptoRng, RngCore};
+use sha2::{Digest, Sha512};
+use zeroize::Zeroize;
+
+use super::group::{self, Group, ScalarField};
+use super::polynomial;
+use super::ristretto::Ristretto;
+use super::share::Share;
+use super::vss::VssError;
+
+// Ed25519 (RFC 8032) keys held as Shamir shares. The signing scalar is shared mod the group order
+// l, the scalar field `ristretto` already uses, with Feldman commitments on the Edwards curve so
+// C_0 is the public key itself. A split key is either reassembled into a `SigningKey`, or never
+// reassembled and used through the FROST-style `commit`/`sign`/`aggregate` rounds, whose output
+// is an ordinary Ed25519 signature.
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Ed25519;
+
+impl Group for Ed25519 {
+    type Scalar = Scalar;
+    type Point = EdwardsPoint;
+
+    fn scalar_from_u64(x: u64) -> Scalar {
+        Scalar::from(x)
+    }
+
+    fn random_scalar(rng: &mut (impl RngCore + CryptoRng)) -> Scalar {
+        Ristretto::random_scalar(rng)
+    }
+
+    fn invert(scalar: &Scalar) -> Option<Scalar> {
+        Ristretto::invert(scalar)
+    }
+
+    fn generator() -> EdwardsPoint {
+        ED25519_BASEPOINT_POINT
+    }
+
+    fn identity() -> EdwardsPoint {
+        EdwardsPoint::default()
+    }
+
+    fn encode_point(point: &EdwardsPoint) -> Vec<u8> {
+        point.compress().as_bytes().to_vec()
+    }
+
+    fn scalar_from_wide(bytes: &[u8; 64]) -> Scalar {
+        Scalar::from_bytes_mod_order_wide(bytes)
+    }
+
+    fn is_valid_point(point: &EdwardsPoint) -> bool {
+        *point != EdwardsPoint::default() && point.is_torsion_free()
+    }
+}
+
+fn hash_to_scalar(parts: &[&[u8]]) -> Scalar {
+    let mut hasher = Sha512::new();
+    for part in parts {
+        hasher.update(part);
+    }
+    let mut wide = [0u8; 64];
+    wide.copy_from_slice(&hasher.finalize());
+    let scalar = Scalar::from_bytes_mod_order_wide(&wide);
+    wide.zeroize();
+    scalar
+}
+
+// k = SHA-512(R || A || M) mod l, as RFC 8032 defines it.
+fn challenge(r: &[u8; 32], public_key: &[u8; 32], message: &[u8]) -> Scalar {
+    hash_to_scalar(&[r, public_key, message])
+}
+
+fn signature(r: &EdwardsPoint, s: &Scalar) -> [u8; 64] {
+    let mut signature = [0u8; 64];
+    signature[..32].copy_from_slice(r.compress().as_bytes());
+    signature[32..].copy_from_slice(s.as_bytes());
+    signature
+}
+
+pub struct SigningKey {
+    scalar: Scalar,
+    // Keys the deterministic nonces.
+    prefix: [u8; 32],
+    public_key: [u8; 32],
+}
+
+impl SigningKey {
+    // RFC 8032 key expansion: the clamped low half of SHA-512(seed) is the scalar and the high
+    // half keys the nonces.
+    pub fn from_seed(seed: &[u8; 32]) -> Self {
+        let mut digest = [0u8; 64];
+        digest.copy_from_slice(&Sha512::digest(seed));
+        let mut low = [0u8; 32];
+        low.copy_from_slice(&digest[..32]);
+        low[0] &= 248;
+        low[31] &= 127;
+        low[31] |= 64;
+        let mut prefix = [0u8; 32];
+        prefix.copy_from_slice(&digest[32..]);
+        let key = Self::from_parts(Scalar::from_bytes_mod_order(low), prefix);
+        digest.zeroize();
+        low.zeroize();
+        prefix.zeroize();
+        key
+    }
+
+    fn from_parts(scalar: Scalar, prefix: [u8; 32]) -> Self {
+        let public_key = (ED25519_BASEPOINT_POINT * scalar).compress().to_bytes();
+        SigningKey { scalar, prefix, public_key }
+    }
+
+    pub fn public_key(&self) -> [u8; 32] {
+        self.public_key
+    }
+
+    pub fn sign(&self, message: &[u8]) -> [u8; 64] {
+        let mut r = hash_to_scalar(&[&self.prefix, message]);
+        let big_r = ED25519_BASEPOINT_POINT * r;
+        let k = challenge(big_r.compress().as_bytes(), &self.public_key, message);
+        let signature = signature(&big_r, &(r + k * self.scalar));
+        r.zeroize();
+        signature
+    }
+}
+
+impl Drop for SigningKey {
+    fn drop(&mut self) {
+        self.scalar.zeroize();
+        self.prefix.zeroize();
+    }
+}
+
+// Cofactorless RFC 8032 verification: S must be canonical and S * B == R + k * A.
+pub fn verify(public_key: &[u8; 32], message: &[u8], signature: &[u8; 64]) -> bool {
+    let Some(a) = CompressedEdwardsY(*public_key).decompress() else {
+        return false;
+    };
+    let mut r = [0u8; 32];
+    r.copy_from_slice(&signature[..32]);
+    let mut s = [0u8; 32];
+    s.copy_from_slice(&signature[32..]);
+    let Some(s) = Option::<Scalar>::from(Scalar::from_canonical_bytes(s)) else {
+        return false;
+    };
+    let k = challenge(&r, public_key, message);
+    EdwardsPoint::vartime_double_scalar_mul_basepoint(&k, &-a, &s).compress().to_bytes() == r
+}
+
+// Shares of the key's scalar and the Edwards commitments to its polynomial; commitments[0]
+// compresses to the public key.
+pub fn split_key(
+    key: &SigningKey,
+    threshold: usize,
+    num_shares: usize,
+    rng: &mut (impl RngCore + CryptoRng),
+) -> Result<(Vec<Share<Scalar>>, Vec<EdwardsPoint>), VssError> {
+    let coeffs = group::generate_polynomial::<Ed25519>(key.scalar, threshold, rng)?;
+    let shares = group::generate_shares::<Ed25519>(&coeffs, num_shares, rng)?;
+    Ok((shares, group::commit_polynomial(&Ed25519, &coeffs)))
+}
+
+pub fn verify_share(share: &Share<Scalar>, commitments: &[EdwardsPoint]) -> bool {
+    group::verify_commitment(&Ed25519, share, commitments)
+}
+
+// The signing key behind a quorum of shares. The seed's nonce key is not shared, so the
+// reassembled key derives its own from the scalar: its signatures differ byte for byte from the
+// original key's but verify under the same public key.
+pub fn reassemble(shares: &[Share<Scalar>]) -> Result<SigningKey, VssError> {
+    let scalar = group::reconstruct_secret::<Ed25519>(shares)?;
+    let mut digest = [0u8; 64];
+    let mut hasher = Sha512::new();
+    hasher.update(b"shamir/ed25519/prefix");
+    hasher.update(scalar.as_bytes());
+    digest.copy_from_slice(&hasher.finalize());
+    let mut prefix = [0u8; 32];
+    prefix.copy_from_slice(&digest[..32]);
+    let key = SigningKey::from_parts(scalar, prefix);
+    digest.zeroize();
+    prefix.zeroize();
+    Ok(key)
+}
+
+// Secret per-signing nonces (d, e); consumed by `sign` so they can never be reused.
+pub struct SigningNonces {
+    hiding: Scalar,
+    binding: Scalar,
+}
+
+impl Drop for SigningNonces {
+    fn drop(&mut self) {
+        self.hiding.zeroize();
+        self.binding.zeroize();
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NonceCommitment {
+    pub index: u64,
+    pub hiding: EdwardsPoint,
+    pub binding: EdwardsPoint,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PartialSignature {
+    pub index: u64,
+    pub z: Scalar,
+}
+
+fn binding_factor(index: u64, message: &[u8], commitments: &[NonceCommitment]) -> Scalar {
+    let mut encoded = Vec::with_capacity(commitments.len() * 72);
+    for c in commitments {
+        encoded.extend_from_slice(&c.index.to_be_bytes());
+        encoded.extend_from_slice(c.hiding.compress().as_bytes());
+        encoded.extend_from_slice(c.binding.compress().as_bytes());
+    }
+    let message_len = (message.len() as u64).to_be_bytes();
+    hash_to_scalar(&[b"shamir/ed25519/rho", &index.to_be_bytes(), &message_len, message, &encoded])
+}
+
+// R = sum of D_i + rho_i * E_i over the signing set.
+fn group_commitment(message: &[u8], commitments: &[NonceCommitment]) -> EdwardsPoint {
+    commitments.iter().fold(EdwardsPoint::default(), |acc, c| {
+        acc + c.hiding + c.binding * binding_factor(c.index, message, commitments)
+    })
+}
+
+fn lagrange(index: u64, commitments: &[NonceCommitment]) -> Result<Scalar, VssError> {
+    let xs: Vec<Scalar> = commitments.iter().map(|c| Scalar::from(c.index)).collect();
+    let position = commitments
+        .iter()
+        .position(|c| c.index == index)
+        .ok_or(VssError::InvalidParameters("index"))?;
+    polynomial::lagrange_at_zero(&ScalarField::<Ed25519>::new(), position, &xs).ok_or(VssError::DuplicateIndex)
+}
+
+fn check_signing_set(commitments: &[NonceCommitment], threshold: usize) -> Result<(), VssError> {
+    if commitments.len() < threshold {
+        return Err(VssError::InsufficientShares { provided: commitments.len(), required: threshold });
+    }
+    for (i, c) in commitments.iter().enumerate() {
+        if commitments[..i].iter().any(|other| other.index == c.index) {
+            return Err(VssError::DuplicateIndex);
+        }
+    }
+    Ok(())
+}
+
+pub fn commit(index: u64, rng: &mut (impl RngCore + CryptoRng)) -> (SigningNonces, NonceCommitment) {
+    let nonces = SigningNonces { hiding: Ed25519::random_scalar(rng), binding: Ed25519::random_scalar(rng) };
+    let commitment = NonceCommitment {
+        index,
+        hiding: ED25519_BASEPOINT_POINT * nonces.hiding,
+        binding: ED25519_BASEPOINT_POINT * nonces.binding,
+    };
+    (nonces, commitment)
+}
+
+pub fn sign(
+    message: &[u8],
+    share: &Share<Scalar>,
+    nonces: SigningNonces,
+    commitments: &[NonceCommitment],
+    public_key: &[u8; 32],
+) -> Result<PartialSignature, VssError> {
+    check_signing_set(commitments, share.threshold)?;
+    let rho = binding_factor(share.index, message, commitments);
+    let lambda = lagrange(share.index, commitments)?;
+    let r = group_commitment(message, commitments);
+    let c = challenge(r.compress().as_bytes(), public_key, message);
+    let z = nonces.hiding + nonces.binding * rho + lambda * share.value * c;
+    Ok(PartialSignature { index: share.index, z })
+}
+
+// z_i * B == D_i + rho_i * E_i + lambda_i * c * Y_i, with Y_i from the key's commitments.
+pub fn verify_partial(
+    message: &[u8],
+    partial: &PartialSignature,
+    commitments: &[NonceCommitment],
+    key_commitments: &[EdwardsPoint],
+) -> Result<bool, VssError> {
+    let own = commitments
+        .iter()
+        .find(|c| c.index == partial.index)
+        .ok_or(VssError::InvalidParameters("partial"))?;
+    let public_key = key_commitments.first().ok_or(VssError::InvalidParameters("key_commitments"))?;
+    let rho = binding_factor(partial.index, message, commitments);
+    let lambda = lagrange(partial.index, commitments)?;
+    let r = group_commitment(message, commitments);
+    let c = challenge(r.compress().as_bytes(), public_key.compress().as_bytes(), message);
+    let verification_share = group::commitment_at(&Ed25519, partial.index, key_commitments);
+    Ok(ED25519_BASEPOINT_POINT * partial.z == own.hiding + own.binding * rho + verification_share * (lambda * c))
+}
+
+// The Ed25519 signature from every partial of the signing set, each checked first.
+pub fn aggregate(
+    message: &[u8],
+    partials: &[PartialSignature],
+    commitments: &[NonceCommitment],
+    key_commitments: &[EdwardsPoint],
+) -> Result<[u8; 64], VssError> {
+    check_signing_set(commitments, key_commitments.len())?;
+    if partials.len() != commitments.len() {
+        return Err(VssError::InsufficientShares { provided: partials.len(), required: commitments.len() });
+    }
+    let mut z = Scalar::ZERO;
+    for partial in partials {
+        if !verify_partial(message, partial, commitments, key_commitments)? {
+            return Err(VssError::VerificationFailed { index: partial.index });
+        }
+        z += partial.z;
+    }
+    Ok(signature(&group_commitment(message, commitments), &z))
+}
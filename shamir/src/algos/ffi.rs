@@ -0,0 +1,152 @@
+use alloc::vec::Vec;
+use core::slice;
+
+use super::vss::{self, VssConfig};
+use super::{sss, wire};
+
+// Stable C entry points. Shares cross the boundary in the versioned wire format, and every
+// buffer is owned by the caller: query sizes with shamir_share_len, then pass preallocated memory.
+
+pub const SHAMIR_OK: i32 = 0;
+pub const SHAMIR_ERR_NULL: i32 = -1;
+pub const SHAMIR_ERR_BUFFER_TOO_SMALL: i32 = -2;
+pub const SHAMIR_ERR_INVALID: i32 = -3;
+pub const SHAMIR_ERR_MALFORMED: i32 = -4;
+
+unsafe fn input<'a>(ptr: *const u8, len: usize) -> Option<&'a [u8]> {
+    if ptr.is_null() && len > 0 {
+        return None;
+    }
+    if len == 0 {
+        return Some(&[]);
+    }
+    // SAFETY: the caller guarantees ptr points to len readable bytes.
+    Some(unsafe { slice::from_raw_parts(ptr, len) })
+}
+
+/// Size in bytes of each encoded share produced by `shamir_split` for a secret of `secret_len` bytes.
+#[unsafe(no_mangle)]
+pub extern "C" fn shamir_share_len(secret_len: usize) -> usize {
+    secret_len + wire::OVERHEAD
+}
+
+/// Splits `secret` into `num_shares` shares, written back to back into `out`, each
+/// `shamir_share_len(secret_len)` bytes long.
+///
+/// # Safety
+///
+/// `secret` must point to `secret_len` readable bytes and `out` to `out_len` writable bytes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn shamir_split(
+    secret: *const u8,
+    secret_len: usize,
+    threshold: usize,
+    num_shares: usize,
+    out: *mut u8,
+    out_len: usize,
+) -> i32 {
+    let Some(secret) = (unsafe { input(secret, secret_len) }) else {
+        return SHAMIR_ERR_NULL;
+    };
+    if out.is_null() {
+        return SHAMIR_ERR_NULL;
+    }
+    let share_len = shamir_share_len(secret_len);
+    if num_shares.checked_mul(share_len).is_none_or(|needed| out_len < needed) {
+        return SHAMIR_ERR_BUFFER_TOO_SMALL;
+    }
+    let shares = match sss::split_bytes(secret, threshold, num_shares, &mut rand::thread_rng()) {
+        Ok(shares) => shares,
+        Err(_) => return SHAMIR_ERR_INVALID,
+    };
+    // SAFETY: out_len >= num_shares * share_len was checked above.
+    let out = unsafe { slice::from_raw_parts_mut(out, out_len) };
+    for (share, chunk) in shares.iter().zip(out.chunks_mut(share_len)) {
+        chunk.copy_from_slice(&wire::encode(share));
+    }
+    SHAMIR_OK
+}
+
+/// Combines `count` encoded shares of `share_len` bytes each, stored back to back in `shares`.
+/// The secret is written to `out` and its length to `secret_len`.
+///
+/// # Safety
+///
+/// `shares` must point to `count * share_len` readable bytes, `out` to `out_len` writable bytes,
+/// and `secret_len` to a writable `size_t`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn shamir_combine(
+    shares: *const u8,
+    share_len: usize,
+    count: usize,
+    out: *mut u8,
+    out_len: usize,
+    secret_len: *mut usize,
+) -> i32 {
+    let Some(total) = share_len.checked_mul(count) else {
+        return SHAMIR_ERR_INVALID;
+    };
+    let Some(bytes) = (unsafe { input(shares, total) }) else {
+        return SHAMIR_ERR_NULL;
+    };
+    if out.is_null() || secret_len.is_null() {
+        return SHAMIR_ERR_NULL;
+    }
+    if share_len == 0 {
+        return SHAMIR_ERR_MALFORMED;
+    }
+    let shares = match bytes.chunks(share_len).map(wire::decode::<Vec<u8>>).collect::<Result<Vec<_>, _>>() {
+        Ok(shares) => shares,
+        Err(_) => return SHAMIR_ERR_MALFORMED,
+    };
+    let secret = match sss::combine_bytes(&shares) {
+        Ok(secret) => secret,
+        Err(_) => return SHAMIR_ERR_INVALID,
+    };
+    // SAFETY: secret_len was checked to be non-null.
+    unsafe { *secret_len = secret.len() };
+    if out_len < secret.len() {
+        return SHAMIR_ERR_BUFFER_TOO_SMALL;
+    }
+    // SAFETY: out points to out_len >= secret.len() writable bytes.
+    unsafe { slice::from_raw_parts_mut(out, secret.len()) }.copy_from_slice(&secret);
+    SHAMIR_OK
+}
+
+/// Checks an encoded Feldman VSS share against the dealer's commitments. Returns 1 if the share
+/// is valid, 0 if it is not, and a negative error code otherwise.
+///
+/// # Safety
+///
+/// `share` must point to `share_len` readable bytes and `commitments` to `count` readable values.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn vss_verify_share(
+    share: *const u8,
+    share_len: usize,
+    commitments: *const i64,
+    count: usize,
+    prime: i64,
+    generator: i64,
+    threshold: usize,
+    num_shares: usize,
+) -> i32 {
+    let Some(bytes) = (unsafe { input(share, share_len) }) else {
+        return SHAMIR_ERR_NULL;
+    };
+    if commitments.is_null() && count > 0 {
+        return SHAMIR_ERR_NULL;
+    }
+    let Ok(config) = VssConfig::new(prime as i128, generator as i128, threshold, num_shares) else {
+        return SHAMIR_ERR_INVALID;
+    };
+    let Ok(share) = wire::decode::<i128>(bytes) else {
+        return SHAMIR_ERR_MALFORMED;
+    };
+    let commitments: Vec<i128> = if count == 0 {
+        Vec::new()
+    } else {
+        // SAFETY: the caller guarantees commitments points to count readable values.
+        unsafe { slice::from_raw_parts(commitments, count) }.iter().map(|&c| c as i128).collect()
+    };
+    i32::from(vss::verify_share(&share, &commitments, &config))
+}
@@ -0,0 +1,238 @@
+use rand::{CryptoRng, Rng, RngCore};
+
+use super::share::ShareValue;
+use super::sss::ShamirError;
+
+// Prime-field arithmetic behind the sharing code. The field value carries the modulus, so one
+// implementation serves every parameter set; elements are the plain values stored in shares.
+// Operations accept unreduced inputs and always return reduced ones.
+pub trait FiniteField {
+    type Element: ShareValue + Clone + PartialEq;
+
+    fn zero(&self) -> Self::Element;
+    fn one(&self) -> Self::Element;
+    fn element(&self, x: u64) -> Self::Element;
+    fn add(&self, a: &Self::Element, b: &Self::Element) -> Self::Element;
+    fn sub(&self, a: &Self::Element, b: &Self::Element) -> Self::Element;
+    fn mul(&self, a: &Self::Element, b: &Self::Element) -> Self::Element;
+    // None for zero.
+    fn inv(&self, a: &Self::Element) -> Option<Self::Element>;
+    fn random(&self, rng: &mut (impl RngCore + CryptoRng)) -> Self::Element;
+
+    fn neg(&self, a: &Self::Element) -> Self::Element {
+        self.sub(&self.zero(), a)
+    }
+
+    fn pow(&self, base: &Self::Element, mut exp: u64) -> Self::Element {
+        let mut result = self.one();
+        let mut base = base.clone();
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = self.mul(&result, &base);
+            }
+            exp >>= 1;
+            base = self.mul(&base, &base);
+        }
+        result
+    }
+}
+
+// Z_p for any p below 2^64, with u128 intermediates. Holds the SSS field (`sss::PRIME`) and the
+// Z_p* groups behind VSS.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PrimeField64 {
+    modulus: u64,
+}
+
+impl PrimeField64 {
+    // The modulus is taken on trust; `inv` is only meaningful when it is prime.
+    pub const fn new(modulus: u64) -> Self {
+        PrimeField64 { modulus }
+    }
+
+    // A caller-chosen modulus, accepted only if it is prime.
+    pub fn checked(modulus: u64) -> Result<Self, ShamirError> {
+        if !is_prime(modulus) {
+            return Err(ShamirError::InvalidModulus);
+        }
+        Ok(PrimeField64 { modulus })
+    }
+
+    pub fn modulus(&self) -> u64 {
+        self.modulus
+    }
+
+    #[cfg(not(feature = "constant-time"))]
+    fn reduce(&self, x: u128) -> u64 {
+        (x % self.modulus as u128) as u64
+    }
+
+    #[cfg(feature = "constant-time")]
+    fn reduce(&self, x: u128) -> u64 {
+        super::ct::reduce(x, self.modulus)
+    }
+}
+
+impl FiniteField for PrimeField64 {
+    type Element = u64;
+
+    fn zero(&self) -> u64 {
+        0
+    }
+
+    fn one(&self) -> u64 {
+        1 % self.modulus
+    }
+
+    fn element(&self, x: u64) -> u64 {
+        self.reduce(x as u128)
+    }
+
+    fn add(&self, a: &u64, b: &u64) -> u64 {
+        self.reduce(*a as u128 + *b as u128)
+    }
+
+    fn sub(&self, a: &u64, b: &u64) -> u64 {
+        let m = self.modulus as u128;
+        self.reduce(self.reduce(*a as u128) as u128 + m - self.reduce(*b as u128) as u128)
+    }
+
+    fn mul(&self, a: &u64, b: &u64) -> u64 {
+        self.reduce(*a as u128 * *b as u128)
+    }
+
+    #[cfg(feature = "constant-time")]
+    fn inv(&self, a: &u64) -> Option<u64> {
+        let a = self.reduce(*a as u128);
+        (a != 0).then(|| super::ct::inv_mod(a, self.modulus))
+    }
+
+    #[cfg(not(feature = "constant-time"))]
+    fn inv(&self, a: &u64) -> Option<u64> {
+        let (mut t, mut new_t) = (0i128, 1i128);
+        let (mut r, mut new_r) = (self.modulus as i128, (a % self.modulus) as i128);
+        while new_r != 0 {
+            let quotient = r / new_r;
+            (t, new_t) = (new_t, t - quotient * new_t);
+            (r, new_r) = (new_r, r - quotient * new_r);
+        }
+        if r != 1 {
+            return None;
+        }
+        Some(if t < 0 { t + self.modulus as i128 } else { t } as u64)
+    }
+
+    fn random(&self, rng: &mut (impl RngCore + CryptoRng)) -> u64 {
+        rng.gen_range(0..self.modulus)
+    }
+
+    #[cfg(feature = "constant-time")]
+    fn pow(&self, base: &u64, exp: u64) -> u64 {
+        super::ct::pow_mod(*base, exp, self.modulus)
+    }
+}
+
+// Deterministic Miller–Rabin: the first twelve primes as bases are exact for every n < 2^64.
+pub fn is_prime(n: u64) -> bool {
+    const BASES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+    if n < 2 {
+        return false;
+    }
+    if let Some(&base) = BASES.iter().find(|&&base| n.is_multiple_of(base)) {
+        return n == base;
+    }
+    let field = PrimeField64::new(n);
+    let rounds = (n - 1).trailing_zeros();
+    let d = (n - 1) >> rounds;
+    'witness: for base in BASES {
+        let mut x = field.pow(&base, d);
+        if x == 1 || x == n - 1 {
+            continue;
+        }
+        for _ in 1..rounds {
+            x = field.mul(&x, &x);
+            if x == n - 1 {
+                continue 'witness;
+            }
+        }
+        return false;
+    }
+    true
+}
+
+// The exponent field Z_q of the toy VSS groups, on the signed values VSS shares carry. The modulus
+// stays below 2^63, so products of reduced elements fit an i128 and inversion reuses `PrimeField64`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SmallPrimeField {
+    modulus: i128,
+}
+
+impl SmallPrimeField {
+    pub const fn new(modulus: i128) -> Self {
+        SmallPrimeField { modulus }
+    }
+
+    pub fn modulus(&self) -> i128 {
+        self.modulus
+    }
+
+    #[cfg(not(feature = "constant-time"))]
+    pub(crate) fn reduce(&self, a: i128) -> i128 {
+        let r = a % self.modulus;
+        if r < 0 { r + self.modulus } else { r }
+    }
+
+    // Reduces |a| and, for negative a, takes m - r (0 when r is 0) through a mask.
+    #[cfg(feature = "constant-time")]
+    pub(crate) fn reduce(&self, a: i128) -> i128 {
+        use super::ct;
+        let barrett = ct::Barrett::new(self.modulus as u64);
+        let r = barrett.reduce(a.unsigned_abs());
+        let negated = barrett.reduce((self.modulus as u64 - r) as u128);
+        ct::select((a >> 127) as u64 & 1, negated, r) as i128
+    }
+
+    fn wide(&self) -> PrimeField64 {
+        PrimeField64::new(self.modulus as u64)
+    }
+}
+
+impl FiniteField for SmallPrimeField {
+    type Element = i128;
+
+    fn zero(&self) -> i128 {
+        0
+    }
+
+    fn one(&self) -> i128 {
+        self.reduce(1)
+    }
+
+    fn element(&self, x: u64) -> i128 {
+        self.reduce(x as i128)
+    }
+
+    fn add(&self, a: &i128, b: &i128) -> i128 {
+        self.reduce(self.reduce(*a) + self.reduce(*b))
+    }
+
+    fn sub(&self, a: &i128, b: &i128) -> i128 {
+        self.reduce(self.reduce(*a) - self.reduce(*b))
+    }
+
+    fn mul(&self, a: &i128, b: &i128) -> i128 {
+        self.reduce(self.reduce(*a) * self.reduce(*b))
+    }
+
+    fn inv(&self, a: &i128) -> Option<i128> {
+        self.wide().inv(&(self.reduce(*a) as u64)).map(i128::from)
+    }
+
+    fn random(&self, rng: &mut (impl RngCore + CryptoRng)) -> i128 {
+        rng.gen_range(0..self.modulus)
+    }
+
+    fn pow(&self, base: &i128, exp: u64) -> i128 {
+        self.wide().pow(&(self.reduce(*base) as u64), exp) as i128
+    }
+}
@@ -0,0 +1,199 @@
+use alloc::vec::Vec;
+
+use rand::{CryptoRng, RngCore};
+use sha2::{Digest, Sha512};
+use zeroize::Zeroize;
+
+use super::field::FiniteField;
+use super::group::{self, Group, ScalarField};
+use super::polynomial;
+use super::share::{Share, ShareValue};
+use super::vss::VssError;
+
+// FROST-style two-round threshold Schnorr signing in any `group::Group` (Ristretto, BLS12-381 G1,
+// Edwards25519): each signer commits to a pair of nonces, then answers with a partial signature
+// that is checked against the key's Feldman commitments before aggregation. The key's shares and
+// commitments come from a `group` dealing; commitments[0] is the public key.
+
+// Secret per-signing nonces (d, e); consumed by `sign` so they can never be reused.
+pub struct SigningNonces<G: Group> {
+    hiding: G::Scalar,
+    binding: G::Scalar,
+}
+
+impl<G: Group> Drop for SigningNonces<G> {
+    fn drop(&mut self) {
+        self.hiding.wipe();
+        self.binding.wipe();
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NonceCommitment<P> {
+    pub index: u64,
+    pub hiding: P,
+    pub binding: P,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PartialSignature<S> {
+    pub index: u64,
+    pub z: S,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Signature<P, S> {
+    pub r: P,
+    pub z: S,
+}
+
+fn hash_to_scalar<G: Group>(domain: &[u8], parts: &[&[u8]]) -> G::Scalar {
+    let mut hasher = Sha512::new();
+    hasher.update(domain);
+    for part in parts {
+        hasher.update((part.len() as u64).to_be_bytes());
+        hasher.update(part);
+    }
+    let mut wide = [0u8; 64];
+    wide.copy_from_slice(&hasher.finalize());
+    let scalar = G::scalar_from_wide(&wide);
+    wide.zeroize();
+    scalar
+}
+
+fn binding_factor<G: Group>(index: u64, message: &[u8], commitments: &[NonceCommitment<G::Point>]) -> G::Scalar {
+    let mut encoded = Vec::new();
+    for c in commitments {
+        encoded.extend_from_slice(&c.index.to_be_bytes());
+        encoded.extend_from_slice(&G::encode_point(&c.hiding));
+        encoded.extend_from_slice(&G::encode_point(&c.binding));
+    }
+    hash_to_scalar::<G>(b"FROST-rho", &[&index.to_be_bytes(), message, &encoded])
+}
+
+fn challenge<G: Group>(r: &G::Point, public_key: &G::Point, message: &[u8]) -> G::Scalar {
+    hash_to_scalar::<G>(b"FROST-chal", &[&G::encode_point(r), &G::encode_point(public_key), message])
+}
+
+// R = sum of D_i + rho_i * E_i over the signing set.
+fn group_commitment<G: Group>(message: &[u8], commitments: &[NonceCommitment<G::Point>]) -> G::Point {
+    commitments.iter().fold(G::identity(), |acc, c| {
+        acc + c.hiding + c.binding * binding_factor::<G>(c.index, message, commitments)
+    })
+}
+
+fn lagrange<G: Group>(index: u64, commitments: &[NonceCommitment<G::Point>]) -> Result<G::Scalar, VssError> {
+    let xs: Vec<G::Scalar> = commitments.iter().map(|c| G::scalar_from_u64(c.index)).collect();
+    let position = commitments
+        .iter()
+        .position(|c| c.index == index)
+        .ok_or(VssError::InvalidParameters("index"))?;
+    polynomial::lagrange_at_zero(&ScalarField::<G>::new(), position, &xs).ok_or(VssError::DuplicateIndex)
+}
+
+// At least `threshold` signers at distinct nonzero scalar indices, each with well-formed nonce
+// commitments.
+fn check_signing_set<G: Group>(commitments: &[NonceCommitment<G::Point>], threshold: usize) -> Result<(), VssError> {
+    if commitments.len() < threshold {
+        return Err(VssError::InsufficientShares { provided: commitments.len(), required: threshold });
+    }
+    let field = ScalarField::<G>::new();
+    let xs: Vec<G::Scalar> = commitments.iter().map(|c| field.element(c.index)).collect();
+    for (i, (c, x)) in commitments.iter().zip(&xs).enumerate() {
+        if *x == field.zero() {
+            return Err(VssError::ZeroIndex);
+        }
+        if xs[..i].contains(x) {
+            return Err(VssError::DuplicateIndex);
+        }
+        if !G::is_valid_point(&c.hiding) || !G::is_valid_point(&c.binding) {
+            return Err(VssError::VerificationFailed { index: c.index });
+        }
+    }
+    Ok(())
+}
+
+pub fn commit<G: Group>(
+    index: u64,
+    rng: &mut (impl RngCore + CryptoRng),
+) -> (SigningNonces<G>, NonceCommitment<G::Point>) {
+    let nonces = SigningNonces::<G> { hiding: G::random_scalar(rng), binding: G::random_scalar(rng) };
+    let commitment = NonceCommitment {
+        index,
+        hiding: G::generator() * nonces.hiding,
+        binding: G::generator() * nonces.binding,
+    };
+    (nonces, commitment)
+}
+
+pub fn sign<G: Group>(
+    message: &[u8],
+    share: &Share<G::Scalar>,
+    nonces: SigningNonces<G>,
+    commitments: &[NonceCommitment<G::Point>],
+    public_key: &G::Point,
+) -> Result<PartialSignature<G::Scalar>, VssError> {
+    check_signing_set::<G>(commitments, share.threshold)?;
+    let rho = binding_factor::<G>(share.index, message, commitments);
+    let lambda = lagrange::<G>(share.index, commitments)?;
+    let r = group_commitment::<G>(message, commitments);
+    let c = challenge::<G>(&r, public_key, message);
+    let z = nonces.hiding + nonces.binding * rho + lambda * share.value * c;
+    Ok(PartialSignature { index: share.index, z })
+}
+
+// z_i * G == D_i + rho_i * E_i + lambda_i * c * Y_i, with Y_i from the key's commitments.
+pub fn verify_partial<G: Group + Default>(
+    message: &[u8],
+    partial: &PartialSignature<G::Scalar>,
+    commitments: &[NonceCommitment<G::Point>],
+    key_commitments: &[G::Point],
+) -> Result<bool, VssError> {
+    check_signing_set::<G>(commitments, key_commitments.len())?;
+    let own = commitments
+        .iter()
+        .find(|c| c.index == partial.index)
+        .ok_or(VssError::InvalidParameters("partial"))?;
+    let public_key = key_commitments.first().ok_or(VssError::InvalidParameters("key_commitments"))?;
+    let rho = binding_factor::<G>(partial.index, message, commitments);
+    let lambda = lagrange::<G>(partial.index, commitments)?;
+    let r = group_commitment::<G>(message, commitments);
+    let c = challenge::<G>(&r, public_key, message);
+    let verification_share = group::commitment_at(&G::default(), partial.index, key_commitments);
+    Ok(G::generator() * partial.z == own.hiding + own.binding * rho + verification_share * (lambda * c))
+}
+
+// The signature from exactly one checked partial per member of the signing set.
+pub fn aggregate<G: Group + Default>(
+    message: &[u8],
+    partials: &[PartialSignature<G::Scalar>],
+    commitments: &[NonceCommitment<G::Point>],
+    key_commitments: &[G::Point],
+) -> Result<Signature<G::Point, G::Scalar>, VssError> {
+    check_signing_set::<G>(commitments, key_commitments.len())?;
+    if partials.len() != commitments.len() {
+        return Err(VssError::InsufficientShares { provided: partials.len(), required: commitments.len() });
+    }
+    for (i, partial) in partials.iter().enumerate() {
+        if partials[..i].iter().any(|other| other.index == partial.index) {
+            return Err(VssError::DuplicateIndex);
+        }
+    }
+    let mut z = G::scalar_from_u64(0);
+    for partial in partials {
+        if !verify_partial::<G>(message, partial, commitments, key_commitments)? {
+            return Err(VssError::VerificationFailed { index: partial.index });
+        }
+        z = z + partial.z;
+    }
+    Ok(Signature { r: group_commitment::<G>(message, commitments), z })
+}
+
+// z * G == R + c * Y.
+pub fn verify<G: Group>(message: &[u8], signature: &Signature<G::Point, G::Scalar>, public_key: &G::Point) -> bool {
+    if !G::is_valid_point(&signature.r) || !G::is_valid_point(public_key) {
+        return false;
+    }
+    let c = challenge::<G>(&signature.r, public_key, message);
+    G::generator() * signature.z == signature.r + *public_key * c
+}
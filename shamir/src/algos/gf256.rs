@@ -0,0 +1,215 @@
+use alloc::{vec, vec::Vec};
+
+use rand::{CryptoRng, Rng, RngCore};
+use zeroize::Zeroizing;
+
+use super::sss::ShamirError;
+
+// x^8 + x^4 + x^3 + x^2 + 1, the reduction polynomial used by gfshare. The log/exp tables are
+// built at compile time, so they need no runtime init and work without std; the
+// `constant-time` feature swaps them for the bitwise routines from `ct`.
+pub(crate) const POLY: u16 = 0x11d;
+
+#[cfg(not(feature = "constant-time"))]
+struct Tables {
+    log: [u8; 256],
+    exp: [u8; 510],
+}
+
+#[cfg(not(feature = "constant-time"))]
+const fn build_tables() -> Tables {
+    let mut log = [0u8; 256];
+    let mut exp = [0u8; 510];
+    let mut x: u16 = 1;
+    let mut i = 0;
+    while i < 255 {
+        exp[i] = x as u8;
+        exp[i + 255] = x as u8;
+        log[x as usize] = i as u8;
+        x <<= 1;
+        if x & 0x100 != 0 {
+            x ^= POLY;
+        }
+        i += 1;
+    }
+    Tables { log, exp }
+}
+
+#[cfg(not(feature = "constant-time"))]
+static TABLES: Tables = build_tables();
+
+pub fn add(a: u8, b: u8) -> u8 {
+    a ^ b
+}
+
+#[cfg(feature = "constant-time")]
+pub fn mul(a: u8, b: u8) -> u8 {
+    super::ct::gf256_mul(a, b)
+}
+
+#[cfg(feature = "constant-time")]
+pub fn inv(a: u8) -> Option<u8> {
+    if a == 0 {
+        return None;
+    }
+    Some(super::ct::gf256_inv(a))
+}
+
+#[cfg(not(feature = "constant-time"))]
+pub fn mul(a: u8, b: u8) -> u8 {
+    if a == 0 || b == 0 {
+        return 0;
+    }
+    let t = &TABLES;
+    t.exp[t.log[a as usize] as usize + t.log[b as usize] as usize]
+}
+
+#[cfg(not(feature = "constant-time"))]
+pub fn inv(a: u8) -> Option<u8> {
+    if a == 0 {
+        return None;
+    }
+    let t = &TABLES;
+    Some(t.exp[255 - t.log[a as usize] as usize])
+}
+
+pub fn div(a: u8, b: u8) -> Option<u8> {
+    inv(b).map(|b_inv| mul(a, b_inv))
+}
+
+pub fn evaluate_polynomial(coeffs: &[u8], x: u8) -> u8 {
+    let mut result = 0;
+    for &coeff in coeffs.iter().rev() {
+        result = add(mul(result, x), coeff);
+    }
+    result
+}
+
+pub fn split(
+    secret: &[u8],
+    threshold: usize,
+    num_shares: usize,
+    rng: &mut (impl RngCore + CryptoRng),
+) -> Result<Vec<(u8, Vec<u8>)>, ShamirError> {
+    if threshold < 2 {
+        return Err(ShamirError::InvalidThreshold);
+    }
+    if num_shares < threshold || num_shares > 255 {
+        return Err(ShamirError::InvalidShareCount);
+    }
+    let xs: Vec<u8> = (1..=num_shares as u8).collect();
+    split_at(secret, threshold, &xs, rng)
+}
+
+// Shares at the caller's x-coordinates, which must be nonzero and distinct.
+pub fn split_at(
+    secret: &[u8],
+    threshold: usize,
+    xs: &[u8],
+    rng: &mut (impl RngCore + CryptoRng),
+) -> Result<Vec<(u8, Vec<u8>)>, ShamirError> {
+    if threshold < 2 {
+        return Err(ShamirError::InvalidThreshold);
+    }
+    if xs.len() < threshold {
+        return Err(ShamirError::InvalidShareCount);
+    }
+    let wide: Vec<u64> = xs.iter().map(|&x| x as u64).collect();
+    super::sss::check_indices(&wide, 256)?;
+
+    let mut shares: Vec<(u8, Vec<u8>)> = xs.iter().map(|&x| (x, Vec::with_capacity(secret.len()))).collect();
+    let mut coeffs = Zeroizing::new(vec![0u8; threshold]);
+    for &byte in secret {
+        coeffs[0] = byte;
+        rng.fill(&mut coeffs[1..]);
+        for (x, value) in shares.iter_mut() {
+            value.push(evaluate_polynomial(&coeffs, *x));
+        }
+    }
+    Ok(shares)
+}
+
+// Lagrange basis values at x = 0 (or another point) for a fixed quorum, reusable across every
+// byte and chunk reconstructed from the same x-coordinates.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LagrangeCoefficients {
+    xs: Vec<u8>,
+    basis: Vec<u8>,
+}
+
+impl LagrangeCoefficients {
+    pub fn precompute(xs: &[u8]) -> Result<Self, ShamirError> {
+        Self::precompute_at(xs, 0)
+    }
+
+    // Basis values at x, so `combine` yields the shares' polynomial there rather than the secret.
+    pub fn precompute_at(xs: &[u8], x: u8) -> Result<Self, ShamirError> {
+        if xs.len() < 2 {
+            return Err(ShamirError::InsufficientShares { provided: xs.len(), required: 2 });
+        }
+        if xs.contains(&0) {
+            return Err(ShamirError::ZeroIndex);
+        }
+        if xs.iter().enumerate().any(|(i, x)| xs[..i].contains(x)) {
+            return Err(ShamirError::DuplicateIndex);
+        }
+        let mut basis = Vec::with_capacity(xs.len());
+        for (i, &x_i) in xs.iter().enumerate() {
+            let mut numerator = 1;
+            let mut denominator = 1;
+            for (j, &x_j) in xs.iter().enumerate() {
+                if i != j {
+                    numerator = mul(numerator, add(x, x_j));
+                    denominator = mul(denominator, add(x_i, x_j));
+                }
+            }
+            basis.push(div(numerator, denominator).ok_or(ShamirError::InconsistentShares)?);
+        }
+        Ok(LagrangeCoefficients { xs: xs.to_vec(), basis })
+    }
+
+    pub fn xs(&self) -> &[u8] {
+        &self.xs
+    }
+
+    // values[i] is the share held at xs()[i].
+    pub fn combine<V: AsRef<[u8]>>(&self, values: &[V]) -> Result<Zeroizing<Vec<u8>>, ShamirError> {
+        if values.len() != self.basis.len() {
+            return Err(ShamirError::InconsistentShares);
+        }
+        let len = values[0].as_ref().len();
+        if values.iter().any(|value| value.as_ref().len() != len) {
+            return Err(ShamirError::InconsistentShares);
+        }
+
+        let mut secret = Zeroizing::new(vec![0u8; len]);
+        for (value, &l) in values.iter().zip(&self.basis) {
+            for (byte, &y) in secret.iter_mut().zip(value.as_ref()) {
+                *byte = add(*byte, mul(y, l));
+            }
+        }
+        Ok(secret)
+    }
+}
+
+pub fn combine(shares: &[(u8, Vec<u8>)]) -> Result<Zeroizing<Vec<u8>>, ShamirError> {
+    let xs: Vec<u8> = shares.iter().map(|&(x, _)| x).collect();
+    let values: Vec<&[u8]> = shares.iter().map(|(_, value)| value.as_slice()).collect();
+    LagrangeCoefficients::precompute(&xs)?.combine(&values)
+}
+
+// The x-coordinates of the shares past the first `threshold` that disagree with the polynomial
+// through those first shares.
+pub fn outliers(shares: &[(u8, Vec<u8>)], threshold: usize) -> Result<Vec<u8>, ShamirError> {
+    let (base, rest) = shares.split_at(threshold.min(shares.len()));
+    let xs: Vec<u8> = base.iter().map(|&(x, _)| x).collect();
+    let values: Vec<&[u8]> = base.iter().map(|(_, value)| value.as_slice()).collect();
+    let mut outliers = Vec::new();
+    for (x, value) in rest {
+        let expected = LagrangeCoefficients::precompute_at(&xs, *x)?.combine(&values)?;
+        if expected.as_slice() != value.as_slice() {
+            outliers.push(*x);
+        }
+    }
+    Ok(outliers)
+}
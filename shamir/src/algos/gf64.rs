@@ -0,0 +1,253 @@
+use alloc::{vec, vec::Vec};
+
+use rand::{CryptoRng, Rng, RngCore};
+use zeroize::Zeroizing;
+
+use super::field::FiniteField;
+use super::gf256;
+use super::polynomial;
+use super::sss::ShamirError;
+
+// GF(2^64) under x^64 + x^4 + x^3 + x + 1, for splitting bulk data a 64-bit word at a time rather
+// than a byte at a time. Multiplication is one carry-less multiply and a shift-and-xor reduction:
+// PCLMULQDQ on x86_64 and PMULL on aarch64 when the CPU has them (detected at runtime with std,
+// from the target features without), otherwise a branch-free portable loop. Every path is constant
+// time, so the `constant-time` feature changes nothing here.
+//
+// Shares use the same `(x, bytes)` form as `gf256` and are as long as the secret: whole 8-byte
+// little-endian words are shared in GF(2^64) and the last `len % 8` bytes in GF(256), so
+// x-coordinates stay within 1..=255.
+
+pub const WORD: usize = 8;
+
+pub fn add(a: u64, b: u64) -> u64 {
+    a ^ b
+}
+
+// The 128-bit carry-less product, one bit at a time under a mask.
+pub fn clmul_portable(a: u64, b: u64) -> u128 {
+    let mut product = 0u128;
+    for i in 0..64 {
+        let mask = 0u128.wrapping_sub(((b >> i) & 1) as u128);
+        product ^= ((a as u128) << i) & mask;
+    }
+    product
+}
+
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "pclmulqdq")]
+fn clmul_hardware(a: u64, b: u64) -> u128 {
+    use core::arch::x86_64::{_mm_clmulepi64_si128, _mm_cvtsi128_si64, _mm_set_epi64x, _mm_unpackhi_epi64};
+    let product = _mm_clmulepi64_si128(_mm_set_epi64x(0, a as i64), _mm_set_epi64x(0, b as i64), 0);
+    let lo = _mm_cvtsi128_si64(product) as u64;
+    let hi = _mm_cvtsi128_si64(_mm_unpackhi_epi64(product, product)) as u64;
+    ((hi as u128) << 64) | lo as u128
+}
+
+#[cfg(target_arch = "aarch64")]
+#[target_feature(enable = "neon,aes")]
+fn clmul_hardware(a: u64, b: u64) -> u128 {
+    core::arch::aarch64::vmull_p64(a, b)
+}
+
+// Whether `mul` is using the CPU's carry-less multiply.
+pub fn hardware_accelerated() -> bool {
+    #[cfg(all(target_arch = "x86_64", feature = "std"))]
+    return std::is_x86_feature_detected!("pclmulqdq");
+    #[cfg(all(target_arch = "x86_64", not(feature = "std")))]
+    return cfg!(target_feature = "pclmulqdq");
+    #[cfg(all(target_arch = "aarch64", feature = "std"))]
+    return std::arch::is_aarch64_feature_detected!("aes");
+    #[cfg(all(target_arch = "aarch64", not(feature = "std")))]
+    return cfg!(target_feature = "aes");
+    #[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64")))]
+    false
+}
+
+pub fn clmul(a: u64, b: u64) -> u128 {
+    #[cfg(any(target_arch = "x86_64", target_arch = "aarch64"))]
+    if hardware_accelerated() {
+        // The feature was just detected.
+        return unsafe { clmul_hardware(a, b) };
+    }
+    clmul_portable(a, b)
+}
+
+// Folds the high word back in twice: hi * x^64 = hi * (x^4 + x^3 + x + 1), and the at most 4 bits
+// that overflow from that are folded the same way, which no longer overflows.
+fn reduce(product: u128) -> u64 {
+    let (hi, lo) = ((product >> 64) as u64, product as u64);
+    let h = hi ^ (hi >> 63) ^ (hi >> 61) ^ (hi >> 60);
+    lo ^ h ^ (h << 1) ^ (h << 3) ^ (h << 4)
+}
+
+pub fn mul(a: u64, b: u64) -> u64 {
+    reduce(clmul(a, b))
+}
+
+// a^(2^64 - 2), by 63 squarings and multiplies.
+pub fn inv(a: u64) -> Option<u64> {
+    if a == 0 {
+        return None;
+    }
+    let mut result = 1;
+    let mut power = a;
+    for _ in 1..64 {
+        power = mul(power, power);
+        result = mul(result, power);
+    }
+    Some(result)
+}
+
+pub fn div(a: u64, b: u64) -> Option<u64> {
+    inv(b).map(|b_inv| mul(a, b_inv))
+}
+
+pub fn evaluate_polynomial(coeffs: &[u64], x: u64) -> u64 {
+    let mut result = 0;
+    for &coeff in coeffs.iter().rev() {
+        result = add(mul(result, x), coeff);
+    }
+    result
+}
+
+// The field for `polynomial` and the other generic schemes.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Gf64;
+
+impl FiniteField for Gf64 {
+    type Element = u64;
+
+    fn zero(&self) -> u64 {
+        0
+    }
+
+    fn one(&self) -> u64 {
+        1
+    }
+
+    fn element(&self, x: u64) -> u64 {
+        x
+    }
+
+    fn add(&self, a: &u64, b: &u64) -> u64 {
+        add(*a, *b)
+    }
+
+    fn sub(&self, a: &u64, b: &u64) -> u64 {
+        add(*a, *b)
+    }
+
+    fn mul(&self, a: &u64, b: &u64) -> u64 {
+        mul(*a, *b)
+    }
+
+    fn inv(&self, a: &u64) -> Option<u64> {
+        inv(*a)
+    }
+
+    fn random(&self, rng: &mut (impl RngCore + CryptoRng)) -> u64 {
+        rng.r#gen()
+    }
+}
+
+fn word(bytes: &[u8]) -> u64 {
+    let mut word = [0u8; WORD];
+    word.copy_from_slice(bytes);
+    u64::from_le_bytes(word)
+}
+
+pub fn split(
+    secret: &[u8],
+    threshold: usize,
+    num_shares: usize,
+    rng: &mut (impl RngCore + CryptoRng),
+) -> Result<Vec<(u8, Vec<u8>)>, ShamirError> {
+    if threshold < 2 {
+        return Err(ShamirError::InvalidThreshold);
+    }
+    if num_shares < threshold || num_shares > 255 {
+        return Err(ShamirError::InvalidShareCount);
+    }
+    let xs: Vec<u8> = (1..=num_shares as u8).collect();
+    split_at(secret, threshold, &xs, rng)
+}
+
+// Shares at the caller's x-coordinates, which must be nonzero and distinct.
+pub fn split_at(
+    secret: &[u8],
+    threshold: usize,
+    xs: &[u8],
+    rng: &mut (impl RngCore + CryptoRng),
+) -> Result<Vec<(u8, Vec<u8>)>, ShamirError> {
+    let body = secret.len() - secret.len() % WORD;
+    // Also does the parameter and x-coordinate checks.
+    let tails = gf256::split_at(&secret[body..], threshold, xs, rng)?;
+
+    let mut shares: Vec<(u8, Vec<u8>)> = xs.iter().map(|&x| (x, Vec::with_capacity(secret.len()))).collect();
+    let mut coeffs = Zeroizing::new(vec![0u64; threshold]);
+    for chunk in secret[..body].chunks_exact(WORD) {
+        coeffs[0] = word(chunk);
+        for coeff in coeffs[1..].iter_mut() {
+            *coeff = rng.r#gen();
+        }
+        for (x, value) in shares.iter_mut() {
+            value.extend_from_slice(&evaluate_polynomial(&coeffs, *x as u64).to_le_bytes());
+        }
+    }
+    for ((_, value), (_, tail)) in shares.iter_mut().zip(tails) {
+        value.extend_from_slice(&tail);
+    }
+    Ok(shares)
+}
+
+// Lagrange basis values at x = 0 for a fixed quorum, in both fields.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LagrangeCoefficients {
+    basis: Vec<u64>,
+    tail: gf256::LagrangeCoefficients,
+}
+
+impl LagrangeCoefficients {
+    pub fn precompute(xs: &[u8]) -> Result<Self, ShamirError> {
+        // Also rejects zero and repeated x-coordinates.
+        let tail = gf256::LagrangeCoefficients::precompute(xs)?;
+        let xs: Vec<u64> = xs.iter().map(|&x| x as u64).collect();
+        let basis = polynomial::lagrange_basis_at_zero(&Gf64, &xs).ok_or(ShamirError::InconsistentShares)?;
+        Ok(LagrangeCoefficients { basis, tail })
+    }
+
+    pub fn xs(&self) -> &[u8] {
+        self.tail.xs()
+    }
+
+    // values[i] is the share held at xs()[i].
+    pub fn combine<V: AsRef<[u8]>>(&self, values: &[V]) -> Result<Zeroizing<Vec<u8>>, ShamirError> {
+        if values.len() != self.basis.len() {
+            return Err(ShamirError::InconsistentShares);
+        }
+        let len = values[0].as_ref().len();
+        if values.iter().any(|value| value.as_ref().len() != len) {
+            return Err(ShamirError::InconsistentShares);
+        }
+
+        let body = len - len % WORD;
+        let mut secret = Zeroizing::new(Vec::with_capacity(len));
+        for offset in (0..body).step_by(WORD) {
+            let mut sum = 0;
+            for (value, &l) in values.iter().zip(&self.basis) {
+                sum = add(sum, mul(word(&value.as_ref()[offset..offset + WORD]), l));
+            }
+            secret.extend_from_slice(&sum.to_le_bytes());
+        }
+        let tails: Vec<&[u8]> = values.iter().map(|value| &value.as_ref()[body..]).collect();
+        secret.extend_from_slice(&self.tail.combine(&tails)?);
+        Ok(secret)
+    }
+}
+
+pub fn combine(shares: &[(u8, Vec<u8>)]) -> Result<Zeroizing<Vec<u8>>, ShamirError> {
+    let xs: Vec<u8> = shares.iter().map(|&(x, _)| x).collect();
+    let values: Vec<&[u8]> = shares.iter().map(|(_, value)| value.as_slice()).collect();
+    LagrangeCoefficients::precompute(&xs)?.combine(&values)
+}
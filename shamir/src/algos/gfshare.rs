@@ -0,0 +1,87 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use rand::{CryptoRng, Rng, RngCore};
+use zeroize::Zeroizing;
+
+use super::gf256;
+use super::sss::ShamirError;
+
+// libgfshare/gfsplit compatibility. gfsplit writes each share to `<name>.NNN`, where NNN is the
+// share's x-coordinate as three decimal digits and the file holds the raw share bytes, one per
+// secret byte. The field (0x11d, secret as the constant term) matches `gf256`, so only the file
+// naming and the choice of x-coordinates differ: gfsplit draws distinct nonzero x at random
+// instead of numbering shares from 1.
+
+fn invalid(err: ShamirError) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, err)
+}
+
+pub fn split(
+    secret: &[u8],
+    threshold: usize,
+    num_shares: usize,
+    rng: &mut (impl RngCore + CryptoRng),
+) -> Result<Vec<(u8, Vec<u8>)>, ShamirError> {
+    if threshold < 2 {
+        return Err(ShamirError::InvalidThreshold);
+    }
+    if num_shares < threshold || num_shares > 255 {
+        return Err(ShamirError::InvalidShareCount);
+    }
+    let mut xs = Vec::with_capacity(num_shares);
+    while xs.len() < num_shares {
+        let x = rng.gen_range(1..=255u8);
+        if !xs.contains(&x) {
+            xs.push(x);
+        }
+    }
+    gf256::split_at(secret, threshold, &xs, rng)
+}
+
+pub fn combine(shares: &[(u8, Vec<u8>)]) -> Result<Zeroizing<Vec<u8>>, ShamirError> {
+    gf256::combine(shares)
+}
+
+pub fn share_path(prefix: &Path, x: u8) -> PathBuf {
+    let mut name = prefix.as_os_str().to_os_string();
+    name.push(format!(".{:03}", x));
+    PathBuf::from(name)
+}
+
+// The x-coordinate encoded in a gfsplit file name, if the name has that shape.
+pub fn parse_share_path(path: &Path) -> Option<u8> {
+    let ext = path.extension()?.to_str()?;
+    if ext.len() != 3 || !ext.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    ext.parse().ok().filter(|&x| x != 0)
+}
+
+pub fn write_shares(prefix: &Path, shares: &[(u8, Vec<u8>)]) -> io::Result<Vec<PathBuf>> {
+    let mut paths = Vec::with_capacity(shares.len());
+    for (x, value) in shares {
+        let path = share_path(prefix, *x);
+        fs::write(&path, value)?;
+        paths.push(path);
+    }
+    Ok(paths)
+}
+
+pub fn read_shares<P: AsRef<Path>>(paths: &[P]) -> io::Result<Vec<(u8, Vec<u8>)>> {
+    paths
+        .iter()
+        .map(|path| {
+            let path = path.as_ref();
+            let x = parse_share_path(path).ok_or_else(|| {
+                io::Error::new(io::ErrorKind::InvalidInput, format!("{} is not a gfshare file name", path.display()))
+            })?;
+            Ok((x, fs::read(path)?))
+        })
+        .collect()
+}
+
+pub fn combine_files<P: AsRef<Path>>(paths: &[P]) -> io::Result<Zeroizing<Vec<u8>>> {
+    combine(&read_shares(paths)?).map_err(invalid)
+}
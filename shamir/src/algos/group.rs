@@ -0,0 +1,232 @@
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+use core::ops::{Add, Deref, Mul, Sub};
+
+use rand::{CryptoRng, Rng, RngCore};
+
+use super::field::FiniteField;
+use super::polynomial;
+use super::share::{self, Share, ShareValue};
+use super::sss;
+use super::vss::{self, VssError};
+
+// A prime-order group for Feldman commitments: shares live in the scalar field and C_k = a_k * G.
+// The backends (`ristretto`, `bls12`) implement this; the dealing logic below is shared.
+pub trait Group {
+    type Scalar: ShareValue
+        + Copy
+        + PartialEq
+        + Add<Output = Self::Scalar>
+        + Sub<Output = Self::Scalar>
+        + Mul<Output = Self::Scalar>;
+    type Point: Copy + PartialEq + Add<Output = Self::Point> + Mul<Self::Scalar, Output = Self::Point>;
+
+    fn scalar_from_u64(x: u64) -> Self::Scalar;
+    fn random_scalar(rng: &mut (impl RngCore + CryptoRng)) -> Self::Scalar;
+    fn invert(scalar: &Self::Scalar) -> Option<Self::Scalar>;
+    fn generator() -> Self::Point;
+    fn identity() -> Self::Point;
+    // The canonical encoding of a point, for hashing it into transcripts.
+    fn encode_point(point: &Self::Point) -> Vec<u8>;
+    // A scalar from 64 uniform bytes, reduced with negligible bias.
+    fn scalar_from_wide(bytes: &[u8; 64]) -> Self::Scalar;
+    // Whether a point from another party is a non-identity element of the prime-order group.
+    fn is_valid_point(point: &Self::Point) -> bool;
+}
+
+// A group's scalar field as a `FiniteField`, so its polynomials go through `polynomial`.
+pub struct ScalarField<G>(PhantomData<G>);
+
+impl<G: Group> ScalarField<G> {
+    pub fn new() -> Self {
+        ScalarField(PhantomData)
+    }
+}
+
+impl<G: Group> Clone for ScalarField<G> {
+    fn clone(&self) -> Self {
+        Self::new()
+    }
+}
+
+impl<G: Group> Default for ScalarField<G> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<G: Group> FiniteField for ScalarField<G> {
+    type Element = G::Scalar;
+
+    fn zero(&self) -> G::Scalar {
+        G::scalar_from_u64(0)
+    }
+
+    fn one(&self) -> G::Scalar {
+        G::scalar_from_u64(1)
+    }
+
+    fn element(&self, x: u64) -> G::Scalar {
+        G::scalar_from_u64(x)
+    }
+
+    fn add(&self, a: &G::Scalar, b: &G::Scalar) -> G::Scalar {
+        *a + *b
+    }
+
+    fn sub(&self, a: &G::Scalar, b: &G::Scalar) -> G::Scalar {
+        *a - *b
+    }
+
+    fn mul(&self, a: &G::Scalar, b: &G::Scalar) -> G::Scalar {
+        *a * *b
+    }
+
+    fn inv(&self, a: &G::Scalar) -> Option<G::Scalar> {
+        G::invert(a)
+    }
+
+    fn random(&self, rng: &mut (impl RngCore + CryptoRng)) -> G::Scalar {
+        G::random_scalar(rng)
+    }
+}
+
+// Feldman commitments C_k = a_k * G in whatever group backs them, written additively. Both the
+// `Group` backends and the Z_p* parameters in `vss::VssConfig` implement this, so committing and
+// checking shares against commitments is written once.
+pub trait Commitment {
+    type Scalar: ShareValue + Clone;
+    type Point: Clone + PartialEq;
+
+    fn commit(&self, scalar: &Self::Scalar) -> Self::Point;
+    fn combine(&self, a: &Self::Point, b: &Self::Point) -> Self::Point;
+    fn scale(&self, point: &Self::Point, scalar: &Self::Scalar) -> Self::Point;
+    fn identity(&self) -> Self::Point;
+    // A share index as a scalar.
+    fn index(&self, x: u64) -> Self::Scalar;
+}
+
+impl<G: Group> Commitment for G {
+    type Scalar = G::Scalar;
+    type Point = G::Point;
+
+    fn commit(&self, scalar: &G::Scalar) -> G::Point {
+        G::generator() * *scalar
+    }
+
+    fn combine(&self, a: &G::Point, b: &G::Point) -> G::Point {
+        *a + *b
+    }
+
+    fn scale(&self, point: &G::Point, scalar: &G::Scalar) -> G::Point {
+        *point * *scalar
+    }
+
+    fn identity(&self) -> G::Point {
+        G::identity()
+    }
+
+    fn index(&self, x: u64) -> G::Scalar {
+        G::scalar_from_u64(x)
+    }
+}
+
+pub fn commit_polynomial<C: Commitment>(scheme: &C, coeffs: &[C::Scalar]) -> Vec<C::Point> {
+    coeffs.iter().map(|coeff| scheme.commit(coeff)).collect()
+}
+
+// The commitment to f(x), from the coefficient commitments alone (Horner in the group).
+pub fn commitment_at<C: Commitment>(scheme: &C, x: u64, commitments: &[C::Point]) -> C::Point {
+    let x = scheme.index(x);
+    commitments
+        .iter()
+        .rev()
+        .fold(scheme.identity(), |acc, commitment| scheme.combine(&scheme.scale(&acc, &x), commitment))
+}
+
+pub fn verify_commitment<C: Commitment>(scheme: &C, share: &Share<C::Scalar>, commitments: &[C::Point]) -> bool {
+    if commitments.len() != share.threshold || share.index == 0 {
+        return false;
+    }
+    scheme.commit(&share.value) == commitment_at(scheme, share.index, commitments)
+}
+
+// Polynomial coefficients, wiped through `ShareValue::wipe` on drop.
+pub struct Coefficients<S: ShareValue>(Vec<S>);
+
+impl<S: ShareValue> Deref for Coefficients<S> {
+    type Target = [S];
+
+    fn deref(&self) -> &[S] {
+        &self.0
+    }
+}
+
+impl<S: ShareValue> Drop for Coefficients<S> {
+    fn drop(&mut self) {
+        for coeff in &mut self.0 {
+            coeff.wipe();
+        }
+    }
+}
+
+pub fn generate_polynomial<G: Group>(
+    secret: G::Scalar,
+    threshold: usize,
+    rng: &mut (impl RngCore + CryptoRng),
+) -> Result<Coefficients<G::Scalar>, VssError> {
+    if threshold < 2 {
+        return Err(VssError::InvalidParameters("threshold"));
+    }
+    Ok(Coefficients(polynomial::generate(&ScalarField::<G>::new(), secret, threshold, rng)))
+}
+
+pub fn generate_shares<G: Group>(
+    coeffs: &[G::Scalar],
+    num_shares: usize,
+    rng: &mut (impl RngCore + CryptoRng),
+) -> Result<Vec<Share<G::Scalar>>, VssError> {
+    if num_shares < coeffs.len() {
+        return Err(VssError::InvalidParameters("num_shares"));
+    }
+    let scheme_id = rng.r#gen();
+    Ok(share::dealt(
+        (1..=num_shares as u64)
+            .map(|x| {
+                let value = polynomial::evaluate(&ScalarField::<G>::new(), coeffs, &G::scalar_from_u64(x));
+                Share::new(x, value, coeffs.len(), scheme_id)
+            })
+            .collect(),
+        rng,
+    ))
+}
+
+pub fn reconstruct_secret<G: Group>(shares: &[Share<G::Scalar>]) -> Result<G::Scalar, VssError> {
+    let threshold = shares.first().map_or(1, |share| share.threshold);
+    if shares.len() < threshold {
+        return Err(VssError::InsufficientShares { provided: shares.len(), required: threshold });
+    }
+    if !share::check_same_dealing(shares) {
+        return Err(VssError::InconsistentShares);
+    }
+    let field = ScalarField::<G>::new();
+    sss::check_field_indices(&field, shares).map_err(vss::index_error)?;
+    let outliers = polynomial::outliers(&field, shares, threshold).ok_or(VssError::DuplicateIndex)?;
+    if !outliers.is_empty() {
+        return Err(VssError::Outliers(outliers));
+    }
+    polynomial::interpolate_at_zero(&field, &shares[..threshold]).ok_or(VssError::DuplicateIndex)
+}
+
+// `reconstruct_secret`, then the secret's commitment against C_0.
+pub fn reconstruct_checked<G: Group>(
+    shares: &[Share<G::Scalar>],
+    commitments: &[G::Point],
+) -> Result<G::Scalar, VssError> {
+    let secret = reconstruct_secret::<G>(shares)?;
+    let c0 = commitments.first().ok_or(VssError::InvalidParameters("commitments"))?;
+    if G::generator() * secret != *c0 {
+        return Err(VssError::ReconstructionMismatch);
+    }
+    Ok(secret)
+}
@@ -0,0 +1,245 @@
+use std::collections::BTreeMap;
+use std::convert::Infallible;
+use std::sync::{Arc, Mutex};
+
+use rand::Rng;
+use tonic::codegen::http::uri::PathAndQuery;
+use tonic::codegen::{Body, BoxFuture, Context, Poll, Service, StdError, http};
+use tonic::server::{NamedService, UnaryService};
+use tokio::net::TcpListener;
+use tonic::transport::server::TcpIncoming;
+use tonic::transport::{Channel, Endpoint, Server};
+use tonic::{Code, Request, Response, Status};
+use tonic_prost::ProstCodec;
+
+use super::proto::{
+    DealRequest, DealResponse, ReconstructRequest, ReconstructResponse, SubmitShareRequest, SubmitShareResponse,
+    VerifyShareRequest, VerifyShareResponse,
+};
+use super::share::Share;
+use super::vss::{self, CommitmentBundle, VssConfig, VssError};
+
+// The shamir.v1.Shamir service from proto/shamir.proto over tonic, written out by hand like the
+// message types in `proto` so the build needs no protoc. The server deals VSS shares and keeps
+// only each ceremony's commitments; holders then submit their shares, each checked on arrival,
+// until Reconstruct has a quorum.
+
+pub const SERVICE_NAME: &str = "shamir.v1.Shamir";
+
+const DEAL: &str = "/shamir.v1.Shamir/Deal";
+const VERIFY_SHARE: &str = "/shamir.v1.Shamir/VerifyShare";
+const SUBMIT_SHARE: &str = "/shamir.v1.Shamir/SubmitShare";
+const RECONSTRUCT: &str = "/shamir.v1.Shamir/Reconstruct";
+
+struct Ceremony {
+    config: VssConfig,
+    commitments: Vec<i128>,
+    shares: Vec<Share<i128>>,
+}
+
+fn invalid(err: VssError) -> Status {
+    Status::invalid_argument(err.to_string())
+}
+
+fn required<T>(message: Option<T>, field: &str) -> Result<T, Status> {
+    message.ok_or_else(|| Status::invalid_argument(format!("missing {}", field)))
+}
+
+fn decode<T, M: TryInto<T>>(message: M, field: &str) -> Result<T, Status> {
+    message.try_into().map_err(|_| Status::invalid_argument(format!("malformed {}", field)))
+}
+
+// The request handlers, independent of the transport.
+#[derive(Default)]
+pub struct ShamirService {
+    ceremonies: Mutex<BTreeMap<String, Ceremony>>,
+}
+
+impl ShamirService {
+    pub fn deal(&self, request: DealRequest) -> Result<DealResponse, Status> {
+        let defaults = VssConfig::default();
+        let config =
+            VssConfig::new(defaults.prime(), defaults.generator(), request.threshold as usize, request.shares as usize)
+                .map_err(invalid)?;
+        let secret = request.secret as i128;
+        if !(0..config.order()).contains(&secret) {
+            return Err(invalid(VssError::InvalidParameters("secret")));
+        }
+
+        let mut rng = rand::thread_rng();
+        let coeffs = vss::generate_polynomial(secret, &config, &mut rng);
+        let shares = vss::generate_shares(&coeffs, &config, &mut rng);
+        let commitments = vss::generate_commitments(&coeffs, &config);
+        let bundle = CommitmentBundle { commitments, verification_key: None };
+        let ceremony: String = (0..16).map(|_| format!("{:02x}", rng.r#gen::<u8>())).collect();
+
+        let response = DealResponse {
+            ceremony: ceremony.clone(),
+            shares: shares.iter().map(Into::into).collect(),
+            bundle: Some((&bundle).try_into().map_err(|_| Status::internal("commitments out of range"))?),
+        };
+        let entry = Ceremony { config, commitments: bundle.commitments, shares: Vec::new() };
+        self.ceremonies.lock().expect("ceremony lock poisoned").insert(ceremony, entry);
+        Ok(response)
+    }
+
+    pub fn verify_share(&self, request: VerifyShareRequest) -> Result<VerifyShareResponse, Status> {
+        let share: Share<i128> = decode(required(request.share, "share")?, "share")?;
+        let bundle: CommitmentBundle = decode(required(request.bundle, "bundle")?, "bundle")?;
+        let defaults = VssConfig::default();
+        let valid = VssConfig::new(defaults.prime(), defaults.generator(), share.threshold, share.threshold)
+            .is_ok_and(|config| vss::verify_share(&share, &bundle.commitments, &config));
+        Ok(VerifyShareResponse { valid })
+    }
+
+    pub fn submit_share(&self, request: SubmitShareRequest) -> Result<SubmitShareResponse, Status> {
+        let share: Share<i128> = decode(required(request.share, "share")?, "share")?;
+        let mut ceremonies = self.ceremonies.lock().expect("ceremony lock poisoned");
+        let ceremony = ceremonies.get_mut(&request.ceremony).ok_or_else(|| Status::not_found("unknown ceremony"))?;
+        if !vss::verify_share(&share, &ceremony.commitments, &ceremony.config) {
+            return Err(invalid(VssError::VerificationFailed { index: share.index }));
+        }
+        if ceremony.shares.iter().any(|other| other.index == share.index) {
+            return Err(Status::already_exists("share already submitted"));
+        }
+        ceremony.shares.push(share);
+        Ok(SubmitShareResponse {
+            received: ceremony.shares.len() as u32,
+            threshold: ceremony.config.threshold() as u32,
+        })
+    }
+
+    pub fn reconstruct(&self, request: ReconstructRequest) -> Result<ReconstructResponse, Status> {
+        let ceremonies = self.ceremonies.lock().expect("ceremony lock poisoned");
+        let ceremony = ceremonies.get(&request.ceremony).ok_or_else(|| Status::not_found("unknown ceremony"))?;
+        let result = vss::reconstruct_verified(&ceremony.shares, &ceremony.commitments, &ceremony.config)
+            .map_err(invalid)?;
+        let secret = result.secret.ok_or_else(|| {
+            Status::failed_precondition(format!(
+                "{} of {} shares submitted",
+                ceremony.shares.len(),
+                ceremony.config.threshold()
+            ))
+        })?;
+        Ok(ReconstructResponse { secret: secret as i64, accepted: result.accepted })
+    }
+}
+
+type Handler<Req, Res> = fn(&ShamirService, Req) -> Result<Res, Status>;
+
+struct Unary<Req, Res> {
+    service: Arc<ShamirService>,
+    handler: Handler<Req, Res>,
+}
+
+impl<Req, Res: Send + 'static> UnaryService<Req> for Unary<Req, Res> {
+    type Response = Res;
+    type Future = BoxFuture<Response<Res>, Status>;
+
+    fn call(&mut self, request: Request<Req>) -> Self::Future {
+        let result = (self.handler)(&self.service, request.into_inner()).map(Response::new);
+        Box::pin(async move { result })
+    }
+}
+
+fn unary<Req, Res, B>(
+    service: Arc<ShamirService>,
+    handler: Handler<Req, Res>,
+    request: http::Request<B>,
+) -> BoxFuture<http::Response<tonic::body::Body>, Infallible>
+where
+    Req: prost::Message + Default + Send + 'static,
+    Res: prost::Message + Send + 'static,
+    B: Body + Send + 'static,
+    B::Error: Into<StdError> + Send + 'static,
+{
+    Box::pin(async move {
+        let mut grpc = tonic::server::Grpc::new(ProstCodec::<Res, Req>::default());
+        Ok(grpc.unary(Unary { service, handler }, request).await)
+    })
+}
+
+// The tonic service; add it to a `tonic::transport::Server` or use `serve`.
+#[derive(Clone, Default)]
+pub struct ShamirServer {
+    inner: Arc<ShamirService>,
+}
+
+impl ShamirServer {
+    pub fn new(service: ShamirService) -> Self {
+        ShamirServer { inner: Arc::new(service) }
+    }
+}
+
+impl<B> Service<http::Request<B>> for ShamirServer
+where
+    B: Body + Send + 'static,
+    B::Error: Into<StdError> + Send + 'static,
+{
+    type Response = http::Response<tonic::body::Body>;
+    type Error = Infallible;
+    type Future = BoxFuture<Self::Response, Infallible>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Infallible>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: http::Request<B>) -> Self::Future {
+        let service = self.inner.clone();
+        match request.uri().path() {
+            DEAL => unary(service, ShamirService::deal, request),
+            VERIFY_SHARE => unary(service, ShamirService::verify_share, request),
+            SUBMIT_SHARE => unary(service, ShamirService::submit_share, request),
+            RECONSTRUCT => unary(service, ShamirService::reconstruct, request),
+            _ => Box::pin(async { Ok(Status::new(Code::Unimplemented, "unknown method").into_http()) }),
+        }
+    }
+}
+
+impl NamedService for ShamirServer {
+    const NAME: &'static str = SERVICE_NAME;
+}
+
+// Serves the service on an already bound listener until the process exits.
+pub async fn serve(listener: TcpListener) -> Result<(), tonic::transport::Error> {
+    Server::builder().add_service(ShamirServer::default()).serve_with_incoming(TcpIncoming::from(listener)).await
+}
+
+pub struct ShamirClient {
+    inner: tonic::client::Grpc<Channel>,
+}
+
+impl ShamirClient {
+    // `endpoint` is a URI such as "http://127.0.0.1:50051".
+    pub async fn connect(endpoint: String) -> Result<Self, tonic::transport::Error> {
+        let channel = Endpoint::from_shared(endpoint)?.connect().await?;
+        Ok(ShamirClient { inner: tonic::client::Grpc::new(channel) })
+    }
+
+    async fn call<Req, Res>(&mut self, path: &'static str, request: Req) -> Result<Res, Status>
+    where
+        Req: prost::Message + Send + 'static,
+        Res: prost::Message + Default + Send + 'static,
+    {
+        self.inner.ready().await.map_err(|err| Status::unavailable(err.to_string()))?;
+        let codec = ProstCodec::<Req, Res>::default();
+        let response = self.inner.unary(Request::new(request), PathAndQuery::from_static(path), codec).await?;
+        Ok(response.into_inner())
+    }
+
+    pub async fn deal(&mut self, request: DealRequest) -> Result<DealResponse, Status> {
+        self.call(DEAL, request).await
+    }
+
+    pub async fn verify_share(&mut self, request: VerifyShareRequest) -> Result<VerifyShareResponse, Status> {
+        self.call(VERIFY_SHARE, request).await
+    }
+
+    pub async fn submit_share(&mut self, request: SubmitShareRequest) -> Result<SubmitShareResponse, Status> {
+        self.call(SUBMIT_SHARE, request).await
+    }
+
+    pub async fn reconstruct(&mut self, request: ReconstructRequest) -> Result<ReconstructResponse, Status> {
+        self.call(RECONSTRUCT, request).await
+    }
+}
@@ -0,0 +1,119 @@
+use alloc::{vec, vec::Vec};
+
+use rand::{CryptoRng, Rng, RngCore};
+
+use super::robust;
+use super::share::{self, Share};
+use super::sss::{self, ShamirError, PRIME};
+
+// Tassa's conjunctive hierarchical sharing. `thresholds` are cumulative and strictly increasing:
+// a set of shares is authorized when, for every level j, at least thresholds[j] of them come from
+// levels 0..=j. The last entry is the overall threshold.
+
+fn check_thresholds(thresholds: &[usize]) -> Result<usize, ShamirError> {
+    let last = *thresholds.last().ok_or(ShamirError::InvalidThreshold)?;
+    if thresholds[0] == 0 || last < 2 || thresholds.windows(2).any(|pair| pair[0] >= pair[1]) {
+        return Err(ShamirError::InvalidThreshold);
+    }
+    Ok(last)
+}
+
+// Order of the derivative of the dealer polynomial handed out at a level.
+fn derivative_order(thresholds: &[usize], level: usize) -> usize {
+    if level == 0 { 0 } else { thresholds[level - 1] }
+}
+
+// Coefficients of f^(d)(x) with respect to a_0..a_{k-1}: l! / (l - d)! * x^(l - d) for l >= d.
+fn derivative_row(x: u64, order: usize, degree: usize) -> Vec<u64> {
+    let mut row = vec![0; degree];
+    let mut power = 1;
+    for (l, entry) in row.iter_mut().enumerate().skip(order) {
+        let falling = ((l - order + 1)..=l).fold(1, |acc, m| acc * m as u64 % PRIME);
+        *entry = falling * power % PRIME;
+        power = power * x % PRIME;
+    }
+    row
+}
+
+pub fn generate_shares(
+    secret: u64,
+    thresholds: &[usize],
+    counts: &[usize],
+    rng: &mut (impl RngCore + CryptoRng),
+) -> Result<Vec<Share>, ShamirError> {
+    let threshold = check_thresholds(thresholds)?;
+    if counts.len() != thresholds.len() {
+        return Err(ShamirError::InvalidParameters("counts"));
+    }
+    let mut available = 0;
+    for (&count, &threshold) in counts.iter().zip(thresholds) {
+        available += count;
+        if available < threshold {
+            return Err(ShamirError::InvalidShareCount);
+        }
+    }
+    if secret >= PRIME {
+        return Err(ShamirError::SecretOutOfRange);
+    }
+
+    let coeffs = sss::generate_polynomial(secret, threshold, rng)?;
+    let scheme_id = rng.r#gen();
+    // Higher levels get larger indices, as Tassa's non-singularity argument assumes.
+    let mut shares = Vec::with_capacity(available);
+    let mut x = 0;
+    for (level, &count) in counts.iter().enumerate() {
+        let order = derivative_order(thresholds, level);
+        for _ in 0..count {
+            x += 1;
+            let row = derivative_row(x, order, threshold);
+            let value = row.iter().zip(coeffs.iter()).fold(0, |acc, (&r, &a)| (acc + r * a) % PRIME);
+            let mut share = Share::new(x, value, threshold, scheme_id);
+            share.level = level as u32;
+            shares.push(share);
+        }
+    }
+    Ok(share::dealt(shares, rng))
+}
+
+pub fn is_authorized(shares: &[Share], thresholds: &[usize]) -> bool {
+    thresholds.iter().enumerate().all(|(level, &threshold)| {
+        shares.iter().filter(|share| share.level as usize <= level).count() >= threshold
+    })
+}
+
+// Birkhoff interpolation: solve for the polynomial coefficients from derivative values.
+pub fn reconstruct_secret(shares: &[Share], thresholds: &[usize]) -> Result<u64, ShamirError> {
+    let threshold = check_thresholds(thresholds)?;
+    if shares.is_empty() {
+        return Err(ShamirError::InsufficientShares { provided: 0, required: threshold });
+    }
+    if shares[0].threshold != threshold || !share::check_same_dealing(shares) {
+        return Err(ShamirError::InconsistentShares);
+    }
+    for (i, share) in shares.iter().enumerate() {
+        if share.level as usize >= thresholds.len()
+            || share.index == 0
+            || shares[..i].iter().any(|other| other.index == share.index)
+        {
+            return Err(ShamirError::InconsistentShares);
+        }
+    }
+    if !is_authorized(shares, thresholds) {
+        return Err(ShamirError::InsufficientShares { provided: shares.len(), required: threshold });
+    }
+
+    let rows = shares
+        .iter()
+        .map(|share| {
+            let order = derivative_order(thresholds, share.level as usize);
+            let mut row = derivative_row(share.index % PRIME, order, threshold);
+            row.push(share.value % PRIME);
+            row
+        })
+        .collect();
+    let (coeffs, rank) = robust::solve(rows, threshold).ok_or(ShamirError::InconsistentShares)?;
+    if rank < threshold {
+        return Err(ShamirError::InsufficientShares { provided: rank, required: threshold });
+    }
+    Ok(coeffs[0])
+}
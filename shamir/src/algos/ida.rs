@@ -0,0 +1,25 @@
+use alloc::vec::Vec;
+
+use rand::{CryptoRng, RngCore};
+use zeroize::Zeroizing;
+
+use super::ramp;
+use super::share::Share;
+use super::sss::ShamirError;
+
+// Rabin's information dispersal: any threshold of the n fragments rebuild the data and each
+// fragment is about 1 / threshold of its size. Fragments hide nothing about the data, so this
+// is only for payloads that are already encrypted (see `krawczyk`).
+
+pub fn split(
+    data: &[u8],
+    threshold: usize,
+    num_shares: usize,
+    rng: &mut (impl RngCore + CryptoRng),
+) -> Result<Vec<Share<Vec<u8>>>, ShamirError> {
+    ramp::disperse(data, 0, threshold, num_shares, rng)
+}
+
+pub fn combine(fragments: &[Share<Vec<u8>>]) -> Result<Zeroizing<Vec<u8>>, ShamirError> {
+    ramp::recover(fragments, 0)
+}
@@ -0,0 +1,106 @@
+use keyring::{Entry, Error};
+use zeroize::Zeroizing;
+
+use super::share::{Share, ShareValue};
+use super::store::ShareStore;
+
+// A `ShareStore` in the operating system's credential store, through the keyring crate: macOS
+// Keychain, Windows Credential Manager, or libsecret. Each share is one credential under the
+// store's service name, with account `<dealing as 8 hex digits>/<index>` and the share's text form
+// as the secret. Credential stores can't be listed portably, so a second credential per dealing,
+// account `<dealing>`, keeps its indices.
+pub struct KeychainStore {
+    service: String,
+}
+
+pub const DEFAULT_SERVICE: &str = "shamir";
+
+impl Default for KeychainStore {
+    fn default() -> Self {
+        Self::new(DEFAULT_SERVICE)
+    }
+}
+
+impl KeychainStore {
+    pub fn new(service: &str) -> Self {
+        KeychainStore { service: service.into() }
+    }
+
+    fn share_entry(&self, dealing: u32, index: u64) -> Result<Entry, Error> {
+        Entry::new(&self.service, &format!("{:08x}/{}", dealing, index))
+    }
+
+    fn index_entry(&self, dealing: u32) -> Result<Entry, Error> {
+        Entry::new(&self.service, &format!("{:08x}", dealing))
+    }
+
+    fn write_index(&self, dealing: u32, indices: &[u64]) -> Result<(), Error> {
+        let entry = self.index_entry(dealing)?;
+        if indices.is_empty() {
+            return absent_ok(entry.delete_credential());
+        }
+        let list: Vec<String> = indices.iter().map(u64::to_string).collect();
+        entry.set_password(&list.join(","))
+    }
+}
+
+// Deleting what was never stored is not a failure.
+fn absent_ok(result: Result<(), Error>) -> Result<(), Error> {
+    match result {
+        Err(Error::NoEntry) => Ok(()),
+        result => result,
+    }
+}
+
+impl<V: ShareValue> ShareStore<V> for KeychainStore {
+    type Error = Error;
+
+    fn put(&mut self, share: &Share<V>) -> Result<(), Error> {
+        let text = Zeroizing::new(share.to_string());
+        self.share_entry(share.scheme_id, share.index)?.set_password(&text)?;
+        let mut indices = ShareStore::<V>::list(self, share.scheme_id)?;
+        if let Err(at) = indices.binary_search(&share.index) {
+            indices.insert(at, share.index);
+            self.write_index(share.scheme_id, &indices)?;
+        }
+        Ok(())
+    }
+
+    fn get(&self, dealing: u32, index: u64) -> Result<Option<Share<V>>, Error> {
+        let text = match self.share_entry(dealing, index)?.get_password() {
+            Ok(text) => Zeroizing::new(text),
+            Err(Error::NoEntry) => return Ok(None),
+            Err(err) => return Err(err),
+        };
+        let share: Share<V> = text.parse().map_err(|err| Error::Invalid("share".into(), format!("{:?}", err)))?;
+        if share.scheme_id != dealing || share.index != index {
+            return Err(Error::Invalid("share".into(), "stored under the wrong account".into()));
+        }
+        Ok(Some(share))
+    }
+
+    fn list(&self, dealing: u32) -> Result<Vec<u64>, Error> {
+        let list = match self.index_entry(dealing)?.get_password() {
+            Ok(list) => list,
+            Err(Error::NoEntry) => return Ok(Vec::new()),
+            Err(err) => return Err(err),
+        };
+        list.split(',')
+            .map(|index| index.parse().map_err(|_| Error::Invalid("index".into(), list.clone())))
+            .collect()
+    }
+
+    fn delete(&mut self, dealing: u32, index: u64) -> Result<bool, Error> {
+        let deleted = match self.share_entry(dealing, index)?.delete_credential() {
+            Ok(()) => true,
+            Err(Error::NoEntry) => false,
+            Err(err) => return Err(err),
+        };
+        let mut indices = ShareStore::<V>::list(self, dealing)?;
+        if let Ok(at) = indices.binary_search(&index) {
+            indices.remove(at);
+            self.write_index(dealing, &indices)?;
+        }
+        Ok(deleted)
+    }
+}
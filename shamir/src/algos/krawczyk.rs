@@ -0,0 +1,79 @@
+use alloc::vec::Vec;
+
+use chacha20poly1305::aead::{Aead, KeyInit};
+use chacha20poly1305::{ChaCha20Poly1305, Key, Nonce};
+use rand::{CryptoRng, Rng, RngCore};
+use zeroize::Zeroizing;
+
+use super::ida;
+use super::share::{self, Share};
+use super::sss::{self, ShamirError};
+
+// Krawczyk's computational secret sharing: encrypt the payload under a fresh ChaCha20-Poly1305
+// key, Shamir-share only the 32-byte key and disperse nonce || ciphertext with `ida`. Each
+// holder stores about len / threshold bytes instead of a full copy.
+
+const KEY_LEN: usize = 32;
+const NONCE_LEN: usize = 12;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct KrawczykShare {
+    pub key: Share<Vec<u8>>,
+    pub fragment: Share<Vec<u8>>,
+}
+
+pub fn split(
+    secret: &[u8],
+    threshold: usize,
+    num_shares: usize,
+    rng: &mut (impl RngCore + CryptoRng),
+) -> Result<Vec<KrawczykShare>, ShamirError> {
+    let mut key = Zeroizing::new([0u8; KEY_LEN]);
+    rng.fill(&mut key[..]);
+    let mut payload = Vec::with_capacity(NONCE_LEN + secret.len() + 16);
+    payload.resize(NONCE_LEN, 0);
+    rng.fill(&mut payload[..]);
+
+    let cipher = ChaCha20Poly1305::new(Key::from_slice(&key[..]));
+    let ciphertext = cipher
+        .encrypt(Nonce::from_slice(&payload), secret)
+        .map_err(|_| ShamirError::SecretOutOfRange)?;
+    payload.extend_from_slice(&ciphertext);
+
+    let keys = sss::split_bytes(&key[..], threshold, num_shares, rng)?;
+    let fragments = ida::split(&payload, threshold, num_shares, rng)?;
+    Ok(keys
+        .into_iter()
+        .zip(fragments)
+        .map(|(key, mut fragment)| {
+            fragment.scheme_id = key.scheme_id;
+            KrawczykShare { key, fragment }
+        })
+        .collect())
+}
+
+pub fn combine(shares: &[KrawczykShare]) -> Result<Zeroizing<Vec<u8>>, ShamirError> {
+    let keys: Vec<Share<Vec<u8>>> = shares.iter().map(|share| share.key.clone()).collect();
+    let fragments: Vec<Share<Vec<u8>>> = shares.iter().map(|share| share.fragment.clone()).collect();
+    if shares.iter().any(|share| share.key.scheme_id != share.fragment.scheme_id || share.key.index != share.fragment.index)
+        || !share::check_same_dealing(&fragments)
+    {
+        return Err(ShamirError::InconsistentShares);
+    }
+
+    let key = sss::combine_bytes(&keys)?;
+    if key.len() != KEY_LEN {
+        return Err(ShamirError::InconsistentShares);
+    }
+    let payload = ida::combine(&fragments)?;
+    if payload.len() < NONCE_LEN {
+        return Err(ShamirError::InconsistentShares);
+    }
+    let (nonce, ciphertext) = payload.split_at(NONCE_LEN);
+    let cipher = ChaCha20Poly1305::new(Key::from_slice(&key));
+    cipher
+        .decrypt(Nonce::from_slice(nonce), ciphertext)
+        .map(Zeroizing::new)
+        .map_err(|_| ShamirError::DigestMismatch)
+}
@@ -0,0 +1,123 @@
+use std::sync::OnceLock;
+
+use sha2::{Digest, Sha256};
+
+use super::share::Share;
+use super::sss::ShamirError;
+
+const WORDLIST: &str = include_str!("wordlists/bip39_english.txt");
+const RADIX_BITS: usize = 11;
+const MAX_WORD: usize = (1 << RADIX_BITS) - 1;
+const CHECKSUM_WORDS: usize = 2;
+// index, threshold, three words of scheme id, value length. There are no words for the dealing
+// size or fingerprint, so decoded shares have both zero (unknown).
+const HEADER_WORDS: usize = 6;
+
+fn words() -> &'static Vec<&'static str> {
+    static WORDS: OnceLock<Vec<&'static str>> = OnceLock::new();
+    WORDS.get_or_init(|| WORDLIST.lines().collect())
+}
+
+// BIP-39 words are unique in their first four letters, so a four-letter prefix is accepted too.
+fn word_index(word: &str) -> Option<u16> {
+    let word = word.to_lowercase();
+    let list = words();
+    if let Ok(i) = list.binary_search(&word.as_str()) {
+        return Some(i as u16);
+    }
+    if word.len() != 4 {
+        return None;
+    }
+    let start = list.partition_point(|w| *w < word.as_str());
+    list.get(start)
+        .filter(|w| w.starts_with(&word))
+        .map(|_| start as u16)
+}
+
+fn checksum(data: &[u16]) -> [u16; CHECKSUM_WORDS] {
+    let mut hasher = Sha256::new();
+    for word in data {
+        hasher.update(word.to_be_bytes());
+    }
+    let digest = hasher.finalize();
+    let bits = u32::from_be_bytes([digest[0], digest[1], digest[2], digest[3]]);
+    [(bits >> 21) as u16, ((bits >> 10) & MAX_WORD as u32) as u16]
+}
+
+pub fn to_mnemonic(share: &Share<Vec<u8>>) -> Result<String, ShamirError> {
+    if share.epoch != 0
+        || share.level != 0
+        || share.index == 0
+        || share.index > MAX_WORD as u64
+        || share.threshold > MAX_WORD
+        || share.value.len() > MAX_WORD
+    {
+        return Err(ShamirError::InvalidParameters("share"));
+    }
+
+    let value_words = (share.value.len() * 8).div_ceil(RADIX_BITS);
+    let mut data: Vec<u16> = Vec::with_capacity(HEADER_WORDS + value_words + CHECKSUM_WORDS);
+    data.push(share.index as u16);
+    data.push(share.threshold as u16);
+    let id = share.scheme_id as u64;
+    data.extend([(id >> 22) as u16, ((id >> 11) & MAX_WORD as u64) as u16, (id & MAX_WORD as u64) as u16]);
+    data.push(share.value.len() as u16);
+
+    let mut acc: u32 = 0;
+    let mut bits = 0;
+    for &byte in &share.value {
+        acc = (acc << 8) | byte as u32;
+        bits += 8;
+        if bits >= RADIX_BITS {
+            bits -= RADIX_BITS;
+            data.push(((acc >> bits) & MAX_WORD as u32) as u16);
+        }
+    }
+    if bits > 0 {
+        data.push(((acc << (RADIX_BITS - bits)) & MAX_WORD as u32) as u16);
+    }
+
+    let checksum = checksum(&data);
+    data.extend_from_slice(&checksum);
+    Ok(data.iter().map(|&i| words()[i as usize]).collect::<Vec<_>>().join(" "))
+}
+
+pub fn from_mnemonic(mnemonic: &str) -> Result<Share<Vec<u8>>, ShamirError> {
+    let data = mnemonic
+        .split_whitespace()
+        .map(word_index)
+        .collect::<Option<Vec<u16>>>()
+        .ok_or(ShamirError::InvalidMnemonic)?;
+    if data.len() < HEADER_WORDS + CHECKSUM_WORDS {
+        return Err(ShamirError::InvalidMnemonic);
+    }
+    let (body, check) = data.split_at(data.len() - CHECKSUM_WORDS);
+    if checksum(body) != check {
+        return Err(ShamirError::InvalidChecksum);
+    }
+
+    let len = body[5] as usize;
+    let payload = &body[HEADER_WORDS..];
+    if payload.len() != (len * 8).div_ceil(RADIX_BITS) {
+        return Err(ShamirError::InvalidMnemonic);
+    }
+    let mut value = Vec::with_capacity(len);
+    let mut acc: u32 = 0;
+    let mut bits = 0;
+    for &word in payload {
+        acc = (acc << RADIX_BITS) | word as u32;
+        bits += RADIX_BITS;
+        while bits >= 8 && value.len() < len {
+            bits -= 8;
+            value.push((acc >> bits) as u8);
+            acc &= (1 << bits) - 1;
+        }
+    }
+    if acc != 0 {
+        return Err(ShamirError::InvalidMnemonic);
+    }
+
+    let scheme_id = ((body[2] as u64) << 22) | ((body[3] as u64) << 11) | body[4] as u64;
+    let scheme_id = u32::try_from(scheme_id).map_err(|_| ShamirError::InvalidMnemonic)?;
+    Ok(Share::new(body[0] as u64, value, body[1] as usize, scheme_id))
+}
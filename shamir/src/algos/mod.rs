@@ -1,2 +1,83 @@
+pub mod additive;
+#[cfg(feature = "age")]
+pub mod age;
+pub mod audit;
+pub mod auth;
+pub mod bech32;
+pub mod bigint;
+#[cfg(feature = "bls12-381")]
+pub mod bls;
+#[cfg(feature = "bls12-381")]
+pub mod bls12;
+pub mod cbor;
+pub mod complaint;
+pub mod ct;
+pub mod dealer;
+pub mod dleq;
+pub mod dkg;
+#[cfg(feature = "ed25519")]
+pub mod ed25519;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+pub mod field;
+pub mod frost;
+pub mod gf256;
+pub mod gf64;
+#[cfg(feature = "std")]
+pub mod gfshare;
+pub mod group;
+#[cfg(feature = "grpc")]
+pub mod grpc;
+pub mod hierarchical;
+pub mod ida;
+#[cfg(feature = "keyring")]
+pub mod keychain;
+pub mod krawczyk;
+#[cfg(feature = "std")]
+pub mod mnemonic;
+pub mod montgomery;
+pub mod multi;
+pub mod multipoint;
+#[cfg(feature = "net")]
+pub mod net;
+pub mod params;
+#[cfg(feature = "argon2")]
+pub mod passphrase;
+pub mod pedersen;
+pub mod pok;
+pub mod polynomial;
+#[cfg(feature = "protobuf")]
+pub mod proto;
+#[cfg(feature = "qr")]
+pub mod qr;
+pub mod ramp;
+pub mod refresh;
+pub mod repair;
+pub mod reshare;
+#[cfg(feature = "rest")]
+pub mod rest;
+#[cfg(feature = "ristretto")]
+pub mod ristretto;
+pub mod robust;
+pub mod secp256k1;
+pub mod session;
+pub mod share;
+#[cfg(feature = "json")]
+pub mod sharefile;
+#[cfg(feature = "std")]
+pub mod sim;
+#[cfg(feature = "std")]
+pub mod slip39;
 pub mod sss;
-pub mod vss;
\ No newline at end of file
+pub mod ssss;
+pub mod store;
+#[cfg(feature = "std")]
+pub mod stream;
+#[cfg(feature = "json")]
+pub mod unseal;
+pub mod vss;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+pub mod weighted;
+pub mod wif;
+pub mod wire;
@@ -0,0 +1,268 @@
+use alloc::{vec, vec::Vec};
+
+use num_bigint::BigUint;
+use num_traits::One;
+use zeroize::Zeroize;
+
+use super::sss::ShamirError;
+
+// Montgomery arithmetic modulo an odd BigUint, on little-endian 64-bit limbs: a residue a is kept
+// as a * R mod m with R = 2^(64 * limbs), so a product needs a word-by-word reduction (CIOS)
+// instead of a long division. Converting in and out costs a multiplication each, so this pays off
+// where one conversion is amortised over many operations, Horner evaluation at many points and
+// Lagrange interpolation, and more so as the threshold grows; at a handful of coefficients the
+// conversions cost about what they save (see the `montgomery` bench). Multiplication, addition
+// and subtraction of residues do the same work whatever the values, ending in a masked rather
+// than branching final subtraction; converting in and out and `inv` go through BigUint, whose
+// reduction and `modinv` are not constant time.
+// (`BigUint::modpow` already uses Montgomery multiplication for odd moduli, so exponentiation
+// needs nothing from here.)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Montgomery {
+    modulus: BigUint,
+    limbs: Vec<u64>,
+    // -m^-1 mod 2^64.
+    m_prime: u64,
+    // R^2 mod m, for converting in.
+    r2: Vec<u64>,
+}
+
+// A value in Montgomery form, for the context that made it. Wiped on drop.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Residue(Vec<u64>);
+
+impl Drop for Residue {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+fn to_limbs(value: &BigUint, len: usize) -> Vec<u64> {
+    let mut limbs = value.to_u64_digits();
+    limbs.resize(len, 0);
+    limbs
+}
+
+// out = a - b over the limbs, returning the borrow.
+fn sub_limbs(out: &mut [u64], a: &[u64], b: &[u64]) -> u64 {
+    let mut borrow = 0;
+    for ((out, &a), &b) in out.iter_mut().zip(a).zip(b) {
+        let (diff, b1) = a.overflowing_sub(b);
+        let (diff, b2) = diff.overflowing_sub(borrow);
+        *out = diff;
+        borrow = (b1 | b2) as u64;
+    }
+    borrow
+}
+
+// dst = src where take, in constant time.
+fn select(dst: &mut [u64], src: &[u64], take: bool) {
+    let mask = 0u64.wrapping_sub(take as u64);
+    for (d, &s) in dst.iter_mut().zip(src) {
+        *d = (s & mask) | (*d & !mask);
+    }
+}
+
+// a += b & mask over the limbs, returning the carry.
+fn add_masked(a: &mut [u64], b: &[u64], mask: u64) -> u64 {
+    let mut carry = 0;
+    for (a, &b) in a.iter_mut().zip(b) {
+        let sum = *a as u128 + (b & mask) as u128 + carry as u128;
+        *a = sum as u64;
+        carry = (sum >> 64) as u64;
+    }
+    carry
+}
+
+impl Montgomery {
+    pub fn new(modulus: &BigUint) -> Result<Self, ShamirError> {
+        if modulus <= &BigUint::one() || !modulus.bit(0) {
+            return Err(ShamirError::InvalidModulus);
+        }
+        let limbs = modulus.to_u64_digits();
+        // Newton's iteration doubles the correct low bits of m^-1 mod 2^64 each round: 1, 2, ..., 64.
+        let mut inv: u64 = 1;
+        for _ in 0..6 {
+            inv = inv.wrapping_mul(2u64.wrapping_sub(limbs[0].wrapping_mul(inv)));
+        }
+        let r2 = (BigUint::one() << (128 * limbs.len())) % modulus;
+        Ok(Montgomery { modulus: modulus.clone(), r2: to_limbs(&r2, limbs.len()), m_prime: inv.wrapping_neg(), limbs })
+    }
+
+    pub fn modulus(&self) -> &BigUint {
+        &self.modulus
+    }
+
+    // out = a * b * R^-1 mod m, for a and b below m, with t as n + 2 limbs of scratch. Writing into
+    // caller buffers keeps the loops below free of allocations.
+    fn mul_into(&self, out: &mut [u64], a: &[u64], b: &[u64], t: &mut [u64]) {
+        let m = &self.limbs;
+        let n = m.len();
+        t.fill(0);
+        for &b_i in b {
+            // t += a * b_i
+            let mut carry = 0u64;
+            for (t_j, &a_j) in t.iter_mut().zip(a) {
+                let sum = *t_j as u128 + a_j as u128 * b_i as u128 + carry as u128;
+                *t_j = sum as u64;
+                carry = (sum >> 64) as u64;
+            }
+            let sum = t[n] as u128 + carry as u128;
+            t[n] = sum as u64;
+            t[n + 1] = (sum >> 64) as u64;
+
+            // t = (t + u * m) / 2^64, with u chosen to clear the low limb.
+            let u = t[0].wrapping_mul(self.m_prime);
+            let mut carry = ((t[0] as u128 + u as u128 * m[0] as u128) >> 64) as u64;
+            for j in 1..n {
+                let sum = t[j] as u128 + u as u128 * m[j] as u128 + carry as u128;
+                t[j - 1] = sum as u64;
+                carry = (sum >> 64) as u64;
+            }
+            let sum = t[n] as u128 + carry as u128;
+            t[n - 1] = sum as u64;
+            t[n] = t[n + 1] + (sum >> 64) as u64;
+        }
+        // t < 2m; subtract m unless that borrows past t's top limb.
+        let borrow = sub_limbs(out, &t[..n], m);
+        select(out, &t[..n], borrow > t[n]);
+    }
+
+    // a = a + b mod m, with t as n limbs of scratch.
+    fn add_into(&self, a: &mut [u64], b: &[u64], t: &mut [u64]) {
+        let carry = add_masked(a, b, u64::MAX);
+        let borrow = sub_limbs(t, a, &self.limbs);
+        // The sum stays unreduced only if it's below m: subtracting borrowed and the sum hadn't overflowed.
+        select(t, a, borrow > carry);
+        a.copy_from_slice(t);
+    }
+
+    // a = a - b mod m.
+    fn sub_into(&self, a: &mut [u64], b: &[u64], t: &mut [u64]) {
+        t.copy_from_slice(a);
+        let borrow = sub_limbs(a, t, b);
+        add_masked(a, &self.limbs, 0u64.wrapping_sub(borrow));
+    }
+
+    fn mul_limbs(&self, a: &[u64], b: &[u64]) -> Vec<u64> {
+        let n = self.limbs.len();
+        let mut t = vec![0u64; n + 2];
+        let mut out = vec![0u64; n];
+        self.mul_into(&mut out, a, b, &mut t);
+        t.zeroize();
+        out
+    }
+
+    pub fn to_montgomery(&self, a: &BigUint) -> Residue {
+        let mut limbs = to_limbs(&(a % &self.modulus), self.limbs.len());
+        let residue = Residue(self.mul_limbs(&limbs, &self.r2));
+        limbs.zeroize();
+        residue
+    }
+
+    pub fn from_montgomery(&self, a: &Residue) -> BigUint {
+        let mut one = vec![0u64; self.limbs.len()];
+        one[0] = 1;
+        let mut limbs = self.mul_limbs(&a.0, &one);
+        let digits: Vec<u32> = limbs.iter().flat_map(|&limb| [limb as u32, (limb >> 32) as u32]).collect();
+        limbs.zeroize();
+        BigUint::new(digits)
+    }
+
+    pub fn mul(&self, a: &Residue, b: &Residue) -> Residue {
+        Residue(self.mul_limbs(&a.0, &b.0))
+    }
+
+    pub fn add(&self, a: &Residue, b: &Residue) -> Residue {
+        let mut sum = a.clone();
+        let mut t = vec![0u64; self.limbs.len()];
+        self.add_into(&mut sum.0, &b.0, &mut t);
+        t.zeroize();
+        sum
+    }
+
+    pub fn sub(&self, a: &Residue, b: &Residue) -> Residue {
+        let mut diff = a.clone();
+        let mut t = vec![0u64; self.limbs.len()];
+        self.sub_into(&mut diff.0, &b.0, &mut t);
+        t.zeroize();
+        diff
+    }
+
+    // None for zero. Goes out through BigUint::modinv, so it isn't constant time.
+    pub fn inv(&self, a: &Residue) -> Option<Residue> {
+        let inverse = self.from_montgomery(a).modinv(&self.modulus)?;
+        Some(self.to_montgomery(&inverse))
+    }
+
+    // Every inverse for the one `inv`, as `polynomial::batch_inverse` does it. None if any is zero.
+    pub fn batch_inv(&self, values: &[Residue]) -> Option<Vec<Residue>> {
+        let mut prefixes = Vec::with_capacity(values.len());
+        let mut product = self.to_montgomery(&BigUint::one());
+        for value in values {
+            let next = self.mul(&product, value);
+            prefixes.push(core::mem::replace(&mut product, next));
+        }
+        let mut inverse = self.inv(&product)?;
+        let mut inverses: Vec<Residue> = prefixes.iter().map(|_| Residue(vec![0u64; self.limbs.len()])).collect();
+        for ((slot, prefix), value) in inverses.iter_mut().zip(&prefixes).zip(values).rev() {
+            *slot = self.mul(&inverse, prefix);
+            inverse = self.mul(&inverse, value);
+        }
+        Some(inverses)
+    }
+
+    pub fn to_montgomery_all(&self, values: &[BigUint]) -> Vec<Residue> {
+        values.iter().map(|value| self.to_montgomery(value)).collect()
+    }
+
+    // Horner's rule over coefficients already in Montgomery form, so evaluating at many points
+    // converts them once.
+    pub fn evaluate(&self, coeffs: &[Residue], x: &BigUint) -> BigUint {
+        let n = self.limbs.len();
+        let x = self.to_montgomery(x);
+        let (mut result, mut product) = (Residue(vec![0u64; n]), Residue(vec![0u64; n]));
+        let mut t = Residue(vec![0u64; n + 2]);
+        for coeff in coeffs.iter().rev() {
+            self.mul_into(&mut product.0, &result.0, &x.0, &mut t.0);
+            self.add_into(&mut product.0, &coeff.0, &mut t.0[..n]);
+            core::mem::swap(&mut result, &mut product);
+        }
+        self.from_montgomery(&result)
+    }
+
+    // The polynomial through the points, at zero. None if two points share an x-coordinate.
+    pub fn interpolate_at_zero(&self, points: &[(BigUint, BigUint)]) -> Option<BigUint> {
+        let n = self.limbs.len();
+        let xs: Vec<Residue> = points.iter().map(|(x, _)| self.to_montgomery(x)).collect();
+        let one = self.to_montgomery(&BigUint::one());
+        let (mut product, mut diff) = (Residue(vec![0u64; n]), Residue(vec![0u64; n]));
+        let mut t = Residue(vec![0u64; n + 2]);
+        let mut numerators = Vec::with_capacity(points.len());
+        let mut denominators = Vec::with_capacity(points.len());
+        for (j, x_j) in xs.iter().enumerate() {
+            // l_j(0) = prod x_m / (x_m - x_j) over m != j.
+            let (mut numerator, mut denominator) = (one.clone(), one.clone());
+            for (m, x_m) in xs.iter().enumerate() {
+                if m != j {
+                    self.mul_into(&mut product.0, &numerator.0, &x_m.0, &mut t.0);
+                    core::mem::swap(&mut numerator, &mut product);
+                    diff.0.copy_from_slice(&x_m.0);
+                    self.sub_into(&mut diff.0, &x_j.0, &mut t.0[..n]);
+                    self.mul_into(&mut product.0, &denominator.0, &diff.0, &mut t.0);
+                    core::mem::swap(&mut denominator, &mut product);
+                }
+            }
+            numerators.push(numerator);
+            denominators.push(denominator);
+        }
+        let mut secret = Residue(vec![0u64; n]);
+        let inverses = self.batch_inv(&denominators)?;
+        for (((_, y), numerator), inverse) in points.iter().zip(&numerators).zip(&inverses) {
+            self.mul_into(&mut diff.0, &numerator.0, &inverse.0, &mut t.0);
+            self.mul_into(&mut product.0, &self.to_montgomery(y).0, &diff.0, &mut t.0);
+            self.add_into(&mut secret.0, &product.0, &mut t.0[..n]);
+        }
+        Some(self.from_montgomery(&secret))
+    }
+}
@@ -0,0 +1,74 @@
+use alloc::vec::Vec;
+
+use rand::{CryptoRng, Rng, RngCore};
+use zeroize::Zeroizing;
+
+use super::field::FiniteField;
+use super::polynomial;
+use super::share::{self, Share};
+use super::sss::{self, ShamirError, FIELD, PRIME};
+
+// The i-th secret sits at x = -i, so it never collides with share indices 1..=n.
+fn secret_point(i: usize) -> u64 {
+    FIELD.neg(&(i as u64))
+}
+
+// Packs all secrets into one polynomial of degree threshold - 1. Any threshold shares recover
+// every secret; fewer than threshold - secrets.len() shares reveal nothing about them.
+pub fn generate_shares(
+    secrets: &[u64],
+    threshold: usize,
+    num_shares: usize,
+    rng: &mut (impl RngCore + CryptoRng),
+) -> Result<Vec<Share>, ShamirError> {
+    if secrets.is_empty() || threshold <= secrets.len() {
+        return Err(ShamirError::InvalidThreshold);
+    }
+    if num_shares < threshold || num_shares as u64 >= PRIME - threshold as u64 {
+        return Err(ShamirError::InvalidShareCount);
+    }
+    if secrets.iter().any(|&secret| secret >= PRIME) {
+        return Err(ShamirError::SecretOutOfRange);
+    }
+
+    let scheme_id = rng.r#gen();
+    let mut points = Zeroizing::new(Vec::with_capacity(threshold));
+    for (i, &secret) in secrets.iter().enumerate() {
+        points.push(Share::new(secret_point(i), secret, threshold, scheme_id));
+    }
+    for i in secrets.len()..threshold {
+        points.push(Share::new(secret_point(i), FIELD.random(rng), threshold, scheme_id));
+    }
+
+    // The points sit at distinct x-coordinates, so the basis always exists.
+    (1..=num_shares as u64)
+        .map(|x| {
+            let value = polynomial::interpolate_at(&FIELD, &points, &x).ok_or(ShamirError::DuplicateIndex)?;
+            Ok(Share::new(x, value, threshold, scheme_id))
+        })
+        .collect::<Result<_, _>>()
+        .map(|shares| share::dealt(shares, rng))
+}
+
+pub fn reconstruct_secrets(shares: &[Share], count: usize) -> Result<Zeroizing<Vec<u64>>, ShamirError> {
+    let threshold = sss::quorum(shares)?;
+    if count == 0 || count >= threshold {
+        return Err(ShamirError::InvalidParameters("count"));
+    }
+    if !share::check_same_dealing(shares) {
+        return Err(ShamirError::InconsistentShares);
+    }
+
+    sss::check_share_indices(shares, PRIME)?;
+    // Shares beyond the threshold must lie on the same polynomial rather than go unchecked.
+    sss::check_consistent(&FIELD, shares, threshold)?;
+
+    let quorum = &shares[..threshold];
+    let mut secrets = Zeroizing::new(Vec::with_capacity(count));
+    for i in 0..count {
+        secrets.push(
+            polynomial::interpolate_at(&FIELD, quorum, &secret_point(i)).ok_or(ShamirError::InconsistentShares)?,
+        );
+    }
+    Ok(secrets)
+}
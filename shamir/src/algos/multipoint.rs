@@ -0,0 +1,199 @@
+use alloc::{boxed::Box, vec, vec::Vec};
+
+use zeroize::Zeroizing;
+
+use super::sss::{self, PRIME};
+
+// Polynomials are coefficient vectors over the SSS field, lowest degree first.
+//
+// Products of long polynomials go through number-theoretic transforms modulo three NTT-friendly
+// primes; their product (about 2^86) exceeds every exact convolution coefficient
+// (length * 2^62), so CRT recovers the integers before reducing mod PRIME.
+const P1: u64 = 998244353;
+const P2: u64 = 167772161;
+const P3: u64 = 469762049;
+const NTT_ROOT: u64 = 3;
+const SCHOOLBOOK_LEN: usize = 64;
+const LEAF_POINTS: usize = 64;
+
+// Below this degree, Horner at every point beats building the subproduct tree.
+pub const MIN_DEGREE: usize = 2048;
+
+fn pow_mod(mut base: u64, mut exp: u64, m: u64) -> u64 {
+    let mut result = 1;
+    base %= m;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = result * base % m;
+        }
+        base = base * base % m;
+        exp >>= 1;
+    }
+    result
+}
+
+// The modulus is a const parameter so every reduction compiles to multiplications.
+fn ntt<const P: u64>(a: &mut [u64], invert: bool) {
+    let n = a.len();
+    let mut j = 0;
+    for i in 1..n {
+        let mut bit = n >> 1;
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+        j |= bit;
+        if i < j {
+            a.swap(i, j);
+        }
+    }
+
+    let mut len = 2;
+    while len <= n {
+        let mut w_len = pow_mod(NTT_ROOT, (P - 1) / len as u64, P);
+        if invert {
+            w_len = pow_mod(w_len, P - 2, P);
+        }
+        let twiddles: Vec<u64> = core::iter::successors(Some(1), |&w| Some(w * w_len % P)).take(len / 2).collect();
+        for chunk in a.chunks_mut(len) {
+            let (lo, hi) = chunk.split_at_mut(len / 2);
+            for ((u, v), &w) in lo.iter_mut().zip(hi.iter_mut()).zip(&twiddles) {
+                let t = *v * w % P;
+                *v = if *u >= t { *u - t } else { *u + P - t };
+                *u = if *u + t >= P { *u + t - P } else { *u + t };
+            }
+        }
+        len <<= 1;
+    }
+
+    if invert {
+        let n_inv = pow_mod(n as u64, P - 2, P);
+        for x in a.iter_mut() {
+            *x = *x * n_inv % P;
+        }
+    }
+}
+
+fn convolve<const P: u64>(a: &[u64], b: &[u64]) -> Vec<u64> {
+    let size = (a.len() + b.len() - 1).next_power_of_two();
+    let mut fa: Vec<u64> = a.iter().map(|&x| x % P).collect();
+    let mut fb: Vec<u64> = b.iter().map(|&x| x % P).collect();
+    fa.resize(size, 0);
+    fb.resize(size, 0);
+    ntt::<P>(&mut fa, false);
+    ntt::<P>(&mut fb, false);
+    for (x, y) in fa.iter_mut().zip(&fb) {
+        *x = *x * y % P;
+    }
+    ntt::<P>(&mut fa, true);
+    fa.truncate(a.len() + b.len() - 1);
+    fa
+}
+
+pub fn multiply(a: &[u64], b: &[u64]) -> Vec<u64> {
+    if a.is_empty() || b.is_empty() {
+        return Vec::new();
+    }
+    if a.len().min(b.len()) <= SCHOOLBOOK_LEN {
+        let mut out = vec![0; a.len() + b.len() - 1];
+        for (i, &x) in a.iter().enumerate() {
+            for (j, &y) in b.iter().enumerate() {
+                out[i + j] = (out[i + j] + x * y) % PRIME;
+            }
+        }
+        return out;
+    }
+
+    let (p1, p2, p3) = (P1, P2, P3);
+    let (r1, r2, r3) = (convolve::<P1>(a, b), convolve::<P2>(a, b), convolve::<P3>(a, b));
+    let p1_inv = pow_mod(p1, p2 - 2, p2);
+    let p12_inv = pow_mod(p1 * p2 % p3, p3 - 2, p3);
+    r1.iter()
+        .zip(&r2)
+        .zip(&r3)
+        .map(|((&x1, &x2), &x3)| {
+            // Garner's algorithm: x = x1 + p1 * k2 + p1 * p2 * k3.
+            let k2 = (x2 + p2 - x1 % p2) % p2 * p1_inv % p2;
+            let x12 = x1 + p1 * k2;
+            let k3 = (x3 + p3 - x12 % p3) % p3 * p12_inv % p3;
+            ((x12 as u128 + (p1 * p2) as u128 * k3 as u128) % PRIME as u128) as u64
+        })
+        .collect()
+}
+
+// Inverse of f modulo x^k by Newton iteration; f[0] must be nonzero.
+fn inverse_series(f: &[u64], k: usize) -> Vec<u64> {
+    let mut g = vec![sss::mod_inverse(f[0])];
+    let mut len = 1;
+    while len < k {
+        len *= 2;
+        let mut t = multiply(&f[..f.len().min(len)], &g);
+        t.truncate(len);
+        for x in t.iter_mut() {
+            *x = (PRIME - *x) % PRIME;
+        }
+        t[0] = (t[0] + 2) % PRIME;
+        g = multiply(&g, &t);
+        g.truncate(len);
+    }
+    g.truncate(k);
+    g
+}
+
+// a mod b for monic b, via the reversed-polynomial quotient.
+fn remainder(a: &[u64], b: &[u64]) -> Zeroizing<Vec<u64>> {
+    if a.len() < b.len() {
+        return Zeroizing::new(a.to_vec());
+    }
+    let m = a.len() - b.len() + 1;
+    let rev_a: Zeroizing<Vec<u64>> = Zeroizing::new(a.iter().rev().take(m).copied().collect());
+    let rev_b: Vec<u64> = b.iter().rev().copied().collect();
+    let mut quotient = Zeroizing::new(multiply(&rev_a, &inverse_series(&rev_b, m)));
+    quotient.resize(m, 0);
+    quotient.reverse();
+
+    let product = Zeroizing::new(multiply(b, &quotient));
+    Zeroizing::new(a.iter().zip(product.iter()).take(b.len() - 1).map(|(&x, &y)| (x + PRIME - y) % PRIME).collect())
+}
+
+struct Node {
+    poly: Vec<u64>,
+    children: Option<Box<(Node, Node)>>,
+}
+
+// Node polynomials are the monic products of (X - x) over their points.
+fn build(xs: &[u64]) -> Node {
+    if xs.len() <= LEAF_POINTS {
+        let mut poly = vec![1];
+        for &x in xs {
+            poly = multiply(&poly, &[(PRIME - x % PRIME) % PRIME, 1]);
+        }
+        return Node { poly, children: None };
+    }
+    let (left, right) = xs.split_at(xs.len() / 2);
+    let (left, right) = (build(left), build(right));
+    Node { poly: multiply(&left.poly, &right.poly), children: Some(Box::new((left, right))) }
+}
+
+fn descend(node: &Node, f: &[u64], xs: &[u64], out: &mut Vec<u64>) {
+    let reduced = remainder(f, &node.poly);
+    match &node.children {
+        None => out.extend(xs.iter().map(|&x| sss::evaluate_polynomial(&reduced, x % PRIME))),
+        Some(children) => {
+            let (left, right) = xs.split_at(xs.len() / 2);
+            descend(&children.0, &reduced, left, out);
+            descend(&children.1, &reduced, right, out);
+        }
+    }
+}
+
+// Evaluates the polynomial at every x: Horner for small inputs, otherwise the subproduct tree
+// with NTT multiplication in O(M(n) log n).
+pub fn evaluate_many(coeffs: &[u64], xs: &[u64]) -> Vec<u64> {
+    if coeffs.len() < MIN_DEGREE || xs.len() < MIN_DEGREE {
+        return xs.iter().map(|&x| sss::evaluate_polynomial(coeffs, x % PRIME)).collect();
+    }
+    let mut out = Vec::with_capacity(xs.len());
+    descend(&build(xs), coeffs, xs, &mut out);
+    out
+}
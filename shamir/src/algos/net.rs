@@ -0,0 +1,248 @@
+use std::fmt;
+use std::io;
+use std::net::SocketAddr;
+use std::time::Duration;
+
+use rand::{CryptoRng, RngCore};
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::task::JoinSet;
+use tokio::time;
+
+use super::cbor;
+use super::share::Share;
+use super::vss::{self, CommitmentBundle, VerifiedReconstruction, VssConfig, VssError};
+use super::wire::DecodeError;
+
+// Dealing and reconstruction over TCP. Every message is one frame: a big-endian u32 length, a
+// message type byte, then CBOR items from `cbor`. A dealer connects to each participant in turn
+// and hands over its share with the commitments; a participant checks the share before
+// acknowledging it. Later a coordinator asks every participant for its share, checks each against
+// the commitments, and reconstructs once enough have arrived. Every network step is bounded by
+// the caller's timeout.
+
+pub const MAX_FRAME: usize = 1 << 20;
+
+const DEAL: u8 = 1;
+const ACK: u8 = 2;
+const REQUEST: u8 = 3;
+const SUBMIT: u8 = 4;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Message {
+    Deal { share: Share<i128>, bundle: CommitmentBundle },
+    Ack,
+    Request,
+    Submit(Share<i128>),
+}
+
+#[derive(Debug)]
+pub enum NetError {
+    Io(io::Error),
+    Timeout,
+    // A frame that doesn't decode, or a message that isn't valid at this point of the protocol.
+    Protocol(&'static str),
+    Decode(DecodeError),
+    Vss(VssError),
+}
+
+impl fmt::Display for NetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NetError::Io(err) => write!(f, "network error: {}", err),
+            NetError::Timeout => write!(f, "peer did not answer in time"),
+            NetError::Protocol(what) => write!(f, "protocol error: {}", what),
+            NetError::Decode(err) => write!(f, "malformed message: {:?}", err),
+            NetError::Vss(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for NetError {}
+
+impl From<io::Error> for NetError {
+    fn from(err: io::Error) -> Self {
+        NetError::Io(err)
+    }
+}
+
+impl From<DecodeError> for NetError {
+    fn from(err: DecodeError) -> Self {
+        NetError::Decode(err)
+    }
+}
+
+impl From<VssError> for NetError {
+    fn from(err: VssError) -> Self {
+        NetError::Vss(err)
+    }
+}
+
+async fn within<T>(timeout: Duration, future: impl Future<Output = Result<T, NetError>>) -> Result<T, NetError> {
+    time::timeout(timeout, future).await.map_err(|_| NetError::Timeout)?
+}
+
+pub async fn write_frame(stream: &mut (impl AsyncWrite + Unpin), payload: &[u8]) -> Result<(), NetError> {
+    if payload.len() > MAX_FRAME {
+        return Err(NetError::Protocol("frame too large"));
+    }
+    stream.write_all(&(payload.len() as u32).to_be_bytes()).await?;
+    stream.write_all(payload).await?;
+    stream.flush().await?;
+    Ok(())
+}
+
+pub async fn read_frame(stream: &mut (impl AsyncRead + Unpin)) -> Result<Vec<u8>, NetError> {
+    let len = stream.read_u32().await? as usize;
+    if len > MAX_FRAME {
+        return Err(NetError::Protocol("frame too large"));
+    }
+    let mut payload = vec![0u8; len];
+    stream.read_exact(&mut payload).await?;
+    Ok(payload)
+}
+
+impl Message {
+    pub fn encode(&self) -> Vec<u8> {
+        match self {
+            Message::Deal { share, bundle } => {
+                let share = cbor::encode_share(share);
+                let mut out = vec![DEAL];
+                out.extend_from_slice(&(share.len() as u32).to_be_bytes());
+                out.extend_from_slice(&share);
+                out.extend_from_slice(&cbor::encode_bundle(bundle));
+                out
+            }
+            Message::Ack => vec![ACK],
+            Message::Request => vec![REQUEST],
+            Message::Submit(share) => {
+                let mut out = vec![SUBMIT];
+                out.extend_from_slice(&cbor::encode_share(share));
+                out
+            }
+        }
+    }
+
+    pub fn decode(bytes: &[u8]) -> Result<Self, NetError> {
+        let (&kind, body) = bytes.split_first().ok_or(DecodeError::Truncated)?;
+        match kind {
+            DEAL => {
+                let len = body.get(..4).ok_or(DecodeError::Truncated)?;
+                let len = u32::from_be_bytes(len.try_into().map_err(|_| DecodeError::Truncated)?) as usize;
+                let share = body.get(4..4 + len).ok_or(DecodeError::Truncated)?;
+                Ok(Message::Deal { share: cbor::decode_share(share)?, bundle: cbor::decode_bundle(&body[4 + len..])? })
+            }
+            ACK if body.is_empty() => Ok(Message::Ack),
+            REQUEST if body.is_empty() => Ok(Message::Request),
+            SUBMIT => Ok(Message::Submit(cbor::decode_share(body)?)),
+            _ => Err(NetError::Protocol("unknown message")),
+        }
+    }
+}
+
+pub async fn send(stream: &mut (impl AsyncWrite + Unpin), message: &Message) -> Result<(), NetError> {
+    write_frame(stream, &message.encode()).await
+}
+
+pub async fn recv(stream: &mut (impl AsyncRead + Unpin)) -> Result<Message, NetError> {
+    Message::decode(&read_frame(stream).await?)
+}
+
+// Deals `secret` to `participants`, the i-th address receiving share i + 1, and returns the
+// commitments once every participant has acknowledged its share.
+pub async fn deal(
+    secret: i128,
+    participants: &[SocketAddr],
+    config: &VssConfig,
+    timeout: Duration,
+    rng: &mut (impl RngCore + CryptoRng),
+) -> Result<CommitmentBundle, NetError> {
+    if participants.len() != config.shares() {
+        return Err(VssError::InvalidParameters("participants").into());
+    }
+    let coeffs = vss::generate_polynomial(secret, config, rng);
+    let shares = vss::generate_shares(&coeffs, config, rng);
+    let bundle = CommitmentBundle { commitments: vss::generate_commitments(&coeffs, config), verification_key: None };
+    drop(coeffs);
+
+    for (addr, share) in participants.iter().zip(shares) {
+        within(timeout, async {
+            let mut stream = TcpStream::connect(addr).await?;
+            send(&mut stream, &Message::Deal { share, bundle: bundle.clone() }).await?;
+            match recv(&mut stream).await? {
+                Message::Ack => Ok(()),
+                _ => Err(NetError::Protocol("expected an acknowledgement")),
+            }
+        })
+        .await?;
+    }
+    Ok(bundle)
+}
+
+// Accepts one dealer connection and returns the share it delivers, acknowledging it only once it
+// matches the commitments that came with it.
+pub async fn receive_share(
+    listener: &TcpListener,
+    config: &VssConfig,
+    timeout: Duration,
+) -> Result<(Share<i128>, CommitmentBundle), NetError> {
+    let (mut stream, _) = within(timeout, async { Ok(listener.accept().await?) }).await?;
+    within(timeout, async {
+        let Message::Deal { share, bundle } = recv(&mut stream).await? else {
+            return Err(NetError::Protocol("expected a dealing"));
+        };
+        if !vss::verify_share(&share, &bundle.commitments, config) {
+            return Err(VssError::VerificationFailed { index: share.index }.into());
+        }
+        send(&mut stream, &Message::Ack).await?;
+        Ok((share, bundle))
+    })
+    .await
+}
+
+// Answers one coordinator request with this participant's share.
+pub async fn serve_share(listener: &TcpListener, share: &Share<i128>, timeout: Duration) -> Result<(), NetError> {
+    let (mut stream, _) = within(timeout, async { Ok(listener.accept().await?) }).await?;
+    within(timeout, async {
+        match recv(&mut stream).await? {
+            Message::Request => send(&mut stream, &Message::Submit(share.clone())).await,
+            _ => Err(NetError::Protocol("expected a share request")),
+        }
+    })
+    .await
+}
+
+async fn request_share(addr: SocketAddr, timeout: Duration) -> Result<Share<i128>, NetError> {
+    within(timeout, async {
+        let mut stream = TcpStream::connect(addr).await?;
+        send(&mut stream, &Message::Request).await?;
+        match recv(&mut stream).await? {
+            Message::Submit(share) => Ok(share),
+            _ => Err(NetError::Protocol("expected a share")),
+        }
+    })
+    .await
+}
+
+// Asks every participant for its share at once and reconstructs from those that answer in time.
+// Participants that are unreachable or too slow are left out; shares that fail the commitments
+// are listed as rejected, as in `vss::reconstruct_verified`.
+pub async fn gather(
+    participants: &[SocketAddr],
+    commitments: &[i128],
+    config: &VssConfig,
+    timeout: Duration,
+) -> Result<VerifiedReconstruction, NetError> {
+    let mut requests = JoinSet::new();
+    for &addr in participants {
+        requests.spawn(request_share(addr, timeout));
+    }
+    let mut shares = Vec::with_capacity(participants.len());
+    while let Some(result) = requests.join_next().await {
+        if let Ok(Ok(share)) = result {
+            shares.push(share);
+        }
+    }
+    shares.sort_by_key(|share| share.index);
+    Ok(vss::reconstruct_verified(&shares, commitments, config)?)
+}
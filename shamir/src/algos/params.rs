@@ -0,0 +1,49 @@
+use rand::{CryptoRng, Rng, RngCore};
+
+use super::field::{self, FiniteField, PrimeField64};
+use super::vss::{VssConfig, VssError};
+
+// Fresh VSS parameters instead of the toy constants in `vss`: a random safe prime p = 2q + 1 of
+// the requested size and a generator of its order-q subgroup. `VssConfig` keeps p below 2^63,
+// which bounds the size; that is still only good for tests and demos, not real secrecy.
+
+pub const MIN_BITS: u32 = 3;
+pub const MAX_BITS: u32 = 63;
+
+// A uniformly chosen safe prime with exactly `bits` bits (Sophie Germain q with bits - 1 bits).
+pub fn safe_prime(bits: u32, rng: &mut (impl RngCore + CryptoRng)) -> Result<u64, VssError> {
+    if !(MIN_BITS..=MAX_BITS).contains(&bits) {
+        return Err(VssError::InvalidParameters("bits"));
+    }
+    let low = 1u64 << (bits - 2);
+    loop {
+        let q = rng.gen_range(low..low << 1) | 1;
+        // q = 1 mod 3 puts 3 in 2q + 1; skip those before running Miller–Rabin.
+        if q % 3 != 1 && field::is_prime(q) && field::is_prime(2 * q + 1) {
+            return Ok(2 * q + 1);
+        }
+    }
+}
+
+// A random generator of the order-q subgroup of Z_p* for a safe prime p: squares form that
+// subgroup, and every square other than 1 generates it.
+pub fn generator(prime: u64, rng: &mut (impl RngCore + CryptoRng)) -> u64 {
+    let group = PrimeField64::new(prime);
+    loop {
+        let h = rng.gen_range(2..prime - 1);
+        let g = group.mul(&h, &h);
+        if g != 1 {
+            return g;
+        }
+    }
+}
+
+pub fn generate(
+    bits: u32,
+    threshold: usize,
+    shares: usize,
+    rng: &mut (impl RngCore + CryptoRng),
+) -> Result<VssConfig, VssError> {
+    let prime = safe_prime(bits, rng)?;
+    VssConfig::new(prime as i128, generator(prime, rng) as i128, threshold, shares)
+}
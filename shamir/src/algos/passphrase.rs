@@ -0,0 +1,192 @@
+use alloc::string::{String, ToString};
+use alloc::{vec, vec::Vec};
+use core::fmt;
+use core::str::FromStr;
+
+use argon2::{Algorithm, Argon2, Params, Version};
+use chacha20poly1305::aead::{Aead, KeyInit};
+use chacha20poly1305::{ChaCha20Poly1305, Key, Nonce};
+use rand::{CryptoRng, Rng, RngCore};
+use unicode_normalization::UnicodeNormalization;
+use zeroize::Zeroizing;
+
+use super::share::{ParseShareError, Share, ShareValue};
+use super::sss::{self, ShamirError};
+
+// Splitting a master password: the passphrase goes through Argon2id and the derived key is what
+// gets shared, never the passphrase itself. Every share carries the KDF parameters and salt, so a
+// quorum recovers the key and anyone who still knows the passphrase can re-derive it and check it
+// against the shares without reconstructing.
+
+// Argon2id settings and salt, stored alongside each share.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct KdfParams {
+    pub salt: Vec<u8>,
+    pub memory_kib: u32,
+    pub iterations: u32,
+    pub parallelism: u32,
+    pub key_len: usize,
+}
+
+pub const SALT_LEN: usize = 16;
+pub const KEY_LEN: usize = 32;
+
+impl KdfParams {
+    // The argon2 crate's recommended cost (19 MiB, 2 passes, 1 lane) with a fresh salt.
+    pub fn new(rng: &mut (impl RngCore + CryptoRng)) -> Self {
+        Self::with_cost(Params::DEFAULT_M_COST, Params::DEFAULT_T_COST, Params::DEFAULT_P_COST, rng)
+    }
+
+    pub fn with_cost(memory_kib: u32, iterations: u32, parallelism: u32, rng: &mut (impl RngCore + CryptoRng)) -> Self {
+        let mut salt = vec![0u8; SALT_LEN];
+        rng.fill(&mut salt[..]);
+        KdfParams { salt, memory_kib, iterations, parallelism, key_len: KEY_LEN }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PassphraseShare {
+    pub share: Share<Vec<u8>>,
+    pub kdf: KdfParams,
+}
+
+// The passphrase is normalized to NFC first, as in `sss::split_str`.
+pub fn derive_key(passphrase: &str, params: &KdfParams) -> Result<Zeroizing<Vec<u8>>, ShamirError> {
+    let argon2_params = Params::new(params.memory_kib, params.iterations, params.parallelism, Some(params.key_len))
+        .map_err(|_| ShamirError::InvalidParameters("kdf"))?;
+    let normalized = Zeroizing::new(passphrase.nfc().collect::<String>());
+    let mut key = Zeroizing::new(vec![0u8; params.key_len]);
+    Argon2::new(Algorithm::Argon2id, Version::V0x13, argon2_params)
+        .hash_password_into(normalized.as_bytes(), &params.salt, &mut key)
+        .map_err(|_| ShamirError::InvalidParameters("kdf"))?;
+    Ok(key)
+}
+
+pub fn split_passphrase(
+    passphrase: &str,
+    threshold: usize,
+    num_shares: usize,
+    rng: &mut (impl RngCore + CryptoRng),
+) -> Result<Vec<PassphraseShare>, ShamirError> {
+    let params = KdfParams::new(rng);
+    split_passphrase_with(passphrase, &params, threshold, num_shares, rng)
+}
+
+pub fn split_passphrase_with(
+    passphrase: &str,
+    params: &KdfParams,
+    threshold: usize,
+    num_shares: usize,
+    rng: &mut (impl RngCore + CryptoRng),
+) -> Result<Vec<PassphraseShare>, ShamirError> {
+    let key = derive_key(passphrase, params)?;
+    Ok(sss::split_bytes(&key, threshold, num_shares, rng)?
+        .into_iter()
+        .map(|share| PassphraseShare { share, kdf: params.clone() })
+        .collect())
+}
+
+// The derived key, from shares that all carry the same KDF parameters.
+pub fn combine_key(shares: &[PassphraseShare]) -> Result<Zeroizing<Vec<u8>>, ShamirError> {
+    if shares.windows(2).any(|pair| pair[0].kdf != pair[1].kdf) {
+        return Err(ShamirError::InconsistentShares);
+    }
+    let inner: Vec<Share<Vec<u8>>> = shares.iter().map(|share| share.share.clone()).collect();
+    sss::combine_bytes(&inner)
+}
+
+// Whether `passphrase` is the one the shares were made from, by re-deriving the key and combining.
+pub fn check_passphrase(passphrase: &str, shares: &[PassphraseShare]) -> Result<bool, ShamirError> {
+    let first = shares.first().ok_or(ShamirError::InsufficientShares { provided: 0, required: 1 })?;
+    let candidate = derive_key(passphrase, &first.kdf)?;
+    let key = combine_key(shares)?;
+    // Both are the KDF's output, so the comparison needn't hide where they differ.
+    Ok(candidate == key)
+}
+
+// A share encrypted at rest under its holder's own passphrase: ChaCha20-Poly1305 over the share's
+// text form, keyed by Argon2id. The text form is one line,
+// `sealed-<memory_kib>-<iterations>-<parallelism>-<salt>-<nonce>-<ciphertext>` with hex fields.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SealedShare {
+    pub kdf: KdfParams,
+    pub nonce: Vec<u8>,
+    pub ciphertext: Vec<u8>,
+}
+
+const NONCE_LEN: usize = 12;
+
+pub fn seal_share(
+    share: &Share<Vec<u8>>,
+    passphrase: &str,
+    rng: &mut (impl RngCore + CryptoRng),
+) -> Result<SealedShare, ShamirError> {
+    let params = KdfParams::new(rng);
+    seal_share_with(share, passphrase, params, rng)
+}
+
+pub fn seal_share_with(
+    share: &Share<Vec<u8>>,
+    passphrase: &str,
+    kdf: KdfParams,
+    rng: &mut (impl RngCore + CryptoRng),
+) -> Result<SealedShare, ShamirError> {
+    // The cipher takes a 256-bit key whatever the parameters asked for.
+    let kdf = KdfParams { key_len: KEY_LEN, ..kdf };
+    let key = derive_key(passphrase, &kdf)?;
+    let mut nonce = vec![0u8; NONCE_LEN];
+    rng.fill(&mut nonce[..]);
+    let text = Zeroizing::new(share.to_string());
+    let ciphertext = ChaCha20Poly1305::new(Key::from_slice(&key))
+        .encrypt(Nonce::from_slice(&nonce), text.as_bytes())
+        .map_err(|_| ShamirError::InvalidParameters("share"))?;
+    Ok(SealedShare { kdf, nonce, ciphertext })
+}
+
+pub fn open_share(sealed: &SealedShare, passphrase: &str) -> Result<Share<Vec<u8>>, ShamirError> {
+    if sealed.nonce.len() != NONCE_LEN || sealed.kdf.key_len != KEY_LEN {
+        return Err(ShamirError::InvalidParameters("sealed share"));
+    }
+    let key = derive_key(passphrase, &sealed.kdf)?;
+    let text = ChaCha20Poly1305::new(Key::from_slice(&key))
+        .decrypt(Nonce::from_slice(&sealed.nonce), &sealed.ciphertext[..])
+        .map(Zeroizing::new)
+        .map_err(|_| ShamirError::DecryptionFailed)?;
+    let text = core::str::from_utf8(&text).map_err(|_| ShamirError::DecryptionFailed)?;
+    text.parse().map_err(|_| ShamirError::DecryptionFailed)
+}
+
+impl fmt::Display for SealedShare {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let kdf = &self.kdf;
+        write!(f, "sealed-{}-{}-{}-", kdf.memory_kib, kdf.iterations, kdf.parallelism)?;
+        for field in [&kdf.salt, &self.nonce] {
+            field.fmt_value(f)?;
+            write!(f, "-")?;
+        }
+        self.ciphertext.fmt_value(f)
+    }
+}
+
+impl FromStr for SealedShare {
+    type Err = ParseShareError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.trim().split('-');
+        if parts.next() != Some("sealed") {
+            return Err(ParseShareError::Malformed);
+        }
+        let mut number = || parts.next().and_then(|part| part.parse().ok()).ok_or(ParseShareError::Malformed);
+        let (memory_kib, iterations, parallelism) = (number()?, number()?, number()?);
+        let mut bytes = || parts.next().and_then(Vec::<u8>::parse_value).ok_or(ParseShareError::Malformed);
+        let (salt, nonce, ciphertext) = (bytes()?, bytes()?, bytes()?);
+        if parts.next().is_some() {
+            return Err(ParseShareError::Malformed);
+        }
+        let kdf = KdfParams { salt, memory_kib, iterations, parallelism, key_len: KEY_LEN };
+        Ok(SealedShare { kdf, nonce, ciphertext })
+    }
+}
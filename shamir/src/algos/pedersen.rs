@@ -0,0 +1,131 @@
+use alloc::vec::Vec;
+
+use rand::{CryptoRng, Rng, RngCore};
+
+use super::dleq;
+use super::field::FiniteField;
+use super::group::{self, Commitment, Group, ScalarField};
+use super::polynomial;
+use super::share::{Share, ShareValue};
+use super::vss::{self, VssConfig, VssError};
+
+// Pedersen commitments C_k = a_k * G + b_k * H: the dealer shares the secret with f and a random
+// blinding polynomial r, commits to both coefficient lists at once, and hands out (f(i), r(i)).
+// Feldman's C_0 = secret * G leaks anything a discrete-log solver can recover from it; here C_0 is
+// uniformly random whatever the secret, so the commitments hide it unconditionally. Binding rests
+// on nobody knowing log_G(H), which is why H is derived by hashing rather than picked.
+
+pub struct Pedersen<C: Commitment> {
+    scheme: C,
+    h: C::Point,
+}
+
+// A share together with its share of the blinding polynomial, both needed to check it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BlindedShare<S: ShareValue> {
+    pub share: Share<S>,
+    pub blinding: S,
+}
+
+impl<S: ShareValue> Drop for BlindedShare<S> {
+    fn drop(&mut self) {
+        self.blinding.wipe();
+    }
+}
+
+impl<C: Commitment> Pedersen<C> {
+    // `h` must be a generator whose discrete log to the scheme's generator nobody knows.
+    pub fn new(scheme: C, h: C::Point) -> Self {
+        Pedersen { scheme, h }
+    }
+
+    pub fn h(&self) -> &C::Point {
+        &self.h
+    }
+
+    pub fn commit(&self, value: &C::Scalar, blinding: &C::Scalar) -> C::Point {
+        self.scheme.combine(&self.scheme.commit(value), &self.scheme.scale(&self.h, blinding))
+    }
+
+    pub fn commit_polynomials(&self, coeffs: &[C::Scalar], blinding: &[C::Scalar]) -> Vec<C::Point> {
+        coeffs.iter().zip(blinding).map(|(a, b)| self.commit(a, b)).collect()
+    }
+
+    pub fn verify_share(&self, share: &BlindedShare<C::Scalar>, commitments: &[C::Point]) -> bool {
+        let index = share.share.index;
+        if commitments.len() != share.share.threshold || index == 0 {
+            return false;
+        }
+        // Horner in the group works for any linear commitment, Pedersen included.
+        self.commit(&share.share.value, &share.blinding) == group::commitment_at(&self.scheme, index, commitments)
+    }
+}
+
+impl Pedersen<VssConfig> {
+    // H hashed into the order-q subgroup of Z_p*.
+    pub fn from_config(config: VssConfig) -> Self {
+        let h = dleq::hash_to_group(b"shamir/pedersen/h", &config);
+        Pedersen { scheme: config, h }
+    }
+
+    pub fn config(&self) -> &VssConfig {
+        &self.scheme
+    }
+
+    // Deals `secret` with the config's threshold and share count; returns the shares and the
+    // commitments to publish.
+    pub fn deal(&self, secret: i128, rng: &mut (impl RngCore + CryptoRng)) -> (Vec<BlindedShare<i128>>, Vec<i128>) {
+        let config = &self.scheme;
+        let coeffs = vss::generate_polynomial(secret, config, rng);
+        let blinding = vss::generate_polynomial(rng.gen_range(0..config.order()), config, rng);
+        let field = config.scalar_field();
+        let shares = vss::generate_shares(&coeffs, config, rng)
+            .into_iter()
+            .map(|share| {
+                let blinding = polynomial::evaluate(&field, &blinding, &field.element(share.index));
+                BlindedShare { share, blinding }
+            })
+            .collect();
+        (shares, self.commit_polynomials(&coeffs, &blinding))
+    }
+
+    pub fn verify_shares(&self, shares: &[BlindedShare<i128>], commitments: &[i128]) -> Result<(), VssError> {
+        match shares.iter().find(|share| !self.verify_share(share, commitments)) {
+            Some(share) => Err(VssError::VerificationFailed { index: share.share.index }),
+            None => Ok(()),
+        }
+    }
+
+    pub fn reconstruct_secret(&self, shares: &[BlindedShare<i128>]) -> Result<i128, VssError> {
+        let shares: Vec<Share<i128>> = shares.iter().map(|share| share.share.clone()).collect();
+        vss::reconstruct_secret(&shares, &self.scheme)
+    }
+}
+
+// Shares and commitments from `Pedersen::deal_in`.
+pub type GroupDealing<G> = (Vec<BlindedShare<<G as Group>::Scalar>>, Vec<<G as Group>::Point>);
+
+impl<G: Group> Pedersen<G> {
+    // Deals over one of the `Group` backends. The caller supplies H (e.g. from the backend's
+    // hash-to-curve) through `Pedersen::new`.
+    pub fn deal_in(
+        &self,
+        secret: G::Scalar,
+        threshold: usize,
+        num_shares: usize,
+        rng: &mut (impl RngCore + CryptoRng),
+    ) -> Result<GroupDealing<G>, VssError> {
+        let coeffs = group::generate_polynomial::<G>(secret, threshold, rng)?;
+        let blinding = group::generate_polynomial::<G>(G::random_scalar(rng), threshold, rng)?;
+        let field = ScalarField::<G>::new();
+        let shares = group::generate_shares::<G>(&coeffs, num_shares, rng)?
+            .into_iter()
+            .map(|share| {
+                let blinding = polynomial::evaluate(&field, &blinding, &G::scalar_from_u64(share.index));
+                BlindedShare { share, blinding }
+            })
+            .collect();
+        Ok((shares, self.commit_polynomials(&coeffs, &blinding)))
+    }
+}
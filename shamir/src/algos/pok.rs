@@ -0,0 +1,104 @@
+use alloc::vec::Vec;
+
+use rand::{CryptoRng, Rng, RngCore};
+use sha2::{Digest, Sha256};
+use zeroize::Zeroize;
+
+use super::group::Commitment;
+use super::share::Share;
+use super::vss::{self, VssConfig};
+
+// Schnorr proofs of knowledge of a discrete log, made non-interactive with Fiat-Shamir. A dealer
+// proves it knows the secret behind C_0 = g^secret and ships the proof with the commitments, so
+// participants can tell a dealing someone actually made from commitments pulled out of thin air
+// (or copied from another dealer) before spending a complaint round on it.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SchnorrProof {
+    pub challenge: i128,
+    pub response: i128,
+}
+
+// Feldman commitments together with a proof of knowledge of log_g(C_0).
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ProvenCommitments {
+    pub commitments: Vec<i128>,
+    pub proof: SchnorrProof,
+}
+
+fn challenge(elements: &[i128], context: &[u8], config: &VssConfig) -> i128 {
+    let mut hasher = Sha256::new();
+    hasher.update(b"shamir/pok");
+    hasher.update((context.len() as u64).to_be_bytes());
+    hasher.update(context);
+    for element in elements {
+        hasher.update(element.to_be_bytes());
+    }
+    let digest = hasher.finalize();
+    let mut wide = [0u8; 16];
+    wide.copy_from_slice(&digest[..16]);
+    (u128::from_be_bytes(wide) % config.order() as u128) as i128
+}
+
+fn in_subgroup(x: i128, config: &VssConfig) -> bool {
+    (1..config.prime()).contains(&x) && config.scale(&x, &config.order()) == 1
+}
+
+// Proves knowledge of x with g^x; `context` is hashed into the challenge so the proof only
+// verifies for the same context.
+pub fn prove(x: i128, context: &[u8], config: &VssConfig, rng: &mut (impl RngCore + CryptoRng)) -> SchnorrProof {
+    let q = config.order();
+    let mut k = rng.gen_range(1..q);
+    let c = challenge(&[config.generator(), config.commit(&x), config.commit(&k)], context, config);
+    let response = vss::mod_norm(k - c * x, q);
+    k.zeroize();
+    SchnorrProof { challenge: c, response }
+}
+
+// g^z * Y^c reproduces the nonce commitment the challenge was hashed from.
+pub fn verify(y: i128, proof: &SchnorrProof, context: &[u8], config: &VssConfig) -> bool {
+    if !in_subgroup(y, config) {
+        return false;
+    }
+    let t = config.combine(&config.commit(&proof.response), &config.scale(&y, &proof.challenge));
+    challenge(&[config.generator(), y, t], context, config) == proof.challenge
+}
+
+// The rest of the commitments are the proof's context, so it can't be lifted onto another
+// dealing that happens to reuse C_0.
+fn commitments_context(commitments: &[i128]) -> Vec<u8> {
+    commitments.iter().flat_map(|c| c.to_be_bytes()).collect()
+}
+
+// Commits to `coeffs` and proves knowledge of the constant term.
+pub fn prove_commitments(
+    coeffs: &[i128],
+    config: &VssConfig,
+    rng: &mut (impl RngCore + CryptoRng),
+) -> ProvenCommitments {
+    let commitments = vss::generate_commitments(coeffs, config);
+    let proof = prove(coeffs[0], &commitments_context(&commitments), config, rng);
+    ProvenCommitments { commitments, proof }
+}
+
+// Whether the dealing is well formed: the right number of commitments, all group elements, and a
+// valid proof for C_0. Individual shares still need `vss::verify_share` against the commitments.
+pub fn verify_commitments(bundle: &ProvenCommitments, config: &VssConfig) -> bool {
+    let commitments = &bundle.commitments;
+    commitments.len() == config.threshold()
+        && commitments.iter().all(|&c| in_subgroup(c, config))
+        && verify(commitments[0], &bundle.proof, &commitments_context(commitments), config)
+}
+
+// Deals a Feldman VSS sharing with proven commitments.
+pub fn deal(
+    secret: i128,
+    config: &VssConfig,
+    rng: &mut (impl RngCore + CryptoRng),
+) -> (Vec<Share<i128>>, ProvenCommitments) {
+    let coeffs = vss::generate_polynomial(secret, config, rng);
+    let shares = vss::generate_shares(&coeffs, config, rng);
+    (shares, prove_commitments(&coeffs, config, rng))
+}
@@ -0,0 +1,346 @@
+use alloc::{vec, vec::Vec};
+use core::fmt;
+use core::ops::{Add, Mul, Neg, Sub};
+
+use rand::{CryptoRng, RngCore, SeedableRng};
+use rand_chacha::ChaCha20Rng;
+
+use super::field::FiniteField;
+use super::share::{Share, ShareValue};
+
+// The one polynomial implementation behind every prime-field scheme. Coefficients are stored
+// lowest degree first, so coeffs[0] is the secret.
+
+// A uniformly random polynomial with f(0) = secret and `threshold` coefficients. Callers own
+// the wiping of the result.
+pub fn generate<F: FiniteField>(
+    field: &F,
+    secret: F::Element,
+    threshold: usize,
+    rng: &mut (impl RngCore + CryptoRng),
+) -> Vec<F::Element> {
+    let mut coeffs = Vec::with_capacity(threshold);
+    coeffs.push(field.add(&secret, &field.zero()));
+    for _ in 1..threshold {
+        coeffs.push(field.random(rng));
+    }
+    coeffs
+}
+
+// A ChaCha20 stream keyed by `seed`, for test vectors and ceremonies that must be replayable.
+// Anyone with the seed can recompute every coefficient, so it is as secret as the secret itself;
+// everywhere else the caller's RNG, normally OS entropy, stays the default.
+pub fn seeded_rng(seed: [u8; 32]) -> ChaCha20Rng {
+    ChaCha20Rng::from_seed(seed)
+}
+
+// Horner's rule.
+pub fn evaluate<F: FiniteField>(field: &F, coeffs: &[F::Element], x: &F::Element) -> F::Element {
+    coeffs.iter().rev().fold(field.zero(), |acc, coeff| field.add(&field.mul(&acc, x), coeff))
+}
+
+// Lagrange basis polynomial for xs[j], evaluated at x; None if two xs coincide.
+pub fn lagrange_at<F: FiniteField>(field: &F, j: usize, xs: &[F::Element], x: &F::Element) -> Option<F::Element> {
+    let mut numerator = field.one();
+    let mut denominator = field.one();
+    for (_, x_m) in xs.iter().enumerate().filter(|&(m, _)| m != j) {
+        numerator = field.mul(&numerator, &field.sub(x, x_m));
+        denominator = field.mul(&denominator, &field.sub(&xs[j], x_m));
+    }
+    Some(field.mul(&numerator, &field.inv(&denominator)?))
+}
+
+pub fn lagrange_at_zero<F: FiniteField>(field: &F, j: usize, xs: &[F::Element]) -> Option<F::Element> {
+    lagrange_at(field, j, xs, &field.zero())
+}
+
+// Every basis value at x, `lagrange_at` for each j but with a single inversion between them.
+pub fn lagrange_basis_at<F: FiniteField>(field: &F, xs: &[F::Element], x: &F::Element) -> Option<Vec<F::Element>> {
+    let mut numerators = Vec::with_capacity(xs.len());
+    let mut denominators = Vec::with_capacity(xs.len());
+    for (j, x_j) in xs.iter().enumerate() {
+        let mut numerator = field.one();
+        let mut denominator = field.one();
+        for (_, x_m) in xs.iter().enumerate().filter(|&(m, _)| m != j) {
+            numerator = field.mul(&numerator, &field.sub(x, x_m));
+            denominator = field.mul(&denominator, &field.sub(x_j, x_m));
+        }
+        numerators.push(numerator);
+        denominators.push(denominator);
+    }
+    let inverses = batch_inverse(field, &denominators)?;
+    Some(numerators.iter().zip(&inverses).map(|(n, d)| field.mul(n, d)).collect())
+}
+
+pub fn lagrange_basis_at_zero<F: FiniteField>(field: &F, xs: &[F::Element]) -> Option<Vec<F::Element>> {
+    lagrange_basis_at(field, xs, &field.zero())
+}
+
+// Montgomery's trick: the inverses of all the values for one inversion and 3(n - 1)
+// multiplications, from the running products a_0 ... a_i. None if any value is zero.
+pub fn batch_inverse<F: FiniteField>(field: &F, values: &[F::Element]) -> Option<Vec<F::Element>> {
+    let mut prefixes = Vec::with_capacity(values.len());
+    let mut product = field.one();
+    for value in values {
+        prefixes.push(product.clone());
+        product = field.mul(&product, value);
+    }
+    // inverse holds (a_0 ... a_i)^-1 going down, so a_i^-1 is it times a_0 ... a_(i-1).
+    let mut inverse = field.inv(&product)?;
+    let mut inverses = vec![field.zero(); values.len()];
+    for ((slot, prefix), value) in inverses.iter_mut().zip(&prefixes).zip(values).rev() {
+        *slot = field.mul(&inverse, prefix);
+        inverse = field.mul(&inverse, value);
+    }
+    Some(inverses)
+}
+
+// f(x) through every share given; callers choose which shares and check they belong together.
+pub fn interpolate_at<F: FiniteField>(field: &F, shares: &[Share<F::Element>], x: &F::Element) -> Option<F::Element> {
+    let xs: Vec<F::Element> = shares.iter().map(|share| field.element(share.index)).collect();
+    let basis = lagrange_basis_at(field, &xs, x)?;
+    let mut result = field.zero();
+    for (share, lambda) in shares.iter().zip(&basis) {
+        result = field.add(&result, &field.mul(&share.value, lambda));
+    }
+    Some(result)
+}
+
+pub fn interpolate_at_zero<F: FiniteField>(field: &F, shares: &[Share<F::Element>]) -> Option<F::Element> {
+    interpolate_at(field, shares, &field.zero())
+}
+
+// Indices of the shares past the first `threshold` that are off the polynomial through those
+// first shares; empty when all of them lie on one polynomial of degree below `threshold`. None
+// if two of the first shares share an index.
+pub fn outliers<F: FiniteField>(field: &F, shares: &[Share<F::Element>], threshold: usize) -> Option<Vec<u64>> {
+    let (base, rest) = shares.split_at(threshold.min(shares.len()));
+    let points: Vec<(F::Element, F::Element)> =
+        base.iter().map(|share| (field.element(share.index), share.value.clone())).collect();
+    let mut coeffs = interpolate(field, &points)?;
+    let outliers = rest
+        .iter()
+        .filter(|share| evaluate(field, &coeffs, &field.element(share.index)) != share.value)
+        .map(|share| share.index)
+        .collect();
+    coeffs.iter_mut().for_each(ShareValue::wipe);
+    Some(outliers)
+}
+
+// The coefficients of the unique polynomial of degree below points.len() through the points
+// (x, y), lowest degree first. None if two xs coincide. Quadratic in the number of points.
+pub fn interpolate<F: FiniteField>(field: &F, points: &[(F::Element, F::Element)]) -> Option<Vec<F::Element>> {
+    // Product of (x - x_i) over every point, lowest degree first.
+    let mut master = Vec::with_capacity(points.len() + 1);
+    master.push(field.one());
+    for (x_i, _) in points {
+        master.push(field.zero());
+        for k in (0..master.len()).rev() {
+            let shifted = if k == 0 { field.zero() } else { master[k - 1].clone() };
+            master[k] = field.sub(&shifted, &field.mul(&master[k], x_i));
+        }
+    }
+
+    // Each basis polynomial's value at its own point, prod (x_i - x_j) over j != i, inverted together.
+    let denominators: Vec<F::Element> = points
+        .iter()
+        .enumerate()
+        .map(|(i, (x_i, _))| {
+            points.iter().enumerate().filter(|&(j, _)| j != i).fold(field.one(), |acc, (_, (x_j, _))| {
+                field.mul(&acc, &field.sub(x_i, x_j))
+            })
+        })
+        .collect();
+    let inverses = batch_inverse(field, &denominators)?;
+
+    let mut coeffs = vec![field.zero(); points.len()];
+    for ((x_i, y_i), inverse) in points.iter().zip(&inverses) {
+        // master / (x - x_i) by synthetic division, from the top coefficient down.
+        let mut basis = vec![field.zero(); points.len()];
+        let mut carry = field.zero();
+        for k in (0..points.len()).rev() {
+            carry = field.add(&master[k + 1], &field.mul(&carry, x_i));
+            basis[k] = carry.clone();
+        }
+        let scale = field.mul(y_i, inverse);
+        for (coeff, b) in coeffs.iter_mut().zip(&basis) {
+            *coeff = field.add(coeff, &field.mul(b, &scale));
+        }
+    }
+    Some(coeffs)
+}
+
+// f(0) for the polynomial through the points, without computing the other coefficients.
+pub fn interpolate_constant<F: FiniteField>(field: &F, points: &[(F::Element, F::Element)]) -> Option<F::Element> {
+    let xs: Vec<F::Element> = points.iter().map(|(x, _)| x.clone()).collect();
+    let basis = lagrange_basis_at_zero(field, &xs)?;
+    let mut result = field.zero();
+    for ((_, y), lambda) in points.iter().zip(&basis) {
+        result = field.add(&result, &field.mul(y, lambda));
+    }
+    Some(result)
+}
+
+// A polynomial that carries its field, for protocols that combine whole polynomials: a refresh
+// adds one with a zero constant term, and a DKG's joint polynomial is the sum of the dealers'.
+// Coefficients are lowest degree first and are wiped on drop.
+pub struct Polynomial<F: FiniteField> {
+    field: F,
+    coeffs: Vec<F::Element>,
+}
+
+impl<F: FiniteField + Clone> Polynomial<F> {
+    pub fn new(field: F, coeffs: Vec<F::Element>) -> Self {
+        Polynomial { field, coeffs }
+    }
+
+    pub fn zero(field: F) -> Self {
+        Polynomial { field, coeffs: Vec::new() }
+    }
+
+    // See `generate`.
+    pub fn random(field: F, secret: F::Element, threshold: usize, rng: &mut (impl RngCore + CryptoRng)) -> Self {
+        let coeffs = generate(&field, secret, threshold, rng);
+        Polynomial { field, coeffs }
+    }
+
+    // `random` driven by `seeded_rng(seed)`: the same seed gives the same polynomial.
+    pub fn from_seed(field: F, secret: F::Element, threshold: usize, seed: [u8; 32]) -> Self {
+        Self::random(field, secret, threshold, &mut seeded_rng(seed))
+    }
+
+    // The polynomial through the points; see `interpolate`.
+    pub fn interpolate(field: F, points: &[(F::Element, F::Element)]) -> Option<Self> {
+        let coeffs = interpolate(&field, points)?;
+        Some(Polynomial { field, coeffs })
+    }
+
+    pub fn field(&self) -> &F {
+        &self.field
+    }
+
+    pub fn coeffs(&self) -> &[F::Element] {
+        &self.coeffs
+    }
+
+    // None for the zero polynomial. Zero high coefficients don't count.
+    pub fn degree(&self) -> Option<usize> {
+        let zero = self.field.zero();
+        self.coeffs.iter().rposition(|c| *c != zero)
+    }
+
+    pub fn constant(&self) -> F::Element {
+        self.coeffs.first().cloned().unwrap_or_else(|| self.field.zero())
+    }
+
+    pub fn evaluate(&self, x: &F::Element) -> F::Element {
+        evaluate(&self.field, &self.coeffs, x)
+    }
+
+    pub fn scale(&self, scalar: &F::Element) -> Self {
+        let coeffs = self.coeffs.iter().map(|c| self.field.mul(c, scalar)).collect();
+        Polynomial { field: self.field.clone(), coeffs }
+    }
+
+    fn zip_with(&self, other: &Self, op: impl Fn(&F::Element, &F::Element) -> F::Element) -> Self {
+        let zero = self.field.zero();
+        let len = self.coeffs.len().max(other.coeffs.len());
+        let coeffs = (0..len)
+            .map(|k| op(self.coeffs.get(k).unwrap_or(&zero), other.coeffs.get(k).unwrap_or(&zero)))
+            .collect();
+        Polynomial { field: self.field.clone(), coeffs }
+    }
+}
+
+impl<F: FiniteField + Clone> Clone for Polynomial<F> {
+    fn clone(&self) -> Self {
+        Polynomial { field: self.field.clone(), coeffs: self.coeffs.clone() }
+    }
+}
+
+impl<F: FiniteField> fmt::Debug for Polynomial<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Polynomial(degree < {})", self.coeffs.len())
+    }
+}
+
+// Equal as polynomials, whatever zero high coefficients either one carries.
+impl<F: FiniteField + Clone> PartialEq for Polynomial<F> {
+    fn eq(&self, other: &Self) -> bool {
+        let zero = self.field.zero();
+        (0..self.coeffs.len().max(other.coeffs.len()))
+            .all(|k| self.coeffs.get(k).unwrap_or(&zero) == other.coeffs.get(k).unwrap_or(&zero))
+    }
+}
+
+impl<F: FiniteField> Drop for Polynomial<F> {
+    fn drop(&mut self) {
+        self.coeffs.iter_mut().for_each(ShareValue::wipe);
+    }
+}
+
+impl<F: FiniteField + Clone> Add for &Polynomial<F> {
+    type Output = Polynomial<F>;
+
+    fn add(self, other: &Polynomial<F>) -> Polynomial<F> {
+        self.zip_with(other, |a, b| self.field.add(a, b))
+    }
+}
+
+impl<F: FiniteField + Clone> Sub for &Polynomial<F> {
+    type Output = Polynomial<F>;
+
+    fn sub(self, other: &Polynomial<F>) -> Polynomial<F> {
+        self.zip_with(other, |a, b| self.field.sub(a, b))
+    }
+}
+
+impl<F: FiniteField + Clone> Mul for &Polynomial<F> {
+    type Output = Polynomial<F>;
+
+    fn mul(self, other: &Polynomial<F>) -> Polynomial<F> {
+        if self.coeffs.is_empty() || other.coeffs.is_empty() {
+            return Polynomial::zero(self.field.clone());
+        }
+        let mut coeffs = vec![self.field.zero(); self.coeffs.len() + other.coeffs.len() - 1];
+        for (i, a) in self.coeffs.iter().enumerate() {
+            for (j, b) in other.coeffs.iter().enumerate() {
+                coeffs[i + j] = self.field.add(&coeffs[i + j], &self.field.mul(a, b));
+            }
+        }
+        Polynomial { field: self.field.clone(), coeffs }
+    }
+}
+
+impl<F: FiniteField + Clone> Neg for &Polynomial<F> {
+    type Output = Polynomial<F>;
+
+    fn neg(self) -> Polynomial<F> {
+        let coeffs = self.coeffs.iter().map(|c| self.field.neg(c)).collect();
+        Polynomial { field: self.field.clone(), coeffs }
+    }
+}
+
+impl<F: FiniteField + Clone> Add for Polynomial<F> {
+    type Output = Polynomial<F>;
+
+    fn add(self, other: Polynomial<F>) -> Polynomial<F> {
+        &self + &other
+    }
+}
+
+impl<F: FiniteField + Clone> Sub for Polynomial<F> {
+    type Output = Polynomial<F>;
+
+    fn sub(self, other: Polynomial<F>) -> Polynomial<F> {
+        &self - &other
+    }
+}
+
+impl<F: FiniteField + Clone> Mul for Polynomial<F> {
+    type Output = Polynomial<F>;
+
+    fn mul(self, other: Polynomial<F>) -> Polynomial<F> {
+        &self * &other
+    }
+}
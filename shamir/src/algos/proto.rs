@@ -0,0 +1,240 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use super::auth;
+use super::complaint;
+use super::refresh::RefreshContribution;
+use super::share;
+use super::vss;
+use super::wire::{DecodeError, WireValue};
+
+// Protobuf messages matching proto/shamir.proto, in the form prost-build generates, checked in so
+// building the crate needs no protoc. Encode and decode with `prost::Message`; the conversions
+// below move between these and the crate's own types.
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Share {
+    #[prost(uint64, tag = "1")]
+    pub index: u64,
+    #[prost(uint32, tag = "2")]
+    pub threshold: u32,
+    #[prost(uint32, tag = "3")]
+    pub scheme_id: u32,
+    #[prost(uint32, tag = "4")]
+    pub epoch: u32,
+    #[prost(uint32, tag = "5")]
+    pub level: u32,
+    #[prost(uint32, tag = "6")]
+    pub field: u32,
+    #[prost(bytes = "vec", tag = "7")]
+    pub value: Vec<u8>,
+    #[prost(uint32, tag = "8")]
+    pub total: u32,
+    #[prost(uint64, tag = "9")]
+    pub fingerprint: u64,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct VerificationKey {
+    #[prost(uint32, tag = "1")]
+    pub scheme_id: u32,
+    #[prost(bytes = "vec", tag = "2")]
+    pub key: Vec<u8>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct CommitmentBundle {
+    #[prost(int64, repeated, tag = "1")]
+    pub commitments: Vec<i64>,
+    #[prost(message, optional, tag = "2")]
+    pub verification_key: Option<VerificationKey>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Complaint {
+    #[prost(uint64, tag = "1")]
+    pub complainant: u64,
+    #[prost(uint64, tag = "2")]
+    pub dealer: u64,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct RefreshMessage {
+    #[prost(int64, repeated, tag = "1")]
+    pub commitments: Vec<i64>,
+    #[prost(message, repeated, tag = "2")]
+    pub shares: Vec<Share>,
+}
+
+impl<V: WireValue> From<&share::Share<V>> for Share {
+    fn from(share: &share::Share<V>) -> Self {
+        Share {
+            index: share.index,
+            threshold: share.threshold as u32,
+            scheme_id: share.scheme_id,
+            epoch: share.epoch,
+            level: share.level,
+            field: V::FIELD_ID as u32,
+            value: share.value.to_payload(),
+            total: share.total as u32,
+            fingerprint: share.fingerprint,
+        }
+    }
+}
+
+impl<V: WireValue> TryFrom<Share> for share::Share<V> {
+    type Error = DecodeError;
+
+    fn try_from(message: Share) -> Result<Self, DecodeError> {
+        if message.field != V::FIELD_ID as u32 {
+            return Err(DecodeError::FieldMismatch);
+        }
+        let value = V::from_payload(&message.value).ok_or(DecodeError::Malformed)?;
+        let mut share = share::Share::new(message.index, value, message.threshold as usize, message.scheme_id);
+        share.epoch = message.epoch;
+        share.level = message.level;
+        share.total = message.total as usize;
+        share.fingerprint = message.fingerprint;
+        Ok(share)
+    }
+}
+
+// VSS commitments live below p < 2^63, so they fit an int64; anything else is malformed.
+fn to_int64(values: &[i128]) -> Result<Vec<i64>, DecodeError> {
+    values.iter().map(|&value| i64::try_from(value).map_err(|_| DecodeError::Malformed)).collect()
+}
+
+impl From<&auth::VerificationKey> for VerificationKey {
+    fn from(key: &auth::VerificationKey) -> Self {
+        VerificationKey { scheme_id: key.scheme_id, key: key.key.to_vec() }
+    }
+}
+
+impl TryFrom<VerificationKey> for auth::VerificationKey {
+    type Error = DecodeError;
+
+    fn try_from(message: VerificationKey) -> Result<Self, DecodeError> {
+        let key = message.key.as_slice().try_into().map_err(|_| DecodeError::Malformed)?;
+        Ok(auth::VerificationKey { scheme_id: message.scheme_id, key })
+    }
+}
+
+impl TryFrom<&vss::CommitmentBundle> for CommitmentBundle {
+    type Error = DecodeError;
+
+    fn try_from(bundle: &vss::CommitmentBundle) -> Result<Self, DecodeError> {
+        Ok(CommitmentBundle {
+            commitments: to_int64(&bundle.commitments)?,
+            verification_key: bundle.verification_key.as_ref().map(VerificationKey::from),
+        })
+    }
+}
+
+impl TryFrom<CommitmentBundle> for vss::CommitmentBundle {
+    type Error = DecodeError;
+
+    fn try_from(message: CommitmentBundle) -> Result<Self, DecodeError> {
+        Ok(vss::CommitmentBundle {
+            commitments: message.commitments.into_iter().map(i128::from).collect(),
+            verification_key: message.verification_key.map(auth::VerificationKey::try_from).transpose()?,
+        })
+    }
+}
+
+impl From<&complaint::Complaint> for Complaint {
+    fn from(complaint: &complaint::Complaint) -> Self {
+        Complaint { complainant: complaint.complainant, dealer: complaint.dealer }
+    }
+}
+
+impl From<Complaint> for complaint::Complaint {
+    fn from(message: Complaint) -> Self {
+        complaint::Complaint { complainant: message.complainant, dealer: message.dealer }
+    }
+}
+
+impl TryFrom<&RefreshContribution> for RefreshMessage {
+    type Error = DecodeError;
+
+    fn try_from(contribution: &RefreshContribution) -> Result<Self, DecodeError> {
+        Ok(RefreshMessage {
+            commitments: to_int64(&contribution.commitments)?,
+            shares: contribution.shares.iter().map(Share::from).collect(),
+        })
+    }
+}
+
+impl TryFrom<RefreshMessage> for RefreshContribution {
+    type Error = DecodeError;
+
+    fn try_from(message: RefreshMessage) -> Result<Self, DecodeError> {
+        Ok(RefreshContribution {
+            commitments: message.commitments.into_iter().map(i128::from).collect(),
+            shares: message.shares.into_iter().map(share::Share::try_from).collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct DealRequest {
+    #[prost(int64, tag = "1")]
+    pub secret: i64,
+    #[prost(uint32, tag = "2")]
+    pub threshold: u32,
+    #[prost(uint32, tag = "3")]
+    pub shares: u32,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct DealResponse {
+    #[prost(string, tag = "1")]
+    pub ceremony: String,
+    #[prost(message, repeated, tag = "2")]
+    pub shares: Vec<Share>,
+    #[prost(message, optional, tag = "3")]
+    pub bundle: Option<CommitmentBundle>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct VerifyShareRequest {
+    #[prost(message, optional, tag = "1")]
+    pub share: Option<Share>,
+    #[prost(message, optional, tag = "2")]
+    pub bundle: Option<CommitmentBundle>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct VerifyShareResponse {
+    #[prost(bool, tag = "1")]
+    pub valid: bool,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct SubmitShareRequest {
+    #[prost(string, tag = "1")]
+    pub ceremony: String,
+    #[prost(message, optional, tag = "2")]
+    pub share: Option<Share>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct SubmitShareResponse {
+    #[prost(uint32, tag = "1")]
+    pub received: u32,
+    #[prost(uint32, tag = "2")]
+    pub threshold: u32,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ReconstructRequest {
+    #[prost(string, tag = "1")]
+    pub ceremony: String,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ReconstructResponse {
+    #[prost(int64, tag = "1")]
+    pub secret: i64,
+    #[prost(uint64, repeated, tag = "2")]
+    pub accepted: Vec<u64>,
+}
@@ -0,0 +1,62 @@
+use image::Luma;
+use qrcode::render::{svg, unicode};
+use qrcode::{EcLevel, QrCode};
+
+use super::bech32::Bech32Error;
+use super::share::Share;
+use super::wire::WireValue;
+
+pub const HRP: &str = "shr";
+
+#[derive(Debug)]
+pub enum QrError {
+    Encode,
+    Image,
+    NotFound,
+    Share(Bech32Error),
+}
+
+impl From<Bech32Error> for QrError {
+    fn from(err: Bech32Error) -> Self {
+        QrError::Share(err)
+    }
+}
+
+// Upper-case bech32m fits QR alphanumeric mode, which is denser than byte mode.
+fn code<V: WireValue>(share: &Share<V>) -> Result<QrCode, QrError> {
+    let text = share.to_bech32(HRP)?.to_ascii_uppercase();
+    QrCode::with_error_correction_level(text, EcLevel::M).map_err(|_| QrError::Encode)
+}
+
+pub fn to_svg<V: WireValue>(share: &Share<V>) -> Result<String, QrError> {
+    Ok(code(share)?.render::<svg::Color>().min_dimensions(256, 256).build())
+}
+
+pub fn to_terminal<V: WireValue>(share: &Share<V>) -> Result<String, QrError> {
+    Ok(code(share)?
+        .render::<unicode::Dense1x2>()
+        .dark_color(unicode::Dense1x2::Light)
+        .light_color(unicode::Dense1x2::Dark)
+        .build())
+}
+
+pub fn to_png<V: WireValue>(share: &Share<V>) -> Result<Vec<u8>, QrError> {
+    let image = code(share)?.render::<Luma<u8>>().build();
+    let mut png = Vec::new();
+    image
+        .write_to(&mut std::io::Cursor::new(&mut png), image::ImageFormat::Png)
+        .map_err(|_| QrError::Image)?;
+    Ok(png)
+}
+
+pub fn from_image<V: WireValue>(bytes: &[u8]) -> Result<Share<V>, QrError> {
+    let image = image::load_from_memory(bytes).map_err(|_| QrError::Image)?.to_luma8();
+    let mut prepared = rqrr::PreparedImage::prepare_from_greyscale(
+        image.width() as usize,
+        image.height() as usize,
+        |x, y| image.get_pixel(x as u32, y as u32)[0],
+    );
+    let grid = prepared.detect_grids().into_iter().next().ok_or(QrError::NotFound)?;
+    let (_, text) = grid.decode().map_err(|_| QrError::NotFound)?;
+    Ok(Share::from_bech32(&text, HRP)?)
+}
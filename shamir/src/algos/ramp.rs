@@ -0,0 +1,143 @@
+use alloc::{vec, vec::Vec};
+
+use rand::{CryptoRng, Rng, RngCore};
+use zeroize::Zeroizing;
+
+use super::gf256::{add, div, mul};
+use super::share::{self, Share};
+use super::sss::{self, ShamirError};
+
+// Each chunk of threshold - privacy secret bytes is packed into one polynomial through the points
+// x = 255, 254, ..., followed by privacy random points. Shares sit at x = 1..=n, so every share
+// carries roughly 1 / (threshold - privacy) of the secret.
+fn packed_points(threshold: usize) -> Vec<u8> {
+    (0..threshold).map(|i| 255 - i as u8).collect()
+}
+
+// Lagrange basis values for the given points, evaluated at x.
+fn basis_at(xs: &[u8], x: u8) -> Option<Vec<u8>> {
+    xs.iter()
+        .enumerate()
+        .map(|(i, &x_i)| {
+            let mut numerator = 1;
+            let mut denominator = 1;
+            for (j, &x_j) in xs.iter().enumerate() {
+                if i != j {
+                    numerator = mul(numerator, add(x, x_j));
+                    denominator = mul(denominator, add(x_i, x_j));
+                }
+            }
+            div(numerator, denominator)
+        })
+        .collect()
+}
+
+fn dot(basis: &[u8], values: impl Iterator<Item = u8>) -> u8 {
+    basis.iter().zip(values).fold(0, |acc, (&l, v)| add(acc, mul(l, v)))
+}
+
+pub fn split(
+    secret: &[u8],
+    privacy: usize,
+    threshold: usize,
+    num_shares: usize,
+    rng: &mut (impl RngCore + CryptoRng),
+) -> Result<Vec<Share<Vec<u8>>>, ShamirError> {
+    if privacy == 0 {
+        return Err(ShamirError::InvalidThreshold);
+    }
+    disperse(secret, privacy, threshold, num_shares, rng)
+}
+
+// Also backs `ida`, which is the privacy = 0 case: whole chunks of data, no random points.
+pub(crate) fn disperse(
+    secret: &[u8],
+    privacy: usize,
+    threshold: usize,
+    num_shares: usize,
+    rng: &mut (impl RngCore + CryptoRng),
+) -> Result<Vec<Share<Vec<u8>>>, ShamirError> {
+    if threshold <= privacy {
+        return Err(ShamirError::InvalidThreshold);
+    }
+    if num_shares < threshold || num_shares + threshold > 255 {
+        return Err(ShamirError::InvalidShareCount);
+    }
+    let len = u32::try_from(secret.len()).map_err(|_| ShamirError::SecretOutOfRange)?;
+    let width = threshold - privacy;
+
+    // The length prefix lets combine strip the zero padding of the last chunk.
+    let padded = (4 + secret.len()).div_ceil(width) * width;
+    let mut framed = Zeroizing::new(Vec::with_capacity(padded));
+    framed.extend_from_slice(&len.to_be_bytes());
+    framed.extend_from_slice(secret);
+    framed.resize(padded, 0);
+
+    let xs = packed_points(threshold);
+    let bases = (1..=num_shares as u8)
+        .map(|x| basis_at(&xs, x).ok_or(ShamirError::InvalidParameters("num_shares")))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let chunks = framed.len() / width;
+    let mut values = vec![Vec::with_capacity(chunks); num_shares];
+    let mut points = Zeroizing::new(vec![0u8; threshold]);
+    for chunk in framed.chunks(width) {
+        points[..width].copy_from_slice(chunk);
+        rng.fill(&mut points[width..]);
+        for (value, basis) in values.iter_mut().zip(&bases) {
+            value.push(dot(basis, points.iter().copied()));
+        }
+    }
+
+    let scheme_id = rng.r#gen();
+    let shares = values.into_iter().zip(1..).map(|(value, x)| Share::new(x, value, threshold, scheme_id)).collect();
+    Ok(share::dealt(shares, rng))
+}
+
+pub fn combine(shares: &[Share<Vec<u8>>], privacy: usize) -> Result<Zeroizing<Vec<u8>>, ShamirError> {
+    if privacy == 0 {
+        return Err(ShamirError::InvalidParameters("privacy"));
+    }
+    recover(shares, privacy)
+}
+
+pub(crate) fn recover(shares: &[Share<Vec<u8>>], privacy: usize) -> Result<Zeroizing<Vec<u8>>, ShamirError> {
+    let threshold = sss::quorum(shares)?;
+    if threshold <= privacy {
+        return Err(ShamirError::InvalidParameters("privacy"));
+    }
+    if !share::check_same_dealing(shares) {
+        return Err(ShamirError::InconsistentShares);
+    }
+    let shares = &shares[..threshold];
+    let chunks = shares[0].value.len();
+    if shares.iter().any(|share| share.value.len() != chunks) {
+        return Err(ShamirError::InconsistentShares);
+    }
+
+    let xs = shares
+        .iter()
+        .map(|share| u8::try_from(share.index).map_err(|_| ShamirError::InconsistentShares))
+        .collect::<Result<Vec<_>, _>>()?;
+    let width = threshold - privacy;
+    let bases = packed_points(threshold)[..width]
+        .iter()
+        .map(|&x| basis_at(&xs, x).ok_or(ShamirError::InconsistentShares))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut framed = Zeroizing::new(Vec::with_capacity(chunks * width));
+    for pos in 0..chunks {
+        for basis in &bases {
+            framed.push(dot(basis, shares.iter().map(|share| share.value[pos])));
+        }
+    }
+
+    if framed.len() < 4 {
+        return Err(ShamirError::InconsistentShares);
+    }
+    let len = u32::from_be_bytes([framed[0], framed[1], framed[2], framed[3]]) as usize;
+    if len > framed.len() - 4 {
+        return Err(ShamirError::InconsistentShares);
+    }
+    Ok(Zeroizing::new(framed[4..4 + len].to_vec()))
+}
@@ -0,0 +1,79 @@
+use alloc::vec::Vec;
+
+use rand::{CryptoRng, RngCore};
+
+use super::polynomial::Polynomial;
+use super::share::Share;
+use super::vss::{self, VssConfig, VssError};
+
+// One shareholder's contribution to a refresh round: a dealing of zero.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RefreshContribution {
+    pub commitments: Vec<i128>,
+    pub shares: Vec<Share<i128>>,
+}
+
+pub fn generate_refresh(config: &VssConfig, rng: &mut (impl RngCore + CryptoRng)) -> RefreshContribution {
+    let zero = Polynomial::random(config.scalar_field(), 0, config.threshold(), rng);
+    RefreshContribution {
+        commitments: vss::generate_commitments(zero.coeffs(), config),
+        shares: vss::generate_shares(zero.coeffs(), config, rng),
+    }
+}
+
+fn verify_sub_share(sub_share: &Share<i128>, commitments: &[i128], config: &VssConfig) -> Result<(), VssError> {
+    // g^0 = 1, so a non-unit C_0 means the dealer tried to shift the secret.
+    if commitments.first() != Some(&1) || !vss::verify_share(sub_share, commitments, config) {
+        return Err(VssError::VerificationFailed { index: sub_share.index });
+    }
+    Ok(())
+}
+
+pub fn verify_refresh(contribution: &RefreshContribution, config: &VssConfig) -> Result<(), VssError> {
+    for sub_share in &contribution.shares {
+        verify_sub_share(sub_share, &contribution.commitments, config)?;
+    }
+    Ok(())
+}
+
+pub fn apply_refresh(
+    share: &Share<i128>,
+    contributions: &[RefreshContribution],
+    config: &VssConfig,
+) -> Result<Share<i128>, VssError> {
+    let q = config.order();
+    let mut value = vss::mod_norm(share.value, q);
+    for contribution in contributions {
+        let sub_share = contribution
+            .shares
+            .iter()
+            .find(|sub_share| sub_share.index == share.index)
+            .ok_or(VssError::InvalidParameters("contributions"))?;
+        verify_sub_share(sub_share, &contribution.commitments, config)?;
+        value = vss::mod_norm(value + vss::mod_norm(sub_share.value, q), q);
+    }
+
+    let mut refreshed = share.clone();
+    refreshed.value = value;
+    refreshed.epoch += 1;
+    Ok(refreshed)
+}
+
+pub fn refresh_commitments(
+    commitments: &[i128],
+    contributions: &[RefreshContribution],
+    config: &VssConfig,
+) -> Result<Vec<i128>, VssError> {
+    let p = config.prime();
+    let mut refreshed = commitments.to_vec();
+    for contribution in contributions {
+        if contribution.commitments.len() != refreshed.len() {
+            return Err(VssError::InvalidParameters("commitments"));
+        }
+        for (c, &delta) in refreshed.iter_mut().zip(&contribution.commitments) {
+            *c = vss::mod_norm(vss::mod_norm(*c, p) * vss::mod_norm(delta, p), p);
+        }
+    }
+    Ok(refreshed)
+}
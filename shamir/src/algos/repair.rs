@@ -0,0 +1,141 @@
+use alloc::vec::Vec;
+
+use rand::{CryptoRng, RngCore};
+
+use super::field::FiniteField;
+use super::polynomial;
+use super::share::Share;
+use super::vss::{self, VssConfig, VssError};
+
+// Recovery of a lost share (the enrollment protocol of Laing and Stinson): t helpers regenerate
+// f(lost) without anyone learning f(0) or each other's shares. Each helper j splits its term
+// lambda_j(lost) * s_j into t random summands, one per helper; each helper adds up what it
+// received and hands only that sum to the recovering party, which adds the t sums.
+
+// A summand from helper `from` to helper `to` in the first round.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RepairDelta {
+    pub from: u64,
+    pub to: u64,
+    pub lost: u64,
+    pub value: i128,
+}
+
+// A helper's blinded sum, sent to the recovering party in the second round.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RepairSum {
+    pub helper: u64,
+    pub lost: u64,
+    pub scheme_id: u32,
+    pub epoch: u32,
+    pub total: usize,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub fingerprint: u64,
+    pub value: i128,
+}
+
+fn check_helpers(helpers: &[u64], lost: u64, config: &VssConfig) -> Result<(), VssError> {
+    if helpers.len() < config.threshold() {
+        return Err(VssError::InsufficientShares { provided: helpers.len(), required: config.threshold() });
+    }
+    let field = config.scalar_field();
+    if lost == 0 || field.element(lost) == 0 || helpers.iter().any(|&h| field.element(h) == 0) {
+        return Err(VssError::ZeroIndex);
+    }
+    if helpers.iter().enumerate().any(|(i, &h)| h == lost || helpers[..i].contains(&h)) {
+        return Err(VssError::DuplicateIndex);
+    }
+    Ok(())
+}
+
+// First round for the holder of `share`: its summands, one addressed to each helper (itself
+// included). `helpers` must list the same t indices, in the same order, for every helper.
+pub fn split_delta(
+    share: &Share<i128>,
+    helpers: &[u64],
+    lost: u64,
+    config: &VssConfig,
+    rng: &mut (impl RngCore + CryptoRng),
+) -> Result<Vec<RepairDelta>, VssError> {
+    check_helpers(helpers, lost, config)?;
+    let position = helpers
+        .iter()
+        .position(|&h| h == share.index)
+        .ok_or(VssError::InvalidParameters("helpers"))?;
+    let field = config.scalar_field();
+    let xs: Vec<i128> = helpers.iter().map(|&h| field.element(h)).collect();
+    let lambda = polynomial::lagrange_at(&field, position, &xs, &field.element(lost)).ok_or(VssError::NoInverse)?;
+
+    let mut remaining = field.mul(&lambda, &vss::mod_norm(share.value, config.order()));
+    let mut deltas = Vec::with_capacity(helpers.len());
+    for (i, &to) in helpers.iter().enumerate() {
+        let value = if i + 1 == helpers.len() { remaining } else { field.random(rng) };
+        remaining = field.sub(&remaining, &value);
+        deltas.push(RepairDelta { from: share.index, to, lost, value });
+    }
+    Ok(deltas)
+}
+
+// Second round: the sum of the summands addressed to `share`'s holder, one from every helper.
+pub fn aggregate_deltas(
+    share: &Share<i128>,
+    deltas: &[RepairDelta],
+    helpers: &[u64],
+    lost: u64,
+    config: &VssConfig,
+) -> Result<RepairSum, VssError> {
+    check_helpers(helpers, lost, config)?;
+    let field = config.scalar_field();
+    let mut value = 0;
+    for &from in helpers {
+        let delta = deltas
+            .iter()
+            .find(|delta| delta.from == from && delta.to == share.index && delta.lost == lost)
+            .ok_or(VssError::InsufficientShares { provided: deltas.len(), required: helpers.len() })?;
+        value = field.add(&value, &delta.value);
+    }
+    Ok(RepairSum {
+        helper: share.index,
+        lost,
+        scheme_id: share.scheme_id,
+        epoch: share.epoch,
+        total: share.total,
+        fingerprint: share.fingerprint,
+        value,
+    })
+}
+
+// Final step for the recovering party: the rebuilt share, checked against the dealing's
+// commitments so a helper that cheated in either round is caught here.
+pub fn recover_share(
+    lost: u64,
+    sums: &[RepairSum],
+    commitments: &[i128],
+    config: &VssConfig,
+) -> Result<Share<i128>, VssError> {
+    let helpers: Vec<u64> = sums.iter().map(|sum| sum.helper).collect();
+    check_helpers(&helpers, lost, config)?;
+    let first = &sums[0];
+    if sums.iter().any(|sum| {
+        sum.lost != lost
+            || sum.scheme_id != first.scheme_id
+            || sum.epoch != first.epoch
+            || sum.total != first.total
+            || sum.fingerprint != first.fingerprint
+    }) {
+        return Err(VssError::InconsistentShares);
+    }
+
+    let field = config.scalar_field();
+    let value = sums.iter().fold(0, |acc, sum| field.add(&acc, &sum.value));
+    let mut share = Share::new(lost, value, config.threshold(), first.scheme_id);
+    share.epoch = first.epoch;
+    share.total = first.total;
+    share.fingerprint = first.fingerprint;
+    if !vss::verify_share(&share, commitments, config) {
+        return Err(VssError::VerificationFailed { index: lost });
+    }
+    Ok(share)
+}
@@ -0,0 +1,137 @@
+use alloc::{vec, vec::Vec};
+
+use rand::{CryptoRng, RngCore};
+
+use super::share::{self, Share};
+use super::vss::{self, VssConfig, VssError};
+
+// Redistribution to a new (t', n') committee (Desmedt–Jajodia with Feldman checks). At least t
+// old holders each deal their own share to the new committee; every new member combines the
+// sub-shares it receives with the old holders' Lagrange weights. The secret is never rebuilt
+// and the new shares lie on a fresh degree t' - 1 polynomial with the same constant term.
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ReshareContribution {
+    // Index of the old share being redistributed.
+    pub from: u64,
+    pub scheme_id: u32,
+    pub epoch: u32,
+    pub commitments: Vec<i128>,
+    pub shares: Vec<Share<i128>>,
+}
+
+fn check_configs(old_config: &VssConfig, new_config: &VssConfig) -> Result<(), VssError> {
+    if old_config.prime() != new_config.prime() || old_config.generator() != new_config.generator() {
+        return Err(VssError::InvalidParameters("new_config"));
+    }
+    Ok(())
+}
+
+pub fn generate_reshare(
+    share: &Share<i128>,
+    new_config: &VssConfig,
+    rng: &mut (impl RngCore + CryptoRng),
+) -> ReshareContribution {
+    let coeffs = vss::generate_polynomial(share.value, new_config, rng);
+    ReshareContribution {
+        from: share.index,
+        scheme_id: share.scheme_id,
+        epoch: share.epoch,
+        commitments: vss::generate_commitments(&coeffs, new_config),
+        shares: vss::generate_shares(&coeffs, new_config, rng),
+    }
+}
+
+fn verify_source(
+    contribution: &ReshareContribution,
+    old_commitments: &[i128],
+    old_config: &VssConfig,
+) -> Result<(), VssError> {
+    // The sub-dealing's C_0 must be the public image of the old share it claims to redistribute.
+    let expected = vss::commitment_at(contribution.from, old_commitments, old_config);
+    if old_commitments.len() != old_config.threshold() || contribution.commitments.first() != Some(&expected) {
+        return Err(VssError::VerificationFailed { index: contribution.from });
+    }
+    Ok(())
+}
+
+pub fn verify_reshare(
+    contribution: &ReshareContribution,
+    old_commitments: &[i128],
+    old_config: &VssConfig,
+    new_config: &VssConfig,
+) -> Result<(), VssError> {
+    check_configs(old_config, new_config)?;
+    verify_source(contribution, old_commitments, old_config)?;
+    vss::verify_shares(&contribution.shares, &contribution.commitments, new_config)
+}
+
+fn weights(contributions: &[ReshareContribution], old_config: &VssConfig) -> Result<Vec<i128>, VssError> {
+    if contributions.len() < old_config.threshold() {
+        return Err(VssError::InsufficientShares { provided: contributions.len(), required: old_config.threshold() });
+    }
+    let first = &contributions[0];
+    if contributions.iter().any(|c| c.scheme_id != first.scheme_id || c.epoch != first.epoch) {
+        return Err(VssError::InconsistentShares);
+    }
+    let xs: Vec<i128> = contributions.iter().map(|c| c.from as i128).collect();
+    if xs.iter().enumerate().any(|(i, x)| xs[..i].contains(x)) {
+        return Err(VssError::DuplicateIndex);
+    }
+    vss::lagrange_basis_at_zero(&xs, old_config.order())
+}
+
+// The new share at `index`, from the sub-shares addressed to it in every contribution.
+pub fn combine_reshares(
+    index: u64,
+    contributions: &[ReshareContribution],
+    old_commitments: &[i128],
+    old_config: &VssConfig,
+    new_config: &VssConfig,
+) -> Result<Share<i128>, VssError> {
+    check_configs(old_config, new_config)?;
+    let lambdas = weights(contributions, old_config)?;
+    let q = new_config.order();
+    let mut value = 0;
+    for (contribution, lambda) in contributions.iter().zip(lambdas) {
+        verify_source(contribution, old_commitments, old_config)?;
+        let sub_share = contribution
+            .shares
+            .iter()
+            .find(|sub_share| sub_share.index == index)
+            .ok_or(VssError::InvalidParameters("index"))?;
+        if !vss::verify_share(sub_share, &contribution.commitments, new_config) {
+            return Err(VssError::VerificationFailed { index: contribution.from });
+        }
+        value = vss::mod_norm(value + vss::mod_norm(vss::mod_norm(sub_share.value, q) * lambda, q), q);
+    }
+
+    let mut share = Share::new(index, value, new_config.threshold(), contributions[0].scheme_id);
+    share.epoch = contributions[0].epoch + 1;
+    share.total = new_config.shares();
+    let commitments = reshare_commitments(contributions, old_config, new_config)?;
+    share.fingerprint = share::joint_fingerprint(b"reshare", &commitments);
+    Ok(share)
+}
+
+// Feldman commitments for the new committee's polynomial.
+pub fn reshare_commitments(
+    contributions: &[ReshareContribution],
+    old_config: &VssConfig,
+    new_config: &VssConfig,
+) -> Result<Vec<i128>, VssError> {
+    check_configs(old_config, new_config)?;
+    let lambdas = weights(contributions, old_config)?;
+    let p = new_config.prime();
+    let mut commitments = vec![1; new_config.threshold()];
+    for (contribution, lambda) in contributions.iter().zip(lambdas) {
+        if contribution.commitments.len() != commitments.len() {
+            return Err(VssError::InvalidParameters("contributions"));
+        }
+        for (c, &sub) in commitments.iter_mut().zip(&contribution.commitments) {
+            *c = vss::mod_norm(*c * vss::mod_pow(sub, lambda, p), p);
+        }
+    }
+    Ok(commitments)
+}
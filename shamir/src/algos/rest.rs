@@ -0,0 +1,125 @@
+use std::io;
+
+use axum::extract::rejection::JsonRejection;
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use axum::routing::post;
+use axum::{Json, Router};
+use serde::{Deserialize, Serialize};
+use tokio::net::TcpListener;
+
+use super::share::ShareValue;
+use super::sharefile::{self, ShareFile};
+use super::sss::{self, ShamirError};
+
+// A JSON-over-HTTP front for byte secrets, for callers that would rather not link the crate.
+// Shares travel as the `sharefile` JSON documents, so each one carries the commitments that let
+// /verify and /combine catch a file from another dealing or an edited value. Secrets are hex.
+//
+//   POST /split    {"secret": "68656c6c6f", "threshold": 2, "shares": 3} -> {"shares": [ShareFile]}
+//   POST /combine  {"shares": [ShareFile]}                             -> {"secret": "68656c6c6f"}
+//   POST /verify   {"shares": [ShareFile]}                             -> {"valid": bool, "error": ...}
+//
+// Failures answer {"error": "..."}: 400 for a body that isn't the expected JSON or bad
+// parameters, 422 for shares that can't be combined.
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SplitRequest {
+    pub secret: String,
+    pub threshold: usize,
+    pub shares: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SplitResponse {
+    pub shares: Vec<ShareFile>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SharesRequest {
+    pub shares: Vec<ShareFile>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CombineResponse {
+    pub secret: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VerifyResponse {
+    pub valid: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ErrorResponse {
+    pub error: String,
+}
+
+pub struct ApiError {
+    status: StatusCode,
+    message: String,
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        (self.status, Json(ErrorResponse { error: self.message })).into_response()
+    }
+}
+
+impl From<JsonRejection> for ApiError {
+    fn from(rejection: JsonRejection) -> Self {
+        ApiError { status: StatusCode::BAD_REQUEST, message: rejection.body_text() }
+    }
+}
+
+impl From<ShamirError> for ApiError {
+    fn from(err: ShamirError) -> Self {
+        let status = match err {
+            ShamirError::InvalidThreshold
+            | ShamirError::InvalidShareCount
+            | ShamirError::InvalidParameters(_)
+            | ShamirError::SecretOutOfRange => StatusCode::BAD_REQUEST,
+            _ => StatusCode::UNPROCESSABLE_ENTITY,
+        };
+        ApiError { status, message: err.to_string() }
+    }
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+async fn split(request: Result<Json<SplitRequest>, JsonRejection>) -> Result<Json<SplitResponse>, ApiError> {
+    let Json(request) = request?;
+    let secret = Vec::<u8>::parse_value(&request.secret).ok_or(ShamirError::InvalidParameters("secret"))?;
+    let shares = sss::split_bytes(&secret, request.threshold, request.shares, &mut rand::thread_rng())?;
+    Ok(Json(SplitResponse { shares: sharefile::from_shares(&shares) }))
+}
+
+async fn combine(request: Result<Json<SharesRequest>, JsonRejection>) -> Result<Json<CombineResponse>, ApiError> {
+    let Json(request) = request?;
+    let shares = sharefile::to_shares(&request.shares)?;
+    let secret = sss::combine_bytes(&shares)?;
+    Ok(Json(CombineResponse { secret: hex(&secret) }))
+}
+
+// A well-formed request always answers 200; `valid` says whether the files belong to one dealing
+// and match their commitments, and `error` why not.
+async fn verify(request: Result<Json<SharesRequest>, JsonRejection>) -> Result<Json<VerifyResponse>, ApiError> {
+    let Json(request) = request?;
+    Ok(Json(match sharefile::to_shares(&request.shares) {
+        Ok(_) => VerifyResponse { valid: true, error: None },
+        Err(err) => VerifyResponse { valid: false, error: Some(err.to_string()) },
+    }))
+}
+
+pub fn router() -> Router {
+    Router::new().route("/split", post(split)).route("/combine", post(combine)).route("/verify", post(verify))
+}
+
+// Serves the API on an already bound listener until the process exits.
+pub async fn serve(listener: TcpListener) -> io::Result<()> {
+    axum::serve(listener, router()).await
+}
@@ -0,0 +1,117 @@
+use alloc::vec::Vec;
+use core::fmt;
+
+use curve25519_dalek::constants::RISTRETTO_BASEPOINT_POINT;
+use curve25519_dalek::{RistrettoPoint, Scalar};
+use rand::{CryptoRng, Rng, RngCore};
+use zeroize::{Zeroize, Zeroizing};
+
+use super::group::{self, Coefficients, Group};
+use super::share::{Share, ShareValue};
+use super::vss::VssError;
+use super::wire::WireValue;
+
+// Feldman VSS in the Ristretto group: shares are scalars mod the Curve25519 group order l and
+// commitments are C_k = a_k * B for the basepoint B. Unlike the toy Z_p* parameters in `vss`,
+// opening a commitment here means solving a 252-bit discrete log.
+
+impl ShareValue for Scalar {
+    fn fmt_value(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for byte in self.as_bytes() {
+            write!(f, "{:02x}", byte)?;
+        }
+        Ok(())
+    }
+
+    fn parse_value(s: &str) -> Option<Self> {
+        let bytes: [u8; 32] = Vec::<u8>::parse_value(s)?.try_into().ok()?;
+        Option::from(Scalar::from_canonical_bytes(bytes))
+    }
+
+    fn wipe(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl WireValue for Scalar {
+    const FIELD_ID: u8 = 5;
+
+    fn to_payload(&self) -> Vec<u8> {
+        self.as_bytes().to_vec()
+    }
+
+    fn from_payload(payload: &[u8]) -> Option<Self> {
+        Option::from(Scalar::from_canonical_bytes(payload.try_into().ok()?))
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Ristretto;
+
+impl Group for Ristretto {
+    type Scalar = Scalar;
+    type Point = RistrettoPoint;
+
+    fn scalar_from_u64(x: u64) -> Scalar {
+        Scalar::from(x)
+    }
+
+    fn random_scalar(rng: &mut (impl RngCore + CryptoRng)) -> Scalar {
+        let mut wide = Zeroizing::new([0u8; 64]);
+        rng.fill(&mut wide[..]);
+        Scalar::from_bytes_mod_order_wide(&wide)
+    }
+
+    fn invert(scalar: &Scalar) -> Option<Scalar> {
+        (*scalar != Scalar::ZERO).then(|| scalar.invert())
+    }
+
+    fn generator() -> RistrettoPoint {
+        RISTRETTO_BASEPOINT_POINT
+    }
+
+    fn identity() -> RistrettoPoint {
+        RistrettoPoint::default()
+    }
+
+    fn encode_point(point: &RistrettoPoint) -> Vec<u8> {
+        point.compress().as_bytes().to_vec()
+    }
+
+    fn scalar_from_wide(bytes: &[u8; 64]) -> Scalar {
+        Scalar::from_bytes_mod_order_wide(bytes)
+    }
+
+    // Every Ristretto point is in the prime-order group.
+    fn is_valid_point(point: &RistrettoPoint) -> bool {
+        *point != RistrettoPoint::default()
+    }
+}
+
+pub fn generate_polynomial(
+    secret: Scalar,
+    threshold: usize,
+    rng: &mut (impl RngCore + CryptoRng),
+) -> Result<Coefficients<Scalar>, VssError> {
+    group::generate_polynomial::<Ristretto>(secret, threshold, rng)
+}
+
+pub fn generate_shares(
+    coeffs: &[Scalar],
+    num_shares: usize,
+    rng: &mut (impl RngCore + CryptoRng),
+) -> Result<Vec<Share<Scalar>>, VssError> {
+    group::generate_shares::<Ristretto>(coeffs, num_shares, rng)
+}
+
+pub fn generate_commitments(coeffs: &[Scalar]) -> Vec<RistrettoPoint> {
+    group::commit_polynomial(&Ristretto, coeffs)
+}
+
+pub fn verify_share(share: &Share<Scalar>, commitments: &[RistrettoPoint]) -> bool {
+    group::verify_commitment(&Ristretto, share, commitments)
+}
+
+pub fn reconstruct_secret(shares: &[Share<Scalar>]) -> Result<Scalar, VssError> {
+    group::reconstruct_secret::<Ristretto>(shares)
+}
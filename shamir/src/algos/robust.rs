@@ -0,0 +1,181 @@
+use alloc::{vec, vec::Vec};
+
+use super::field::FiniteField;
+use super::polynomial;
+use super::share::{self, Share};
+use super::sss::{self, ShamirError, FIELD, PRIME};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RobustReconstruction {
+    pub secret: u64,
+    pub corrupted: Vec<u64>,
+}
+
+// Solves the augmented system by Gauss-Jordan elimination, returning the solution (free variables
+// set to zero) and the rank, or None if the system is inconsistent.
+pub(crate) fn solve(mut rows: Vec<Vec<u64>>, unknowns: usize) -> Option<(Vec<u64>, usize)> {
+    let mut pivots = Vec::with_capacity(unknowns);
+    let mut rank = 0;
+    for col in 0..unknowns {
+        let Some(pivot) = (rank..rows.len()).find(|&r| rows[r][col] != 0) else {
+            continue;
+        };
+        rows.swap(rank, pivot);
+        let inv = FIELD.inv(&rows[rank][col])?;
+        for v in rows[rank].iter_mut() {
+            *v = FIELD.mul(v, &inv);
+        }
+        let pivot_row = rows[rank].clone();
+        for (r, row) in rows.iter_mut().enumerate() {
+            if r != rank && row[col] != 0 {
+                let factor = row[col];
+                for (v, &p) in row.iter_mut().zip(&pivot_row).skip(col) {
+                    *v = FIELD.sub(v, &FIELD.mul(&factor, &p));
+                }
+            }
+        }
+        pivots.push(col);
+        rank += 1;
+    }
+    if rows[rank..].iter().any(|row| row[unknowns] != 0) {
+        return None;
+    }
+
+    let mut solution = vec![0; unknowns];
+    for (row, &col) in pivots.iter().enumerate() {
+        solution[col] = rows[row][unknowns];
+    }
+    Some((solution, rank))
+}
+
+// Divides by a monic polynomial, returning (quotient, remainder). Coefficients are low-order first.
+fn divide(numerator: &[u64], divisor: &[u64]) -> (Vec<u64>, Vec<u64>) {
+    let mut remainder = numerator.to_vec();
+    let degree = divisor.len() - 1;
+    if remainder.len() <= degree {
+        return (vec![0], remainder);
+    }
+    let mut quotient = vec![0; remainder.len() - degree];
+    for i in (0..quotient.len()).rev() {
+        let coeff = remainder[i + degree];
+        quotient[i] = coeff;
+        for (j, &d) in divisor.iter().enumerate() {
+            remainder[i + j] = FIELD.sub(&remainder[i + j], &FIELD.mul(&coeff, &d));
+        }
+    }
+    remainder.truncate(degree);
+    (quotient, remainder)
+}
+
+// Berlekamp-Welch decoding: with n shares of a degree t-1 polynomial, up to (n - t) / 2
+// corrupted shares are corrected and reported.
+pub fn reconstruct_robust(shares: &[Share]) -> Result<RobustReconstruction, ShamirError> {
+    let threshold = sss::quorum(shares)?;
+    if !share::check_same_dealing(shares) {
+        return Err(ShamirError::InconsistentShares);
+    }
+    sss::check_share_indices(shares, PRIME)?;
+
+    let errors = (shares.len() - threshold) / 2;
+    let q_len = errors + threshold;
+    let unknowns = q_len + errors;
+
+    // Q(x_i) - y_i * (e_0 + ... + e_{k-1} x_i^{k-1}) = y_i * x_i^k, with E monic of degree k.
+    let rows = shares
+        .iter()
+        .map(|share| {
+            let (x, y) = (FIELD.element(share.index), FIELD.element(share.value));
+            let mut row = Vec::with_capacity(unknowns + 1);
+            let mut power = 1;
+            for _ in 0..q_len {
+                row.push(power);
+                power = FIELD.mul(&power, &x);
+            }
+            power = 1;
+            for _ in 0..errors {
+                row.push(FIELD.neg(&FIELD.mul(&y, &power)));
+                power = FIELD.mul(&power, &x);
+            }
+            row.push(FIELD.mul(&y, &power));
+            row
+        })
+        .collect();
+    let (solution, _) = solve(rows, unknowns).ok_or(ShamirError::InconsistentShares)?;
+
+    let mut locator = solution[q_len..].to_vec();
+    locator.push(1);
+    let (polynomial, remainder) = divide(&solution[..q_len], &locator);
+    if remainder.iter().any(|&r| r != 0) {
+        return Err(ShamirError::InconsistentShares);
+    }
+
+    let corrupted: Vec<u64> = shares
+        .iter()
+        .filter(|share| polynomial::evaluate(&FIELD, &polynomial, &share.index) != FIELD.element(share.value))
+        .map(|share| share.index)
+        .collect();
+    if corrupted.len() > errors {
+        return Err(ShamirError::InconsistentShares);
+    }
+    Ok(RobustReconstruction { secret: polynomial[0], corrupted })
+}
+
+// Advances a sorted k-subset of 0..n to the next one in lexicographic order.
+fn next_subset(subset: &mut [usize], n: usize) -> bool {
+    let k = subset.len();
+    let Some(i) = (0..k).rev().find(|&i| subset[i] < n - k + i) else {
+        return false;
+    };
+    subset[i] += 1;
+    for j in i + 1..k {
+        subset[j] = subset[j - 1] + 1;
+    }
+    true
+}
+
+// Majority decoding for when no commitments were kept: interpolates every threshold-sized subset
+// and returns the secret of the polynomial that the most shares lie on, reporting the rest as
+// corrupted. A tie between different polynomials is an error rather than a guess. Costs up to
+// C(n, t) interpolations, but stops once a polynomial has more support than any other could.
+pub fn reconstruct_consensus(shares: &[Share]) -> Result<RobustReconstruction, ShamirError> {
+    let threshold = sss::quorum(shares)?;
+    if !share::check_same_dealing(shares) {
+        return Err(ShamirError::InconsistentShares);
+    }
+    sss::check_share_indices(shares, PRIME)?;
+
+    let n = shares.len();
+    let points: Vec<(u64, u64)> =
+        shares.iter().map(|share| (FIELD.element(share.index), FIELD.element(share.value))).collect();
+    let mut best: Option<(Vec<u64>, usize)> = None;
+    let mut tied = false;
+    let mut subset: Vec<usize> = (0..threshold).collect();
+    loop {
+        let chosen: Vec<(u64, u64)> = subset.iter().map(|&i| points[i]).collect();
+        let coeffs = polynomial::interpolate(&FIELD, &chosen).ok_or(ShamirError::DuplicateIndex)?;
+        let support = points.iter().filter(|&&(x, y)| polynomial::evaluate(&FIELD, &coeffs, &x) == y).count();
+        match &best {
+            Some((best_coeffs, best_support)) if support == *best_support && coeffs != *best_coeffs => tied = true,
+            Some((_, best_support)) if support <= *best_support => {}
+            _ => {
+                best = Some((coeffs, support));
+                tied = false;
+            }
+        }
+        // Another polynomial shares at most t - 1 points with this one, so it can't catch up.
+        if 2 * support > n + threshold - 1 || !next_subset(&mut subset, n) {
+            break;
+        }
+    }
+    let Some((coeffs, _)) = best.filter(|_| !tied) else {
+        return Err(ShamirError::InconsistentShares);
+    };
+
+    let corrupted = shares
+        .iter()
+        .zip(&points)
+        .filter(|&(_, &(x, y))| polynomial::evaluate(&FIELD, &coeffs, &x) != y)
+        .map(|(share, _)| share.index)
+        .collect();
+    Ok(RobustReconstruction { secret: coeffs[0], corrupted })
+}
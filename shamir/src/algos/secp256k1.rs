@@ -0,0 +1,58 @@
+use alloc::vec::Vec;
+
+use num_bigint::BigUint;
+use num_traits::Zero;
+use rand::{CryptoRng, RngCore};
+use zeroize::Zeroizing;
+
+use super::bigint::{self, BigPrimeField};
+use super::share::{Share, ShareValue};
+use super::sss::ShamirError;
+
+// Order n of the secp256k1 group; private keys are scalars in [1, n).
+pub const ORDER_HEX: &str = "fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141";
+
+pub fn field() -> BigPrimeField {
+    let order = BigUint::parse_bytes(ORDER_HEX.as_bytes(), 16).expect("valid group order");
+    BigPrimeField::from_prime(order)
+}
+
+pub fn scalar_from_bytes(bytes: &[u8; 32]) -> Result<BigUint, ShamirError> {
+    let scalar = BigUint::from_bytes_be(bytes);
+    if scalar.is_zero() || &scalar >= field().modulus() {
+        return Err(ShamirError::SecretOutOfRange);
+    }
+    Ok(scalar)
+}
+
+pub fn scalar_to_bytes(scalar: &BigUint) -> Result<[u8; 32], ShamirError> {
+    let raw = scalar.to_bytes_be();
+    if raw.len() > 32 {
+        return Err(ShamirError::SecretOutOfRange);
+    }
+    let mut bytes = [0u8; 32];
+    bytes[32 - raw.len()..].copy_from_slice(&raw);
+    Ok(bytes)
+}
+
+pub fn split_key(
+    key: &[u8; 32],
+    threshold: usize,
+    num_shares: usize,
+    rng: &mut (impl RngCore + CryptoRng),
+) -> Result<Vec<Share<BigUint>>, ShamirError> {
+    let mut scalar = scalar_from_bytes(key)?;
+    let shares = bigint::generate_shares(&scalar, threshold, num_shares, &field(), rng);
+    scalar.wipe();
+    shares
+}
+
+pub fn combine_key(shares: &[Share<BigUint>]) -> Result<Zeroizing<[u8; 32]>, ShamirError> {
+    let mut scalar = bigint::reconstruct_secret(shares, &field())?;
+    if scalar.is_zero() {
+        return Err(ShamirError::SecretOutOfRange);
+    }
+    let key = scalar_to_bytes(&scalar).map(Zeroizing::new);
+    scalar.wipe();
+    key
+}
@@ -0,0 +1,190 @@
+use alloc::vec::Vec;
+
+use rand::{CryptoRng, RngCore};
+
+use super::complaint::{self, Complaint, Dispute, Response, Verdict};
+use super::dkg::Dealing;
+use super::share::Share;
+use super::vss::{self, VssConfig, VssError};
+
+// One Feldman dealing seen from each side, as plain values in and out so any transport can carry
+// the messages. A participant goes AwaitingShares -> Verified, or -> Complained when its share is
+// missing or wrong, then -> Finalized once everyone has resolved the complaint round; the dealer
+// answers complaints and reaches the same verdict. A DKG runs one `Participant` per dealer.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParticipantState {
+    AwaitingShares,
+    Verified,
+    Complained,
+    Finalized,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DealerState {
+    // Shares are out; complaints may still arrive.
+    Dealt,
+    Answering,
+    Finalized,
+}
+
+pub struct Participant {
+    index: u64,
+    dealer: u64,
+    config: VssConfig,
+    state: ParticipantState,
+    share: Option<Share<i128>>,
+    commitments: Vec<i128>,
+}
+
+impl Participant {
+    pub fn new(index: u64, dealer: u64, config: VssConfig) -> Result<Self, VssError> {
+        if index == 0 || index > config.shares() as u64 {
+            return Err(VssError::InvalidParameters("index"));
+        }
+        Ok(Participant {
+            index,
+            dealer,
+            config,
+            state: ParticipantState::AwaitingShares,
+            share: None,
+            commitments: Vec::new(),
+        })
+    }
+
+    pub fn index(&self) -> u64 {
+        self.index
+    }
+
+    pub fn state(&self) -> ParticipantState {
+        self.state
+    }
+
+    pub fn commitments(&self) -> &[i128] {
+        &self.commitments
+    }
+
+    // Takes the dealer's broadcast and this participant's share, `None` if it never arrived.
+    // Returns the complaint to broadcast when the share is missing or fails the commitments.
+    pub fn receive(
+        &mut self,
+        share: Option<Share<i128>>,
+        commitments: Vec<i128>,
+    ) -> Result<Option<Complaint>, VssError> {
+        if self.state != ParticipantState::AwaitingShares {
+            return Err(VssError::InvalidState);
+        }
+        if commitments.len() != self.config.threshold() {
+            return Err(VssError::InvalidParameters("commitments"));
+        }
+        let complaint = complaint::check_dealing(self.index, self.dealer, share.as_ref(), &commitments, &self.config);
+        self.commitments = commitments;
+        if complaint.is_some() {
+            self.state = ParticipantState::Complained;
+        } else {
+            self.share = share;
+            self.state = ParticipantState::Verified;
+        }
+        Ok(complaint)
+    }
+
+    // Applies the verdict every party reached with `complaint::Dispute`. Returns this
+    // participant's share, taken from the dealer's public answer if it had complained, or `None`
+    // when the dealer was disqualified.
+    pub fn finalize(&mut self, verdict: &Verdict) -> Result<Option<Share<i128>>, VssError> {
+        if !matches!(self.state, ParticipantState::Verified | ParticipantState::Complained) {
+            return Err(VssError::InvalidState);
+        }
+        let share = match verdict {
+            Verdict::Disqualified(_) => None,
+            Verdict::Qualified { .. } if self.state == ParticipantState::Verified => self.share.take(),
+            Verdict::Qualified { revealed } => {
+                let share = revealed
+                    .iter()
+                    .find(|share| share.index == self.index)
+                    .ok_or(VssError::InvalidParameters("verdict"))?;
+                if !vss::verify_share(share, &self.commitments, &self.config) {
+                    return Err(VssError::VerificationFailed { index: self.index });
+                }
+                Some(share.clone())
+            }
+        };
+        self.state = ParticipantState::Finalized;
+        Ok(share)
+    }
+}
+
+pub struct DealerSession {
+    dealer: u64,
+    shares: Vec<Share<i128>>,
+    dispute: Dispute,
+    state: DealerState,
+}
+
+impl DealerSession {
+    // Deals `secret`; the caller sends shares[i] to participant i + 1 and broadcasts the
+    // commitments. The polynomial is dropped here; only the shares are kept, to answer complaints.
+    pub fn deal(
+        dealer: u64,
+        secret: i128,
+        config: VssConfig,
+        rng: &mut (impl RngCore + CryptoRng),
+    ) -> Result<(Self, Dealing), VssError> {
+        if !(0..config.order()).contains(&secret) {
+            return Err(VssError::InvalidParameters("secret"));
+        }
+        let coeffs = vss::generate_polynomial(secret, &config, rng);
+        let shares = vss::generate_shares(&coeffs, &config, rng);
+        let commitments = vss::generate_commitments(&coeffs, &config);
+        let dealing = Dealing { dealer, commitments: commitments.clone(), shares: shares.clone() };
+        let dispute = Dispute::new(dealer, commitments, config)?;
+        Ok((DealerSession { dealer, shares, dispute, state: DealerState::Dealt }, dealing))
+    }
+
+    pub fn state(&self) -> DealerState {
+        self.state
+    }
+
+    pub fn file(&mut self, complaint: &Complaint) -> Result<(), VssError> {
+        if self.state != DealerState::Dealt {
+            return Err(VssError::InvalidState);
+        }
+        self.dispute.file(complaint)
+    }
+
+    // Closes the complaint window and publishes the share of every complainant, unless there are
+    // threshold or more of them: those shares would reveal the secret, and the dealer is
+    // disqualified either way.
+    pub fn answer(&mut self) -> Result<Vec<Response>, VssError> {
+        if self.state != DealerState::Dealt {
+            return Err(VssError::InvalidState);
+        }
+        self.dispute.close()?;
+        self.state = DealerState::Answering;
+        let threshold = self.shares.first().map_or(0, |share| share.threshold);
+        if self.dispute.complaints().count() >= threshold {
+            return Ok(Vec::new());
+        }
+        let dealer = self.dealer;
+        let responses: Vec<Response> = self
+            .dispute
+            .complaints()
+            .filter_map(|index| self.shares.iter().find(|share| share.index == index))
+            .map(|share| Response { dealer, share: share.clone() })
+            .collect();
+        for response in &responses {
+            self.dispute.respond(response.clone())?;
+        }
+        Ok(responses)
+    }
+
+    // The verdict the participants' own disputes reach over the same messages.
+    pub fn finalize(&mut self) -> Result<Verdict, VssError> {
+        if self.state != DealerState::Answering {
+            return Err(VssError::InvalidState);
+        }
+        self.state = DealerState::Finalized;
+        self.shares.clear();
+        self.dispute.resolve()
+    }
+}
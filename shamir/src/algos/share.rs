@@ -0,0 +1,329 @@
+use alloc::vec::Vec;
+use core::fmt;
+use core::str::FromStr;
+
+use num_bigint::BigUint;
+use rand::{CryptoRng, Rng, RngCore};
+use sha2::{Digest, Sha256};
+use zeroize::Zeroize;
+
+use super::sss::ShamirError;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Share<V: ShareValue = u64> {
+    pub index: u64,
+    pub value: V,
+    pub threshold: usize,
+    pub scheme_id: u32,
+    // Bumped each time the share is proactively refreshed.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub epoch: u32,
+    // Level in a hierarchical dealing; zero for ordinary threshold shares.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub level: u32,
+    // How many shares the dealing produced; zero when unknown.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub total: usize,
+    // Drawn at random once per dealing and carried by each of its shares, so shares of two splits
+    // never combine even when their scheme ids collide; zero when unknown.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub fingerprint: u64,
+    // Unix time in seconds after which the share should no longer be used; None never expires.
+    // Only serde carries it; the text and binary encodings leave it out.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub valid_until: Option<u64>,
+}
+
+impl<V: ShareValue> Share<V> {
+    pub fn new(index: u64, value: V, threshold: usize, scheme_id: u32) -> Self {
+        Share { index, value, threshold, scheme_id, epoch: 0, level: 0, total: 0, fingerprint: 0, valid_until: None }
+    }
+}
+
+// Records the size of a fresh dealing and a new fingerprint on each of its shares.
+pub(crate) fn dealt<V: ShareValue>(
+    mut shares: Vec<Share<V>>,
+    rng: &mut (impl RngCore + CryptoRng),
+) -> Vec<Share<V>> {
+    let total = shares.len();
+    let fingerprint = rng.gen_range(1..=u64::MAX);
+    for share in shares.iter_mut() {
+        share.total = total;
+        share.fingerprint = fingerprint;
+    }
+    shares
+}
+
+// The fingerprint of a dealing run jointly (DKG, resharing), taken from its public commitments so
+// every member arrives at the same one without further rounds.
+pub(crate) fn joint_fingerprint(domain: &[u8], commitments: &[i128]) -> u64 {
+    let mut hasher = Sha256::new();
+    hasher.update(domain);
+    for c in commitments {
+        hasher.update(c.to_be_bytes());
+    }
+    let digest = hasher.finalize();
+    let mut bytes = [0u8; 8];
+    bytes.copy_from_slice(&digest[..8]);
+    u64::from_be_bytes(bytes).max(1)
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ParseShareError {
+    Malformed,
+    // The share parsed but its trailing checksum does not match, e.g. after a typo.
+    Corrupted,
+}
+
+impl<V: ShareValue> Zeroize for Share<V> {
+    fn zeroize(&mut self) {
+        self.value.wipe();
+        self.index.zeroize();
+    }
+}
+
+impl<V: ShareValue> Drop for Share<V> {
+    fn drop(&mut self) {
+        self.value.wipe();
+    }
+}
+
+pub trait ShareValue: Sized {
+    fn fmt_value(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result;
+    fn parse_value(s: &str) -> Option<Self>;
+    fn wipe(&mut self);
+}
+
+impl ShareValue for u64 {
+    fn fmt_value(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self)
+    }
+
+    fn parse_value(s: &str) -> Option<Self> {
+        s.parse().ok()
+    }
+
+    fn wipe(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl ShareValue for i128 {
+    fn fmt_value(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self)
+    }
+
+    fn parse_value(s: &str) -> Option<Self> {
+        s.parse().ok()
+    }
+
+    fn wipe(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl ShareValue for BigUint {
+    fn fmt_value(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:x}", self)
+    }
+
+    fn parse_value(s: &str) -> Option<Self> {
+        BigUint::parse_bytes(s.as_bytes(), 16)
+    }
+
+    // BigUint has no Zeroize impl; clearing bits from the bottom up overwrites every
+    // limb in place before the final normalisation can shrink the allocation.
+    fn wipe(&mut self) {
+        for bit in 0..self.bits() {
+            self.set_bit(bit, false);
+        }
+    }
+}
+
+impl ShareValue for Vec<u8> {
+    fn fmt_value(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for byte in self {
+            write!(f, "{:02x}", byte)?;
+        }
+        Ok(())
+    }
+
+    fn parse_value(s: &str) -> Option<Self> {
+        if !s.len().is_multiple_of(2) || !s.is_ascii() {
+            return None;
+        }
+        (0..s.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&s[i..i + 2], 16).ok())
+            .collect()
+    }
+
+    fn wipe(&mut self) {
+        self.zeroize();
+    }
+}
+
+// Everything in the text form except the trailing checksum.
+struct Body<'a, V: ShareValue>(&'a Share<V>);
+
+impl<V: ShareValue> fmt::Display for Body<'_, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let share = self.0;
+        write!(f, "{:08x}", share.scheme_id)?;
+        if share.epoch > 0 {
+            write!(f, ".{}", share.epoch)?;
+        }
+        write!(f, "-{}", share.threshold)?;
+        if share.total > 0 {
+            write!(f, "/{}", share.total)?;
+        }
+        if share.fingerprint > 0 {
+            write!(f, "#{:016x}", share.fingerprint)?;
+        }
+        write!(f, "-{}", share.index)?;
+        if share.level > 0 {
+            write!(f, "@{}", share.level)?;
+        }
+        write!(f, "-")?;
+        share.value.fmt_value(f)
+    }
+}
+
+struct DigestWriter(Sha256);
+
+impl fmt::Write for DigestWriter {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0.update(s.as_bytes());
+        Ok(())
+    }
+}
+
+// First four bytes of SHA-256 over the canonical body, so re-typing hex in upper case still verifies.
+fn checksum<V: ShareValue>(share: &Share<V>) -> u32 {
+    let mut writer = DigestWriter(Sha256::new());
+    let _ = fmt::Write::write_fmt(&mut writer, format_args!("{}", Body(share)));
+    let digest = writer.0.finalize();
+    u32::from_be_bytes([digest[0], digest[1], digest[2], digest[3]])
+}
+
+impl<V: ShareValue> fmt::Display for Share<V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{:08x}", Body(self), checksum(self))
+    }
+}
+
+impl<V: ShareValue> FromStr for Share<V> {
+    type Err = ParseShareError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (body, sum) = s.trim().rsplit_once('-').ok_or(ParseShareError::Malformed)?;
+        if sum.len() != 8 {
+            return Err(ParseShareError::Malformed);
+        }
+        let sum = u32::from_str_radix(sum, 16).map_err(|_| ParseShareError::Malformed)?;
+        let mut parts = body.splitn(4, '-');
+        let mut next = || parts.next().ok_or(ParseShareError::Malformed);
+        let id = next()?;
+        let (id, epoch) = match id.split_once('.') {
+            Some((id, epoch)) => (id, epoch.parse().map_err(|_| ParseShareError::Malformed)?),
+            None => (id, 0),
+        };
+        let scheme_id = u32::from_str_radix(id, 16).map_err(|_| ParseShareError::Malformed)?;
+        let threshold = next()?;
+        let (threshold, fingerprint) = match threshold.split_once('#') {
+            Some((threshold, fingerprint)) if fingerprint.len() == 16 => {
+                (threshold, u64::from_str_radix(fingerprint, 16).map_err(|_| ParseShareError::Malformed)?)
+            }
+            Some(_) => return Err(ParseShareError::Malformed),
+            None => (threshold, 0),
+        };
+        let (threshold, total) = match threshold.split_once('/') {
+            Some((threshold, total)) => (threshold, total.parse().map_err(|_| ParseShareError::Malformed)?),
+            None => (threshold, 0),
+        };
+        let threshold = threshold.parse().map_err(|_| ParseShareError::Malformed)?;
+        let index = next()?;
+        let (index, level) = match index.split_once('@') {
+            Some((index, level)) => (index, level.parse().map_err(|_| ParseShareError::Malformed)?),
+            None => (index, 0),
+        };
+        let index = index.parse().map_err(|_| ParseShareError::Malformed)?;
+        let value = V::parse_value(next()?).ok_or(ParseShareError::Malformed)?;
+        let share = Share { index, value, threshold, scheme_id, epoch, level, total, fingerprint, valid_until: None };
+        if checksum(&share) != sum {
+            return Err(ParseShareError::Corrupted);
+        }
+        Ok(share)
+    }
+}
+
+pub(crate) fn check_same_dealing<V: ShareValue>(shares: &[Share<V>]) -> bool {
+    // A total or fingerprint of zero is unknown and matches any other.
+    let total = shares.iter().map(|share| share.total).find(|&total| total > 0);
+    let fingerprint = shares.iter().map(|share| share.fingerprint).find(|&fingerprint| fingerprint > 0);
+    shares.windows(2).all(|pair| {
+        pair[0].scheme_id == pair[1].scheme_id
+            && pair[0].threshold == pair[1].threshold
+            && pair[0].epoch == pair[1].epoch
+    }) && shares.iter().all(|share| {
+        (share.total == 0 || Some(share.total) == total)
+            && (share.fingerprint == 0 || Some(share.fingerprint) == fingerprint)
+    })
+}
+
+// What to do with shares left behind by a refresh or past their `valid_until`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum StalePolicy {
+    // Fail, naming them.
+    #[default]
+    Refuse,
+    // Leave them out, report them, and go on with the rest.
+    Warn,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Staleness {
+    // Indices of shares from an older epoch than the newest one given.
+    pub stale: Vec<u64>,
+    // Indices of shares whose `valid_until` is before `now`.
+    pub expired: Vec<u64>,
+}
+
+impl Staleness {
+    pub fn is_empty(&self) -> bool {
+        self.stale.is_empty() && self.expired.is_empty()
+    }
+}
+
+// Splits off the shares that are expired at `now` (skipped when None, e.g. without a clock) or
+// older than the newest epoch present; shares of different epochs never lie on one polynomial.
+pub fn check_fresh<V: ShareValue + Clone>(
+    shares: &[Share<V>],
+    now: Option<u64>,
+    policy: StalePolicy,
+) -> Result<(Vec<Share<V>>, Staleness), ShamirError> {
+    let expired = |share: &Share<V>| matches!((share.valid_until, now), (Some(until), Some(now)) if until < now);
+    let newest = shares.iter().filter(|share| !expired(share)).map(|share| share.epoch).max();
+    let mut fresh = Vec::with_capacity(shares.len());
+    let mut staleness = Staleness::default();
+    for share in shares {
+        if expired(share) {
+            staleness.expired.push(share.index);
+        } else if Some(share.epoch) != newest {
+            staleness.stale.push(share.index);
+        } else {
+            fresh.push(share.clone());
+        }
+    }
+    if policy == StalePolicy::Refuse {
+        if !staleness.expired.is_empty() {
+            return Err(ShamirError::ExpiredShares(staleness.expired));
+        }
+        if !staleness.stale.is_empty() {
+            return Err(ShamirError::StaleShares(staleness.stale));
+        }
+    }
+    Ok((fresh, staleness))
+}
@@ -0,0 +1,122 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use sha2::{Digest, Sha256};
+
+use super::share::{Share, ShareValue};
+use super::sss::ShamirError;
+
+// One JSON file per byte share, `share-NN.json`. Besides the share itself every file repeats the
+// dealing's parameters and a SHA-256 commitment to each share of it, so a combiner can tell that
+// files from different dealings were mixed, or that a value was edited, before interpolating.
+
+pub const VERSION: u8 = 1;
+pub const SCHEME: &str = "gf256";
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ShareFile {
+    pub version: u8,
+    pub scheme: String,
+    pub index: u64,
+    pub threshold: usize,
+    pub shares: usize,
+    pub scheme_id: u32,
+    // Hex, as in the text form of a share.
+    pub value: String,
+    // Hex SHA-256 of every share in the dealing, in the order they were dealt.
+    pub commitments: Vec<String>,
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+fn commitment(share: &Share<Vec<u8>>) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(share.scheme_id.to_be_bytes());
+    hasher.update(share.index.to_be_bytes());
+    hasher.update(&share.value);
+    hex(&hasher.finalize())
+}
+
+// Whether the dealer recorded this exact share among a dealing's commitments.
+pub fn is_committed(share: &Share<Vec<u8>>, commitments: &[String]) -> bool {
+    commitments.contains(&commitment(share))
+}
+
+pub fn from_shares(shares: &[Share<Vec<u8>>]) -> Vec<ShareFile> {
+    let commitments: Vec<String> = shares.iter().map(commitment).collect();
+    shares
+        .iter()
+        .map(|share| ShareFile {
+            version: VERSION,
+            scheme: SCHEME.into(),
+            index: share.index,
+            threshold: share.threshold,
+            shares: shares.len(),
+            scheme_id: share.scheme_id,
+            value: hex(&share.value),
+            commitments: commitments.clone(),
+        })
+        .collect()
+}
+
+impl ShareFile {
+    // The share, once its value matches the commitment the dealer recorded for it.
+    pub fn to_share(&self) -> Result<Share<Vec<u8>>, ShamirError> {
+        if self.version != VERSION {
+            return Err(ShamirError::InvalidParameters("version"));
+        }
+        if self.scheme != SCHEME {
+            return Err(ShamirError::InvalidParameters("scheme"));
+        }
+        let value = Vec::<u8>::parse_value(&self.value).ok_or(ShamirError::InvalidParameters("value"))?;
+        let mut share = Share::new(self.index, value, self.threshold, self.scheme_id);
+        if !is_committed(&share, &self.commitments) {
+            return Err(ShamirError::InconsistentShares);
+        }
+        share.total = self.shares;
+        Ok(share)
+    }
+}
+
+// The shares of a set of files, which must all describe the same dealing.
+pub fn to_shares(files: &[ShareFile]) -> Result<Vec<Share<Vec<u8>>>, ShamirError> {
+    if let Some(first) = files.first() {
+        let same_dealing = |file: &ShareFile| {
+            file.scheme_id == first.scheme_id
+                && file.threshold == first.threshold
+                && file.shares == first.shares
+                && file.commitments == first.commitments
+        };
+        if !files.iter().all(same_dealing) {
+            return Err(ShamirError::InconsistentShares);
+        }
+    }
+    files.iter().map(ShareFile::to_share).collect()
+}
+
+pub fn file_name(index: u64) -> String {
+    format!("share-{:02}.json", index)
+}
+
+pub fn write_files(dir: &Path, shares: &[Share<Vec<u8>>]) -> io::Result<Vec<PathBuf>> {
+    fs::create_dir_all(dir)?;
+    let mut paths = Vec::with_capacity(shares.len());
+    for file in from_shares(shares) {
+        let path = dir.join(file_name(file.index));
+        fs::write(&path, serde_json::to_string_pretty(&file).map_err(io::Error::from)?)?;
+        paths.push(path);
+    }
+    Ok(paths)
+}
+
+pub fn read_file(path: &Path) -> io::Result<ShareFile> {
+    Ok(serde_json::from_slice(&fs::read(path)?)?)
+}
+
+pub fn read_files<P: AsRef<Path>>(paths: &[P]) -> io::Result<Vec<Share<Vec<u8>>>> {
+    let files = paths.iter().map(|path| read_file(path.as_ref())).collect::<io::Result<Vec<_>>>()?;
+    to_shares(&files).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+}
@@ -0,0 +1,324 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::mpsc::{self, Receiver, Sender};
+
+use rand::{CryptoRng, RngCore};
+
+use super::complaint::{Complaint, Dispute, Response, Verdict};
+use super::refresh::{self, RefreshContribution};
+use super::session::{DealerSession, Participant};
+use super::share::Share;
+use super::vss::{self, VerifiedReconstruction, VssConfig, VssError};
+
+// An in-process run of the whole share lifecycle: a dealer and n participants, each with its own
+// channel, go through dealing, the complaint round, one proactive refresh, and reconstruction at
+// a coordinator. Faults drop or alter chosen point-to-point messages on the way, which is how a
+// test stands in for a lossy link or a cheating dealer or holder. Broadcasts are assumed reliable,
+// so every participant resolves the same complaints.
+
+pub const DEALER: u64 = 0;
+pub const COORDINATOR: u64 = u64::MAX;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Phase {
+    // dealer -> participant `to`
+    Deal,
+    // the dealer's public answer to complainant `to`; altering it is the dealer cheating
+    Response,
+    // holder `from` -> holder `to`
+    Refresh,
+    // holder `from` -> COORDINATOR
+    Reconstruct,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FaultKind {
+    Drop,
+    // The carried share's value is shifted by one, so it no longer matches its commitments.
+    Corrupt,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Fault {
+    pub phase: Phase,
+    pub from: u64,
+    pub to: u64,
+    pub kind: FaultKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SimReport {
+    pub complaints: Vec<Complaint>,
+    pub verdict: Verdict,
+    // Holders whose refresh was left out after a complaint.
+    pub excluded_refreshes: Vec<u64>,
+    pub reconstruction: Option<VerifiedReconstruction>,
+}
+
+enum Message {
+    Share(Share<i128>),
+    Commitments(Vec<i128>),
+    Complaint(Complaint),
+    Response(Response),
+    RefreshCommitments { from: u64, commitments: Vec<i128> },
+    SubShare { from: u64, sub_share: Share<i128> },
+}
+
+struct Node {
+    participant: Participant,
+    inbox: Receiver<Message>,
+    share: Option<Share<i128>>,
+}
+
+struct Network {
+    senders: BTreeMap<u64, Sender<Message>>,
+    faults: Vec<Fault>,
+    order: i128,
+}
+
+impl Network {
+    fn fault(&self, phase: Phase, from: u64, to: u64) -> Option<FaultKind> {
+        self.faults
+            .iter()
+            .find(|fault| fault.phase == phase && fault.from == from && fault.to == to)
+            .map(|fault| fault.kind)
+    }
+
+    // The share as it arrives, or None if it was dropped.
+    fn tamper(&self, phase: Phase, from: u64, to: u64, share: &Share<i128>) -> Option<Share<i128>> {
+        let mut share = share.clone();
+        match self.fault(phase, from, to) {
+            Some(FaultKind::Drop) => return None,
+            Some(FaultKind::Corrupt) => share.value = vss::mod_norm(share.value + 1, self.order),
+            None => {}
+        }
+        Some(share)
+    }
+
+    fn send(&self, to: u64, message: Message) {
+        // A receiver only goes away once the simulation is over.
+        let _ = self.senders[&to].send(message);
+    }
+
+    fn broadcast(&self, message: impl Fn() -> Message) {
+        for &to in self.senders.keys() {
+            self.send(to, message());
+        }
+    }
+}
+
+// The holders named in complaints about their refresh; complaints about the dealer name DEALER.
+fn refresh_complaints(inbox: &Receiver<Message>) -> BTreeSet<u64> {
+    inbox
+        .try_iter()
+        .filter_map(|message| match message {
+            Message::Complaint(complaint) if complaint.dealer != DEALER => Some(complaint.dealer),
+            _ => None,
+        })
+        .collect()
+}
+
+pub struct Simulation {
+    config: VssConfig,
+    faults: Vec<Fault>,
+}
+
+impl Simulation {
+    pub fn new(config: VssConfig) -> Self {
+        Simulation { config, faults: Vec::new() }
+    }
+
+    pub fn with_fault(mut self, fault: Fault) -> Self {
+        self.faults.push(fault);
+        self
+    }
+
+    pub fn run(&self, secret: i128, rng: &mut (impl RngCore + CryptoRng)) -> Result<SimReport, VssError> {
+        let config = self.config;
+        let mut senders = BTreeMap::new();
+        let mut inboxes = BTreeMap::new();
+        for id in (0..=config.shares() as u64).chain([COORDINATOR]) {
+            let (sender, receiver) = mpsc::channel();
+            senders.insert(id, sender);
+            inboxes.insert(id, receiver);
+        }
+        let network = Network { senders, faults: self.faults.clone(), order: config.order() };
+        let dealer_inbox = inboxes.remove(&DEALER).ok_or(VssError::InvalidState)?;
+        let coordinator_inbox = inboxes.remove(&COORDINATOR).ok_or(VssError::InvalidState)?;
+        let mut nodes = inboxes
+            .into_iter()
+            .map(|(index, inbox)| {
+                Ok(Node { participant: Participant::new(index, DEALER, config)?, inbox, share: None })
+            })
+            .collect::<Result<Vec<_>, VssError>>()?;
+
+        // Dealing.
+        let (mut dealer, dealing) = DealerSession::deal(DEALER, secret, config, rng)?;
+        for share in &dealing.shares {
+            if let Some(share) = network.tamper(Phase::Deal, DEALER, share.index, share) {
+                network.send(share.index, Message::Share(share));
+            }
+        }
+        network.broadcast(|| Message::Commitments(dealing.commitments.clone()));
+        drop(dealing.shares);
+
+        // Complaints, broadcast once every participant has checked its share.
+        let mut filed = Vec::new();
+        for node in &mut nodes {
+            let mut share = None;
+            let mut commitments = Vec::new();
+            for message in node.inbox.try_iter() {
+                match message {
+                    Message::Share(received) => share = Some(received),
+                    Message::Commitments(received) => commitments = received,
+                    _ => return Err(VssError::InvalidState),
+                }
+            }
+            filed.extend(node.participant.receive(share, commitments)?);
+        }
+        for complaint in filed {
+            network.broadcast(|| Message::Complaint(complaint.clone()));
+        }
+        let complaints: Vec<Complaint> = dealer_inbox
+            .try_iter()
+            .filter_map(|message| match message {
+                Message::Complaint(complaint) => Some(complaint),
+                _ => None,
+            })
+            .collect();
+        for complaint in &complaints {
+            dealer.file(complaint)?;
+        }
+        for mut response in dealer.answer()? {
+            if let Some(share) = network.tamper(Phase::Response, DEALER, response.share.index, &response.share) {
+                response.share = share;
+                network.broadcast(|| Message::Response(response.clone()));
+            }
+        }
+        dealer.finalize()?;
+
+        // Every participant resolves the round from what it saw on the broadcast channel.
+        let mut verdict = None;
+        for node in &mut nodes {
+            let mut dispute = Dispute::new(DEALER, node.participant.commitments().to_vec(), config)?;
+            let mut responses = Vec::new();
+            for message in node.inbox.try_iter() {
+                match message {
+                    Message::Complaint(complaint) => dispute.file(&complaint)?,
+                    Message::Response(response) => responses.push(response),
+                    _ => return Err(VssError::InvalidState),
+                }
+            }
+            dispute.close()?;
+            for response in responses {
+                dispute.respond(response)?;
+            }
+            let resolved = dispute.resolve()?;
+            node.share = node.participant.finalize(&resolved)?;
+            verdict = Some(resolved);
+        }
+        let verdict = verdict.ok_or(VssError::InvalidState)?;
+        if matches!(verdict, Verdict::Disqualified(_)) {
+            return Ok(SimReport { complaints, verdict, excluded_refreshes: Vec::new(), reconstruction: None });
+        }
+
+        // Refresh: every holder deals a sharing of zero; a holder whose sub-share is missing or
+        // wrong complains, and every holder then leaves out the contributions complained about.
+        for node in &nodes {
+            let from = node.participant.index();
+            let contribution = refresh::generate_refresh(&config, rng);
+            let commitments = &contribution.commitments;
+            network.broadcast(|| Message::RefreshCommitments { from, commitments: commitments.clone() });
+            for sub_share in &contribution.shares {
+                if let Some(sub_share) = network.tamper(Phase::Refresh, from, sub_share.index, sub_share) {
+                    network.send(sub_share.index, Message::SubShare { from, sub_share });
+                }
+            }
+        }
+        let mut received = Vec::with_capacity(nodes.len());
+        let mut filed = Vec::new();
+        for node in &nodes {
+            let mut commitments = BTreeMap::new();
+            let mut sub_shares = BTreeMap::new();
+            for message in node.inbox.try_iter() {
+                match message {
+                    Message::RefreshCommitments { from, commitments: received } => {
+                        commitments.insert(from, received);
+                    }
+                    Message::SubShare { from, sub_share } => {
+                        sub_shares.insert(from, sub_share);
+                    }
+                    _ => return Err(VssError::InvalidState),
+                }
+            }
+            let mut contributions = BTreeMap::new();
+            for (from, commitments) in commitments {
+                let shares = sub_shares.remove(&from).into_iter().collect();
+                let contribution = RefreshContribution { commitments, shares };
+                if contribution.shares.is_empty() || refresh::verify_refresh(&contribution, &config).is_err() {
+                    filed.push(Complaint { complainant: node.participant.index(), dealer: from });
+                }
+                contributions.insert(from, contribution);
+            }
+            received.push(contributions);
+        }
+        for complaint in filed {
+            network.broadcast(|| Message::Complaint(complaint.clone()));
+        }
+        for (node, contributions) in nodes.iter_mut().zip(received) {
+            let excluded = refresh_complaints(&node.inbox);
+            let accepted: Vec<RefreshContribution> = contributions
+                .into_iter()
+                .filter(|(from, _)| !excluded.contains(from))
+                .map(|(_, contribution)| contribution)
+                .collect();
+            if let Some(share) = &node.share {
+                node.share = Some(refresh::apply_refresh(share, &accepted, &config)?);
+            }
+        }
+
+        // The coordinator follows the broadcasts to learn the refreshed commitments.
+        let mut commitments = Vec::new();
+        let mut refreshes = BTreeMap::new();
+        let mut excluded = BTreeSet::new();
+        for message in coordinator_inbox.try_iter() {
+            match message {
+                Message::Commitments(received) => commitments = received,
+                Message::RefreshCommitments { from, commitments } => {
+                    refreshes.insert(from, RefreshContribution { commitments, shares: Vec::new() });
+                }
+                Message::Complaint(complaint) if complaint.dealer != DEALER => {
+                    excluded.insert(complaint.dealer);
+                }
+                _ => {}
+            }
+        }
+        refreshes.retain(|from, _| !excluded.contains(from));
+        let refreshes: Vec<RefreshContribution> = refreshes.into_values().collect();
+        let commitments = refresh::refresh_commitments(&commitments, &refreshes, &config)?;
+
+        // Reconstruction.
+        for node in &nodes {
+            if let Some(share) = &node.share
+                && let Some(share) = network.tamper(Phase::Reconstruct, share.index, COORDINATOR, share)
+            {
+                network.send(COORDINATOR, Message::Share(share));
+            }
+        }
+        let mut shares: Vec<Share<i128>> = coordinator_inbox
+            .try_iter()
+            .filter_map(|message| match message {
+                Message::Share(share) => Some(share),
+                _ => None,
+            })
+            .collect();
+        shares.sort_by_key(|share| share.index);
+        let reconstruction = vss::reconstruct_verified(&shares, &commitments, &config)?;
+
+        Ok(SimReport {
+            complaints,
+            verdict,
+            excluded_refreshes: excluded.into_iter().collect(),
+            reconstruction: Some(reconstruction),
+        })
+    }
+}
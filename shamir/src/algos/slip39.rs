@@ -0,0 +1,453 @@
+use std::collections::BTreeMap;
+use std::sync::OnceLock;
+
+use hmac::{Hmac, Mac};
+use rand::{CryptoRng, Rng, RngCore};
+use sha2::Sha256;
+use zeroize::Zeroizing;
+
+use super::sss::ShamirError;
+
+const WORDLIST: &str = include_str!("wordlists/slip39_english.txt");
+const RADIX_BITS: usize = 10;
+const ID_BITS: usize = 15;
+const CHECKSUM_WORDS: usize = 3;
+const HEADER_WORDS: usize = 4;
+const MIN_SECRET_BYTES: usize = 16;
+const MIN_MNEMONIC_WORDS: usize = HEADER_WORDS + 13 + CHECKSUM_WORDS;
+const DIGEST_INDEX: u8 = 254;
+const SECRET_INDEX: u8 = 255;
+const DIGEST_LEN: usize = 4;
+const BASE_ITERATIONS: u32 = 10000;
+const ROUNDS: u8 = 4;
+const MAX_SHARES: u8 = 16;
+
+fn words() -> &'static Vec<&'static str> {
+    static WORDS: OnceLock<Vec<&'static str>> = OnceLock::new();
+    WORDS.get_or_init(|| WORDLIST.lines().collect())
+}
+
+fn word_index(word: &str) -> Option<u16> {
+    let word = word.to_lowercase();
+    words().binary_search(&word.as_str()).ok().map(|i| i as u16)
+}
+
+fn customization(extendable: bool) -> &'static [u8] {
+    if extendable { b"shamir_extendable" } else { b"shamir" }
+}
+
+fn rs1024_polymod(values: impl IntoIterator<Item = u32>) -> u32 {
+    const GEN: [u32; 10] = [
+        0xe0e040, 0x1c1c080, 0x3838100, 0x7070200, 0xe0e0009,
+        0x1c0c2412, 0x38086c24, 0x3090fc48, 0x21b1f890, 0x3f3f120,
+    ];
+    let mut chk = 1u32;
+    for v in values {
+        let b = chk >> 20;
+        chk = ((chk & 0xfffff) << 10) ^ v;
+        for (i, g) in GEN.iter().enumerate() {
+            if (b >> i) & 1 == 1 {
+                chk ^= g;
+            }
+        }
+    }
+    chk
+}
+
+fn rs1024_create_checksum(extendable: bool, data: &[u16]) -> [u16; 3] {
+    let values = customization(extendable)
+        .iter()
+        .map(|&c| c as u32)
+        .chain(data.iter().map(|&d| d as u32))
+        .chain([0, 0, 0]);
+    let polymod = rs1024_polymod(values) ^ 1;
+    [(polymod >> 20) as u16 & 1023, (polymod >> 10) as u16 & 1023, polymod as u16 & 1023]
+}
+
+fn rs1024_verify_checksum(extendable: bool, data: &[u16]) -> bool {
+    let values = customization(extendable)
+        .iter()
+        .map(|&c| c as u32)
+        .chain(data.iter().map(|&d| d as u32));
+    rs1024_polymod(values) == 1
+}
+
+// SLIP-0039 uses the Rijndael polynomial x^8 + x^4 + x^3 + x + 1, unlike the gfshare one in gf256.
+fn gf_mul(a: u8, b: u8) -> u8 {
+    let (mut a, mut b, mut product) = (a as u16, b, 0u16);
+    while b != 0 {
+        if b & 1 == 1 {
+            product ^= a;
+        }
+        a <<= 1;
+        if a & 0x100 != 0 {
+            a ^= 0x11b;
+        }
+        b >>= 1;
+    }
+    product as u8
+}
+
+fn gf_inv(a: u8) -> u8 {
+    let mut square = a;
+    let mut result = 1;
+    for _ in 1..8 {
+        square = gf_mul(square, square);
+        result = gf_mul(result, square);
+    }
+    result
+}
+
+fn interpolate(points: &[(u8, Vec<u8>)], x: u8) -> Result<Vec<u8>, ShamirError> {
+    let len = points.first().ok_or(ShamirError::InsufficientShares { provided: 0, required: 1 })?.1.len();
+    if points.iter().any(|(_, value)| value.len() != len) {
+        return Err(ShamirError::InconsistentShares);
+    }
+    if let Some((_, value)) = points.iter().find(|(xi, _)| *xi == x) {
+        return Ok(value.clone());
+    }
+
+    let mut result = vec![0u8; len];
+    for (i, (x_i, value)) in points.iter().enumerate() {
+        let mut numerator = 1;
+        let mut denominator = 1;
+        for (j, (x_j, _)) in points.iter().enumerate() {
+            if i != j {
+                if x_i == x_j {
+                    return Err(ShamirError::InconsistentShares);
+                }
+                numerator = gf_mul(numerator, x ^ x_j);
+                denominator = gf_mul(denominator, x_i ^ x_j);
+            }
+        }
+        let basis = gf_mul(numerator, gf_inv(denominator));
+        for (out, &byte) in result.iter_mut().zip(value) {
+            *out ^= gf_mul(byte, basis);
+        }
+    }
+    Ok(result)
+}
+
+fn digest(random_part: &[u8], secret: &[u8]) -> [u8; DIGEST_LEN] {
+    let mut mac = Hmac::<Sha256>::new_from_slice(random_part).expect("HMAC accepts any key length");
+    mac.update(secret);
+    let mut out = [0u8; DIGEST_LEN];
+    out.copy_from_slice(&mac.finalize().into_bytes()[..DIGEST_LEN]);
+    out
+}
+
+fn split_secret(
+    threshold: u8,
+    count: u8,
+    secret: &[u8],
+    rng: &mut (impl RngCore + CryptoRng),
+) -> Result<Vec<(u8, Vec<u8>)>, ShamirError> {
+    if threshold == 0 || threshold > count || count > MAX_SHARES {
+        return Err(ShamirError::InvalidThreshold);
+    }
+    if threshold == 1 {
+        return Ok((0..count).map(|i| (i, secret.to_vec())).collect());
+    }
+
+    let mut shares: Vec<(u8, Vec<u8>)> = (0..threshold - 2)
+        .map(|i| {
+            let mut value = vec![0u8; secret.len()];
+            rng.fill(&mut value[..]);
+            (i, value)
+        })
+        .collect();
+    let mut random_part = vec![0u8; secret.len() - DIGEST_LEN];
+    rng.fill(&mut random_part[..]);
+    let mut digest_share = digest(&random_part, secret).to_vec();
+    digest_share.extend_from_slice(&random_part);
+
+    let mut base = shares.clone();
+    base.push((DIGEST_INDEX, digest_share));
+    base.push((SECRET_INDEX, secret.to_vec()));
+    for i in threshold - 2..count {
+        shares.push((i, interpolate(&base, i)?));
+    }
+    Ok(shares)
+}
+
+fn recover_secret(threshold: u8, points: &[(u8, Vec<u8>)]) -> Result<Vec<u8>, ShamirError> {
+    if threshold == 1 {
+        return Ok(points.first().ok_or(ShamirError::InsufficientShares { provided: 0, required: 1 })?.1.clone());
+    }
+    let secret = interpolate(points, SECRET_INDEX)?;
+    let digest_share = interpolate(points, DIGEST_INDEX)?;
+    if digest_share[..DIGEST_LEN] != digest(&digest_share[DIGEST_LEN..], &secret) {
+        return Err(ShamirError::DigestMismatch);
+    }
+    Ok(secret)
+}
+
+fn feistel(
+    input: &[u8],
+    passphrase: &[u8],
+    iteration_exponent: u8,
+    identifier: u16,
+    extendable: bool,
+    rounds: impl Iterator<Item = u8>,
+) -> Vec<u8> {
+    let half = input.len() / 2;
+    let mut left = input[..half].to_vec();
+    let mut right = input[half..].to_vec();
+    let mut salt_prefix = Vec::new();
+    if !extendable {
+        salt_prefix.extend_from_slice(customization(false));
+        salt_prefix.extend_from_slice(&identifier.to_be_bytes());
+    }
+    let iterations = (BASE_ITERATIONS << iteration_exponent) / ROUNDS as u32;
+
+    for round in rounds {
+        let mut password = vec![round];
+        password.extend_from_slice(passphrase);
+        let mut salt = salt_prefix.clone();
+        salt.extend_from_slice(&right);
+        let mut f = vec![0u8; half];
+        pbkdf2::pbkdf2_hmac::<Sha256>(&password, &salt, iterations, &mut f);
+        let next = left.iter().zip(&f).map(|(l, f)| l ^ f).collect();
+        left = std::mem::replace(&mut right, next);
+    }
+    right.extend_from_slice(&left);
+    right
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Slip39Share {
+    pub identifier: u16,
+    pub extendable: bool,
+    pub iteration_exponent: u8,
+    pub group_index: u8,
+    pub group_threshold: u8,
+    pub group_count: u8,
+    pub member_index: u8,
+    pub member_threshold: u8,
+    pub value: Vec<u8>,
+}
+
+impl Slip39Share {
+    pub fn to_mnemonic(&self) -> String {
+        let value_words = (self.value.len() * 8).div_ceil(RADIX_BITS);
+        let mut data: Vec<u16> = Vec::with_capacity(HEADER_WORDS + value_words + CHECKSUM_WORDS);
+
+        let id_exp = ((self.identifier as u32) << 5)
+            | ((self.extendable as u32) << 4)
+            | self.iteration_exponent as u32;
+        data.push((id_exp >> 10) as u16);
+        data.push((id_exp & 1023) as u16);
+        let params = ((self.group_index as u32) << 16)
+            | (((self.group_threshold - 1) as u32) << 12)
+            | (((self.group_count - 1) as u32) << 8)
+            | ((self.member_index as u32) << 4)
+            | (self.member_threshold - 1) as u32;
+        data.push((params >> 10) as u16);
+        data.push((params & 1023) as u16);
+
+        // The value is left-padded with zero bits up to a whole number of words.
+        let mut acc: u32 = 0;
+        let mut bits = value_words * RADIX_BITS - self.value.len() * 8;
+        for &byte in &self.value {
+            acc = (acc << 8) | byte as u32;
+            bits += 8;
+            while bits >= RADIX_BITS {
+                bits -= RADIX_BITS;
+                data.push(((acc >> bits) & 1023) as u16);
+            }
+        }
+
+        let checksum = rs1024_create_checksum(self.extendable, &data);
+        data.extend_from_slice(&checksum);
+        data.iter().map(|&i| words()[i as usize]).collect::<Vec<_>>().join(" ")
+    }
+
+    pub fn from_mnemonic(mnemonic: &str) -> Result<Self, ShamirError> {
+        let data = mnemonic
+            .split_whitespace()
+            .map(word_index)
+            .collect::<Option<Vec<u16>>>()
+            .ok_or(ShamirError::InvalidMnemonic)?;
+        if data.len() < MIN_MNEMONIC_WORDS {
+            return Err(ShamirError::InvalidMnemonic);
+        }
+
+        let padding = (RADIX_BITS * (data.len() - HEADER_WORDS - CHECKSUM_WORDS)) % 16;
+        if padding > 8 {
+            return Err(ShamirError::InvalidMnemonic);
+        }
+
+        let id_exp = ((data[0] as u32) << 10) | data[1] as u32;
+        let extendable = (id_exp >> 4) & 1 == 1;
+        if !rs1024_verify_checksum(extendable, &data) {
+            return Err(ShamirError::InvalidChecksum);
+        }
+
+        let params = ((data[2] as u32) << 10) | data[3] as u32;
+        let group_threshold = ((params >> 12) & 15) as u8 + 1;
+        let group_count = ((params >> 8) & 15) as u8 + 1;
+        if group_threshold > group_count {
+            return Err(ShamirError::InvalidThreshold);
+        }
+
+        let value_data = &data[HEADER_WORDS..data.len() - CHECKSUM_WORDS];
+        let mut value = Vec::with_capacity(value_data.len() * RADIX_BITS / 8);
+        let mut acc: u32 = 0;
+        let mut bits = 0;
+        let mut skip = padding;
+        for &word in value_data {
+            acc = (acc << RADIX_BITS) | word as u32;
+            bits += RADIX_BITS;
+            if skip > 0 {
+                if acc >> (bits - skip) != 0 {
+                    return Err(ShamirError::InvalidMnemonic);
+                }
+                bits -= skip;
+                acc &= (1 << bits) - 1;
+                skip = 0;
+            }
+            while bits >= 8 {
+                bits -= 8;
+                value.push((acc >> bits) as u8);
+                acc &= (1 << bits) - 1;
+            }
+        }
+        if value.len() < MIN_SECRET_BYTES || !value.len().is_multiple_of(2) {
+            return Err(ShamirError::InvalidMnemonic);
+        }
+
+        Ok(Slip39Share {
+            identifier: (id_exp >> 5) as u16,
+            extendable,
+            iteration_exponent: (id_exp & 15) as u8,
+            group_index: (params >> 16) as u8,
+            group_threshold,
+            group_count,
+            member_index: ((params >> 4) & 15) as u8,
+            member_threshold: (params & 15) as u8 + 1,
+            value,
+        })
+    }
+}
+
+// `groups` lists (member threshold, member count) for each group.
+pub fn generate_mnemonics(
+    group_threshold: u8,
+    groups: &[(u8, u8)],
+    master_secret: &[u8],
+    passphrase: &[u8],
+    extendable: bool,
+    iteration_exponent: u8,
+    rng: &mut (impl RngCore + CryptoRng),
+) -> Result<Vec<Vec<String>>, ShamirError> {
+    if master_secret.len() < MIN_SECRET_BYTES || !master_secret.len().is_multiple_of(2) {
+        return Err(ShamirError::SecretOutOfRange);
+    }
+    if iteration_exponent > 15 {
+        return Err(ShamirError::InvalidParameters("iteration_exponent"));
+    }
+    if !passphrase.iter().all(|b| (32..=126).contains(b)) {
+        return Err(ShamirError::InvalidParameters("passphrase"));
+    }
+    if group_threshold == 0 || group_threshold as usize > groups.len() || groups.len() > MAX_SHARES as usize {
+        return Err(ShamirError::InvalidThreshold);
+    }
+    if groups.iter().any(|&(t, n)| t == 0 || t > n || n > MAX_SHARES || (t == 1 && n > 1)) {
+        return Err(ShamirError::InvalidShareCount);
+    }
+
+    let identifier = rng.gen_range(0..1u16 << ID_BITS);
+    let encrypted = Zeroizing::new(feistel(
+        master_secret,
+        passphrase,
+        iteration_exponent,
+        identifier,
+        extendable,
+        0..ROUNDS,
+    ));
+    let group_shares = split_secret(group_threshold, groups.len() as u8, &encrypted, rng)?;
+
+    let mut mnemonics = Vec::with_capacity(groups.len());
+    for (&(member_threshold, member_count), (group_index, group_secret)) in groups.iter().zip(group_shares) {
+        let members = split_secret(member_threshold, member_count, &group_secret, rng)?;
+        mnemonics.push(
+            members
+                .into_iter()
+                .map(|(member_index, value)| {
+                    Slip39Share {
+                        identifier,
+                        extendable,
+                        iteration_exponent,
+                        group_index,
+                        group_threshold,
+                        group_count: groups.len() as u8,
+                        member_index,
+                        member_threshold,
+                        value,
+                    }
+                    .to_mnemonic()
+                })
+                .collect(),
+        );
+    }
+    Ok(mnemonics)
+}
+
+pub fn combine_mnemonics<S: AsRef<str>>(mnemonics: &[S], passphrase: &[u8]) -> Result<Zeroizing<Vec<u8>>, ShamirError> {
+    let shares = mnemonics
+        .iter()
+        .map(|m| Slip39Share::from_mnemonic(m.as_ref()))
+        .collect::<Result<Vec<_>, _>>()?;
+    let first = shares.first().ok_or(ShamirError::InsufficientShares { provided: 0, required: 1 })?;
+    let same_set = shares.iter().all(|s| {
+        s.identifier == first.identifier
+            && s.extendable == first.extendable
+            && s.iteration_exponent == first.iteration_exponent
+            && s.group_threshold == first.group_threshold
+            && s.group_count == first.group_count
+            && s.value.len() == first.value.len()
+    });
+    if !same_set {
+        return Err(ShamirError::InconsistentShares);
+    }
+
+    let mut groups: BTreeMap<u8, Vec<&Slip39Share>> = BTreeMap::new();
+    for share in &shares {
+        groups.entry(share.group_index).or_default().push(share);
+    }
+    if groups.len() < first.group_threshold as usize {
+        return Err(ShamirError::InsufficientShares { provided: groups.len(), required: first.group_threshold as usize });
+    }
+
+    let mut group_points = Vec::with_capacity(groups.len());
+    for (&group_index, members) in &groups {
+        let member_threshold = members[0].member_threshold;
+        if members.iter().any(|m| m.member_threshold != member_threshold) {
+            return Err(ShamirError::InconsistentShares);
+        }
+        let mut points: Vec<(u8, Vec<u8>)> = Vec::with_capacity(members.len());
+        for member in members {
+            if points.iter().any(|(index, _)| *index == member.member_index) {
+                return Err(ShamirError::InconsistentShares);
+            }
+            points.push((member.member_index, member.value.clone()));
+        }
+        if points.len() != member_threshold as usize {
+            return Err(ShamirError::InsufficientShares { provided: points.len(), required: member_threshold as usize });
+        }
+        group_points.push((group_index, recover_secret(member_threshold, &points)?));
+    }
+    if group_points.len() != first.group_threshold as usize {
+        return Err(ShamirError::InconsistentShares);
+    }
+
+    let encrypted = Zeroizing::new(recover_secret(first.group_threshold, &group_points)?);
+    Ok(Zeroizing::new(feistel(
+        &encrypted,
+        passphrase,
+        first.iteration_exponent,
+        first.identifier,
+        first.extendable,
+        (0..ROUNDS).rev(),
+    )))
+}
@@ -1,110 +1,405 @@
-use rand::Rng;
+use alloc::collections::BTreeSet;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
 
-const PRIME: u64 = 2147483647;
+use rand::{CryptoRng, Rng, RngCore};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use sha2::{Digest, Sha256};
+use unicode_normalization::UnicodeNormalization;
+use zeroize::Zeroizing;
+
+use super::field::{FiniteField, PrimeField64};
+use super::polynomial;
+use super::{gf256, multipoint};
+use super::share::{self, Share, ShareValue, StalePolicy, Staleness};
+use super::vss::VssError;
+
+pub const PRIME: u64 = 2147483647;
+pub const FIELD: PrimeField64 = PrimeField64::new(PRIME);
 
 #[derive(Debug)]
 pub enum ShamirError {
     InvalidThreshold,
     InvalidShareCount,
-    InsufficientShares,
+    InsufficientShares { provided: usize, required: usize },
+    InconsistentShares,
+    InvalidModulus,
+    SecretOutOfRange,
+    // Names the offending parameter.
+    InvalidParameters(&'static str),
+    InvalidMnemonic,
+    InvalidChecksum,
+    DigestMismatch,
+    // Bytes recovered by `combine_to_string` aren't a length-prefixed, padded UTF-8 string.
+    InvalidString,
+    // A sealed share that doesn't open: the wrong passphrase, or the ciphertext was altered.
+    DecryptionFailed,
+    // An x-coordinate that is zero in the field; a share there would be the secret itself.
+    ZeroIndex,
+    DuplicateIndex,
+    // More shares than the threshold were given and these indices disagree with the first ones.
+    Outliers(Vec<u64>),
+    // Shares from before the latest refresh, or past their expiry, under `StalePolicy::Refuse`.
+    StaleShares(Vec<u64>),
+    ExpiredShares(Vec<u64>),
+    Vss(VssError),
+}
+
+impl fmt::Display for ShamirError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ShamirError::InvalidThreshold => write!(f, "threshold must be at least 2"),
+            ShamirError::InvalidShareCount => write!(f, "share count must be at least the threshold and fit the field"),
+            ShamirError::InsufficientShares { provided, required } => {
+                write!(f, "{} shares provided but {} are required", provided, required)
+            }
+            ShamirError::InconsistentShares => write!(f, "shares do not come from the same dealing"),
+            ShamirError::InvalidModulus => write!(f, "modulus is not an odd prime"),
+            ShamirError::SecretOutOfRange => write!(f, "secret does not fit in the field"),
+            ShamirError::InvalidParameters(name) => write!(f, "invalid parameter: {}", name),
+            ShamirError::InvalidMnemonic => write!(f, "mnemonic contains an unknown word or has the wrong length"),
+            ShamirError::InvalidChecksum => write!(f, "checksum mismatch"),
+            ShamirError::DigestMismatch => write!(f, "recovered secret does not match its digest"),
+            ShamirError::InvalidString => write!(f, "recovered secret is not an encoded string"),
+            ShamirError::DecryptionFailed => write!(f, "wrong passphrase or corrupted sealed share"),
+            ShamirError::ZeroIndex => write!(f, "share index is zero in the field"),
+            ShamirError::DuplicateIndex => write!(f, "two shares have the same index"),
+            ShamirError::Outliers(indices) => {
+                write!(f, "shares {:?} do not lie on the same polynomial as the others", indices)
+            }
+            ShamirError::StaleShares(indices) => write!(f, "shares {:?} are from an earlier epoch", indices),
+            ShamirError::ExpiredShares(indices) => write!(f, "shares {:?} have expired", indices),
+            ShamirError::Vss(err) => write!(f, "verifiable secret sharing failed: {}", err),
+        }
+    }
+}
+
+impl core::error::Error for ShamirError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            ShamirError::Vss(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<VssError> for ShamirError {
+    fn from(err: VssError) -> Self {
+        ShamirError::Vss(err)
+    }
 }
 
-pub fn generate_polynomial(secret: u64, threshold: usize) -> Result<Vec<u64>, ShamirError> {
+pub fn generate_polynomial(
+    secret: u64,
+    threshold: usize,
+    rng: &mut (impl RngCore + CryptoRng),
+) -> Result<Zeroizing<Vec<u64>>, ShamirError> {
     if threshold < 2 {
         return Err(ShamirError::InvalidThreshold);
     }
 
-    let mut rng = rand::thread_rng();
-    let mut coeffs = vec![secret]; 
-    for _ in 1..threshold {
-        coeffs.push(rng.gen_range(1..PRIME));
-    }
-    Ok(coeffs)
+    Ok(Zeroizing::new(polynomial::generate(&FIELD, secret, threshold, rng)))
 }
 
 pub fn evaluate_polynomial(coeffs: &[u64], x: u64) -> u64 {
-    let mut result = 0;
-    for &coeff in coeffs.iter().rev() {
-        result = (result * x + coeff) % PRIME;
-    }
-    result
+    polynomial::evaluate(&FIELD, coeffs, &x)
 }
 
 pub fn generate_shares(
     secret: u64,
     threshold: usize,
     num_shares: usize,
-) -> Result<Vec<(u64, u64)>, ShamirError> {
+    rng: &mut (impl RngCore + CryptoRng),
+) -> Result<Vec<Share>, ShamirError> {
     if num_shares < threshold {
         return Err(ShamirError::InvalidShareCount);
     }
 
-    let coeffs = generate_polynomial(secret, threshold)?;
-    let mut shares = Vec::with_capacity(num_shares);
-    for x in 1..=num_shares as u64 {
-        shares.push((x, evaluate_polynomial(&coeffs, x)));
-    }
-    Ok(shares)
+    let xs: Vec<u64> = (1..=num_shares as u64).collect();
+    generate_shares_at(secret, threshold, &xs, rng)
 }
 
-fn mod_inverse(a: u64) -> u64 {
-    let mut t: i128 = 0;
-    let mut newt: i128 = 1;
-    let mut r: i128 = PRIME as i128;
-    let mut newr: i128 = a as i128;
+// Sharing over a caller's own prime field (see `PrimeField64::checked`) instead of `FIELD`.
+// Shares don't record the modulus; reconstruct them with `reconstruct_secret_in` and the same field.
+pub fn generate_shares_in(
+    field: &PrimeField64,
+    secret: u64,
+    threshold: usize,
+    num_shares: usize,
+    rng: &mut (impl RngCore + CryptoRng),
+) -> Result<Vec<Share>, ShamirError> {
+    if secret >= field.modulus() {
+        return Err(ShamirError::SecretOutOfRange);
+    }
+    if threshold < 2 {
+        return Err(ShamirError::InvalidThreshold);
+    }
+    if num_shares < threshold || num_shares as u64 >= field.modulus() {
+        return Err(ShamirError::InvalidShareCount);
+    }
+
+    let coeffs = Zeroizing::new(polynomial::generate(field, secret, threshold, rng));
+    let scheme_id = rng.r#gen();
+    Ok(share::dealt(
+        (1..=num_shares as u64)
+            .map(|x| Share::new(x, polynomial::evaluate(field, &coeffs, &x), threshold, scheme_id))
+            .collect(),
+        rng,
+    ))
+}
 
-    while newr != 0 {
-        let quotient = r / newr;
-        let temp_t = t;
-        t = newt;
-        newt = temp_t - quotient * newt;
-        let temp_r = r;
-        r = newr;
-        newr = temp_r - quotient * newr;
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all, fields(shares = shares.len())))]
+pub fn reconstruct_secret_in(field: &PrimeField64, shares: &[Share]) -> Result<u64, ShamirError> {
+    let threshold = quorum(shares)?;
+    if !share::check_same_dealing(shares) {
+        return Err(ShamirError::InconsistentShares);
     }
+    check_share_indices(shares, field.modulus())?;
+    check_consistent(field, shares, threshold)?;
+
+    polynomial::interpolate_at_zero(field, &shares[..threshold]).ok_or(ShamirError::InconsistentShares)
+}
 
-    if t < 0 {
-        t += PRIME as i128;
+// With more than `threshold` shares, the extra ones must agree with the first `threshold`.
+pub(crate) fn check_consistent<F: FiniteField>(
+    field: &F,
+    shares: &[Share<F::Element>],
+    threshold: usize,
+) -> Result<(), ShamirError> {
+    let outliers = polynomial::outliers(field, shares, threshold).ok_or(ShamirError::DuplicateIndex)?;
+    if !outliers.is_empty() {
+        return Err(ShamirError::Outliers(outliers));
     }
-    t as u64
+    Ok(())
 }
 
-pub fn reconstruct_secret(shares: &[(u64, u64)], threshold: usize) -> Result<u64, ShamirError> {
+// The threshold a share set claims, once there are at least that many shares.
+pub(crate) fn quorum<V: ShareValue>(shares: &[Share<V>]) -> Result<usize, ShamirError> {
+    let threshold = shares.first().map_or(1, |share| share.threshold);
     if shares.len() < threshold {
-        return Err(ShamirError::InsufficientShares);
+        return Err(ShamirError::InsufficientShares { provided: shares.len(), required: threshold });
     }
+    Ok(threshold)
+}
 
-    let mut secret: i128 = 0; 
-    for i in 0..threshold {
-        let (x_i, y_i) = shares[i];
-        let mut numerator: i128 = 1;
-        let mut denominator: i128 = 1;
+// Checks that xs are usable share indices in a field of the given size: nonzero and distinct
+// once reduced, and already reduced so the index printed on a share is the one used.
+pub(crate) fn check_indices(xs: &[u64], modulus: u64) -> Result<(), ShamirError> {
+    let mut seen = BTreeSet::new();
+    for &x in xs {
+        if x == 0 {
+            return Err(ShamirError::ZeroIndex);
+        }
+        if x >= modulus {
+            return Err(ShamirError::InvalidParameters("index"));
+        }
+        if !seen.insert(x) {
+            return Err(ShamirError::DuplicateIndex);
+        }
+    }
+    Ok(())
+}
 
-        for j in 0..threshold {
-            if i != j {
-                let (x_j, _) = shares[j];
-                numerator = (numerator * ((PRIME as i128) - x_j as i128)) % (PRIME as i128);
-                let diff = ((x_i as i128) - (x_j as i128) + (PRIME as i128)) % (PRIME as i128);
-                denominator = (denominator * diff) % (PRIME as i128);
-            }
+// The reconstruction-side check: shares' indices reduced into the field must be nonzero (a share
+// at zero is the secret itself) and distinct (Lagrange weights divide by their differences).
+pub(crate) fn check_share_indices<V: ShareValue>(shares: &[Share<V>], modulus: u64) -> Result<(), ShamirError> {
+    let mut seen = BTreeSet::new();
+    for share in shares {
+        let x = share.index % modulus;
+        if x == 0 {
+            return Err(ShamirError::ZeroIndex);
         }
+        if !seen.insert(x) {
+            return Err(ShamirError::DuplicateIndex);
+        }
+    }
+    Ok(())
+}
+
+// `check_share_indices` for fields too large for a u64 modulus, comparing indices as field elements.
+pub(crate) fn check_field_indices<F: FiniteField>(field: &F, shares: &[Share<F::Element>]) -> Result<(), ShamirError> {
+    let mut seen: Vec<F::Element> = Vec::with_capacity(shares.len());
+    for share in shares {
+        let x = field.element(share.index);
+        if x == field.zero() {
+            return Err(ShamirError::ZeroIndex);
+        }
+        if seen.contains(&x) {
+            return Err(ShamirError::DuplicateIndex);
+        }
+        seen.push(x);
+    }
+    Ok(())
+}
+
+// A stable share index for a participant identifier, for use with `generate_shares_at`.
+pub fn index_for(participant: &[u8]) -> u64 {
+    let digest = Sha256::digest(participant);
+    let mut bytes = [0u8; 8];
+    bytes.copy_from_slice(&digest[..8]);
+    u64::from_be_bytes(bytes) % (PRIME - 1) + 1
+}
+
+// Like `generate_shares`, but with the dealer's choice of x-coordinates instead of 1..=n.
+pub fn generate_shares_at(
+    secret: u64,
+    threshold: usize,
+    xs: &[u64],
+    rng: &mut (impl RngCore + CryptoRng),
+) -> Result<Vec<Share>, ShamirError> {
+    if secret >= PRIME {
+        return Err(ShamirError::SecretOutOfRange);
+    }
+    if xs.len() < threshold {
+        return Err(ShamirError::InvalidShareCount);
+    }
+    check_indices(xs, PRIME)?;
+
+    let coeffs = generate_polynomial(secret, threshold, rng)?;
+    let scheme_id = rng.r#gen();
+    if threshold >= multipoint::MIN_DEGREE {
+        let values = multipoint::evaluate_many(&coeffs, xs);
+        let shares = xs.iter().zip(values).map(|(&x, value)| Share::new(x, value, threshold, scheme_id)).collect();
+        return Ok(share::dealt(shares, rng));
+    }
+    #[cfg(feature = "parallel")]
+    let xs = xs.par_iter();
+    #[cfg(not(feature = "parallel"))]
+    let xs = xs.iter();
+    let shares = xs.map(|&x| Share::new(x, evaluate_polynomial(&coeffs, x), threshold, scheme_id)).collect();
+    Ok(share::dealt(shares, rng))
+}
+
+// Zero has no inverse and maps to zero, as in the constant-time Fermat inversion.
+pub(crate) fn mod_inverse(a: u64) -> u64 {
+    FIELD.inv(&a).unwrap_or(0)
+}
+
+pub fn reconstruct_secret(shares: &[Share]) -> Result<u64, ShamirError> {
+    reconstruct_secret_in(&FIELD, shares)
+}
 
-        let lagrange_coeff = (numerator * mod_inverse(denominator as u64) as i128) % (PRIME as i128);
-        secret = (secret + (y_i as i128 * lagrange_coeff) % (PRIME as i128)) % (PRIME as i128);
+// The dealing polynomial at any x, from the first `threshold` shares. At x = 0 this is the
+// secret; at another share's index it is the value that share should hold.
+pub fn interpolate_at(shares: &[Share], x: u64) -> Result<u64, ShamirError> {
+    let threshold = quorum(shares)?;
+    if !share::check_same_dealing(shares) {
+        return Err(ShamirError::InconsistentShares);
     }
-    Ok((secret as u64) % PRIME)
+    check_share_indices(shares, PRIME)?;
+    check_consistent(&FIELD, shares, threshold)?;
+
+    polynomial::interpolate_at(&FIELD, &shares[..threshold], &FIELD.element(x)).ok_or(ShamirError::DuplicateIndex)
 }
 
-pub fn run_shamir_with_secret(secret: u64) -> Result<u64, ShamirError> {
-    let threshold = 3;
-    let num_shares = 5;
+pub fn split_bytes(
+    secret: &[u8],
+    threshold: usize,
+    num_shares: usize,
+    rng: &mut (impl RngCore + CryptoRng),
+) -> Result<Vec<Share<Vec<u8>>>, ShamirError> {
+    let scheme_id = rng.r#gen();
+    Ok(share::dealt(
+        gf256::split(secret, threshold, num_shares, rng)?
+            .into_iter()
+            .map(|(x, value)| Share::new(x as u64, value, threshold, scheme_id))
+            .collect(),
+        rng,
+    ))
+}
 
-    let shares = generate_shares(secret, threshold, num_shares)?;
-    println!("(SSS) Generated shares: {:?}", shares);
+// `split_bytes` with every random choice, the scheme id included, drawn from
+// `polynomial::seeded_rng(seed)`, so a seed reproduces the shares byte for byte.
+pub fn split_bytes_from_seed(
+    secret: &[u8],
+    threshold: usize,
+    num_shares: usize,
+    seed: [u8; 32],
+) -> Result<Vec<Share<Vec<u8>>>, ShamirError> {
+    split_bytes(secret, threshold, num_shares, &mut polynomial::seeded_rng(seed))
+}
 
-    let reconstructed = reconstruct_secret(&shares[..threshold], threshold)?;
-    println!("(SSS) Successfully reconstructed secret: {}", reconstructed);
+pub fn split_bytes_at(
+    secret: &[u8],
+    threshold: usize,
+    xs: &[u8],
+    rng: &mut (impl RngCore + CryptoRng),
+) -> Result<Vec<Share<Vec<u8>>>, ShamirError> {
+    let scheme_id = rng.r#gen();
+    Ok(share::dealt(
+        gf256::split_at(secret, threshold, xs, rng)?
+            .into_iter()
+            .map(|(x, value)| Share::new(x as u64, value, threshold, scheme_id))
+            .collect(),
+        rng,
+    ))
+}
 
-    assert_eq!(reconstructed, secret);
-    Ok(secret)
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all, fields(shares = shares.len())))]
+pub fn combine_bytes(shares: &[Share<Vec<u8>>]) -> Result<Zeroizing<Vec<u8>>, ShamirError> {
+    let threshold = quorum(shares)?;
+    if !share::check_same_dealing(shares) {
+        return Err(ShamirError::InconsistentShares);
+    }
+    let points = shares
+        .iter()
+        .map(|share| match u8::try_from(share.index) {
+            Ok(x) => Ok((x, share.value.clone())),
+            Err(_) => Err(ShamirError::InconsistentShares),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    // Every share, not just the quorum: `outliers` only asks the rest whether they fit.
+    check_share_indices(shares, 256)?;
+    let outliers = gf256::outliers(&points, threshold)?;
+    if !outliers.is_empty() {
+        return Err(ShamirError::Outliers(outliers.into_iter().map(u64::from).collect()));
+    }
+    gf256::combine(&points[..threshold])
+}
+
+// `combine_bytes` over the shares `share::check_fresh` keeps, with what it set aside.
+pub fn combine_fresh(
+    shares: &[Share<Vec<u8>>],
+    now: Option<u64>,
+    policy: StalePolicy,
+) -> Result<(Zeroizing<Vec<u8>>, Staleness), ShamirError> {
+    let (fresh, staleness) = share::check_fresh(shares, now, policy)?;
+    Ok((combine_bytes(&fresh)?, staleness))
+}
+
+// Strings are padded to a multiple of this many bytes before splitting, so a share's length only
+// bounds the passphrase's length instead of giving it away.
+pub const STR_BLOCK: usize = 32;
+
+// Splits a passphrase-style secret. It is normalized to NFC first, so the same passphrase typed
+// with composed or decomposed accents yields the same secret, then encoded as a 2-byte big-endian
+// length, the UTF-8 bytes, and zero padding up to a multiple of `STR_BLOCK`.
+pub fn split_str(
+    secret: &str,
+    threshold: usize,
+    num_shares: usize,
+    rng: &mut (impl RngCore + CryptoRng),
+) -> Result<Vec<Share<Vec<u8>>>, ShamirError> {
+    let normalized = Zeroizing::new(secret.nfc().collect::<String>());
+    let len = u16::try_from(normalized.len()).map_err(|_| ShamirError::InvalidParameters("secret"))?;
+    let padded_len = (2 + normalized.len()).div_ceil(STR_BLOCK) * STR_BLOCK;
+    let mut encoded = Zeroizing::new(Vec::with_capacity(padded_len));
+    encoded.extend_from_slice(&len.to_be_bytes());
+    encoded.extend_from_slice(normalized.as_bytes());
+    encoded.resize(padded_len, 0);
+    split_bytes(&encoded, threshold, num_shares, rng)
+}
+
+// Reverses `split_str`: the recovered string is in NFC.
+pub fn combine_to_string(shares: &[Share<Vec<u8>>]) -> Result<Zeroizing<String>, ShamirError> {
+    let encoded = combine_bytes(shares)?;
+    let (len, rest) = encoded.split_first_chunk::<2>().ok_or(ShamirError::InvalidString)?;
+    let len = u16::from_be_bytes(*len) as usize;
+    if len > rest.len() || rest[len..].iter().any(|&b| b != 0) {
+        return Err(ShamirError::InvalidString);
+    }
+    let text = core::str::from_utf8(&rest[..len]).map_err(|_| ShamirError::InvalidString)?;
+    Ok(Zeroizing::new(String::from(text)))
 }
@@ -0,0 +1,317 @@
+use alloc::string::{String, ToString};
+use alloc::{format, vec, vec::Vec};
+use core::fmt;
+use core::str::FromStr;
+
+use rand::{CryptoRng, Rng, RngCore};
+use zeroize::Zeroizing;
+
+use super::share::{ParseShareError, ShareValue};
+use super::sss::ShamirError;
+
+// Compatibility with B. Poettering's ssss(1). ssss works in GF(2^d) with d = 8 * secret length,
+// evaluates x^t + c_{t-1} x^{t-1} + ... + c_1 x + secret at x = 1..n (note the monic leading
+// term), and by default runs the secret through an XTEA-based diffusion layer first. Shares
+// print as `[token-]index-hex`, the index zero-padded to the width of n.
+
+// Irreducible pentanomials x^d + x^a + x^b + x^c + 1 for d = 8, 16, ..., 1024, as (a, b, c).
+const IRRED_COEFF: [u8; 384] = [
+    4, 3, 1, 5, 3, 1, 4, 3, 1, 7, 3, 2, 5, 4, 3, 5, 3, 2, 7, 4, 2, 4, 3, 1, 10, 9, 3, 9, 4, 2, 7, 6, 2, 10, 9, 6,
+    4, 3, 1, 5, 4, 3, 4, 3, 1, 7, 2, 1, 5, 3, 2, 7, 4, 2, 6, 3, 2, 5, 3, 2, 15, 3, 2, 11, 3, 2, 9, 8, 7, 7, 2, 1,
+    5, 3, 2, 9, 3, 1, 7, 3, 1, 9, 8, 3, 9, 4, 2, 8, 5, 3, 15, 14, 10, 10, 5, 2, 9, 6, 2, 9, 3, 2, 9, 5, 2, 11, 10,
+    1, 7, 3, 2, 11, 2, 1, 9, 7, 4, 4, 3, 1, 8, 3, 1, 7, 4, 1, 7, 2, 1, 13, 11, 6, 5, 3, 2, 7, 3, 2, 8, 7, 5, 12, 3,
+    2, 13, 10, 6, 5, 3, 2, 5, 3, 2, 9, 5, 2, 9, 7, 2, 13, 4, 3, 4, 3, 1, 11, 6, 4, 18, 9, 6, 19, 18, 13, 11, 3, 2,
+    15, 9, 6, 4, 3, 1, 16, 5, 2, 15, 14, 6, 8, 5, 2, 15, 11, 2, 11, 6, 2, 7, 5, 3, 8, 3, 1, 19, 16, 9, 11, 9, 6, 15,
+    7, 6, 13, 4, 3, 14, 13, 3, 13, 6, 3, 9, 5, 2, 19, 13, 6, 19, 10, 3, 11, 6, 5, 9, 2, 1, 14, 3, 2, 13, 3, 1, 7, 5,
+    4, 11, 9, 8, 11, 6, 5, 23, 16, 9, 19, 14, 6, 23, 10, 2, 8, 3, 2, 5, 4, 3, 9, 6, 4, 4, 3, 2, 13, 8, 6, 13, 11, 1,
+    13, 10, 3, 11, 6, 5, 19, 17, 4, 15, 14, 7, 13, 9, 6, 9, 7, 3, 9, 7, 1, 14, 3, 2, 11, 8, 2, 11, 6, 4, 13, 5, 2,
+    11, 5, 1, 11, 4, 1, 19, 10, 3, 21, 10, 6, 13, 3, 1, 15, 7, 5, 19, 18, 10, 7, 5, 3, 12, 7, 2, 7, 5, 1, 14, 9, 6,
+    10, 3, 2, 15, 13, 12, 12, 11, 9, 16, 9, 7, 12, 9, 3, 9, 5, 2, 17, 10, 6, 24, 9, 3, 17, 15, 13, 5, 4, 3, 19, 17,
+    8, 15, 6, 3, 19, 6, 1,
+];
+
+pub const MAX_SECRET_LEN: usize = 128;
+const DIFFUSION_MIN_LEN: usize = 8;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SsssShare {
+    pub token: Option<String>,
+    pub index: u32,
+    // Big-endian field element, one byte per secret byte.
+    pub value: Vec<u8>,
+}
+
+impl fmt::Display for SsssShare {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(token) = &self.token {
+            write!(f, "{}-", token)?;
+        }
+        write!(f, "{:0width$}-", self.index, width = f.width().unwrap_or(1))?;
+        self.value.fmt_value(f)
+    }
+}
+
+impl FromStr for SsssShare {
+    type Err = ParseShareError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (rest, hex) = s.trim().rsplit_once('-').ok_or(ParseShareError::Malformed)?;
+        let (token, index) = match rest.rsplit_once('-') {
+            Some((token, index)) => (Some(String::from(token)), index),
+            None => (None, rest),
+        };
+        let index = index.parse().map_err(|_| ParseShareError::Malformed)?;
+        let value = Vec::<u8>::parse_value(hex).ok_or(ParseShareError::Malformed)?;
+        if index == 0 || value.is_empty() || value.len() > MAX_SECRET_LEN {
+            return Err(ParseShareError::Malformed);
+        }
+        Ok(SsssShare { token, index, value })
+    }
+}
+
+// One line per share, indices padded the way ssss-split pads them.
+pub fn format_shares(shares: &[SsssShare]) -> Vec<String> {
+    let width = shares.iter().map(|share| share.index).max().unwrap_or(0).to_string().len();
+    shares.iter().map(|share| format!("{:width$}", share, width = width)).collect()
+}
+
+struct Field {
+    degree: usize,
+    taps: [u8; 3],
+}
+
+type Element = Vec<u64>;
+
+impl Field {
+    fn new(len: usize) -> Result<Field, ShamirError> {
+        if len == 0 || len > MAX_SECRET_LEN {
+            return Err(ShamirError::SecretOutOfRange);
+        }
+        let taps = [IRRED_COEFF[3 * (len - 1)], IRRED_COEFF[3 * (len - 1) + 1], IRRED_COEFF[3 * (len - 1) + 2]];
+        Ok(Field { degree: 8 * len, taps })
+    }
+
+    fn limbs(&self) -> usize {
+        self.degree / 64 + 1
+    }
+
+    fn import(&self, bytes: &[u8]) -> Element {
+        let mut x = vec![0u64; self.limbs()];
+        for (i, &byte) in bytes.iter().rev().enumerate() {
+            x[i / 8] |= (byte as u64) << (8 * (i % 8));
+        }
+        x
+    }
+
+    fn export(&self, x: &Element) -> Vec<u8> {
+        (0..self.degree / 8).rev().map(|i| (x[i / 8] >> (8 * (i % 8))) as u8).collect()
+    }
+
+    fn element(&self, index: u32) -> Element {
+        let mut x = vec![0u64; self.limbs()];
+        x[0] = index as u64;
+        x
+    }
+
+    fn bit(x: &Element, i: usize) -> bool {
+        (x[i / 64] >> (i % 64)) & 1 == 1
+    }
+
+    fn add(x: &mut Element, y: &Element) {
+        for (a, b) in x.iter_mut().zip(y) {
+            *a ^= b;
+        }
+    }
+
+    fn mul(&self, x: &Element, y: &Element) -> Element {
+        let mut b = x.clone();
+        let mut z = vec![0u64; self.limbs()];
+        if Self::bit(y, 0) {
+            z.copy_from_slice(&b);
+        }
+        for i in 1..self.degree {
+            let mut carry = 0;
+            for limb in b.iter_mut() {
+                let next = *limb >> 63;
+                *limb = (*limb << 1) | carry;
+                carry = next;
+            }
+            if Self::bit(&b, self.degree) {
+                b[self.degree / 64] ^= 1 << (self.degree % 64);
+                for tap in self.taps.iter().map(|&t| t as usize).chain([0]) {
+                    b[tap / 64] ^= 1 << (tap % 64);
+                }
+            }
+            if Self::bit(y, i) {
+                Self::add(&mut z, &b);
+            }
+        }
+        z
+    }
+
+    // x^(2^d - 2) = x^2 * x^4 * ... * x^(2^(d-1)).
+    fn inv(&self, x: &Element) -> Element {
+        let mut square = x.clone();
+        let mut result = self.element(1);
+        for _ in 1..self.degree {
+            square = self.mul(&square, &square);
+            result = self.mul(&result, &square);
+        }
+        result
+    }
+}
+
+// ssss's keyless 64-bit XTEA permutation, applied to overlapping 8-byte windows of the secret.
+fn encipher_block(v: &mut [u32; 2]) {
+    let delta = 0x9E3779B9u32;
+    let mut sum = 0u32;
+    for _ in 0..32 {
+        v[0] = v[0].wrapping_add((((v[1] << 4) ^ (v[1] >> 5)).wrapping_add(v[1])) ^ sum);
+        sum = sum.wrapping_add(delta);
+        v[1] = v[1].wrapping_add((((v[0] << 4) ^ (v[0] >> 5)).wrapping_add(v[0])) ^ sum);
+    }
+}
+
+fn decipher_block(v: &mut [u32; 2]) {
+    let delta = 0x9E3779B9u32;
+    let mut sum = 0xC6EF3720u32;
+    for _ in 0..32 {
+        v[1] = v[1].wrapping_sub((((v[0] << 4) ^ (v[0] >> 5)).wrapping_add(v[0])) ^ sum);
+        sum = sum.wrapping_sub(delta);
+        v[0] = v[0].wrapping_sub((((v[1] << 4) ^ (v[1] >> 5)).wrapping_add(v[1])) ^ sum);
+    }
+}
+
+fn encode_slice(data: &mut [u8], idx: usize, process_block: fn(&mut [u32; 2])) {
+    let len = data.len();
+    let mut v = [0u32; 2];
+    for (i, word) in v.iter_mut().enumerate() {
+        *word = (0..4).fold(0, |acc, j| acc << 8 | data[(idx + 4 * i + j) % len] as u32);
+    }
+    process_block(&mut v);
+    for (i, word) in v.iter().enumerate() {
+        for j in 0..4 {
+            data[(idx + 4 * i + j) % len] = (word >> (24 - 8 * j)) as u8;
+        }
+    }
+}
+
+// ssss exports the element as little-endian-ordered 16-bit big-endian words, so the diffusion
+// layer sees the bytes of each 16-bit word swapped (a trailing odd byte stays put).
+fn swap_pairs(bytes: &mut [u8]) {
+    let even = bytes.len() - bytes.len() % 2;
+    for pair in bytes[..even].chunks_exact_mut(2) {
+        pair.swap(0, 1);
+    }
+}
+
+fn diffuse(secret: &mut [u8], encode: bool) {
+    let len = secret.len();
+    secret.reverse();
+    swap_pairs(secret);
+    if encode {
+        for i in (0..40 * len).step_by(2) {
+            encode_slice(secret, i, encipher_block);
+        }
+    } else {
+        for i in (0..40 * len).step_by(2).rev() {
+            encode_slice(secret, i, decipher_block);
+        }
+    }
+    swap_pairs(secret);
+    secret.reverse();
+}
+
+pub fn split(
+    secret: &[u8],
+    threshold: usize,
+    num_shares: usize,
+    diffusion: bool,
+    rng: &mut (impl RngCore + CryptoRng),
+) -> Result<Vec<SsssShare>, ShamirError> {
+    if threshold < 2 {
+        return Err(ShamirError::InvalidThreshold);
+    }
+    let field = Field::new(secret.len())?;
+    if num_shares < threshold || num_shares > u32::MAX as usize || (field.degree < 32 && num_shares >> field.degree != 0) {
+        return Err(ShamirError::InvalidShareCount);
+    }
+
+    let mut secret = Zeroizing::new(secret.to_vec());
+    if diffusion && secret.len() >= DIFFUSION_MIN_LEN {
+        diffuse(&mut secret, true);
+    }
+    let mut coeffs = Vec::with_capacity(threshold);
+    coeffs.push(Zeroizing::new(field.import(&secret)));
+    let mut random = Zeroizing::new(vec![0u8; secret.len()]);
+    for _ in 1..threshold {
+        rng.fill(&mut random[..]);
+        coeffs.push(Zeroizing::new(field.import(&random)));
+    }
+
+    Ok((1..=num_shares as u32)
+        .map(|index| {
+            let x = field.element(index);
+            let mut y = x.clone();
+            for coeff in coeffs[1..].iter().rev() {
+                Field::add(&mut y, coeff);
+                y = field.mul(&y, &x);
+            }
+            Field::add(&mut y, &coeffs[0]);
+            SsssShare { token: None, index, value: field.export(&y) }
+        })
+        .collect())
+}
+
+pub fn combine(shares: &[SsssShare], threshold: usize, diffusion: bool) -> Result<Zeroizing<Vec<u8>>, ShamirError> {
+    if threshold < 2 {
+        return Err(ShamirError::InvalidThreshold);
+    }
+    if shares.len() < threshold {
+        return Err(ShamirError::InsufficientShares { provided: shares.len(), required: threshold });
+    }
+    let shares = &shares[..threshold];
+    let len = shares[0].value.len();
+    let field = Field::new(len)?;
+    for (i, share) in shares.iter().enumerate() {
+        if share.value.len() != len
+            || share.index == 0
+            || (field.degree < 32 && share.index >> field.degree != 0)
+            || shares[..i].iter().any(|other| other.index == share.index)
+        {
+            return Err(ShamirError::InconsistentShares);
+        }
+    }
+
+    // Strip the x^t term, then interpolate the remaining degree t - 1 polynomial at zero.
+    let xs: Vec<Element> = shares.iter().map(|share| field.element(share.index)).collect();
+    let mut secret = Zeroizing::new(vec![0u64; field.limbs()]);
+    for (i, (share, x_i)) in shares.iter().zip(&xs).enumerate() {
+        let mut y = Zeroizing::new(field.import(&share.value));
+        let mut x_pow = field.element(1);
+        for _ in 0..threshold {
+            x_pow = field.mul(&x_pow, x_i);
+        }
+        Field::add(&mut y, &x_pow);
+
+        let mut numerator = field.element(1);
+        let mut denominator = field.element(1);
+        for (j, x_j) in xs.iter().enumerate() {
+            if i != j {
+                numerator = field.mul(&numerator, x_j);
+                let mut diff = x_i.clone();
+                Field::add(&mut diff, x_j);
+                denominator = field.mul(&denominator, &diff);
+            }
+        }
+        let basis = field.mul(&numerator, &field.inv(&denominator));
+        Field::add(&mut secret, &field.mul(&y, &basis));
+    }
+
+    let mut secret = Zeroizing::new(field.export(&secret));
+    if diffusion && len >= DIFFUSION_MIN_LEN {
+        diffuse(&mut secret, false);
+    }
+    Ok(secret)
+}
@@ -0,0 +1,155 @@
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+use core::convert::Infallible;
+#[cfg(feature = "std")]
+use std::fs;
+#[cfg(feature = "std")]
+use std::io::{self, Write};
+#[cfg(feature = "std")]
+use std::path::{Path, PathBuf};
+
+use super::share::{Share, ShareValue};
+
+// Where shares live between being dealt and being combined. Shares are keyed by dealing (the
+// scheme id every share of one split carries) and index; putting a share with the same key again
+// replaces it, as a refreshed share replaces its predecessor.
+pub trait ShareStore<V: ShareValue> {
+    type Error;
+
+    fn put(&mut self, share: &Share<V>) -> Result<(), Self::Error>;
+    fn get(&self, dealing: u32, index: u64) -> Result<Option<Share<V>>, Self::Error>;
+    // The indices held for a dealing, in ascending order.
+    fn list(&self, dealing: u32) -> Result<Vec<u64>, Self::Error>;
+    // Whether there was a share to delete.
+    fn delete(&mut self, dealing: u32, index: u64) -> Result<bool, Self::Error>;
+
+    // Every share held for a dealing, ready for combining.
+    fn load(&self, dealing: u32) -> Result<Vec<Share<V>>, Self::Error> {
+        let mut shares = Vec::new();
+        for index in self.list(dealing)? {
+            shares.extend(self.get(dealing, index)?);
+        }
+        Ok(shares)
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct MemoryStore<V: ShareValue> {
+    shares: BTreeMap<(u32, u64), Share<V>>,
+}
+
+impl<V: ShareValue> MemoryStore<V> {
+    pub fn new() -> Self {
+        MemoryStore { shares: BTreeMap::new() }
+    }
+}
+
+impl<V: ShareValue + Clone> ShareStore<V> for MemoryStore<V> {
+    type Error = Infallible;
+
+    fn put(&mut self, share: &Share<V>) -> Result<(), Infallible> {
+        self.shares.insert((share.scheme_id, share.index), share.clone());
+        Ok(())
+    }
+
+    fn get(&self, dealing: u32, index: u64) -> Result<Option<Share<V>>, Infallible> {
+        Ok(self.shares.get(&(dealing, index)).cloned())
+    }
+
+    fn list(&self, dealing: u32) -> Result<Vec<u64>, Infallible> {
+        Ok(self.shares.range((dealing, 0)..=(dealing, u64::MAX)).map(|(&(_, index), _)| index).collect())
+    }
+
+    fn delete(&mut self, dealing: u32, index: u64) -> Result<bool, Infallible> {
+        Ok(self.shares.remove(&(dealing, index)).is_some())
+    }
+}
+
+// One file per share, `ROOT/<dealing as 8 hex digits>/<index>.share`, holding the share's text
+// form (checksum included). Files are written to a temporary name and renamed into place, and on
+// Unix are readable by the owner only.
+#[cfg(feature = "std")]
+pub struct FileStore {
+    root: PathBuf,
+}
+
+#[cfg(feature = "std")]
+impl FileStore {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        FileStore { root: root.into() }
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    fn dealing_dir(&self, dealing: u32) -> PathBuf {
+        self.root.join(format!("{:08x}", dealing))
+    }
+
+    fn path(&self, dealing: u32, index: u64) -> PathBuf {
+        self.dealing_dir(dealing).join(format!("{}.share", index))
+    }
+}
+
+#[cfg(feature = "std")]
+fn create_private(path: &Path) -> io::Result<fs::File> {
+    let mut options = fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+    options.open(path)
+}
+
+// A missing file or directory is an empty answer rather than an error.
+#[cfg(feature = "std")]
+fn found<T>(result: io::Result<T>) -> io::Result<Option<T>> {
+    match result {
+        Ok(value) => Ok(Some(value)),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(err) => Err(err),
+    }
+}
+
+#[cfg(feature = "std")]
+impl<V: ShareValue> ShareStore<V> for FileStore {
+    type Error = io::Error;
+
+    fn put(&mut self, share: &Share<V>) -> io::Result<()> {
+        fs::create_dir_all(self.dealing_dir(share.scheme_id))?;
+        let path = self.path(share.scheme_id, share.index);
+        let tmp = path.with_extension("share.tmp");
+        let mut file = create_private(&tmp)?;
+        writeln!(file, "{}", share)?;
+        file.sync_all()?;
+        fs::rename(&tmp, &path)
+    }
+
+    fn get(&self, dealing: u32, index: u64) -> io::Result<Option<Share<V>>> {
+        let Some(text) = found(fs::read_to_string(self.path(dealing, index)))? else {
+            return Ok(None);
+        };
+        let share: Share<V> =
+            text.parse().map_err(|err| io::Error::new(io::ErrorKind::InvalidData, format!("{:?}", err)))?;
+        if share.scheme_id != dealing || share.index != index {
+            return Err(io::Error::new(io::ErrorKind::InvalidData, "share file is under the wrong name"));
+        }
+        Ok(Some(share))
+    }
+
+    fn list(&self, dealing: u32) -> io::Result<Vec<u64>> {
+        let mut indices = Vec::new();
+        for entry in found(fs::read_dir(self.dealing_dir(dealing)))?.into_iter().flatten() {
+            let name = entry?.file_name();
+            if let Some(index) = name.to_str().and_then(|name| name.strip_suffix(".share")) {
+                indices.extend(index.parse::<u64>().ok());
+            }
+        }
+        indices.sort_unstable();
+        Ok(indices)
+    }
+
+    fn delete(&mut self, dealing: u32, index: u64) -> io::Result<bool> {
+        Ok(found(fs::remove_file(self.path(dealing, index)))?.is_some())
+    }
+}
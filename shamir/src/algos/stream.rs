@@ -0,0 +1,170 @@
+use std::io::{self, Read, Write};
+
+use rand::{CryptoRng, Rng, RngCore};
+use zeroize::Zeroizing;
+
+use super::gf256::{self, LagrangeCoefficients};
+use super::sss::ShamirError;
+
+// Every share stream starts with: magic "SHMS" | version u8 | scheme id u32 BE | threshold u8
+// | index u8, followed by one GF(256) share byte per secret byte.
+const MAGIC: [u8; 4] = *b"SHMS";
+const VERSION: u8 = 1;
+const HEADER_LEN: usize = 4 + 1 + 4 + 1 + 1;
+pub const CHUNK_SIZE: usize = 64 * 1024;
+
+fn invalid(err: ShamirError) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, err)
+}
+
+pub struct SplitWriter<W: Write, R: RngCore + CryptoRng> {
+    outputs: Vec<W>,
+    threshold: usize,
+    scheme_id: u32,
+    rng: R,
+    started: bool,
+}
+
+impl<W: Write, R: RngCore + CryptoRng> SplitWriter<W, R> {
+    // outputs[i] receives the share with index i + 1.
+    pub fn new(outputs: Vec<W>, threshold: usize, mut rng: R) -> Result<Self, ShamirError> {
+        if threshold < 2 {
+            return Err(ShamirError::InvalidThreshold);
+        }
+        if outputs.len() < threshold || outputs.len() > 255 {
+            return Err(ShamirError::InvalidShareCount);
+        }
+        let scheme_id = rng.r#gen();
+        Ok(SplitWriter { outputs, threshold, scheme_id, rng, started: false })
+    }
+
+    fn write_headers(&mut self) -> io::Result<()> {
+        if !self.started {
+            for (i, output) in self.outputs.iter_mut().enumerate() {
+                let mut header = [0u8; HEADER_LEN];
+                header[..4].copy_from_slice(&MAGIC);
+                header[4] = VERSION;
+                header[5..9].copy_from_slice(&self.scheme_id.to_be_bytes());
+                header[9] = self.threshold as u8;
+                header[10] = i as u8 + 1;
+                output.write_all(&header)?;
+            }
+            self.started = true;
+        }
+        Ok(())
+    }
+
+    // Writes the headers even for an empty secret, flushes, and hands the streams back.
+    pub fn finish(mut self) -> io::Result<Vec<W>> {
+        self.flush()?;
+        Ok(std::mem::take(&mut self.outputs))
+    }
+}
+
+impl<W: Write, R: RngCore + CryptoRng> Write for SplitWriter<W, R> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.write_headers()?;
+        let chunk = &buf[..buf.len().min(CHUNK_SIZE)];
+        let shares = gf256::split(chunk, self.threshold, self.outputs.len(), &mut self.rng).map_err(invalid)?;
+        for (output, (_, value)) in self.outputs.iter_mut().zip(&shares) {
+            output.write_all(value)?;
+        }
+        Ok(chunk.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.write_headers()?;
+        self.outputs.iter_mut().try_for_each(|output| output.flush())
+    }
+}
+
+pub struct CombineReader<R: Read> {
+    inputs: Vec<R>,
+    coefficients: LagrangeCoefficients,
+    buffer: Zeroizing<Vec<u8>>,
+    pos: usize,
+}
+
+fn read_header(input: &mut impl Read) -> io::Result<(u32, usize, u8)> {
+    let mut header = [0u8; HEADER_LEN];
+    input.read_exact(&mut header)?;
+    if header[..4] != MAGIC || header[4] != VERSION {
+        return Err(invalid(ShamirError::InvalidParameters("header")));
+    }
+    let scheme_id = u32::from_be_bytes([header[5], header[6], header[7], header[8]]);
+    Ok((scheme_id, header[9] as usize, header[10]))
+}
+
+// Reads until buf is full or the stream ends, returning the number of bytes read.
+fn read_full(input: &mut impl Read, buf: &mut [u8]) -> io::Result<usize> {
+    let mut filled = 0;
+    while filled < buf.len() {
+        match input.read(&mut buf[filled..]) {
+            Ok(0) => break,
+            Ok(n) => filled += n,
+            Err(err) if err.kind() == io::ErrorKind::Interrupted => continue,
+            Err(err) => return Err(err),
+        }
+    }
+    Ok(filled)
+}
+
+impl<R: Read> CombineReader<R> {
+    pub fn new(inputs: Vec<R>) -> io::Result<Self> {
+        let mut dealing = None;
+        let mut selected: Vec<(u8, R)> = Vec::new();
+        for mut input in inputs {
+            let (scheme_id, threshold, index) = read_header(&mut input)?;
+            if *dealing.get_or_insert((scheme_id, threshold)) != (scheme_id, threshold)
+                || index == 0
+                || selected.iter().any(|&(x, _)| x == index)
+            {
+                return Err(invalid(ShamirError::InconsistentShares));
+            }
+            if selected.len() < threshold {
+                selected.push((index, input));
+            }
+        }
+        match dealing {
+            Some((_, threshold)) if selected.len() >= threshold => {
+                let xs: Vec<u8> = selected.iter().map(|&(x, _)| x).collect();
+                let coefficients = LagrangeCoefficients::precompute(&xs).map_err(invalid)?;
+                let inputs = selected.into_iter().map(|(_, input)| input).collect();
+                Ok(CombineReader { inputs, coefficients, buffer: Zeroizing::new(Vec::new()), pos: 0 })
+            }
+            _ => Err(invalid(ShamirError::InsufficientShares {
+                provided: selected.len(),
+                required: dealing.map_or(1, |(_, threshold)| threshold),
+            })),
+        }
+    }
+
+    fn fill(&mut self) -> io::Result<()> {
+        let mut chunks = Vec::with_capacity(self.inputs.len());
+        let mut len = None;
+        for input in self.inputs.iter_mut() {
+            let mut chunk = vec![0u8; CHUNK_SIZE];
+            let n = read_full(input, &mut chunk)?;
+            if *len.get_or_insert(n) != n {
+                return Err(invalid(ShamirError::InconsistentShares));
+            }
+            chunk.truncate(n);
+            chunks.push(chunk);
+        }
+        self.buffer = self.coefficients.combine(&chunks).map_err(invalid)?;
+        self.pos = 0;
+        Ok(())
+    }
+}
+
+impl<R: Read> Read for CombineReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.pos == self.buffer.len() {
+            self.fill()?;
+        }
+        let n = buf.len().min(self.buffer.len() - self.pos);
+        buf[..n].copy_from_slice(&self.buffer[self.pos..self.pos + n]);
+        self.pos += n;
+        Ok(n)
+    }
+}
@@ -0,0 +1,67 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use zeroize::Zeroizing;
+
+use super::share::Share;
+use super::sharefile;
+use super::sss::{self, ShamirError};
+
+// Operators hand in shares one at a time, as when unsealing a vault. Each share is checked as it
+// arrives (same dealing as the first, a new index, and, given the dealing's share-file
+// commitments, one the dealer actually issued); a bad share is refused without disturbing the
+// ones already accepted, and the secret is only combined once the threshold is met.
+
+#[derive(Debug)]
+pub enum Progress {
+    NeedMore { provided: usize, required: usize },
+    Unsealed(Zeroizing<Vec<u8>>),
+}
+
+#[derive(Default)]
+pub struct Unseal {
+    shares: Vec<Share<Vec<u8>>>,
+    commitments: Option<Vec<String>>,
+}
+
+impl Unseal {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // Hex SHA-256 commitments from any of the dealing's `share-NN.json` files.
+    pub fn with_commitments(commitments: Vec<String>) -> Self {
+        Unseal { shares: Vec::new(), commitments: Some(commitments) }
+    }
+
+    pub fn provided(&self) -> usize {
+        self.shares.len()
+    }
+
+    // The threshold, once the first share has said what it is.
+    pub fn required(&self) -> Option<usize> {
+        self.shares.first().map(|share| share.threshold)
+    }
+
+    pub fn submit(&mut self, share: Share<Vec<u8>>) -> Result<Progress, ShamirError> {
+        if let Some(commitments) = &self.commitments
+            && !sharefile::is_committed(&share, commitments)
+        {
+            return Err(ShamirError::InconsistentShares);
+        }
+        if let Some(first) = self.shares.first()
+            && (share.scheme_id != first.scheme_id || share.threshold != first.threshold || share.epoch != first.epoch)
+        {
+            return Err(ShamirError::InconsistentShares);
+        }
+        if self.shares.iter().any(|accepted| accepted.index == share.index) {
+            return Err(ShamirError::DuplicateIndex);
+        }
+        let required = share.threshold;
+        self.shares.push(share);
+        if self.shares.len() < required {
+            return Ok(Progress::NeedMore { provided: self.shares.len(), required });
+        }
+        Ok(Progress::Unsealed(sss::combine_bytes(&self.shares)?))
+    }
+}
@@ -1,4 +1,17 @@
-use rand::Rng;
+use alloc::{vec, vec::Vec};
+use core::fmt;
+
+use rand::{CryptoRng, Rng, RngCore};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use zeroize::Zeroizing;
+
+use super::auth::{self, TaggedShare, VerificationKey};
+use super::field::{self, FiniteField, SmallPrimeField};
+use super::group::{self, Commitment};
+use super::polynomial;
+use super::share::{self, Share, StalePolicy, Staleness};
+use super::sss::{self, ShamirError};
 
 pub const Q: i128 = 2003;  
 pub const P: i128 = 4007;  
@@ -6,120 +19,608 @@ pub const THRESHOLD: usize = 3;
 pub const SHARES_COUNT: usize = 5;  
 pub const G: i128 = 2;  
 
-fn mod_norm(a: i128, m: i128) -> i128 {
-    let r = a % m;
-    if r < 0 { r + m } else { r }
+#[derive(Debug)]
+pub enum VssError {
+    NoInverse,
+    InsufficientShares { provided: usize, required: usize },
+    // Names the offending parameter.
+    InvalidParameters(&'static str),
+    // The index of the share (or contribution) that failed its check.
+    VerificationFailed { index: u64 },
+    InconsistentShares,
+    InvalidState,
+    ZeroIndex,
+    DuplicateIndex,
+    // More shares than the threshold were given and these indices disagree with the first ones.
+    Outliers(Vec<u64>),
+    StaleShares(Vec<u64>),
+    ExpiredShares(Vec<u64>),
+    // The shares combined to a secret whose commitment isn't C_0: corrupted or mixed shares.
+    ReconstructionMismatch,
 }
 
-fn mod_pow(mut base: i128, mut exp: i128, modulus: i128) -> i128 {
-    let mut result = 1;
-    base = mod_norm(base, modulus);
-    while exp > 0 {
-        if exp % 2 == 1 {
-            result = mod_norm(result * base, modulus);
+impl fmt::Display for VssError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VssError::NoInverse => write!(f, "value has no inverse modulo the group order"),
+            VssError::InsufficientShares { provided, required } => {
+                write!(f, "{} shares provided but {} are required", provided, required)
+            }
+            VssError::InvalidParameters(name) => write!(f, "invalid parameter: {}", name),
+            VssError::VerificationFailed { index } => {
+                write!(f, "share {} does not match the dealer's commitments", index)
+            }
+            VssError::InconsistentShares => write!(f, "shares do not come from the same dealing"),
+            VssError::InvalidState => write!(f, "message arrived in the wrong protocol state"),
+            VssError::ZeroIndex => write!(f, "share index is zero in the field"),
+            VssError::DuplicateIndex => write!(f, "two shares have the same index"),
+            VssError::Outliers(indices) => {
+                write!(f, "shares {:?} do not lie on the same polynomial as the others", indices)
+            }
+            VssError::StaleShares(indices) => write!(f, "shares {:?} are from an earlier epoch", indices),
+            VssError::ExpiredShares(indices) => write!(f, "shares {:?} have expired", indices),
+            VssError::ReconstructionMismatch => {
+                write!(f, "reconstructed secret does not match the dealer's commitment to it")
+            }
         }
-        exp /= 2;
-        base = mod_norm(base * base, modulus);
     }
-    result
 }
 
-fn mod_inverse(a: i128, m: i128) -> i128 {
-    let (mut t, mut new_t) = (0, 1);
-    let (mut r, mut new_r) = (m, mod_norm(a, m));
-    while new_r != 0 {
-        let quotient = r / new_r;
-        let temp_t = t;
-        t = new_t;
-        new_t = temp_t - quotient * new_t;
-        let temp_r = r;
-        r = new_r;
-        new_r = temp_r - quotient * new_r;
+impl core::error::Error for VssError {}
+
+// Everything `validate_params` found wrong with a parameter set.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ParamFailure {
+    // p must lie in 7..2^63 so products of reduced values fit an i128.
+    PrimeOutOfRange,
+    PrimeComposite,
+    // (p - 1) / 2 is composite, so Z_p* has subgroups of small order.
+    NotSafePrime,
+    GeneratorOutOfRange,
+    // g = 1 or g = p - 1, of order 1 or 2.
+    SmallOrderGenerator,
+    // g^q != 1: g generates all of Z_p* (order 2q) rather than the order-q subgroup.
+    WrongGeneratorOrder,
+    ThresholdTooSmall,
+    TooFewShares,
+    // There must be fewer shares than q nonzero indices.
+    TooManyShares,
+}
+
+impl ParamFailure {
+    fn parameter(self) -> &'static str {
+        match self {
+            ParamFailure::PrimeOutOfRange | ParamFailure::PrimeComposite | ParamFailure::NotSafePrime => "prime",
+            ParamFailure::GeneratorOutOfRange | ParamFailure::SmallOrderGenerator | ParamFailure::WrongGeneratorOrder => {
+                "generator"
+            }
+            ParamFailure::ThresholdTooSmall => "threshold",
+            ParamFailure::TooFewShares | ParamFailure::TooManyShares => "shares",
+        }
     }
-    if r != 1 {
-        panic!("Modular inverse does not exist for {} mod {}", a, m);
+}
+
+impl fmt::Display for ParamFailure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParamFailure::PrimeOutOfRange => write!(f, "prime must be between 7 and 2^63"),
+            ParamFailure::PrimeComposite => write!(f, "prime is composite"),
+            ParamFailure::NotSafePrime => write!(f, "prime is not a safe prime 2q + 1"),
+            ParamFailure::GeneratorOutOfRange => write!(f, "generator is not in Z_p*"),
+            ParamFailure::SmallOrderGenerator => write!(f, "generator has order 1 or 2"),
+            ParamFailure::WrongGeneratorOrder => write!(f, "generator does not have order q"),
+            ParamFailure::ThresholdTooSmall => write!(f, "threshold must be at least 2"),
+            ParamFailure::TooFewShares => write!(f, "share count is below the threshold"),
+            ParamFailure::TooManyShares => write!(f, "share count must be below the group order"),
+        }
     }
-    mod_norm(t, m)
 }
 
-pub fn generate_polynomial(secret: i128, threshold: usize, rng: &mut impl Rng) -> Vec<i128> {
-    let mut coeffs = Vec::with_capacity(threshold);
-    coeffs.push(mod_norm(secret, Q));
-    for _ in 1..threshold {
-        coeffs.push(rng.gen_range(0..Q));
+// Checks that p = 2q + 1 is a safe prime, that g generates the order-q subgroup, and that the
+// threshold and share count fit, reporting every failure rather than the first.
+pub fn validate_params(prime: i128, generator: i128, threshold: usize, shares: usize) -> Result<(), Vec<ParamFailure>> {
+    let mut failures = Vec::new();
+    if !(7..=i64::MAX as i128).contains(&prime) {
+        failures.push(ParamFailure::PrimeOutOfRange);
+    } else if !field::is_prime(prime as u64) {
+        failures.push(ParamFailure::PrimeComposite);
+    } else if !field::is_prime((prime as u64 - 1) / 2) {
+        failures.push(ParamFailure::NotSafePrime);
     }
-    coeffs
+
+    if failures.is_empty() {
+        let order = (prime - 1) / 2;
+        if generator <= 0 || generator >= prime {
+            failures.push(ParamFailure::GeneratorOutOfRange);
+        } else if generator == 1 || generator == prime - 1 {
+            failures.push(ParamFailure::SmallOrderGenerator);
+        } else if mod_pow(generator, order, prime) != 1 {
+            failures.push(ParamFailure::WrongGeneratorOrder);
+        }
+        if shares as i128 >= order {
+            failures.push(ParamFailure::TooManyShares);
+        }
+    }
+    if threshold < 2 {
+        failures.push(ParamFailure::ThresholdTooSmall);
+    }
+    if shares < threshold {
+        failures.push(ParamFailure::TooFewShares);
+    }
+
+    if failures.is_empty() { Ok(()) } else { Err(failures) }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct VssConfig {
+    prime: i128,
+    generator: i128,
+    threshold: usize,
+    shares: usize,
+}
+
+impl VssConfig {
+    pub fn new(prime: i128, generator: i128, threshold: usize, shares: usize) -> Result<Self, VssError> {
+        validate_params(prime, generator, threshold, shares)
+            .map_err(|failures| VssError::InvalidParameters(failures[0].parameter()))?;
+        Ok(VssConfig { prime, generator, threshold, shares })
+    }
+
+    pub fn prime(&self) -> i128 {
+        self.prime
+    }
+
+    pub fn order(&self) -> i128 {
+        (self.prime - 1) / 2
+    }
+
+    pub fn generator(&self) -> i128 {
+        self.generator
+    }
+
+    pub fn threshold(&self) -> usize {
+        self.threshold
+    }
+
+    pub fn shares(&self) -> usize {
+        self.shares
+    }
+
+    // Z_q, where shares and polynomial coefficients live.
+    pub fn scalar_field(&self) -> SmallPrimeField {
+        SmallPrimeField::new(self.order())
+    }
+}
+
+// Deserialized parameters go through the same validation as `VssConfig::new`.
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for VssConfig {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(serde::Deserialize)]
+        struct Params {
+            prime: i128,
+            generator: i128,
+            threshold: usize,
+            shares: usize,
+        }
+
+        let params = Params::deserialize(deserializer)?;
+        VssConfig::new(params.prime, params.generator, params.threshold, params.shares)
+            .map_err(|_| serde::de::Error::custom("invalid VSS parameters"))
+    }
+}
+
+// The order-q subgroup of Z_p* generated by g, written additively: `combine` multiplies mod p
+// and `scale` exponentiates.
+impl Commitment for VssConfig {
+    type Scalar = i128;
+    type Point = i128;
+
+    fn commit(&self, scalar: &i128) -> i128 {
+        self.scale(&self.generator, scalar)
+    }
+
+    fn combine(&self, a: &i128, b: &i128) -> i128 {
+        mod_norm(mod_norm(*a, self.prime) * mod_norm(*b, self.prime), self.prime)
+    }
+
+    fn scale(&self, point: &i128, scalar: &i128) -> i128 {
+        mod_pow(*point, mod_norm(*scalar, self.order()), self.prime)
+    }
+
+    fn identity(&self) -> i128 {
+        1
+    }
+
+    fn index(&self, x: u64) -> i128 {
+        self.scalar_field().element(x)
+    }
+}
+
+impl Default for VssConfig {
+    fn default() -> Self {
+        VssConfig { prime: P, generator: G, threshold: THRESHOLD, shares: SHARES_COUNT }
+    }
+}
+
+pub(crate) fn mod_norm(a: i128, m: i128) -> i128 {
+    SmallPrimeField::new(m).reduce(a)
+}
+
+// Exponentiation in Z_m*; VssConfig keeps every modulus below 2^63.
+pub(crate) fn mod_pow(base: i128, exp: i128, modulus: i128) -> i128 {
+    SmallPrimeField::new(modulus).pow(&base, exp as u64)
+}
+
+pub fn generate_polynomial(secret: i128, config: &VssConfig, rng: &mut (impl RngCore + CryptoRng)) -> Zeroizing<Vec<i128>> {
+    Zeroizing::new(polynomial::generate(&config.scalar_field(), secret, config.threshold, rng))
+}
+
+pub fn generate_shares(coeffs: &[i128], config: &VssConfig, rng: &mut (impl RngCore + CryptoRng)) -> Vec<Share<i128>> {
+    let xs: Vec<u64> = (1..=config.shares as u64).collect();
+    shares_at(coeffs, config, &xs, rng)
 }
 
-fn eval_polynomial(coeffs: &[i128], x: i128) -> i128 {
-    let mut sum = 0;
-    for (i, &coeff) in coeffs.iter().enumerate() {
-        let term = mod_norm(coeff * mod_pow(x, i as i128, Q), Q);
-        sum = mod_norm(sum + term, Q);
+// Shares at the dealer's choice of x-coordinates, each nonzero and distinct below the group order.
+pub fn generate_shares_at(
+    coeffs: &[i128],
+    config: &VssConfig,
+    xs: &[u64],
+    rng: &mut (impl RngCore + CryptoRng),
+) -> Result<Vec<Share<i128>>, VssError> {
+    if xs.len() < config.threshold {
+        return Err(VssError::InsufficientShares { provided: xs.len(), required: config.threshold });
     }
-    sum
+    sss::check_indices(xs, config.order() as u64).map_err(index_error)?;
+    Ok(shares_at(coeffs, config, xs, rng))
+}
+
+fn shares_at(coeffs: &[i128], config: &VssConfig, xs: &[u64], rng: &mut (impl RngCore + CryptoRng)) -> Vec<Share<i128>> {
+    let scheme_id = rng.r#gen();
+    #[cfg(feature = "parallel")]
+    let xs = xs.par_iter();
+    #[cfg(not(feature = "parallel"))]
+    let xs = xs.iter();
+    share::dealt(
+        xs.map(|&x| {
+            let field = config.scalar_field();
+            let value = polynomial::evaluate(&field, coeffs, &field.element(x));
+            Share::new(x, value, config.threshold, scheme_id)
+        })
+        .collect(),
+        rng,
+    )
+}
+
+// A whole dealing, shares and commitments, from `polynomial::seeded_rng(seed)`; the same seed
+// reproduces it exactly.
+pub fn deal_from_seed(secret: i128, config: &VssConfig, seed: [u8; 32]) -> (Vec<Share<i128>>, Vec<i128>) {
+    let mut rng = polynomial::seeded_rng(seed);
+    let coeffs = generate_polynomial(secret, config, &mut rng);
+    (generate_shares(&coeffs, config, &mut rng), generate_commitments(&coeffs, config))
 }
 
-pub fn generate_shares(coeffs: &[i128]) -> Vec<(i128, i128)> {
-    (1..=SHARES_COUNT as i128)
-        .map(|x| (x, eval_polynomial(coeffs, x)))
-        .collect()
+pub fn generate_commitments(coeffs: &[i128], config: &VssConfig) -> Vec<i128> {
+    #[cfg(feature = "parallel")]
+    let coeffs = coeffs.par_iter();
+    #[cfg(not(feature = "parallel"))]
+    let coeffs = coeffs.iter();
+    coeffs.map(|a| config.commit(a)).collect()
 }
 
-pub fn generate_commitments(coeffs: &[i128]) -> Vec<i128> {
-    coeffs.iter()
-        .map(|&a| mod_pow(G, mod_norm(a, Q), P))
-        .collect()
+// g^f(x) computed from the commitments alone: the public image of the share at x.
+pub(crate) fn commitment_at(x: u64, commitments: &[i128], config: &VssConfig) -> i128 {
+    group::commitment_at(config, x, commitments)
 }
 
-pub fn verify_share(share: (i128, i128), commitments: &[i128]) -> bool {
-    let (x, y) = share;
-    let lhs = mod_pow(G, y, P);
-    let mut rhs = 1;
-    for (i, &commitment) in commitments.iter().enumerate() {
-        let exponent = mod_pow(x, i as i128, Q);
-        rhs = mod_norm(rhs * mod_pow(commitment, exponent, P), P);
+pub fn verify_share(share: &Share<i128>, commitments: &[i128], config: &VssConfig) -> bool {
+    if commitments.len() != config.threshold {
+        return false;
     }
-    lhs == rhs
+    config.commit(&share.value) == commitment_at(share.index, commitments, config)
 }
 
-pub fn reconstruct_secret(shares: &[(i128, i128)]) -> i128 {
-    let mut secret = 0;
-    for (j, &(xj, yj)) in shares.iter().enumerate() {
-        let mut num = 1;
-        let mut den = 1;
-        for (m, &(xm, _)) in shares.iter().enumerate() {
-            if m != j {
-                num = mod_norm(num * mod_norm(-xm, Q), Q);
-                let diff = mod_norm(xj - xm, Q);
-                den = mod_norm(den * diff, Q);
+// base^(d * 16^i) for every 4-bit window i of an exponent below q and every digit d, so raising
+// the base to any exponent is one multiplication per window and no squarings. Built once per
+// fixed base, g or a commitment, and reused for every share of a dealing.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FixedBaseTable {
+    windows: Vec<[i128; 16]>,
+    prime: i128,
+    order: i128,
+}
+
+impl FixedBaseTable {
+    pub fn new(base: i128, config: &VssConfig) -> Self {
+        let prime = config.prime;
+        let bits = 128 - config.order().leading_zeros() as usize;
+        let mut windows = Vec::with_capacity(bits.div_ceil(4));
+        let mut power = mod_norm(base, prime);
+        for _ in 0..bits.div_ceil(4) {
+            let mut window = [1; 16];
+            for digit in 1..16 {
+                window[digit] = window[digit - 1] * power % prime;
             }
+            // base^(16^(i + 1)) = (base^(15 * 16^i)) * base^(16^i).
+            power = window[15] * power % prime;
+            windows.push(window);
         }
-        let inv_den = mod_inverse(den, Q);
-        let lambda = mod_norm(num * inv_den, Q);
-        secret = mod_norm(secret + mod_norm(yj * lambda, Q), Q);
+        FixedBaseTable { windows, prime, order: config.order() }
+    }
+
+    pub fn pow(&self, exp: i128) -> i128 {
+        let exp = mod_norm(exp, self.order);
+        self.windows.iter().enumerate().fold(1, |acc, (i, window)| {
+            mod_norm(acc * lookup(window, ((exp >> (4 * i)) & 0xf) as usize), self.prime)
+        })
     }
-    secret
 }
 
-pub fn run_vss(secret: i128) {
-    println!("--- Feldman VSS Demonstration ---");
-    let mut rng = rand::thread_rng();
+#[cfg(not(feature = "constant-time"))]
+fn lookup(window: &[i128; 16], digit: usize) -> i128 {
+    window[digit]
+}
 
-    let coeffs = generate_polynomial(secret, THRESHOLD, &mut rng);
-    println!("Polynomial coefficients: {:?}", coeffs);
+// The share value is the exponent of g, so the table is scanned rather than indexed by it.
+#[cfg(feature = "constant-time")]
+fn lookup(window: &[i128; 16], digit: usize) -> i128 {
+    use super::ct;
+    window.iter().enumerate().fold(0, |entry, (j, &value)| {
+        ct::select(ct::eq(j as u64, digit as u64), value as u64, entry as u64) as i128
+    })
+}
 
-    let shares = generate_shares(&coeffs);
-    println!("Shares: {:?}", shares);
+// Tables for g and each commitment of one dealing. A share then checks as g^y against the
+// product of C_k^(x^k), every exponentiation a table walk.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VerificationTables {
+    generator: FixedBaseTable,
+    commitments: Vec<FixedBaseTable>,
+    config: VssConfig,
+}
 
-    let commitments = generate_commitments(&coeffs);
-    println!("Commitments: {:?}", commitments);
+impl VerificationTables {
+    pub fn new(commitments: &[i128], config: &VssConfig) -> Self {
+        VerificationTables {
+            generator: FixedBaseTable::new(config.generator, config),
+            commitments: commitments.iter().map(|&commitment| FixedBaseTable::new(commitment, config)).collect(),
+            config: *config,
+        }
+    }
 
-    for share in &shares {
-        let valid = verify_share(*share, &commitments);
-        println!("Share {:?} valid: {}", share, valid);
+    pub fn verify_share(&self, share: &Share<i128>) -> bool {
+        if self.commitments.len() != self.config.threshold {
+            return false;
+        }
+        let field = self.config.scalar_field();
+        let x = field.element(share.index);
+        let mut exponent = 1;
+        let mut expected = 1;
+        for table in &self.commitments {
+            expected = expected * table.pow(exponent) % self.config.prime;
+            exponent = field.mul(&exponent, &x);
+        }
+        self.generator.pow(share.value) == expected
     }
+}
+
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all, fields(shares = shares.len())))]
+pub fn verify_shares(shares: &[Share<i128>], commitments: &[i128], config: &VssConfig) -> Result<(), VssError> {
+    let tables = VerificationTables::new(commitments, config);
+    #[cfg(feature = "parallel")]
+    let invalid = shares.par_iter().find_first(|share| !tables.verify_share(share));
+    #[cfg(not(feature = "parallel"))]
+    let invalid = shares.iter().find(|share| !tables.verify_share(share));
+    match invalid {
+        Some(share) => {
+            event!(warn, "share {} does not match the commitments", share.index);
+            Err(VssError::VerificationFailed { index: share.index })
+        }
+        None => Ok(()),
+    }
+}
+
+// `verify_shares` over the shares `share::check_fresh` keeps; `commitments` are the ones for the
+// newest epoch, as `refresh::refresh_commitments` tracks them.
+pub fn verify_fresh(
+    shares: &[Share<i128>],
+    commitments: &[i128],
+    config: &VssConfig,
+    now: Option<u64>,
+    policy: StalePolicy,
+) -> Result<Staleness, VssError> {
+    let (fresh, staleness) = share::check_fresh(shares, now, policy).map_err(index_error)?;
+    verify_shares(&fresh, commitments, config)?;
+    Ok(staleness)
+}
+
+// All shares at once: with random weights r_i, g^(sum r_i s_i) must equal the product of
+// C_k^(sum r_i x_i^k), which costs t + 1 exponentiations however many shares there are. A bad
+// share passes with probability 1/q; if the batch fails, the shares are checked one by one so
+// the error still names the first bad share.
+pub fn verify_shares_batch(
+    shares: &[Share<i128>],
+    commitments: &[i128],
+    config: &VssConfig,
+    rng: &mut (impl RngCore + CryptoRng),
+) -> Result<(), VssError> {
+    if commitments.len() == config.threshold && batch_holds(shares, commitments, config, rng) {
+        return Ok(());
+    }
+    verify_shares(shares, commitments, config)
+}
+
+fn batch_holds(
+    shares: &[Share<i128>],
+    commitments: &[i128],
+    config: &VssConfig,
+    rng: &mut (impl RngCore + CryptoRng),
+) -> bool {
+    let field = config.scalar_field();
+    let mut combined = 0;
+    let mut exponents = vec![0; commitments.len()];
+    for share in shares {
+        let weight = field.random(rng);
+        combined = field.add(&combined, &field.mul(&weight, &mod_norm(share.value, config.order())));
+        let x = config.index(share.index);
+        let mut term = weight;
+        for exponent in exponents.iter_mut() {
+            *exponent = field.add(exponent, &term);
+            term = field.mul(&term, &x);
+        }
+    }
+    let expected = commitments
+        .iter()
+        .zip(&exponents)
+        .fold(config.identity(), |acc, (c, e)| config.combine(&acc, &config.scale(c, e)));
+    config.commit(&combined) == expected
+}
+
+// What the dealer publishes with a dealing. Either part may be absent: a bundle holding only a
+// verification key authenticates shares without Feldman commitments.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CommitmentBundle {
+    pub commitments: Vec<i128>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub verification_key: Option<VerificationKey>,
+}
+
+impl CommitmentBundle {
+    pub fn verify(&self, tagged: &TaggedShare<i128>, config: &VssConfig) -> bool {
+        (self.commitments.is_empty() || verify_share(&tagged.share, &self.commitments, config))
+            && self.verification_key.as_ref().is_none_or(|key| auth::verify(tagged, key))
+    }
+}
+
+pub(crate) fn index_error(err: ShamirError) -> VssError {
+    match err {
+        ShamirError::ZeroIndex => VssError::ZeroIndex,
+        ShamirError::DuplicateIndex => VssError::DuplicateIndex,
+        ShamirError::Outliers(indices) => VssError::Outliers(indices),
+        ShamirError::StaleShares(indices) => VssError::StaleShares(indices),
+        ShamirError::ExpiredShares(indices) => VssError::ExpiredShares(indices),
+        _ => VssError::InvalidParameters("xs"),
+    }
+}
+
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all, fields(shares = shares.len())))]
+pub fn reconstruct_secret(shares: &[Share<i128>], config: &VssConfig) -> Result<i128, VssError> {
+    if shares.len() < config.threshold {
+        return Err(VssError::InsufficientShares { provided: shares.len(), required: config.threshold });
+    }
+    if !share::check_same_dealing(shares) {
+        return Err(VssError::InconsistentShares);
+    }
+    sss::check_share_indices(shares, config.order() as u64).map_err(index_error)?;
+    sss::check_consistent(&config.scalar_field(), shares, config.threshold).map_err(index_error)?;
+    interpolate_zero(&shares[..config.threshold], config.order())
+}
+
+// `reconstruct_secret`, then g^secret against C_0, so a share set that is self-consistent but not
+// the committed dealing's never hands back a secret.
+pub fn reconstruct_checked(shares: &[Share<i128>], commitments: &[i128], config: &VssConfig) -> Result<i128, VssError> {
+    let secret = reconstruct_secret(shares, config)?;
+    check_secret(secret, commitments, config)?;
+    Ok(secret)
+}
+
+pub fn check_secret(secret: i128, commitments: &[i128], config: &VssConfig) -> Result<(), VssError> {
+    let c0 = commitments.first().ok_or(VssError::InvalidParameters("commitments"))?;
+    if config.commit(&secret) != mod_norm(*c0, config.prime) {
+        return Err(VssError::ReconstructionMismatch);
+    }
+    Ok(())
+}
+
+fn interpolate_zero(shares: &[Share<i128>], q: i128) -> Result<i128, VssError> {
+    polynomial::interpolate_at_zero(&SmallPrimeField::new(q), shares).ok_or(VssError::NoInverse)
+}
+
+// The dealing polynomial at x, from the first `threshold` shares; see `sss::interpolate_at`.
+pub fn interpolate_at(shares: &[Share<i128>], x: u64, config: &VssConfig) -> Result<i128, VssError> {
+    if shares.len() < config.threshold {
+        return Err(VssError::InsufficientShares { provided: shares.len(), required: config.threshold });
+    }
+    if !share::check_same_dealing(shares) {
+        return Err(VssError::InconsistentShares);
+    }
+    sss::check_share_indices(shares, config.order() as u64).map_err(index_error)?;
+    let field = config.scalar_field();
+    sss::check_consistent(&field, shares, config.threshold).map_err(index_error)?;
+    polynomial::interpolate_at(&field, &shares[..config.threshold], &field.element(x)).ok_or(VssError::NoInverse)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RejectReason {
+    VerificationFailed,
+    ZeroIndex,
+    DuplicateIndex,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Rejection {
+    pub index: u64,
+    pub reason: RejectReason,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct VerifiedReconstruction {
+    // None when fewer than threshold shares survived verification.
+    pub secret: Option<i128>,
+    pub accepted: Vec<u64>,
+    pub rejected: Vec<Rejection>,
+}
+
+// Checks every share against the commitments before interpolating, so a cheating holder is
+// named instead of silently skewing the result.
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all, fields(shares = shares.len())))]
+pub fn reconstruct_verified(
+    shares: &[Share<i128>],
+    commitments: &[i128],
+    config: &VssConfig,
+) -> Result<VerifiedReconstruction, VssError> {
+    if commitments.len() != config.threshold {
+        return Err(VssError::InvalidParameters("commitments"));
+    }
+    let mut accepted: Vec<Share<i128>> = Vec::with_capacity(shares.len());
+    let mut rejected = Vec::new();
+    for share in shares {
+        let reason = if mod_norm(share.index as i128, config.order()) == 0 {
+            Some(RejectReason::ZeroIndex)
+        } else if accepted.iter().any(|other| {
+            mod_norm(other.index as i128, config.order()) == mod_norm(share.index as i128, config.order())
+        }) {
+            Some(RejectReason::DuplicateIndex)
+        } else if !verify_share(share, commitments, config) {
+            Some(RejectReason::VerificationFailed)
+        } else {
+            None
+        };
+        match reason {
+            Some(reason) => rejected.push(Rejection { index: share.index, reason }),
+            None => accepted.push(share.clone()),
+        }
+    }
+
+    // Verified shares lie on the committed polynomial, so their metadata needn't be compared.
+    let secret = if accepted.len() >= config.threshold {
+        let secret = interpolate_zero(&accepted[..config.threshold], config.order())?;
+        check_secret(secret, commitments, config)?;
+        Some(secret)
+    } else {
+        None
+    };
+    event!(debug, "{} shares accepted, {} rejected", accepted.len(), rejected.len());
+    Ok(VerifiedReconstruction { secret, accepted: accepted.iter().map(|share| share.index).collect(), rejected })
+}
 
-    let recovered = reconstruct_secret(&shares[0..THRESHOLD]);
-    println!("Reconstructed secret (from first {} shares): {}", THRESHOLD, recovered);
+// Every basis polynomial at zero, for one inversion in all.
+pub(crate) fn lagrange_basis_at_zero(xs: &[i128], q: i128) -> Result<Vec<i128>, VssError> {
+    polynomial::lagrange_basis_at_zero(&SmallPrimeField::new(q), xs).ok_or(VssError::NoInverse)
 }
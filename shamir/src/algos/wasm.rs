@@ -0,0 +1,28 @@
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use wasm_bindgen::prelude::*;
+
+use super::share::Share;
+use super::sss;
+
+// Browser entry points. Entropy comes from crypto.getRandomValues via getrandom's `js` backend;
+// shares cross the boundary in their text form so they can be shown, copied, and pasted back.
+
+#[wasm_bindgen]
+pub fn split(secret: &[u8], threshold: usize, shares: usize) -> Result<Vec<String>, JsError> {
+    let shares = sss::split_bytes(secret, threshold, shares, &mut rand::thread_rng())
+        .map_err(|err| JsError::new(&err.to_string()))?;
+    Ok(shares.iter().map(ToString::to_string).collect())
+}
+
+#[wasm_bindgen]
+pub fn combine(shares: Vec<String>) -> Result<Vec<u8>, JsError> {
+    let shares = shares
+        .iter()
+        .map(|share| share.parse::<Share<Vec<u8>>>())
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|_| JsError::new("malformed share"))?;
+    let secret = sss::combine_bytes(&shares).map_err(|err| JsError::new(&err.to_string()))?;
+    Ok(secret.to_vec())
+}
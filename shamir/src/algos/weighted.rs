@@ -0,0 +1,58 @@
+use alloc::vec::Vec;
+
+use rand::{CryptoRng, RngCore};
+
+use super::share::Share;
+use super::sss::{self, ShamirError};
+
+// A participant of weight w holds w ordinary shares of one dealing.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WeightedShare {
+    pub participant: usize,
+    pub shares: Vec<Share>,
+}
+
+impl WeightedShare {
+    pub fn weight(&self) -> usize {
+        self.shares.len()
+    }
+}
+
+pub fn validate_weights(weights: &[usize], threshold: usize) -> Result<(), ShamirError> {
+    if threshold < 2 {
+        return Err(ShamirError::InvalidThreshold);
+    }
+    if weights.contains(&0) {
+        return Err(ShamirError::InvalidParameters("weights"));
+    }
+    if weights.iter().sum::<usize>() < threshold {
+        return Err(ShamirError::InvalidShareCount);
+    }
+    Ok(())
+}
+
+pub fn generate_shares(
+    secret: u64,
+    threshold: usize,
+    weights: &[usize],
+    rng: &mut (impl RngCore + CryptoRng),
+) -> Result<Vec<WeightedShare>, ShamirError> {
+    validate_weights(weights, threshold)?;
+    let mut shares = sss::generate_shares(secret, threshold, weights.iter().sum(), rng)?.into_iter();
+    Ok(weights
+        .iter()
+        .enumerate()
+        .map(|(participant, &weight)| WeightedShare { participant, shares: shares.by_ref().take(weight).collect() })
+        .collect())
+}
+
+pub fn reconstruct_secret(shares: &[WeightedShare]) -> Result<u64, ShamirError> {
+    let flat: Vec<Share> = shares.iter().flat_map(|weighted| weighted.shares.iter().cloned()).collect();
+    for (i, share) in flat.iter().enumerate() {
+        if flat[..i].iter().any(|other| other.index == share.index) {
+            return Err(ShamirError::InconsistentShares);
+        }
+    }
+    sss::reconstruct_secret(&flat)
+}
@@ -0,0 +1,190 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+use core::str::FromStr;
+
+use num_bigint::BigUint;
+use rand::{CryptoRng, RngCore};
+use sha2::{Digest, Sha256};
+use zeroize::Zeroizing;
+
+use super::secp256k1;
+use super::share::{ParseShareError, Share};
+use super::sss::ShamirError;
+
+// Bitcoin private keys in Wallet Import Format: Base58Check over a network byte, the 32-byte
+// secp256k1 scalar, and a trailing 0x01 when the key's public key is used compressed. The scalar is
+// shared with `secp256k1::split_key`; each share carries the network and compression flag so the
+// key comes back as the same WIF it went in as.
+
+const ALPHABET: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Network {
+    Mainnet,
+    Testnet,
+}
+
+impl Network {
+    fn prefix(self) -> u8 {
+        match self {
+            Network::Mainnet => 0x80,
+            Network::Testnet => 0xef,
+        }
+    }
+}
+
+pub struct WifKey {
+    pub key: Zeroizing<[u8; 32]>,
+    pub network: Network,
+    pub compressed: bool,
+}
+
+// A share of a WIF key's scalar with what's needed to re-encode it. The text form puts them in
+// front of the share: `mainnet:compressed:<share>`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WifShare {
+    pub network: Network,
+    pub compressed: bool,
+    pub share: Share<BigUint>,
+}
+
+fn checksum(payload: &[u8]) -> [u8; 4] {
+    let digest = Sha256::digest(Sha256::digest(payload));
+    [digest[0], digest[1], digest[2], digest[3]]
+}
+
+fn base58check_encode(payload: &[u8]) -> Zeroizing<String> {
+    let mut bytes = Zeroizing::new(payload.to_vec());
+    bytes.extend_from_slice(&checksum(payload));
+    // Little-endian base-58 digits, by repeated multiply-and-add over the input bytes.
+    let mut digits = Zeroizing::new(Vec::<u8>::new());
+    for &byte in bytes.iter() {
+        let mut carry = byte as u32;
+        for digit in digits.iter_mut() {
+            carry += (*digit as u32) << 8;
+            *digit = (carry % 58) as u8;
+            carry /= 58;
+        }
+        while carry > 0 {
+            digits.push((carry % 58) as u8);
+            carry /= 58;
+        }
+    }
+    let zeros = bytes.iter().take_while(|&&byte| byte == 0).count();
+    let mut out = Zeroizing::new(String::with_capacity(zeros + digits.len()));
+    out.extend(core::iter::repeat_n('1', zeros));
+    out.extend(digits.iter().rev().map(|&digit| ALPHABET[digit as usize] as char));
+    out
+}
+
+fn base58check_decode(s: &str) -> Result<Zeroizing<Vec<u8>>, ShamirError> {
+    let mut bytes = Zeroizing::new(Vec::<u8>::new());
+    for c in s.bytes() {
+        let mut carry = ALPHABET.iter().position(|&a| a == c).ok_or(ShamirError::InvalidParameters("wif"))? as u32;
+        for byte in bytes.iter_mut() {
+            carry += (*byte as u32) * 58;
+            *byte = carry as u8;
+            carry >>= 8;
+        }
+        while carry > 0 {
+            bytes.push(carry as u8);
+            carry >>= 8;
+        }
+    }
+    bytes.extend(s.bytes().take_while(|&c| c == b'1').map(|_| 0));
+    bytes.reverse();
+    let split = bytes.len().checked_sub(4).ok_or(ShamirError::InvalidParameters("wif"))?;
+    let (payload, sum) = bytes.split_at(split);
+    if checksum(payload) != sum {
+        return Err(ShamirError::InvalidChecksum);
+    }
+    Ok(Zeroizing::new(payload.to_vec()))
+}
+
+impl WifKey {
+    pub fn decode(wif: &str) -> Result<Self, ShamirError> {
+        let payload = base58check_decode(wif.trim())?;
+        let network = match payload.first() {
+            Some(0x80) => Network::Mainnet,
+            Some(0xef) => Network::Testnet,
+            _ => return Err(ShamirError::InvalidParameters("wif")),
+        };
+        let compressed = match payload.len() {
+            33 => false,
+            34 if payload[33] == 0x01 => true,
+            _ => return Err(ShamirError::InvalidParameters("wif")),
+        };
+        let mut key = Zeroizing::new([0u8; 32]);
+        key.copy_from_slice(&payload[1..33]);
+        secp256k1::scalar_from_bytes(&key)?;
+        Ok(WifKey { key, network, compressed })
+    }
+
+    pub fn encode(&self) -> Zeroizing<String> {
+        let mut payload = Zeroizing::new(Vec::with_capacity(34));
+        payload.push(self.network.prefix());
+        payload.extend_from_slice(&self.key[..]);
+        if self.compressed {
+            payload.push(0x01);
+        }
+        base58check_encode(&payload)
+    }
+}
+
+pub fn split_wif(
+    wif: &str,
+    threshold: usize,
+    num_shares: usize,
+    rng: &mut (impl RngCore + CryptoRng),
+) -> Result<Vec<WifShare>, ShamirError> {
+    let key = WifKey::decode(wif)?;
+    Ok(secp256k1::split_key(&key.key, threshold, num_shares, rng)?
+        .into_iter()
+        .map(|share| WifShare { network: key.network, compressed: key.compressed, share })
+        .collect())
+}
+
+// The key as WIF, from shares that agree on network and compression.
+pub fn combine_wif(shares: &[WifShare]) -> Result<Zeroizing<String>, ShamirError> {
+    let first = shares.first().ok_or(ShamirError::InsufficientShares { provided: 0, required: 1 })?;
+    if shares.iter().any(|share| share.network != first.network || share.compressed != first.compressed) {
+        return Err(ShamirError::InconsistentShares);
+    }
+    let inner: Vec<Share<BigUint>> = shares.iter().map(|share| share.share.clone()).collect();
+    let key = secp256k1::combine_key(&inner)?;
+    Ok(WifKey { key, network: first.network, compressed: first.compressed }.encode())
+}
+
+impl fmt::Display for WifShare {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let network = match self.network {
+            Network::Mainnet => "mainnet",
+            Network::Testnet => "testnet",
+        };
+        let compression = if self.compressed { "compressed" } else { "uncompressed" };
+        write!(f, "{}:{}:{}", network, compression, self.share)
+    }
+}
+
+impl FromStr for WifShare {
+    type Err = ParseShareError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.trim().splitn(3, ':');
+        let network = match parts.next() {
+            Some("mainnet") => Network::Mainnet,
+            Some("testnet") => Network::Testnet,
+            _ => return Err(ParseShareError::Malformed),
+        };
+        let compressed = match parts.next() {
+            Some("compressed") => true,
+            Some("uncompressed") => false,
+            _ => return Err(ParseShareError::Malformed),
+        };
+        let share = parts.next().ok_or(ParseShareError::Malformed)?.parse()?;
+        Ok(WifShare { network, compressed, share })
+    }
+}
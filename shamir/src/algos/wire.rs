@@ -0,0 +1,209 @@
+use alloc::vec::Vec;
+
+use num_bigint::BigUint;
+use sha2::{Digest, Sha256};
+
+use super::share::{Share, ShareValue};
+
+// Layout (all integers big-endian):
+//   magic "SHMR" | version u8 | field id u8 | scheme id u32 | epoch u32 | level u32
+//   | threshold u32 | total u32 | fingerprint u64 | index u64 | payload length u32 | payload
+//   | first 4 bytes of SHA-256
+// Version 1 lacks the total and the fingerprint. The fingerprint is a random tag drawn once per
+// dealing (zero when unknown); together with the scheme id it tells a share from another split.
+// Decoders must keep accepting every version ever written.
+pub const MAGIC: [u8; 4] = *b"SHMR";
+pub const VERSION: u8 = 2;
+const HEADER_LEN: usize = 4 + 1 + 1 + 4 + 4 + 4 + 4 + 4 + 8 + 8 + 4;
+const V1_HEADER_LEN: usize = 4 + 1 + 1 + 4 + 4 + 4 + 4 + 8 + 4;
+const CHECKSUM_LEN: usize = 4;
+// Encoded size minus the payload.
+pub const OVERHEAD: usize = HEADER_LEN + CHECKSUM_LEN;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum DecodeError {
+    BadMagic,
+    UnsupportedVersion(u8),
+    FieldMismatch,
+    Truncated,
+    BadChecksum,
+    Malformed,
+}
+
+// The dealing parameters in a share's header, readable without decoding the value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Header {
+    pub version: u8,
+    pub field_id: u8,
+    pub scheme_id: u32,
+    pub epoch: u32,
+    pub level: u32,
+    pub threshold: usize,
+    // Zero in version 1 headers.
+    pub total: usize,
+    // Zero in version 1 headers.
+    pub fingerprint: u64,
+    pub index: u64,
+}
+
+pub trait WireValue: ShareValue {
+    const FIELD_ID: u8;
+    fn to_payload(&self) -> Vec<u8>;
+    fn from_payload(payload: &[u8]) -> Option<Self>;
+}
+
+impl WireValue for u64 {
+    const FIELD_ID: u8 = 1;
+
+    fn to_payload(&self) -> Vec<u8> {
+        self.to_be_bytes().to_vec()
+    }
+
+    fn from_payload(payload: &[u8]) -> Option<Self> {
+        Some(u64::from_be_bytes(payload.try_into().ok()?))
+    }
+}
+
+impl WireValue for Vec<u8> {
+    const FIELD_ID: u8 = 2;
+
+    fn to_payload(&self) -> Vec<u8> {
+        self.clone()
+    }
+
+    fn from_payload(payload: &[u8]) -> Option<Self> {
+        Some(payload.to_vec())
+    }
+}
+
+impl WireValue for i128 {
+    const FIELD_ID: u8 = 3;
+
+    fn to_payload(&self) -> Vec<u8> {
+        self.to_be_bytes().to_vec()
+    }
+
+    fn from_payload(payload: &[u8]) -> Option<Self> {
+        Some(i128::from_be_bytes(payload.try_into().ok()?))
+    }
+}
+
+impl WireValue for BigUint {
+    const FIELD_ID: u8 = 4;
+
+    fn to_payload(&self) -> Vec<u8> {
+        self.to_bytes_be()
+    }
+
+    fn from_payload(payload: &[u8]) -> Option<Self> {
+        Some(BigUint::from_bytes_be(payload))
+    }
+}
+
+fn checksum(bytes: &[u8]) -> [u8; CHECKSUM_LEN] {
+    let digest = Sha256::digest(bytes);
+    [digest[0], digest[1], digest[2], digest[3]]
+}
+
+pub fn encode<V: WireValue>(share: &Share<V>) -> Vec<u8> {
+    let payload = V::to_payload(&share.value);
+    let mut out = Vec::with_capacity(HEADER_LEN + payload.len() + CHECKSUM_LEN);
+    out.extend_from_slice(&MAGIC);
+    out.push(VERSION);
+    out.push(V::FIELD_ID);
+    out.extend_from_slice(&share.scheme_id.to_be_bytes());
+    out.extend_from_slice(&share.epoch.to_be_bytes());
+    out.extend_from_slice(&share.level.to_be_bytes());
+    out.extend_from_slice(&(share.threshold as u32).to_be_bytes());
+    out.extend_from_slice(&(share.total as u32).to_be_bytes());
+    out.extend_from_slice(&share.fingerprint.to_be_bytes());
+    out.extend_from_slice(&share.index.to_be_bytes());
+    out.extend_from_slice(&(payload.len() as u32).to_be_bytes());
+    out.extend_from_slice(&payload);
+    out.extend_from_slice(&checksum(&out));
+    out
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, n: usize) -> Result<&'a [u8], DecodeError> {
+        if self.bytes.len() < n {
+            return Err(DecodeError::Truncated);
+        }
+        let (head, tail) = self.bytes.split_at(n);
+        self.bytes = tail;
+        Ok(head)
+    }
+
+    fn u32(&mut self) -> Result<u32, DecodeError> {
+        Ok(u32::from_be_bytes(self.take(4)?.try_into().map_err(|_| DecodeError::Truncated)?))
+    }
+
+    fn u64(&mut self) -> Result<u64, DecodeError> {
+        Ok(u64::from_be_bytes(self.take(8)?.try_into().map_err(|_| DecodeError::Truncated)?))
+    }
+}
+
+// The header of an encoded share, after checking the checksum, so a combiner can learn the
+// threshold and dealing size or sort shares by dealing before decoding any value.
+pub fn header(bytes: &[u8]) -> Result<Header, DecodeError> {
+    Ok(split(bytes)?.0)
+}
+
+pub fn decode<V: WireValue>(bytes: &[u8]) -> Result<Share<V>, DecodeError> {
+    let (header, payload) = split(bytes)?;
+    if header.field_id != V::FIELD_ID {
+        return Err(DecodeError::FieldMismatch);
+    }
+    let value = V::from_payload(payload).ok_or(DecodeError::Malformed)?;
+    let mut share = Share::new(header.index, value, header.threshold, header.scheme_id);
+    share.epoch = header.epoch;
+    share.level = header.level;
+    share.total = header.total;
+    share.fingerprint = header.fingerprint;
+    Ok(share)
+}
+
+fn split(bytes: &[u8]) -> Result<(Header, &[u8]), DecodeError> {
+    if bytes.len() < MAGIC.len() + 1 {
+        return Err(DecodeError::Truncated);
+    }
+    if bytes[..MAGIC.len()] != MAGIC {
+        return Err(DecodeError::BadMagic);
+    }
+    let version = bytes[MAGIC.len()];
+    let header_len = match version {
+        1 => V1_HEADER_LEN,
+        2 => HEADER_LEN,
+        version => return Err(DecodeError::UnsupportedVersion(version)),
+    };
+    if bytes.len() < header_len + CHECKSUM_LEN {
+        return Err(DecodeError::Truncated);
+    }
+    let (body, sum) = bytes.split_at(bytes.len() - CHECKSUM_LEN);
+    if checksum(body) != sum {
+        return Err(DecodeError::BadChecksum);
+    }
+
+    let mut reader = Reader { bytes: &body[MAGIC.len() + 1..] };
+    let field_id = reader.take(1)?[0];
+    let scheme_id = reader.u32()?;
+    let epoch = reader.u32()?;
+    let level = reader.u32()?;
+    let threshold = reader.u32()? as usize;
+    let (mut total, mut fingerprint) = (0, 0);
+    if version >= 2 {
+        total = reader.u32()? as usize;
+        fingerprint = reader.u64()?;
+    }
+    let index = reader.u64()?;
+    let len = reader.u32()? as usize;
+    let payload = reader.take(len)?;
+    if !reader.bytes.is_empty() {
+        return Err(DecodeError::Malformed);
+    }
+    Ok((Header { version, field_id, scheme_id, epoch, level, threshold, total, fingerprint, index }, payload))
+}
@@ -1 +1,35 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+// Diagnostics go to `tracing` with the feature on. Without it they compile to nothing, though the
+// arguments are still type-checked so variables used only for logging don't turn into warnings.
+#[cfg(feature = "tracing")]
+macro_rules! event {
+    ($level:ident, $($arg:tt)*) => { tracing::$level!($($arg)*) };
+}
+
+#[cfg(not(feature = "tracing"))]
+macro_rules! event {
+    ($level:ident, $($arg:tt)*) => {
+        if false {
+            let _ = format_args!($($arg)*);
+        }
+    };
+}
+
 pub mod algos;
+
+// The entry points most callers want: byte secrets split over GF(256), and Feldman VSS.
+pub use algos::share::Share;
+pub use algos::sss::ShamirError;
+pub use algos::sss::{combine_bytes as combine, split_bytes as split};
+pub use algos::vss;
+
+pub mod prelude {
+    pub use crate::algos::field::FiniteField;
+    pub use crate::algos::group::{Commitment, Group};
+    pub use crate::algos::share::ShareValue;
+    pub use crate::vss::{VssConfig, VssError};
+    pub use crate::{combine, split, ShamirError, Share};
+}
@@ -1,15 +1,551 @@
-mod algos;
+use std::fmt;
+use std::fs;
+use std::io::{self, IsTerminal, Read, Write};
+use std::net::SocketAddr;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use clap::{Parser, Subcommand};
 use rand::Rng;
+use shamir::algos;
+use shamir::algos::share::ParseShareError;
+use shamir::algos::store::{FileStore, ShareStore};
+use shamir::vss;
+use shamir::{ShamirError, Share};
+use tracing_subscriber::EnvFilter;
+use zeroize::Zeroizing;
+
+#[derive(Parser)]
+#[command(name = "shamir", about = "Split and combine secrets with Shamir's secret sharing")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Split a secret file (or stdin) into shares, one per line on stdout
+    Split {
+        #[arg(short, long)]
+        threshold: usize,
+        #[arg(short, long)]
+        shares: usize,
+        /// Print each share as BIP-39 words instead of hex
+        #[arg(short, long)]
+        mnemonic: bool,
+        /// Also write each share as a QR code PNG into this directory (needs the `qr` feature)
+        #[arg(long, value_name = "DIR")]
+        qr: Option<PathBuf>,
+        /// Write gfsplit-compatible share files PREFIX.NNN instead of printing shares
+        #[arg(long, value_name = "PREFIX", conflicts_with_all = ["mnemonic", "qr"])]
+        gfshare: Option<PathBuf>,
+        /// Print shares in ssss-split's index-hex format (secret of at most 128 bytes)
+        #[arg(long, conflicts_with_all = ["mnemonic", "qr", "gfshare"])]
+        ssss: bool,
+        /// Write each share as DIR/share-NN.json, with the dealing's parameters and commitments
+        #[arg(long, value_name = "DIR", conflicts_with_all = ["mnemonic", "qr", "gfshare", "ssss"])]
+        json: Option<PathBuf>,
+        /// Read a Bitcoin WIF private key and share its secp256k1 scalar
+        #[arg(long, conflicts_with_all = ["mnemonic", "qr", "gfshare", "ssss", "json"])]
+        wif: bool,
+        /// Put the shares in a share store at DIR and print the dealing id instead of the shares
+        #[arg(long, value_name = "DIR", conflicts_with_all = ["mnemonic", "qr", "gfshare", "ssss", "json", "wif"])]
+        store: Option<PathBuf>,
+        /// Write each share as DIR/share-N.sealed, encrypted under a passphrase asked for per share
+        /// (needs the `argon2` feature)
+        #[arg(
+            long,
+            value_name = "DIR",
+            conflicts_with_all = ["mnemonic", "qr", "gfshare", "ssss", "json", "wif", "store"]
+        )]
+        encrypt: Option<PathBuf>,
+        file: Option<PathBuf>,
+    },
+    /// Combine share files (or shares on stdin, one per line, hex or words) and print the secret;
+    /// .png files are scanned as QR codes, .json files read as share files from `split --json`, and
+    /// .sealed files from `split --encrypt` opened with a passphrase asked for per file
+    Combine {
+        /// Treat the files as gfsplit output (NAME.NNN holding raw share bytes)
+        #[arg(long, requires = "shares")]
+        gfshare: bool,
+        /// Read ssss-split shares; exactly the threshold number must be given
+        #[arg(long, conflicts_with = "gfshare")]
+        ssss: bool,
+        /// Read shares from `split --wif` and print the key as WIF
+        #[arg(long, conflicts_with_all = ["gfshare", "ssss"])]
+        wif: bool,
+        /// Combine the shares of `--dealing` held in the share store at DIR
+        #[arg(
+            long,
+            value_name = "DIR",
+            requires = "dealing",
+            conflicts_with_all = ["gfshare", "ssss", "wif", "shares"]
+        )]
+        store: Option<PathBuf>,
+        /// Dealing id printed by `split --store`
+        #[arg(long, value_name = "ID", requires = "store")]
+        dealing: Option<String>,
+        shares: Vec<PathBuf>,
+    },
+    /// Prompt for shares one at a time (input hidden) and print the secret once the threshold is met
+    Unseal {
+        /// A share-NN.json from the dealing; each share entered must match its commitments
+        #[arg(long, value_name = "FILE")]
+        commitments: Option<PathBuf>,
+    },
+    /// Run the SSS and VSS walkthrough with a random secret
+    Demo,
+    /// Serve the JSON API (POST /split, /combine, /verify; needs the `rest` feature) until interrupted
+    Serve {
+        /// Serve the shamir.v1.Shamir gRPC key-ceremony service instead (needs the `grpc` feature)
+        #[arg(long)]
+        grpc: bool,
+        /// Defaults to 127.0.0.1:8080, or 127.0.0.1:50051 with --grpc
+        #[arg(long)]
+        listen: Option<SocketAddr>,
+    },
+}
+
+#[derive(Debug)]
+enum CliError {
+    Io(io::Error),
+    Shamir(ShamirError),
+    // 1-based position of the offending share in the input.
+    Parse(usize, ParseShareError),
+    #[cfg(any(not(feature = "qr"), not(feature = "grpc"), not(feature = "rest"), not(feature = "argon2")))]
+    Unsupported(&'static str),
+    #[cfg(feature = "qr")]
+    Qr(algos::qr::QrError),
+}
+
+impl fmt::Display for CliError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CliError::Io(err) => write!(f, "{}", err),
+            CliError::Shamir(err) => write!(f, "{}", err),
+            CliError::Parse(position, ParseShareError::Malformed) => write!(f, "share {} is malformed", position),
+            CliError::Parse(position, ParseShareError::Corrupted) => {
+                write!(f, "share {} is corrupted (checksum mismatch)", position)
+            }
+            #[cfg(any(not(feature = "qr"), not(feature = "grpc"), not(feature = "rest"), not(feature = "argon2")))]
+            CliError::Unsupported(feature) => write!(f, "built without the `{}` feature", feature),
+            #[cfg(feature = "qr")]
+            CliError::Qr(err) => write!(f, "{:?}", err),
+        }
+    }
+}
+
+impl From<io::Error> for CliError {
+    fn from(err: io::Error) -> Self {
+        CliError::Io(err)
+    }
+}
+
+impl From<ShamirError> for CliError {
+    fn from(err: ShamirError) -> Self {
+        CliError::Shamir(err)
+    }
+}
+
+#[cfg(feature = "qr")]
+impl From<algos::qr::QrError> for CliError {
+    fn from(err: algos::qr::QrError) -> Self {
+        CliError::Qr(err)
+    }
+}
+
+fn read_input(file: Option<&PathBuf>) -> io::Result<Vec<u8>> {
+    match file {
+        Some(path) => fs::read(path),
+        None => {
+            let mut buf = Vec::new();
+            io::stdin().read_to_end(&mut buf)?;
+            Ok(buf)
+        }
+    }
+}
+
+#[cfg(feature = "qr")]
+fn write_qr(dir: &Path, shares: &[Share<Vec<u8>>]) -> Result<(), CliError> {
+    fs::create_dir_all(dir)?;
+    for share in shares {
+        fs::write(dir.join(format!("share-{}.png", share.index)), algos::qr::to_png(share)?)?;
+    }
+    Ok(())
+}
+
+#[cfg(not(feature = "qr"))]
+fn write_qr(_dir: &Path, _shares: &[Share<Vec<u8>>]) -> Result<(), CliError> {
+    Err(CliError::Unsupported("qr"))
+}
+
+#[cfg(feature = "argon2")]
+fn write_sealed(dir: &Path, shares: &[Share<Vec<u8>>]) -> Result<(), CliError> {
+    fs::create_dir_all(dir)?;
+    for share in shares {
+        let prompt = format!("Passphrase for share {}: ", share.index);
+        let passphrase = read_secret_line(&prompt)?.ok_or(ShamirError::InvalidParameters("passphrase"))?;
+        if io::stdin().is_terminal() {
+            let again = read_secret_line("Repeat passphrase: ")?.unwrap_or_default();
+            if *again != *passphrase {
+                return Err(ShamirError::InvalidParameters("passphrase").into());
+            }
+        }
+        let sealed = algos::passphrase::seal_share(share, passphrase.trim_end(), &mut rand::thread_rng())?;
+        let path = dir.join(format!("share-{}.sealed", share.index));
+        fs::write(&path, format!("{}\n", sealed))?;
+        println!("{}", path.display());
+    }
+    Ok(())
+}
+
+#[cfg(not(feature = "argon2"))]
+fn write_sealed(_dir: &Path, _shares: &[Share<Vec<u8>>]) -> Result<(), CliError> {
+    Err(CliError::Unsupported("argon2"))
+}
+
+#[cfg(feature = "argon2")]
+fn read_sealed(path: &Path) -> Result<Share<Vec<u8>>, CliError> {
+    let sealed: algos::passphrase::SealedShare =
+        fs::read_to_string(path)?.parse().map_err(|err| CliError::Parse(1, err))?;
+    let prompt = format!("Passphrase for {}: ", path.display());
+    let passphrase = read_secret_line(&prompt)?.ok_or(ShamirError::InvalidParameters("passphrase"))?;
+    Ok(algos::passphrase::open_share(&sealed, passphrase.trim_end())?)
+}
+
+#[cfg(not(feature = "argon2"))]
+fn read_sealed(_path: &Path) -> Result<Share<Vec<u8>>, CliError> {
+    Err(CliError::Unsupported("argon2"))
+}
+
+#[cfg(feature = "qr")]
+fn read_qr(path: &Path) -> Result<Share<Vec<u8>>, CliError> {
+    Ok(algos::qr::from_image(&fs::read(path)?)?)
+}
+
+#[cfg(not(feature = "qr"))]
+fn read_qr(_path: &Path) -> Result<Share<Vec<u8>>, CliError> {
+    Err(CliError::Unsupported("qr"))
+}
+
+fn split_gfshare(threshold: usize, num_shares: usize, prefix: &Path, file: Option<&PathBuf>) -> Result<(), CliError> {
+    let secret = read_input(file)?;
+    let shares = algos::gfshare::split(&secret, threshold, num_shares, &mut rand::thread_rng())?;
+    for path in algos::gfshare::write_shares(prefix, &shares)? {
+        println!("{}", path.display());
+    }
+    Ok(())
+}
+
+fn split_ssss(threshold: usize, num_shares: usize, file: Option<&PathBuf>) -> Result<(), CliError> {
+    let secret = read_input(file)?;
+    let shares = algos::ssss::split(&secret, threshold, num_shares, true, &mut rand::thread_rng())?;
+    for line in algos::ssss::format_shares(&shares) {
+        println!("{}", line);
+    }
+    Ok(())
+}
+
+fn split_json(threshold: usize, num_shares: usize, dir: &Path, file: Option<&PathBuf>) -> Result<(), CliError> {
+    let secret = read_input(file)?;
+    let shares = shamir::split(&secret, threshold, num_shares, &mut rand::thread_rng())?;
+    for path in algos::sharefile::write_files(dir, &shares)? {
+        println!("{}", path.display());
+    }
+    Ok(())
+}
+
+fn split_wif(threshold: usize, num_shares: usize, file: Option<&PathBuf>) -> Result<(), CliError> {
+    let input = Zeroizing::new(read_input(file)?);
+    let wif = std::str::from_utf8(&input).map_err(|_| ShamirError::InvalidParameters("wif"))?;
+    for share in algos::wif::split_wif(wif, threshold, num_shares, &mut rand::thread_rng())? {
+        println!("{}", share);
+    }
+    Ok(())
+}
+
+fn split_store(threshold: usize, num_shares: usize, dir: &Path, file: Option<&PathBuf>) -> Result<(), CliError> {
+    let secret = read_input(file)?;
+    let shares = shamir::split(&secret, threshold, num_shares, &mut rand::thread_rng())?;
+    let mut store = FileStore::new(dir);
+    for share in &shares {
+        store.put(share)?;
+    }
+    println!("{:08x}", shares[0].scheme_id);
+    Ok(())
+}
+
+fn split_sealed(threshold: usize, num_shares: usize, dir: &Path, file: Option<&PathBuf>) -> Result<(), CliError> {
+    let secret = read_input(file)?;
+    let shares = shamir::split(&secret, threshold, num_shares, &mut rand::thread_rng())?;
+    write_sealed(dir, &shares)
+}
+
+fn split(
+    threshold: usize,
+    num_shares: usize,
+    mnemonic: bool,
+    qr: Option<&Path>,
+    file: Option<&PathBuf>,
+) -> Result<(), CliError> {
+    let secret = read_input(file)?;
+    let shares = shamir::split(&secret, threshold, num_shares, &mut rand::thread_rng())?;
+    if let Some(dir) = qr {
+        write_qr(dir, &shares)?;
+    }
+    for share in shares {
+        if mnemonic {
+            println!("{}", algos::mnemonic::to_mnemonic(&share)?);
+        } else {
+            println!("{}", share);
+        }
+    }
+    Ok(())
+}
+
+fn parse_share(position: usize, line: &str) -> Result<Share<Vec<u8>>, CliError> {
+    if line.trim().contains(char::is_whitespace) {
+        Ok(algos::mnemonic::from_mnemonic(line)?)
+    } else {
+        line.parse().map_err(|err| CliError::Parse(position, err))
+    }
+}
 
-fn main() -> Result<(), algos::sss::ShamirError> {
+fn combine_gfshare(files: &[PathBuf]) -> Result<(), CliError> {
+    let secret = algos::gfshare::combine_files(files)?;
+    io::stdout().write_all(&secret)?;
+    Ok(())
+}
+
+fn combine_ssss(files: &[PathBuf]) -> Result<(), CliError> {
+    let input = if files.is_empty() {
+        String::from_utf8_lossy(&read_input(None)?).into_owned()
+    } else {
+        files.iter().map(fs::read_to_string).collect::<io::Result<Vec<_>>>()?.join("\n")
+    };
+    let shares = input
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .enumerate()
+        .map(|(i, line)| line.parse().map_err(|err| CliError::Parse(i + 1, err)))
+        .collect::<Result<Vec<algos::ssss::SsssShare>, _>>()?;
+    let secret = algos::ssss::combine(&shares, shares.len(), true)?;
+    io::stdout().write_all(&secret)?;
+    Ok(())
+}
+
+fn combine_wif(files: &[PathBuf]) -> Result<(), CliError> {
+    let input = if files.is_empty() {
+        String::from_utf8_lossy(&read_input(None)?).into_owned()
+    } else {
+        files.iter().map(fs::read_to_string).collect::<io::Result<Vec<_>>>()?.join("\n")
+    };
+    let shares = input
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .enumerate()
+        .map(|(i, line)| line.parse().map_err(|err| CliError::Parse(i + 1, err)))
+        .collect::<Result<Vec<algos::wif::WifShare>, _>>()?;
+    println!("{}", algos::wif::combine_wif(&shares)?.as_str());
+    Ok(())
+}
+
+fn combine_store(dir: &Path, dealing: &str) -> Result<(), CliError> {
+    let dealing = u32::from_str_radix(dealing, 16).map_err(|_| ShamirError::InvalidParameters("dealing"))?;
+    let shares: Vec<Share<Vec<u8>>> = FileStore::new(dir).load(dealing)?;
+    let secret = shamir::combine(&shares)?;
+    io::stdout().write_all(&secret)?;
+    Ok(())
+}
+
+fn combine(files: &[PathBuf]) -> Result<(), CliError> {
+    let mut shares = Vec::new();
+    let mut share_files = Vec::new();
+    let lines = if files.is_empty() {
+        String::from_utf8_lossy(&read_input(None)?).into_owned()
+    } else {
+        let mut lines = String::new();
+        for path in files {
+            if path.extension().is_some_and(|ext| ext.eq_ignore_ascii_case("png")) {
+                shares.push(read_qr(path)?);
+                continue;
+            }
+            if path.extension().is_some_and(|ext| ext.eq_ignore_ascii_case("sealed")) {
+                shares.push(read_sealed(path)?);
+                continue;
+            }
+            if path.extension().is_some_and(|ext| ext.eq_ignore_ascii_case("json")) {
+                share_files.push(algos::sharefile::read_file(path)?);
+                continue;
+            }
+            lines.push_str(&fs::read_to_string(path)?);
+            lines.push('\n');
+        }
+        lines
+    };
+    for line in lines.lines().filter(|line| !line.trim().is_empty()) {
+        shares.push(parse_share(shares.len() + 1, line)?);
+    }
+    shares.extend(algos::sharefile::to_shares(&share_files)?);
+    let secret = shamir::combine(&shares)?;
+    io::stdout().write_all(&secret)?;
+    Ok(())
+}
+
+// Reads from the terminal without echo; piped input is read line by line instead.
+fn read_secret_line(prompt: &str) -> io::Result<Option<Zeroizing<String>>> {
+    if io::stdin().is_terminal() {
+        return rpassword::prompt_password(prompt).map(|line| Some(Zeroizing::new(line)));
+    }
+    let mut line = Zeroizing::new(String::new());
+    match io::stdin().read_line(&mut line)? {
+        0 => Ok(None),
+        _ => Ok(Some(line)),
+    }
+}
+
+fn unseal(commitments: Option<&Path>) -> Result<(), CliError> {
+    let mut unseal = match commitments {
+        Some(path) => algos::unseal::Unseal::with_commitments(algos::sharefile::read_file(path)?.commitments),
+        None => algos::unseal::Unseal::new(),
+    };
+    loop {
+        let prompt = format!("Share {}: ", unseal.provided() + 1);
+        let Some(line) = read_secret_line(&prompt)? else {
+            return Err(match unseal.required() {
+                Some(required) => ShamirError::InsufficientShares { provided: unseal.provided(), required }.into(),
+                None => io::Error::new(io::ErrorKind::UnexpectedEof, "no valid share was entered").into(),
+            });
+        };
+        if line.trim().is_empty() {
+            continue;
+        }
+        // A mistyped or foreign share is reported and the operator asked again.
+        let share = match parse_share(unseal.provided() + 1, &line) {
+            Ok(share) => share,
+            Err(err) => {
+                eprintln!("rejected: {}", err);
+                continue;
+            }
+        };
+        match unseal.submit(share) {
+            Ok(algos::unseal::Progress::NeedMore { provided, required }) => {
+                eprintln!("{} of {} shares provided", provided, required);
+            }
+            Ok(algos::unseal::Progress::Unsealed(secret)) => {
+                eprintln!("threshold met; unsealed");
+                io::stdout().write_all(&secret)?;
+                return Ok(());
+            }
+            Err(err @ (ShamirError::InconsistentShares | ShamirError::DuplicateIndex)) => {
+                eprintln!("rejected: {}", err);
+            }
+            Err(err) => return Err(err.into()),
+        }
+    }
+}
+
+fn demo() -> Result<(), CliError> {
     let mut rng = rand::thread_rng();
 
     let secret: u64 = rng.gen_range(1..2003);
     println!("Random secret generated: {}", secret);
 
-    algos::sss::run_shamir_with_secret(secret)?;
-    
-    algos::vss::run_vss(secret as i128);
-    
+    let shares = algos::sss::generate_shares(secret, 3, 5, &mut rng)?;
+    for share in &shares {
+        println!("(SSS) Generated share: {}", share);
+    }
+    println!("(SSS) Successfully reconstructed secret: {}", algos::sss::reconstruct_secret(&shares[..3])?);
+
+    let config = vss::VssConfig::default();
+    let coeffs = vss::generate_polynomial(secret as i128, &config, &mut rng);
+    let shares = vss::generate_shares(&coeffs, &config, &mut rng);
+    let commitments = vss::generate_commitments(&coeffs, &config);
+    drop(coeffs);
+    println!("--- Feldman VSS Demonstration ---");
+    for share in &shares {
+        println!("Share: {}", share);
+    }
+    println!("Commitments: {:?}", commitments);
+    for share in &shares {
+        println!("Share {} valid: {}", share.index, vss::verify_share(share, &commitments, &config));
+    }
+    let recovered =
+        vss::reconstruct_checked(&shares[..config.threshold()], &commitments, &config).map_err(ShamirError::from)?;
+    println!("Reconstructed secret (from first {} shares): {}", config.threshold(), recovered);
+    Ok(())
+}
+
+#[cfg(feature = "grpc")]
+fn serve_grpc(listen: SocketAddr) -> Result<(), CliError> {
+    let runtime = tokio::runtime::Runtime::new()?;
+    runtime.block_on(async {
+        let listener = tokio::net::TcpListener::bind(listen).await?;
+        eprintln!("serving shamir.v1.Shamir on {}", listener.local_addr()?);
+        algos::grpc::serve(listener).await.map_err(io::Error::other)
+    })?;
+    Ok(())
+}
+
+#[cfg(not(feature = "grpc"))]
+fn serve_grpc(_listen: SocketAddr) -> Result<(), CliError> {
+    Err(CliError::Unsupported("grpc"))
+}
+
+#[cfg(feature = "rest")]
+fn serve_rest(listen: SocketAddr) -> Result<(), CliError> {
+    let runtime = tokio::runtime::Runtime::new()?;
+    runtime.block_on(async {
+        let listener = tokio::net::TcpListener::bind(listen).await?;
+        eprintln!("serving the JSON API on http://{}", listener.local_addr()?);
+        algos::rest::serve(listener).await
+    })?;
     Ok(())
 }
+
+#[cfg(not(feature = "rest"))]
+fn serve_rest(_listen: SocketAddr) -> Result<(), CliError> {
+    Err(CliError::Unsupported("rest"))
+}
+
+fn main() -> ExitCode {
+    // RUST_LOG sets the level, e.g. RUST_LOG=shamir=debug; by default only warnings show.
+    let filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("warn"));
+    tracing_subscriber::fmt().with_env_filter(filter).with_writer(io::stderr).without_time().with_target(false).init();
+
+    let result = match Cli::parse().command {
+        Command::Split { threshold, shares, gfshare: Some(prefix), file, .. } => {
+            split_gfshare(threshold, shares, &prefix, file.as_ref())
+        }
+        Command::Split { threshold, shares, ssss: true, file, .. } => split_ssss(threshold, shares, file.as_ref()),
+        Command::Split { threshold, shares, json: Some(dir), file, .. } => {
+            split_json(threshold, shares, &dir, file.as_ref())
+        }
+        Command::Split { threshold, shares, wif: true, file, .. } => split_wif(threshold, shares, file.as_ref()),
+        Command::Split { threshold, shares, store: Some(dir), file, .. } => {
+            split_store(threshold, shares, &dir, file.as_ref())
+        }
+        Command::Split { threshold, shares, encrypt: Some(dir), file, .. } => {
+            split_sealed(threshold, shares, &dir, file.as_ref())
+        }
+        Command::Split { threshold, shares, mnemonic, qr, file, .. } => {
+            split(threshold, shares, mnemonic, qr.as_deref(), file.as_ref())
+        }
+        Command::Combine { gfshare: true, shares, .. } => combine_gfshare(&shares),
+        Command::Combine { ssss: true, shares, .. } => combine_ssss(&shares),
+        Command::Combine { wif: true, shares, .. } => combine_wif(&shares),
+        Command::Combine { store: Some(dir), dealing: Some(dealing), .. } => combine_store(&dir, &dealing),
+        Command::Combine { shares, .. } => combine(&shares),
+        Command::Unseal { commitments } => unseal(commitments.as_deref()),
+        Command::Demo => demo(),
+        Command::Serve { grpc: true, listen } => serve_grpc(listen.unwrap_or(([127, 0, 0, 1], 50051).into())),
+        Command::Serve { listen, .. } => serve_rest(listen.unwrap_or(([127, 0, 0, 1], 8080).into())),
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,78 @@
+use rand::{thread_rng, Rng};
+use shamir::algos::additive;
+use shamir::algos::share::Share;
+use shamir::algos::sss::{self, ShamirError};
+
+#[test]
+fn test_shamir_to_additive() {
+    let shares = sss::generate_shares(123456, 3, 5, &mut thread_rng()).expect("Failed to generate shares");
+    let quorum = vec![shares[4].clone(), shares[0].clone(), shares[2].clone()];
+    let values = additive::shamir_to_additive(&quorum).expect("Failed to convert shares");
+    assert_eq!(values.len(), 3);
+    assert_eq!(additive::combine_additive(&values), 123456);
+
+    // Each holder can convert on its own given the agreed quorum.
+    let indices = [5, 1, 3];
+    let local = additive::to_additive(&shares[0], &indices).expect("Failed to convert share");
+    assert_eq!(local, values[1]);
+    assert!(matches!(additive::to_additive(&shares[1], &indices), Err(ShamirError::InconsistentShares)));
+}
+
+#[test]
+fn test_additive_to_shamir() {
+    let mut rng = thread_rng();
+    let values = additive::split_additive(987654, 4, &mut rng).expect("Failed to split secret");
+    assert_eq!(additive::combine_additive(&values), 987654);
+
+    let dealings: Vec<Vec<Share>> = values
+        .iter()
+        .map(|&value| additive::deal_additive(value, 2, 3, &mut rng).expect("Failed to deal value"))
+        .collect();
+    let scheme_id = rng.r#gen();
+    let shares: Vec<Share> = (0..3)
+        .map(|j| {
+            let received: Vec<Share> = dealings.iter().map(|dealing| dealing[j].clone()).collect();
+            additive::additive_to_shamir(j as u64 + 1, &received, scheme_id).expect("Failed to combine sub-shares")
+        })
+        .collect();
+    assert_eq!(sss::reconstruct_secret(&shares[1..]).expect("Failed to reconstruct secret"), 987654);
+}
+
+#[test]
+fn test_additive_rejects_bad_input() {
+    let mut rng = thread_rng();
+    assert!(matches!(additive::split_additive(sss::PRIME, 3, &mut rng), Err(ShamirError::SecretOutOfRange)));
+    let shares = sss::generate_shares(1, 3, 4, &mut rng).expect("Failed to generate shares");
+    assert!(matches!(additive::shamir_to_additive(&shares[..2]), Err(ShamirError::InsufficientShares { .. })));
+    assert!(matches!(additive::additive_to_shamir(1, &shares[..2], 0), Err(ShamirError::InconsistentShares)));
+}
+
+#[test]
+fn test_additive_reduces_unreduced_values() {
+    // Values equal to the reduced ones mod PRIME but near u64::MAX must not overflow the sums.
+    let lift = |value: u64| value + (u64::MAX / sss::PRIME - 1) * sss::PRIME;
+    let values = [5, sss::PRIME - 1, 7];
+    assert_eq!(additive::combine_additive(&values.map(lift)), 11);
+
+    let mut rng = thread_rng();
+    let mut dealings: Vec<Vec<Share>> = values
+        .iter()
+        .map(|&value| additive::deal_additive(value, 2, 3, &mut rng).expect("Failed to deal value"))
+        .collect();
+    for share in dealings.iter_mut().flatten() {
+        share.value = lift(share.value);
+    }
+    let shares: Vec<Share> = (0..3)
+        .map(|j| {
+            let received: Vec<Share> = dealings.iter().map(|dealing| dealing[j].clone()).collect();
+            additive::additive_to_shamir(j as u64 + 1, &received, 7).expect("Failed to combine sub-shares")
+        })
+        .collect();
+    assert!(shares.iter().all(|share| share.value < sss::PRIME));
+    assert_eq!(sss::reconstruct_secret(&shares[..2]).expect("Failed to reconstruct secret"), 11);
+
+    let converted = additive::to_additive(&shares[0], &[1, 2]).expect("Failed to convert share");
+    let mut lifted = shares[0].clone();
+    lifted.value = lift(lifted.value);
+    assert_eq!(additive::to_additive(&lifted, &[1, 2]).expect("Failed to convert share"), converted);
+}
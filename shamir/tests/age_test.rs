@@ -0,0 +1,37 @@
+#![cfg(feature = "age")]
+
+use rand::thread_rng;
+use shamir::algos::age::{self, Identity};
+use shamir::algos::sss::ShamirError;
+
+// The secret is the bytes 1..=32; the recipient was computed independently.
+const SECRET_KEY: &str = "AGE-SECRET-KEY-1QYPQXPQ9QCRSSZG2PVXQ6RS0ZQG3YYC5Z5TPWXQERGD3C8G7RUSQGPQYEE";
+const RECIPIENT: &str = "age1q73he0q5yzfu3d64msd3p6rvksnrwjk3d2598mgtmlqt9wrdr37q2vrn72";
+
+fn identity_file() -> String {
+    format!("# created: 2024-01-01T00:00:00Z\n# public key: {}\n{}\n", RECIPIENT, SECRET_KEY)
+}
+
+#[test]
+fn test_parse_identity_file() {
+    let identity = Identity::parse(&identity_file()).expect("Failed to parse");
+    assert_eq!(identity.recipient(), RECIPIENT);
+    assert_eq!(identity.to_secret_key().as_str(), SECRET_KEY);
+    assert!(identity.to_file().contains(SECRET_KEY));
+
+    let wrong_recipient = identity_file().replace(RECIPIENT, &RECIPIENT.replace("q7", "q8"));
+    assert!(matches!(Identity::parse(&wrong_recipient), Err(ShamirError::InvalidParameters("public key"))));
+    assert!(matches!(Identity::parse("# nothing here\n"), Err(ShamirError::InvalidParameters("identity"))));
+    let typo = SECRET_KEY.replace("QYPQ", "QYPR");
+    assert!(matches!(Identity::from_secret_key(&typo), Err(ShamirError::InvalidChecksum)));
+}
+
+#[test]
+fn test_split_and_reassemble_identity() {
+    let shares = age::split_identity(&identity_file(), 3, 5, &mut thread_rng()).expect("Failed to split");
+    let identity = age::combine_identity(&shares[1..4]).expect("Failed to combine");
+    assert_eq!(identity.recipient(), RECIPIENT);
+    let reparsed = Identity::parse(&identity.to_file()).expect("Failed to parse");
+    assert_eq!(reparsed.to_secret_key(), identity.to_secret_key());
+    assert!(age::combine_identity(&shares[..2]).is_err());
+}
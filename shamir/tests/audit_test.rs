@@ -0,0 +1,61 @@
+use rand::thread_rng;
+use shamir::algos::audit::{self, AuditEvent};
+use shamir::algos::vss::{VssConfig, VssError};
+
+#[test]
+fn test_byte_lifecycle_is_recorded() {
+    let mut events = Vec::new();
+    let shares = audit::split_bytes(b"vault", 2, 3, &mut thread_rng(), &mut events).expect("Failed to split");
+    let scheme_id = shares[0].scheme_id;
+    assert_eq!(events.len(), 3);
+    assert_eq!(events[1], AuditEvent::ShareIssued { scheme_id, index: 2, threshold: 2, epoch: 0 });
+
+    let secret = audit::combine_bytes(&shares[1..], &mut events).expect("Failed to combine");
+    assert_eq!(&secret[..], b"vault");
+    assert!(audit::combine_bytes(&shares[..1], &mut events).is_err());
+    assert_eq!(
+        events[3..],
+        [
+            AuditEvent::ReconstructionAttempted { scheme_id, indices: vec![2, 3], succeeded: true },
+            AuditEvent::ReconstructionAttempted { scheme_id, indices: vec![1], succeeded: false },
+        ]
+    );
+}
+
+#[test]
+fn test_verification_failures_are_recorded() {
+    let config = VssConfig::default();
+    let mut events = Vec::new();
+    let (mut shares, commitments) = audit::deal(99, &config, &mut thread_rng(), &mut events);
+    shares[1].value = (shares[1].value + 1) % config.order();
+    let scheme_id = shares[0].scheme_id;
+
+    events.clear();
+    let result = audit::verify_shares(&shares[..3], &commitments, &config, &mut events);
+    assert!(matches!(result, Err(VssError::VerificationFailed { index: 2 })));
+    assert_eq!(events[1], AuditEvent::VerificationFailed { scheme_id, index: 2 });
+    assert_eq!(events[2], AuditEvent::ShareVerified { scheme_id, index: 3 });
+
+    // A closure works as a sink too.
+    let mut failures = 0;
+    let mut count_failures = |event: AuditEvent| {
+        failures += matches!(event, AuditEvent::VerificationFailed { .. }) as usize;
+    };
+    let reconstruction = audit::reconstruct_verified(&shares, &commitments, &config, &mut count_failures)
+        .expect("Failed to reconstruct");
+    assert_eq!(reconstruction.secret, Some(99));
+    assert_eq!(failures, 1);
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn test_json_lines_sink() {
+    let mut sink = audit::JsonLines(Vec::new());
+    audit::split_bytes(b"k", 2, 2, &mut thread_rng(), &mut sink).expect("Failed to split");
+    let log = String::from_utf8(sink.0).expect("Failed to read log");
+    let lines: Vec<serde_json::Value> =
+        log.lines().map(|line| serde_json::from_str(line).expect("Failed to parse line")).collect();
+    assert_eq!(lines.len(), 2);
+    assert_eq!(lines[0]["event"], "ShareIssued");
+    assert_eq!(lines[1]["index"], 2);
+}
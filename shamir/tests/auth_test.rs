@@ -0,0 +1,54 @@
+use rand::thread_rng;
+use shamir::algos::auth::{self, TaggedShare};
+use shamir::algos::sss;
+use shamir::algos::vss::{self, CommitmentBundle, VssConfig};
+
+const DEALER_SECRET: &[u8] = b"dealer master key";
+
+#[test]
+fn test_tagged_shares_reconstruct() {
+    let shares = sss::generate_shares(4242, 3, 5, &mut thread_rng()).expect("Failed to generate shares");
+    let (tagged, key) = auth::tag_shares(shares, DEALER_SECRET).expect("Failed to tag shares");
+    assert!(tagged.iter().all(|tagged| auth::verify(tagged, &key)));
+    assert_eq!(auth::derive_key(DEALER_SECRET, key.scheme_id), key);
+
+    let authentic = auth::authentic_shares(&tagged[1..4], &key);
+    assert_eq!(sss::reconstruct_secret(&authentic).expect("Failed to reconstruct secret"), 4242);
+}
+
+#[test]
+fn test_tampered_and_forged_shares_are_dropped() {
+    let shares = sss::generate_shares(7, 2, 4, &mut thread_rng()).expect("Failed to generate shares");
+    let (mut tagged, key) = auth::tag_shares(shares, DEALER_SECRET).expect("Failed to tag shares");
+    tagged[0].share.value = (tagged[0].share.value + 1) % sss::PRIME;
+    tagged[1].share.index = 9;
+    let forged_key = auth::derive_key(b"someone else", key.scheme_id);
+    let forged = TaggedShare { tag: auth::tag(&tagged[2].share, &forged_key), share: tagged[2].share.clone() };
+
+    assert!(!auth::verify(&tagged[0], &key));
+    assert!(!auth::verify(&tagged[1], &key));
+    assert!(!auth::verify(&forged, &key));
+    let authentic = auth::authentic_shares(&[tagged[0].clone(), tagged[1].clone(), forged, tagged[3].clone()], &key);
+    assert_eq!(authentic.len(), 1);
+    assert!(matches!(sss::reconstruct_secret(&authentic), Err(sss::ShamirError::InsufficientShares { .. })));
+}
+
+#[test]
+fn test_bundle_checks_commitments_and_tags() {
+    let config = VssConfig::default();
+    let mut rng = thread_rng();
+    let coeffs = vss::generate_polynomial(99, &config, &mut rng);
+    let shares = vss::generate_shares(&coeffs, &config, &mut rng);
+    let (tagged, key) = auth::tag_shares(shares, DEALER_SECRET).expect("Failed to tag shares");
+
+    let bundle = CommitmentBundle { commitments: vss::generate_commitments(&coeffs, &config), verification_key: Some(key) };
+    assert!(tagged.iter().all(|tagged| bundle.verify(tagged, &config)));
+
+    let mut tampered = tagged[0].clone();
+    tampered.share.epoch = 1;
+    assert!(!bundle.verify(&tampered, &config));
+
+    let mac_only = CommitmentBundle { commitments: Vec::new(), ..bundle.clone() };
+    assert!(mac_only.verify(&tagged[1], &config));
+    assert!(!mac_only.verify(&tampered, &config));
+}
@@ -0,0 +1,37 @@
+use rand::thread_rng;
+use shamir::algos::bech32::{self, Bech32Error};
+use shamir::algos::share::Share;
+use shamir::algos::sss;
+
+#[test]
+fn test_bip350_vectors() {
+    for valid in ["A1LQFN3A", "abcdef1l7aum6echk45nj3s0wdvt2fg8x9yrzpqzd3ryx", "?1v759aa"] {
+        bech32::decode(valid).expect("Failed to decode valid bech32m string");
+    }
+    // Plain bech32 checksums are not accepted as bech32m.
+    assert_eq!(bech32::decode("a12uel5l"), Err(Bech32Error::InvalidChecksum));
+    assert_eq!(bech32::decode("A1lqfn3a"), Err(Bech32Error::MixedCase));
+}
+
+#[test]
+fn test_share_round_trip() {
+    let shares = sss::split_bytes(b"paste me", 2, 3, &mut thread_rng()).expect("Failed to split bytes");
+    let encoded = shares[2].to_bech32("shr").expect("Failed to encode share");
+    assert!(encoded.starts_with("shr1"));
+
+    let decoded = Share::<Vec<u8>>::from_bech32(&encoded.to_uppercase(), "shr").expect("Failed to decode share");
+    assert_eq!(decoded, shares[2]);
+}
+
+#[test]
+fn test_hrp_and_typo_detection() {
+    let share = Share::new(1, 77u64, 2, 9);
+    let encoded = share.to_bech32("shr").expect("Failed to encode share");
+    assert_eq!(Share::<u64>::from_bech32(&encoded, "key"), Err(Bech32Error::HrpMismatch));
+
+    let mut typo = encoded.into_bytes();
+    let last = typo.len() - 10;
+    typo[last] = if typo[last] == b'q' { b'p' } else { b'q' };
+    let typo = String::from_utf8(typo).expect("Encoding is ASCII");
+    assert_eq!(Share::<u64>::from_bech32(&typo, "shr"), Err(Bech32Error::InvalidChecksum));
+}
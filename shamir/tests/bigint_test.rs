@@ -0,0 +1,82 @@
+use num_bigint::BigUint;
+use rand::thread_rng;
+use shamir::algos::bigint::{self, BigPrimeField};
+use shamir::algos::share::{Share, ShareValue};
+use shamir::algos::sss::ShamirError;
+
+#[test]
+fn test_reconstruct_256_bit_secret() {
+    let field = BigPrimeField::mersenne_521();
+    let secret = BigUint::from_bytes_be(&[0xffu8; 32]);
+    let shares = bigint::generate_shares(&secret, 3, 5, &field, &mut thread_rng())
+        .expect("Failed to generate shares");
+    assert_eq!(shares.len(), 5);
+    let reconstructed = bigint::reconstruct_secret(&shares[2..], &field)
+        .expect("Failed to reconstruct secret");
+    assert_eq!(reconstructed, secret);
+}
+
+#[test]
+fn test_custom_modulus() {
+    // 2^127 - 1 is a Mersenne prime.
+    let modulus = (BigUint::from(1u8) << 127u32) - 1u8;
+    let field = BigPrimeField::new(modulus).expect("Invalid modulus");
+    let secret = BigUint::from(123_456_789_000_000_000_000u128);
+    let shares = bigint::generate_shares(&secret, 4, 6, &field, &mut thread_rng())
+        .expect("Failed to generate shares");
+    let subset = vec![shares[5].clone(), shares[1].clone(), shares[3].clone(), shares[0].clone()];
+    assert_eq!(bigint::reconstruct_secret(&subset, &field).expect("Failed to reconstruct"), secret);
+}
+
+#[test]
+fn test_secret_out_of_range() {
+    let field = BigPrimeField::new(BigUint::from(2003u32)).expect("Invalid modulus");
+    let result = bigint::generate_shares(&BigUint::from(2003u32), 2, 3, &field, &mut thread_rng());
+    assert!(matches!(result, Err(ShamirError::SecretOutOfRange)));
+    assert!(BigPrimeField::new(BigUint::from(2004u32)).is_err());
+}
+
+#[test]
+fn test_composite_modulus_rejected() {
+    // An odd semiprime and a Carmichael number both fail Miller–Rabin.
+    let semiprime = ((BigUint::from(1u8) << 61u32) - 1u8) * ((BigUint::from(1u8) << 89u32) - 1u8);
+    assert!(matches!(BigPrimeField::new(semiprime), Err(ShamirError::InvalidModulus)));
+    assert!(matches!(BigPrimeField::new(BigUint::from(561u32)), Err(ShamirError::InvalidModulus)));
+    assert!(bigint::is_probable_prime(&((BigUint::from(1u8) << 521u32) - 1u8), 40));
+}
+
+#[test]
+fn test_big_share_string_round_trip() {
+    let field = BigPrimeField::mersenne_521();
+    let secret = BigUint::from(42u8) << 300u32;
+    let shares = bigint::generate_shares(&secret, 2, 2, &field, &mut thread_rng())
+        .expect("Failed to generate shares");
+    let parsed: Share<BigUint> = shares[0].to_string().parse().expect("Failed to parse share");
+    assert_eq!(parsed, shares[0]);
+}
+
+#[test]
+fn test_wipe_big_value() {
+    let mut value = (BigUint::from(1u8) << 400u32) + 12345u32;
+    value.wipe();
+    assert_eq!(value, BigUint::from(0u8));
+}
+
+#[test]
+fn test_reconstruct_rejects_bad_indices() {
+    let field = BigPrimeField::mersenne_521();
+    let secret = BigUint::from(42u8);
+    let shares = bigint::generate_shares(&secret, 2, 3, &field, &mut thread_rng()).expect("Failed to generate shares");
+    // A forged share at x = 0 holding a value of the forger's choosing.
+    let mut forged = shares[0].clone();
+    forged.index = 0;
+    forged.value = BigUint::from(7u8);
+    assert!(matches!(
+        bigint::reconstruct_secret(&[forged, shares[1].clone()], &field),
+        Err(ShamirError::ZeroIndex)
+    ));
+    assert!(matches!(
+        bigint::reconstruct_secret(&[shares[2].clone(), shares[2].clone()], &field),
+        Err(ShamirError::DuplicateIndex)
+    ));
+}
@@ -0,0 +1,38 @@
+#![cfg(feature = "bls12-381")]
+
+use bls12_381::Scalar;
+use rand::thread_rng;
+use shamir::algos::bls12::{self, Bls12};
+use shamir::algos::group;
+use shamir::algos::share::Share;
+use shamir::algos::vss::VssError;
+
+#[test]
+fn test_bls12_vss_roundtrip() {
+    let mut rng = thread_rng();
+    let secret = Scalar::from(424242u64);
+    let coeffs = bls12::generate_polynomial(secret, 3, &mut rng).expect("Failed to generate polynomial");
+    let shares = bls12::generate_shares(&coeffs, 5, &mut rng).expect("Failed to generate shares");
+    let commitments = bls12::generate_commitments(&coeffs);
+    assert!(shares.iter().all(|share| bls12::verify_share(share, &commitments)));
+
+    let subset = vec![shares[2].clone(), shares[0].clone(), shares[4].clone()];
+    assert_eq!(bls12::reconstruct_secret(&subset).expect("Failed to reconstruct secret"), secret);
+    assert!(matches!(bls12::reconstruct_secret(&shares[..1]), Err(VssError::InsufficientShares { .. })));
+}
+
+#[test]
+fn test_bls12_commitments_compress_and_bind() {
+    let mut rng = thread_rng();
+    let coeffs = group::generate_polynomial::<Bls12>(Scalar::from(5u64), 2, &mut rng).expect("Failed to generate polynomial");
+    let mut shares = group::generate_shares::<Bls12>(&coeffs, 3, &mut rng).expect("Failed to generate shares");
+    let commitments = group::commit_polynomial(&Bls12, &coeffs);
+    for commitment in &commitments {
+        assert_eq!(bls12::decompress(&bls12::compress(commitment)), Some(*commitment));
+    }
+
+    shares[1].value += Scalar::one();
+    assert!(!bls12::verify_share(&shares[1], &commitments));
+    let parsed: Share<Scalar> = shares[0].to_string().parse().expect("Failed to parse share");
+    assert_eq!(parsed, shares[0]);
+}
@@ -0,0 +1,62 @@
+#![cfg(feature = "bls12-381")]
+
+use bls12_381::Scalar;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use shamir::algos::bls;
+use shamir::algos::bls12;
+use shamir::algos::vss::VssError;
+
+#[test]
+fn test_threshold_signature_matches_dealer_key() {
+    let mut rng = StdRng::seed_from_u64(76);
+    let secret = Scalar::from(424242u64);
+    let coeffs = bls12::generate_polynomial(secret, 3, &mut rng).expect("Failed to generate polynomial");
+    let shares = bls12::generate_shares(&coeffs, 5, &mut rng).expect("Failed to generate shares");
+    let commitments = bls12::generate_commitments(&coeffs);
+
+    let message = b"block 1";
+    let partials: Vec<_> = shares.iter().map(|share| bls::sign_share(share, message)).collect();
+    assert!(partials.iter().all(|partial| bls::verify_partial(partial, message, &commitments)));
+    let signature = bls::combine(&partials[2..], 3).expect("Failed to combine");
+    assert_eq!(signature, bls::sign(&secret, message));
+    assert!(bls::verify(&commitments[0], message, &signature));
+    assert!(!bls::verify(&commitments[0], b"block 2", &signature));
+
+    let bytes = bls::compress_signature(&signature);
+    assert_eq!(bls::decompress_signature(&bytes), Some(signature));
+}
+
+#[test]
+fn test_dkg_threshold_signing() {
+    let mut rng = StdRng::seed_from_u64(76);
+    let outputs = bls::run_dkg(2, 4, &mut rng).expect("Failed to run DKG");
+    let public_key = outputs[0].public_key;
+    assert!(outputs.iter().all(|output| output.public_key == public_key));
+
+    let message = b"ceremony";
+    let partials = [bls::sign_share(&outputs[3].share, message), bls::sign_share(&outputs[1].share, message)];
+    let signature = bls::combine_verified(&partials, message, &outputs[0].commitments).expect("Failed to combine");
+    assert!(bls::verify(&public_key, message, &signature));
+
+    // Any other quorum yields the same signature.
+    let other = [bls::sign_share(&outputs[0].share, message), bls::sign_share(&outputs[2].share, message)];
+    assert_eq!(bls::combine(&other, 2).expect("Failed to combine"), signature);
+}
+
+#[test]
+fn test_combine_rejects_bad_partials() {
+    let mut rng = StdRng::seed_from_u64(76);
+    let outputs = bls::run_dkg(2, 3, &mut rng).expect("Failed to run DKG");
+    let message = b"m";
+    let mut partials = [bls::sign_share(&outputs[0].share, message), bls::sign_share(&outputs[1].share, message)];
+    assert!(matches!(bls::combine(&partials[..1], 2), Err(VssError::InsufficientShares { .. })));
+
+    partials[1].signature = partials[0].signature;
+    assert!(matches!(
+        bls::combine_verified(&partials, message, &outputs[0].commitments),
+        Err(VssError::VerificationFailed { index: 2 })
+    ));
+    partials[1].index = 1;
+    assert!(matches!(bls::combine(&partials, 2), Err(VssError::DuplicateIndex)));
+}
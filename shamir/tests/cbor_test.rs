@@ -0,0 +1,68 @@
+use num_bigint::BigUint;
+use rand::thread_rng;
+use shamir::algos::cbor;
+use shamir::algos::share::Share;
+use shamir::algos::vss::{self, CommitmentBundle, VssConfig};
+use shamir::algos::wire::DecodeError;
+use shamir::algos::{auth, sss};
+
+fn from_hex(s: &str) -> Vec<u8> {
+    (0..s.len()).step_by(2).map(|i| u8::from_str_radix(&s[i..i + 2], 16).expect("Invalid hex")).collect()
+}
+
+#[test]
+fn test_golden_share() {
+    // 1397247314([1, 0xdeadbeef, 0, 0, 2, 5, 0x0123456789abcdef, 3, 1234567]): field id 1 is u64.
+    let golden = from_hex("da53484d5289011adeadbeef000002051b0123456789abcdef031a0012d687");
+    let mut share = Share::new(3, 1234567u64, 2, 0xdeadbeef);
+    share.total = 5;
+    share.fingerprint = 0x0123456789abcdef;
+    assert_eq!(cbor::encode_share(&share), golden);
+    assert_eq!(cbor::decode_share::<u64>(&golden), Ok(share.clone()));
+
+    // The eight-element form without the fingerprint and the seven-element one without the total
+    // still decode.
+    let early = from_hex("da53484d5288011adeadbeef00000205031a0012d687");
+    share.fingerprint = 0;
+    assert_eq!(cbor::decode_share::<u64>(&early), Ok(share.clone()));
+    let earliest = from_hex("da53484d5287011adeadbeef000002031a0012d687");
+    share.total = 0;
+    assert_eq!(cbor::decode_share::<u64>(&earliest), Ok(share));
+}
+
+#[test]
+fn test_round_trips() {
+    let mut shares = sss::split_bytes(b"cbor", 2, 3, &mut thread_rng()).expect("Failed to split bytes");
+    shares[0].epoch = 300;
+    shares[0].level = 1;
+    let encoded = cbor::encode_share(&shares[0]);
+    assert!(encoded.len() < shares[0].to_string().len());
+    assert_eq!(cbor::decode_share::<Vec<u8>>(&encoded), Ok(shares[0].clone()));
+
+    for value in [0i128, -1, -24, -25, i64::MIN as i128, u64::MAX as i128 + 1, i128::MIN, i128::MAX] {
+        let share = Share::new(1, value, 2, 7);
+        assert_eq!(cbor::decode_share::<i128>(&cbor::encode_share(&share)), Ok(share));
+    }
+    let big = Share::new(2, BigUint::from(1u8) << 300u32, 2, 7);
+    assert_eq!(cbor::decode_share::<BigUint>(&cbor::encode_share(&big)), Ok(big));
+
+    let config = VssConfig::default();
+    let coeffs = vss::generate_polynomial(12, &config, &mut thread_rng());
+    let mut bundle = CommitmentBundle { commitments: vss::generate_commitments(&coeffs, &config), verification_key: None };
+    assert_eq!(cbor::decode_bundle(&cbor::encode_bundle(&bundle)), Ok(bundle.clone()));
+    bundle.verification_key = Some(auth::derive_key(b"dealer", 9));
+    assert_eq!(cbor::decode_bundle(&cbor::encode_bundle(&bundle)), Ok(bundle));
+}
+
+#[test]
+fn test_rejects_non_canonical() {
+    // The golden share with index 3 written as a two-byte head.
+    let long_head = from_hex("da53484d5287011adeadbeef00000218031a0012d687");
+    assert_eq!(cbor::decode_share::<u64>(&long_head), Err(DecodeError::Malformed));
+    let golden = from_hex("da53484d5287011adeadbeef000002031a0012d687");
+    assert_eq!(cbor::decode_share::<u64>(&golden[..golden.len() - 1]), Err(DecodeError::Truncated));
+    assert_eq!(cbor::decode_share::<Vec<u8>>(&golden), Err(DecodeError::FieldMismatch));
+    let mut trailing = golden.clone();
+    trailing.push(0);
+    assert_eq!(cbor::decode_share::<u64>(&trailing), Err(DecodeError::Malformed));
+}
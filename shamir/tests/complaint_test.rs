@@ -0,0 +1,69 @@
+use rand::thread_rng;
+use shamir::algos::complaint::{self, DisputeState, DisqualifyReason, Dispute, Response, Verdict};
+use shamir::algos::vss::{self, VssConfig, VssError};
+
+const DEALER: u64 = 1;
+
+fn dealing(config: &VssConfig) -> (Vec<i128>, Vec<shamir::algos::share::Share<i128>>) {
+    let mut rng = thread_rng();
+    let coeffs = vss::generate_polynomial(777, config, &mut rng);
+    (vss::generate_commitments(&coeffs, config), vss::generate_shares(&coeffs, config, &mut rng))
+}
+
+#[test]
+fn test_answered_complaint_keeps_dealer() {
+    let config = VssConfig::default();
+    let (commitments, shares) = dealing(&config);
+
+    // Holder 4 never received its share.
+    assert_eq!(complaint::check_dealing(2, DEALER, Some(&shares[1]), &commitments, &config), None);
+    let filed = complaint::check_dealing(4, DEALER, None, &commitments, &config).expect("Failed to build complaint");
+
+    let mut dispute = Dispute::new(DEALER, commitments, config).expect("Failed to open dispute");
+    dispute.file(&filed).expect("Failed to file complaint");
+    assert!(matches!(dispute.file(&filed), Err(VssError::DuplicateIndex)));
+    dispute.close().expect("Failed to close complaints");
+    assert_eq!(dispute.state(), DisputeState::AwaitingResponses);
+    dispute.respond(Response { dealer: DEALER, share: shares[3].clone() }).expect("Failed to respond");
+
+    let verdict = dispute.resolve().expect("Failed to resolve dispute");
+    assert_eq!(verdict, Verdict::Qualified { revealed: vec![shares[3].clone()] });
+    assert_eq!(dispute.state(), DisputeState::Resolved);
+}
+
+#[test]
+fn test_cheating_dealer_is_disqualified() {
+    let config = VssConfig::default();
+    let (commitments, mut shares) = dealing(&config);
+    shares[2].value = (shares[2].value + 1) % config.order();
+    let filed = complaint::check_dealing(3, DEALER, Some(&shares[2]), &commitments, &config)
+        .expect("Failed to build complaint");
+
+    let mut dispute = Dispute::new(DEALER, commitments.clone(), config).expect("Failed to open dispute");
+    dispute.file(&filed).expect("Failed to file complaint");
+    dispute.close().expect("Failed to close complaints");
+    dispute.respond(Response { dealer: DEALER, share: shares[2].clone() }).expect("Failed to respond");
+    assert_eq!(dispute.resolve().expect("Failed to resolve"), Verdict::Disqualified(DisqualifyReason::InvalidResponse(3)));
+
+    let mut silent = Dispute::new(DEALER, commitments, config).expect("Failed to open dispute");
+    silent.file(&filed).expect("Failed to file complaint");
+    silent.close().expect("Failed to close complaints");
+    assert_eq!(silent.resolve().expect("Failed to resolve"), Verdict::Disqualified(DisqualifyReason::MissingResponse(3)));
+}
+
+#[test]
+fn test_too_many_complaints_and_state_errors() {
+    let config = VssConfig::default();
+    let (commitments, shares) = dealing(&config);
+    let mut dispute = Dispute::new(DEALER, commitments, config).expect("Failed to open dispute");
+    assert!(matches!(dispute.resolve(), Err(VssError::InvalidState)));
+    assert!(matches!(
+        dispute.respond(Response { dealer: DEALER, share: shares[0].clone() }),
+        Err(VssError::InvalidState)
+    ));
+    for complainant in 1..=config.threshold() as u64 {
+        dispute.file(&complaint::Complaint { complainant, dealer: DEALER }).expect("Failed to file complaint");
+    }
+    dispute.close().expect("Failed to close complaints");
+    assert_eq!(dispute.resolve().expect("Failed to resolve"), Verdict::Disqualified(DisqualifyReason::TooManyComplaints));
+}
@@ -0,0 +1,60 @@
+use shamir::algos::{ct, gf256};
+
+const PRIME: u64 = 2147483647;
+
+fn naive_pow(base: u64, exp: u64, m: u64) -> u64 {
+    let mut result = 1;
+    for _ in 0..exp {
+        result = result * base % m;
+    }
+    result
+}
+
+#[test]
+fn test_select_and_eq() {
+    assert_eq!(ct::select(1, 7, 9), 7);
+    assert_eq!(ct::select(0, 7, 9), 9);
+    assert_eq!(ct::eq(5, 5), 1);
+    assert_eq!(ct::eq(5, 6), 0);
+    assert_eq!(ct::eq(0, u64::MAX), 0);
+}
+
+#[test]
+fn test_pow_mod_matches_naive() {
+    for (base, exp) in [(2, 0), (2, 10), (3, 1000), (12345, 4321), (0, 5)] {
+        assert_eq!(ct::pow_mod(base, exp, PRIME), naive_pow(base, exp, PRIME));
+    }
+    assert_eq!(ct::pow_mod(2, 2003, 4007), naive_pow(2, 2003, 4007));
+}
+
+#[test]
+fn test_barrett_matches_remainder() {
+    for m in [1, 2, 3, 4007, PRIME, (1 << 61) - 1, u64::MAX - 58, u64::MAX] {
+        let barrett = ct::Barrett::new(m);
+        for x in [0, 1, m as u128 - 1, m as u128, m as u128 * m as u128 - 1, u128::MAX >> 1, u128::MAX] {
+            assert_eq!(barrett.reduce(x), (x % m as u128) as u64, "{x} mod {m}");
+        }
+        for (a, b) in [(m - 1, m - 1), (u64::MAX, u64::MAX), (12345, u64::MAX - 1)] {
+            assert_eq!(ct::mul_mod(a, b, m), ((a as u128 * b as u128) % m as u128) as u64);
+        }
+    }
+}
+
+#[test]
+fn test_inv_mod() {
+    for a in [1, 2, 3, 1_000_000, PRIME - 1] {
+        assert_eq!(ct::mul_mod(a, ct::inv_mod(a, PRIME), PRIME), 1);
+    }
+}
+
+#[test]
+fn test_gf256_matches_tables() {
+    for a in 0..=255u8 {
+        for b in 0..=255u8 {
+            assert_eq!(ct::gf256_mul(a, b), gf256::mul(a, b));
+        }
+        if a != 0 {
+            assert_eq!(Some(ct::gf256_inv(a)), gf256::inv(a));
+        }
+    }
+}
@@ -0,0 +1,32 @@
+use rand::thread_rng;
+use shamir::algos::dealer::Dealer;
+use shamir::algos::sss::{self, ShamirError};
+
+#[test]
+fn test_dealer_mints_compatible_shares_later() {
+    let mut dealer = Dealer::new(31337, 3, &mut thread_rng()).expect("Failed to create dealer");
+    let mut shares = dealer.deal(5).expect("Failed to deal shares");
+    assert_eq!(shares.iter().map(|share| share.index).collect::<Vec<_>>(), [1, 2, 3, 4, 5]);
+
+    // Onboard a sixth custodian, then recover using the new share alongside two old ones.
+    let sixth = dealer.issue().expect("Failed to issue share");
+    assert_eq!(sixth.index, 6);
+    assert_eq!(sixth.scheme_id, shares[0].scheme_id);
+    shares.push(sixth);
+    let subset = vec![shares[5].clone(), shares[0].clone(), shares[3].clone()];
+    assert_eq!(sss::reconstruct_secret(&subset).expect("Failed to reconstruct secret"), 31337);
+}
+
+#[test]
+fn test_dealer_tracks_issued_indices() {
+    let mut dealer = Dealer::new(5, 2, &mut thread_rng()).expect("Failed to create dealer");
+    dealer.issue_at(100).expect("Failed to issue share");
+    assert!(matches!(dealer.issue_at(100), Err(ShamirError::DuplicateIndex)));
+    assert!(matches!(dealer.issue_at(0), Err(ShamirError::ZeroIndex)));
+    assert_eq!(dealer.issue().expect("Failed to issue share").index, 101);
+    assert_eq!(dealer.issued().collect::<Vec<_>>(), [100, 101]);
+    assert_eq!(dealer.threshold(), 2);
+
+    assert!(matches!(Dealer::new(sss::PRIME, 2, &mut thread_rng()), Err(ShamirError::SecretOutOfRange)));
+    assert!(matches!(Dealer::new(1, 1, &mut thread_rng()), Err(ShamirError::InvalidThreshold)));
+}
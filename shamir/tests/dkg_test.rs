@@ -0,0 +1,113 @@
+use rand::thread_rng;
+use shamir::algos::dkg::{self, DkgState, Party};
+use shamir::algos::vss::{self, VssConfig, VssError};
+
+#[test]
+fn test_run_dkg() {
+    let config = VssConfig::default();
+    let outputs = dkg::run_dkg(&config, &mut thread_rng()).expect("DKG failed");
+    assert_eq!(outputs.len(), config.shares());
+
+    for output in &outputs {
+        assert_eq!(output.public_key, outputs[0].public_key);
+        assert_eq!(output.commitments, outputs[0].commitments);
+        assert!(vss::verify_share(&output.share, &output.commitments, &config));
+        assert_eq!(output.share.total, config.shares());
+        assert_eq!(output.share.fingerprint, outputs[0].share.fingerprint);
+    }
+
+    // Any quorum reconstructs the joint secret behind the public key.
+    let shares: Vec<_> = outputs.iter().map(|output| output.share.clone()).collect();
+    let secret = vss::reconstruct_secret(&shares[1..4], &config).expect("Failed to reconstruct");
+    assert_eq!(vss::reconstruct_secret(&shares[..3], &config).expect("Failed to reconstruct"), secret);
+    let g = config.generator();
+    let mut expected = 1;
+    for _ in 0..secret {
+        expected = expected * g % config.prime();
+    }
+    assert_eq!(expected, outputs[0].public_key);
+}
+
+#[test]
+fn test_party_rejects_bad_share() {
+    let config = VssConfig::default();
+    let mut rng = thread_rng();
+    let mut alice = Party::new(1, config).expect("Invalid party");
+    let mut bob = Party::new(2, config).expect("Invalid party");
+    alice.deal(&mut rng).expect("Failed to deal");
+    let dealing = bob.deal(&mut rng).expect("Failed to deal");
+
+    let mut share = dealing.shares[0].clone();
+    share.value += 1;
+    assert!(matches!(
+        alice.receive(2, share, dealing.commitments.clone()),
+        Err(VssError::VerificationFailed { .. })
+    ));
+    assert!(matches!(alice.finalize(), Err(VssError::InsufficientShares { .. })));
+    assert_eq!(alice.state(), DkgState::Dealt);
+}
+
+#[test]
+fn test_party_state_transitions() {
+    let config = VssConfig::default();
+    let mut rng = thread_rng();
+    assert!(Party::new(0, config).is_err());
+    let mut party = Party::new(1, config).expect("Invalid party");
+    assert!(matches!(party.finalize(), Err(VssError::InvalidState)));
+    party.deal(&mut rng).expect("Failed to deal");
+    assert!(matches!(party.deal(&mut rng), Err(VssError::InvalidState)));
+}
+
+#[test]
+fn test_party_reduces_dealer_values() {
+    let config = VssConfig::default();
+    let mut rng = thread_rng();
+    let mut parties: Vec<Party> =
+        (1..=config.shares() as u64).map(|i| Party::new(i, config).expect("Invalid party")).collect();
+    let mut dealings: Vec<_> = parties.iter_mut().map(|party| party.deal(&mut rng).expect("Failed to deal")).collect();
+
+    // The same dealing lifted by multiples of p and q still verifies, and must not overflow.
+    let (p, q) = (config.prime(), config.order());
+    for c in dealings[1].commitments.iter_mut() {
+        *c += (i128::MAX / p / 2) * p;
+    }
+    for share in dealings[1].shares.iter_mut() {
+        share.value += (i128::MAX / q / 2) * q;
+    }
+    for dealing in &dealings {
+        for (party, share) in parties.iter_mut().zip(&dealing.shares) {
+            if party.index() != dealing.dealer {
+                party.receive(dealing.dealer, share.clone(), dealing.commitments.clone()).expect("Failed to receive");
+            }
+        }
+    }
+    let outputs: Vec<_> = parties.iter_mut().map(|party| party.finalize().expect("Failed to finalize")).collect();
+    for output in &outputs {
+        assert_eq!(output.public_key, outputs[0].public_key);
+        assert!(output.public_key > 0 && output.public_key < p);
+        assert!(vss::verify_share(&output.share, &output.commitments, &config));
+    }
+}
+
+#[test]
+fn test_party_rejects_bad_dealers() {
+    let config = VssConfig::default();
+    let mut rng = thread_rng();
+    let mut alice = Party::new(1, config).expect("Invalid party");
+    let mut bob = Party::new(2, config).expect("Invalid party");
+    let own = alice.deal(&mut rng).expect("Failed to deal");
+    let dealing = bob.deal(&mut rng).expect("Failed to deal");
+
+    for dealer in [0, 1, config.shares() as u64 + 1, u64::MAX] {
+        let share = if dealer == 1 { own.shares[0].clone() } else { dealing.shares[0].clone() };
+        assert!(matches!(
+            alice.receive(dealer, share, dealing.commitments.clone()),
+            Err(VssError::InvalidParameters("dealer"))
+        ));
+    }
+    alice.receive(2, dealing.shares[0].clone(), dealing.commitments.clone()).expect("Failed to receive");
+    assert!(matches!(
+        alice.receive(2, dealing.shares[0].clone(), dealing.commitments.clone()),
+        Err(VssError::InvalidParameters("dealer"))
+    ));
+}
@@ -0,0 +1,65 @@
+use rand::thread_rng;
+use shamir::algos::dleq;
+use shamir::algos::group::Commitment;
+use shamir::algos::vss::{self, VssConfig, VssError};
+
+#[test]
+fn test_prove_and_verify() {
+    let config = VssConfig::default();
+    let base = dleq::hash_to_group(b"epoch 7", &config);
+    let x = 1234;
+    let proof = dleq::prove(x, base, &config, &mut thread_rng());
+    let (a, b) = (config.commit(&x), config.scale(&base, &x));
+    assert!(dleq::verify(a, b, base, &proof, &config));
+
+    // A different exponent on either side, or a tweaked response, breaks the proof.
+    assert!(!dleq::verify(a, config.scale(&base, &(x + 1)), base, &proof, &config));
+    assert!(!dleq::verify(config.commit(&(x + 1)), b, base, &proof, &config));
+    let tampered = dleq::DleqProof { response: (proof.response + 1) % config.order(), ..proof };
+    assert!(!dleq::verify(a, b, base, &tampered, &config));
+}
+
+#[test]
+fn test_partial_evaluations_combine_in_the_exponent() {
+    let config = VssConfig::default();
+    let mut rng = thread_rng();
+    let coeffs = vss::generate_polynomial(321, &config, &mut rng);
+    let shares = vss::generate_shares(&coeffs, &config, &mut rng);
+    let commitments = vss::generate_commitments(&coeffs, &config);
+    let base = dleq::hash_to_group(b"message", &config);
+
+    let evaluations: Vec<_> = shares[1..4]
+        .iter()
+        .map(|share| dleq::evaluate_share(share, base, &config, &mut rng).expect("Failed to evaluate"))
+        .collect();
+    for evaluation in &evaluations {
+        assert!(dleq::verify_evaluation(evaluation, base, &commitments, &config));
+    }
+    let combined = dleq::combine_evaluations(&evaluations, base, &commitments, &config).expect("Failed to combine");
+    assert_eq!(combined, config.scale(&base, &321));
+}
+
+#[test]
+fn test_forged_evaluation_is_rejected() {
+    let config = VssConfig::default();
+    let mut rng = thread_rng();
+    let coeffs = vss::generate_polynomial(55, &config, &mut rng);
+    let shares = vss::generate_shares(&coeffs, &config, &mut rng);
+    let commitments = vss::generate_commitments(&coeffs, &config);
+    let base = dleq::hash_to_group(b"message", &config);
+
+    let mut evaluations: Vec<_> = shares
+        .iter()
+        .map(|share| dleq::evaluate_share(share, base, &config, &mut rng).expect("Failed to evaluate"))
+        .collect();
+    // Holder 2 publishes a wrong partial with a proof for it under a made-up exponent.
+    let fake = (shares[1].value + 1) % config.order();
+    evaluations[1].value = config.scale(&base, &fake);
+    evaluations[1].proof = dleq::prove(fake, base, &config, &mut rng);
+
+    let verification_share = dleq::verification_share(2, &commitments, &config);
+    assert!(!dleq::verify_evaluation_with(&evaluations[1], base, verification_share, &config));
+    let result = dleq::combine_evaluations(&evaluations, base, &commitments, &config);
+    assert!(matches!(result, Err(VssError::VerificationFailed { index: 2 })));
+    assert!(matches!(dleq::evaluate_share(&shares[0], 1, &config, &mut rng), Err(VssError::InvalidParameters(_))));
+}
@@ -0,0 +1,72 @@
+#![cfg(feature = "ed25519")]
+
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use shamir::algos::ed25519::{self, SigningKey};
+use shamir::algos::vss::VssError;
+
+fn unhex(s: &str) -> Vec<u8> {
+    (0..s.len()).step_by(2).map(|i| u8::from_str_radix(&s[i..i + 2], 16).expect("Failed to parse hex")).collect()
+}
+
+#[test]
+fn test_rfc8032_vector() {
+    // RFC 8032 section 7.1, test 1.
+    let seed: [u8; 32] = unhex("9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60")
+        .try_into()
+        .expect("Failed to read seed");
+    let key = SigningKey::from_seed(&seed);
+    assert_eq!(key.public_key().to_vec(), unhex("d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a"));
+    let signature = key.sign(b"");
+    assert_eq!(
+        signature.to_vec(),
+        unhex(concat!(
+            "e5564300c360ac729086e2cc806e828a84877f1eb8e5d974d873e06522490155",
+            "5fb8821590a33bacc61e39701cf9b46bd25bf5f0595bbe24655141438e7a100b"
+        ))
+    );
+    assert!(ed25519::verify(&key.public_key(), b"", &signature));
+    assert!(!ed25519::verify(&key.public_key(), b"x", &signature));
+}
+
+#[test]
+fn test_split_and_reassemble() {
+    let mut rng = StdRng::seed_from_u64(74);
+    let key = SigningKey::from_seed(&[7; 32]);
+    let (shares, commitments) = ed25519::split_key(&key, 2, 3, &mut rng).expect("Failed to split key");
+    assert_eq!(commitments[0].compress().to_bytes(), key.public_key());
+    assert!(shares.iter().all(|share| ed25519::verify_share(share, &commitments)));
+
+    let reassembled = ed25519::reassemble(&shares[1..]).expect("Failed to reassemble key");
+    assert_eq!(reassembled.public_key(), key.public_key());
+    assert!(ed25519::verify(&key.public_key(), b"custody", &reassembled.sign(b"custody")));
+    assert!(matches!(ed25519::reassemble(&shares[..1]), Err(VssError::InsufficientShares { .. })));
+}
+
+#[test]
+fn test_threshold_signing() {
+    let mut rng = StdRng::seed_from_u64(74);
+    let key = SigningKey::from_seed(&[9; 32]);
+    let public_key = key.public_key();
+    let (shares, commitments) = ed25519::split_key(&key, 3, 5, &mut rng).expect("Failed to split key");
+    let message = b"threshold";
+    let signers = [&shares[0], &shares[3], &shares[4]];
+    let (nonces, nonce_commitments): (Vec<_>, Vec<_>) =
+        signers.iter().map(|share| ed25519::commit(share.index, &mut rng)).unzip();
+    let mut partials: Vec<_> = signers
+        .iter()
+        .zip(nonces)
+        .map(|(share, nonces)| {
+            ed25519::sign(message, share, nonces, &nonce_commitments, &public_key).expect("Failed to sign")
+        })
+        .collect();
+    let signature =
+        ed25519::aggregate(message, &partials, &nonce_commitments, &commitments).expect("Failed to aggregate");
+    assert!(ed25519::verify(&public_key, message, &signature));
+
+    partials[1].z += curve25519_dalek::Scalar::ONE;
+    assert!(matches!(
+        ed25519::aggregate(message, &partials, &nonce_commitments, &commitments),
+        Err(VssError::VerificationFailed { index: 4 })
+    ));
+}
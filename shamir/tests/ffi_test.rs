@@ -0,0 +1,58 @@
+#![cfg(feature = "ffi")]
+
+use rand::thread_rng;
+use shamir::algos::ffi::{self, SHAMIR_ERR_BUFFER_TOO_SMALL, SHAMIR_ERR_INVALID, SHAMIR_OK};
+use shamir::algos::{vss, wire};
+
+#[test]
+fn test_split_and_combine_through_c_abi() {
+    let secret = b"linked from C";
+    let share_len = ffi::shamir_share_len(secret.len());
+    let mut shares = vec![0u8; 4 * share_len];
+    let status = unsafe { ffi::shamir_split(secret.as_ptr(), secret.len(), 3, 4, shares.as_mut_ptr(), shares.len()) };
+    assert_eq!(status, SHAMIR_OK);
+
+    let quorum = &shares[share_len..];
+    let mut out = [0u8; 64];
+    let mut written = 0;
+    let status = unsafe { ffi::shamir_combine(quorum.as_ptr(), share_len, 3, out.as_mut_ptr(), out.len(), &mut written) };
+    assert_eq!(status, SHAMIR_OK);
+    assert_eq!(&out[..written], secret);
+}
+
+#[test]
+fn test_error_codes() {
+    let secret = b"abc";
+    let mut small = [0u8; 8];
+    let status = unsafe { ffi::shamir_split(secret.as_ptr(), secret.len(), 2, 3, small.as_mut_ptr(), small.len()) };
+    assert_eq!(status, SHAMIR_ERR_BUFFER_TOO_SMALL);
+
+    let mut shares = vec![0u8; 3 * ffi::shamir_share_len(secret.len())];
+    let status = unsafe { ffi::shamir_split(secret.as_ptr(), secret.len(), 4, 3, shares.as_mut_ptr(), shares.len()) };
+    assert_eq!(status, SHAMIR_ERR_INVALID);
+}
+
+#[test]
+fn test_vss_verify_share() {
+    let config = vss::VssConfig::default();
+    let mut rng = thread_rng();
+    let coeffs = vss::generate_polynomial(1234, &config, &mut rng);
+    let mut shares = vss::generate_shares(&coeffs, &config, &mut rng);
+    let commitments: Vec<i64> = vss::generate_commitments(&coeffs, &config).iter().map(|&c| c as i64).collect();
+
+    let verify = |bytes: &[u8]| unsafe {
+        ffi::vss_verify_share(
+            bytes.as_ptr(),
+            bytes.len(),
+            commitments.as_ptr(),
+            commitments.len(),
+            config.prime() as i64,
+            config.generator() as i64,
+            config.threshold(),
+            config.shares(),
+        )
+    };
+    assert_eq!(verify(&wire::encode(&shares[0])), 1);
+    shares[0].value += 1;
+    assert_eq!(verify(&wire::encode(&shares[0])), 0);
+}
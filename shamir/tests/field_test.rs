@@ -0,0 +1,49 @@
+use num_bigint::BigUint;
+use rand::thread_rng;
+use shamir::algos::bigint::BigPrimeField;
+use shamir::algos::field::{FiniteField, PrimeField64, SmallPrimeField};
+
+fn check_arithmetic<F: FiniteField>(field: &F) {
+    let mut rng = thread_rng();
+    for _ in 0..20 {
+        let a = field.random(&mut rng);
+        let b = field.random(&mut rng);
+        assert!(field.add(&field.sub(&a, &b), &b) == a);
+        assert!(field.add(&a, &field.neg(&a)) == field.zero());
+        match field.inv(&a) {
+            Some(inverse) => assert!(field.mul(&a, &inverse) == field.one()),
+            None => assert!(a == field.zero()),
+        }
+        assert!(field.pow(&a, 3) == field.mul(&a, &field.mul(&a, &a)));
+    }
+    assert!(field.inv(&field.zero()).is_none());
+}
+
+#[test]
+fn test_field_arithmetic() {
+    check_arithmetic(&PrimeField64::new(shamir::algos::sss::PRIME));
+    check_arithmetic(&PrimeField64::new(18446744073709551557));
+    check_arithmetic(&SmallPrimeField::new(2003));
+    check_arithmetic(&BigPrimeField::mersenne_521());
+}
+
+#[test]
+fn test_small_field_accepts_unreduced_values() {
+    let field = SmallPrimeField::new(2003);
+    assert_eq!(field.mul(&-1, &2004), 2002);
+    assert_eq!(field.element(2003), 0);
+    assert_eq!(field.pow(&-2, 2), 4);
+    let big = BigPrimeField::new(BigUint::from(2003u32)).expect("Failed to create field");
+    assert_eq!(big.sub(&BigUint::from(1u8), &BigUint::from(4005u32)), BigUint::from(2u32));
+}
+
+#[test]
+fn test_is_prime() {
+    use shamir::algos::field::is_prime;
+
+    let primes = [2, 3, 37, 2003, 4007, 2147483647, 18446744073709551557];
+    assert!(primes.iter().all(|&p| is_prime(p)));
+    // Carmichael numbers and a strong pseudoprime to bases 2, 3, 5 and 7.
+    let composites = [0, 1, 4, 561, 41041, 2147483649, 3215031751, 18446744073709551615];
+    assert!(composites.iter().all(|&n| !is_prime(n)));
+}
@@ -0,0 +1,134 @@
+#![cfg(feature = "ristretto")]
+use curve25519_dalek::{RistrettoPoint, Scalar};
+use rand::thread_rng;
+use shamir::algos::frost::{self, NonceCommitment, PartialSignature, Signature};
+use shamir::algos::group::{self, Group};
+use shamir::algos::ristretto::{self, Ristretto};
+use shamir::algos::share::Share;
+use shamir::algos::vss::VssError;
+
+type Partials = Vec<PartialSignature<Scalar>>;
+type Commitments = Vec<NonceCommitment<RistrettoPoint>>;
+
+fn deal_key() -> (Vec<Share<Scalar>>, Vec<RistrettoPoint>) {
+    let mut rng = thread_rng();
+    let coeffs = ristretto::generate_polynomial(Ristretto::random_scalar(&mut rng), 3, &mut rng)
+        .expect("Failed to generate polynomial");
+    let shares = ristretto::generate_shares(&coeffs, 5, &mut rng).expect("Failed to generate shares");
+    (shares, group::commit_polynomial(&Ristretto, &coeffs))
+}
+
+fn sign_with(
+    shares: &[Share<Scalar>],
+    key: &[RistrettoPoint],
+    signers: &[usize],
+    message: &[u8],
+) -> (Partials, Commitments) {
+    let mut rng = thread_rng();
+    let (nonces, commitments): (Vec<_>, Vec<_>) =
+        signers.iter().map(|&i| frost::commit::<Ristretto>(shares[i].index, &mut rng)).unzip();
+    let partials = signers
+        .iter()
+        .zip(nonces)
+        .map(|(&i, nonces)| frost::sign(message, &shares[i], nonces, &commitments, &key[0]).expect("Failed to sign"))
+        .collect();
+    (partials, commitments)
+}
+
+#[test]
+fn test_threshold_signature_verifies() {
+    let (shares, key) = deal_key();
+    let message = b"transfer 1 BTC";
+    let (partials, commitments) = sign_with(&shares, &key, &[0, 2, 4], message);
+    let signature = frost::aggregate::<Ristretto>(message, &partials, &commitments, &key).expect("Failed to aggregate");
+    assert!(frost::verify::<Ristretto>(message, &signature, &key[0]));
+    assert!(!frost::verify::<Ristretto>(b"transfer 2 BTC", &signature, &key[0]));
+}
+
+#[test]
+fn test_bad_partial_is_rejected() {
+    let (shares, key) = deal_key();
+    let message = b"hello";
+    let (mut partials, commitments) = sign_with(&shares, &key, &[1, 2, 3], message);
+    partials[1].z += Scalar::ONE;
+    let valid = frost::verify_partial::<Ristretto>(message, &partials[1], &commitments, &key)
+        .expect("Partial not in signing set");
+    assert!(!valid);
+    assert!(matches!(
+        frost::aggregate::<Ristretto>(message, &partials, &commitments, &key),
+        Err(VssError::VerificationFailed { .. })
+    ));
+}
+
+#[test]
+fn test_partials_must_cover_signing_set() {
+    let (shares, key) = deal_key();
+    let message = b"hello";
+    let (mut partials, commitments) = sign_with(&shares, &key, &[0, 1, 2], message);
+    partials[2] = partials[0];
+    assert!(matches!(
+        frost::aggregate::<Ristretto>(message, &partials, &commitments, &key),
+        Err(VssError::DuplicateIndex)
+    ));
+    assert!(matches!(
+        frost::aggregate::<Ristretto>(message, &partials[..2], &commitments, &key),
+        Err(VssError::InsufficientShares { .. })
+    ));
+}
+
+#[test]
+fn test_signing_set_is_checked() {
+    let (shares, key) = deal_key();
+    let mut rng = thread_rng();
+    let (nonces, commitment) = frost::commit::<Ristretto>(shares[0].index, &mut rng);
+    assert!(matches!(
+        frost::sign(b"m", &shares[0], nonces, &[commitment], &key[0]),
+        Err(VssError::InsufficientShares { .. })
+    ));
+
+    let (_, mut commitments) = sign_with(&shares, &key, &[0, 1, 2], b"m");
+    let (nonces, _) = frost::commit::<Ristretto>(shares[0].index, &mut rng);
+    commitments[2].index = 0;
+    assert!(matches!(frost::sign(b"m", &shares[0], nonces, &commitments, &key[0]), Err(VssError::ZeroIndex)));
+
+    let (nonces, _) = frost::commit::<Ristretto>(shares[0].index, &mut rng);
+    commitments[2].index = commitments[1].index;
+    assert!(matches!(frost::sign(b"m", &shares[0], nonces, &commitments, &key[0]), Err(VssError::DuplicateIndex)));
+
+    let (nonces, _) = frost::commit::<Ristretto>(shares[0].index, &mut rng);
+    commitments[2].index = shares[2].index;
+    commitments[2].binding = Ristretto::identity();
+    assert!(matches!(
+        frost::sign(b"m", &shares[0], nonces, &commitments, &key[0]),
+        Err(VssError::VerificationFailed { .. })
+    ));
+}
+
+#[test]
+fn test_identity_nonce_signature_is_rejected() {
+    let (_, key) = deal_key();
+    let forged = Signature { r: Ristretto::identity(), z: Scalar::ZERO };
+    assert!(!frost::verify::<Ristretto>(b"m", &forged, &key[0]));
+}
+
+#[cfg(feature = "bls12-381")]
+#[test]
+fn test_bls12_threshold_signature_verifies() {
+    use bls12_381::Scalar;
+    use shamir::algos::bls12::{self, Bls12};
+
+    let mut rng = thread_rng();
+    let coeffs = bls12::generate_polynomial(Scalar::from(7u64), 2, &mut rng).expect("Failed to generate polynomial");
+    let shares = bls12::generate_shares(&coeffs, 3, &mut rng).expect("Failed to generate shares");
+    let key = group::commit_polynomial(&Bls12, &coeffs);
+
+    let (nonces, commitments): (Vec<_>, Vec<_>) =
+        shares[1..].iter().map(|share| frost::commit::<Bls12>(share.index, &mut rng)).unzip();
+    let partials: Vec<_> = shares[1..]
+        .iter()
+        .zip(nonces)
+        .map(|(share, nonces)| frost::sign(b"m", share, nonces, &commitments, &key[0]).expect("Failed to sign"))
+        .collect();
+    let signature = frost::aggregate::<Bls12>(b"m", &partials, &commitments, &key).expect("Failed to aggregate");
+    assert!(frost::verify::<Bls12>(b"m", &signature, &key[0]));
+}
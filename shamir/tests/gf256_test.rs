@@ -0,0 +1,55 @@
+use rand::thread_rng;
+use shamir::algos::gf256;
+
+#[test]
+fn test_field_inverse() {
+    for a in 1..=255u8 {
+        let a_inv = gf256::inv(a).expect("Nonzero element has an inverse");
+        assert_eq!(gf256::mul(a, a_inv), 1);
+    }
+    assert_eq!(gf256::inv(0), None);
+}
+
+#[test]
+fn test_known_products() {
+    // Under 0x11d, 2 * 0x80 wraps to 0x1d.
+    assert_eq!(gf256::mul(2, 0x80), 0x1d);
+    assert_eq!(gf256::mul(0, 0x53), 0);
+    assert_eq!(gf256::mul(1, 0x53), 0x53);
+}
+
+#[test]
+fn test_split_and_combine() {
+    let secret = b"correct horse battery staple";
+    let shares = gf256::split(secret, 3, 5, &mut thread_rng()).expect("Failed to split secret");
+    assert_eq!(shares.len(), 5);
+    assert!(shares.iter().all(|(_, value)| value.len() == secret.len()));
+
+    let combined = gf256::combine(&shares[1..4]).expect("Failed to combine shares");
+    assert_eq!(&combined[..], secret);
+    let subset = vec![shares[4].clone(), shares[0].clone(), shares[2].clone()];
+    assert_eq!(&gf256::combine(&subset).expect("Failed to combine shares")[..], secret);
+}
+
+#[test]
+fn test_combine_rejects_mismatched_lengths() {
+    let mut shares = gf256::split(b"secret", 2, 3, &mut thread_rng()).expect("Failed to split secret");
+    shares[1].1.pop();
+    assert!(gf256::combine(&shares[..2]).is_err());
+}
+
+#[test]
+fn test_precomputed_coefficients_reused_across_chunks() {
+    let mut rng = thread_rng();
+    let first = gf256::split(b"first chunk", 3, 5, &mut rng).expect("Failed to split secret");
+    let second = gf256::split(b"second", 3, 5, &mut rng).expect("Failed to split secret");
+
+    let coefficients = gf256::LagrangeCoefficients::precompute(&[2, 4, 5]).expect("Failed to precompute coefficients");
+    for (shares, secret) in [(&first, &b"first chunk"[..]), (&second, &b"second"[..])] {
+        let values = [&shares[1].1, &shares[3].1, &shares[4].1];
+        assert_eq!(&coefficients.combine(&values).expect("Failed to combine")[..], secret);
+    }
+
+    assert!(gf256::LagrangeCoefficients::precompute(&[1, 1, 2]).is_err());
+    assert!(gf256::LagrangeCoefficients::precompute(&[0, 1, 2]).is_err());
+}
@@ -0,0 +1,53 @@
+use rand::{thread_rng, Rng};
+use shamir::algos::field::FiniteField;
+use shamir::algos::gf64;
+
+#[test]
+fn test_mul_matches_portable() {
+    let mut rng = thread_rng();
+    for _ in 0..1000 {
+        let (a, b): (u64, u64) = (rng.r#gen(), rng.r#gen());
+        assert_eq!(gf64::clmul(a, b), gf64::clmul_portable(a, b));
+    }
+    // x^63 * x = x^64 = x^4 + x^3 + x + 1.
+    assert_eq!(gf64::mul(1 << 63, 2), 0x1b);
+    assert_eq!(gf64::mul(u64::MAX, 1), u64::MAX);
+}
+
+#[test]
+fn test_field_inverse() {
+    let mut rng = thread_rng();
+    for a in (1..=256u64).chain((0..100).map(|_| rng.r#gen::<u64>() | 1)) {
+        let a_inv = gf64::inv(a).expect("Nonzero element has an inverse");
+        assert_eq!(gf64::mul(a, a_inv), 1);
+    }
+    assert_eq!(gf64::inv(0), None);
+    assert_eq!(gf64::Gf64.pow(&3, u64::MAX), 1);
+}
+
+#[test]
+fn test_split_and_combine() {
+    let mut rng = thread_rng();
+    // Whole words only, a partial trailing word, and nothing but the tail.
+    for len in [64, 29, 5] {
+        let secret: Vec<u8> = (0..len).map(|_| rng.r#gen()).collect();
+        let shares = gf64::split(&secret, 3, 5, &mut rng).expect("Failed to split secret");
+        assert!(shares.iter().all(|(_, value)| value.len() == len));
+
+        let combined = gf64::combine(&shares[1..4]).expect("Failed to combine shares");
+        assert_eq!(&combined[..], &secret[..]);
+        let subset = vec![shares[4].clone(), shares[0].clone(), shares[2].clone()];
+        assert_eq!(&gf64::combine(&subset).expect("Failed to combine shares")[..], &secret[..]);
+        // Below the threshold gives something else.
+        assert_ne!(&gf64::combine(&shares[..2]).expect("Failed to combine shares")[..], &secret[..]);
+    }
+}
+
+#[test]
+fn test_split_rejects_bad_parameters() {
+    let secret = b"sixteen byte key";
+    assert!(gf64::split(secret, 1, 3, &mut thread_rng()).is_err());
+    assert!(gf64::split_at(secret, 2, &[1, 1], &mut thread_rng()).is_err());
+    assert!(gf64::split_at(secret, 2, &[0, 1], &mut thread_rng()).is_err());
+    assert!(gf64::split(secret, 3, 256, &mut thread_rng()).is_err());
+}
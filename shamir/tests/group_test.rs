@@ -0,0 +1,51 @@
+use rand::thread_rng;
+use shamir::algos::group::{self, Commitment};
+use shamir::algos::share::Share;
+use shamir::algos::vss::{self, VssConfig};
+
+// Checks a dealing through the scheme-agnostic functions only.
+fn check_dealing<C: Commitment>(scheme: &C, coeffs: &[C::Scalar], shares: &[Share<C::Scalar>], tamper: C::Scalar) {
+    let commitments = group::commit_polynomial(scheme, coeffs);
+    assert!(shares.iter().all(|share| group::verify_commitment(scheme, share, &commitments)));
+    assert!(group::commitment_at(scheme, 0, &commitments) == commitments[0]);
+    assert!(shares.iter().all(|share| share.total == shares.len()));
+
+    let mut forged = shares[0].clone();
+    forged.value = tamper;
+    assert!(!group::verify_commitment(scheme, &forged, &commitments));
+    assert!(!group::verify_commitment(scheme, &shares[0], &commitments[1..]));
+}
+
+#[test]
+fn test_zp_commitments() {
+    let config = VssConfig::default();
+    let mut rng = thread_rng();
+    let coeffs = vss::generate_polynomial(1234, &config, &mut rng);
+    let shares = vss::generate_shares(&coeffs, &config, &mut rng);
+    assert_eq!(group::commit_polynomial(&config, &coeffs), vss::generate_commitments(&coeffs, &config));
+    check_dealing(&config, &coeffs, &shares, (shares[0].value + 1) % config.order());
+}
+
+#[cfg(feature = "ristretto")]
+#[test]
+fn test_ristretto_commitments() {
+    use curve25519_dalek::Scalar;
+    use shamir::algos::ristretto::{self, Ristretto};
+
+    let mut rng = thread_rng();
+    let coeffs = ristretto::generate_polynomial(Scalar::from(99u64), 3, &mut rng).expect("Failed to generate polynomial");
+    let shares = ristretto::generate_shares(&coeffs, 4, &mut rng).expect("Failed to generate shares");
+    check_dealing(&Ristretto, &coeffs, &shares, shares[0].value + Scalar::ONE);
+}
+
+#[cfg(feature = "bls12-381")]
+#[test]
+fn test_bls12_commitments() {
+    use bls12_381::Scalar;
+    use shamir::algos::bls12::{self, Bls12};
+
+    let mut rng = thread_rng();
+    let coeffs = bls12::generate_polynomial(Scalar::from(99u64), 3, &mut rng).expect("Failed to generate polynomial");
+    let shares = bls12::generate_shares(&coeffs, 4, &mut rng).expect("Failed to generate shares");
+    check_dealing(&Bls12, &coeffs, &shares, shares[0].value + Scalar::one());
+}
@@ -0,0 +1,48 @@
+#![cfg(feature = "grpc")]
+
+use shamir::algos::grpc::{self, ShamirClient, ShamirService};
+use shamir::algos::proto::{DealRequest, ReconstructRequest, SubmitShareRequest, VerifyShareRequest};
+use tokio::net::TcpListener;
+use tonic::Code;
+
+#[test]
+fn test_service_rejects_bad_requests() {
+    let service = ShamirService::default();
+    let err = service.deal(DealRequest { secret: 1, threshold: 1, shares: 3 }).expect_err("threshold 1 accepted");
+    assert_eq!(err.code(), Code::InvalidArgument);
+    let err = service.deal(DealRequest { secret: -1, threshold: 2, shares: 3 }).expect_err("negative secret accepted");
+    assert_eq!(err.code(), Code::InvalidArgument);
+
+    let dealt = service.deal(DealRequest { secret: 5, threshold: 2, shares: 3 }).expect("Failed to deal");
+    let mut forged = dealt.shares[0].clone();
+    forged.value[15] ^= 1;
+    let request = SubmitShareRequest { ceremony: dealt.ceremony.clone(), share: Some(forged) };
+    assert_eq!(service.submit_share(request).expect_err("forged share accepted").code(), Code::InvalidArgument);
+    let request = SubmitShareRequest { ceremony: "nope".into(), share: Some(dealt.shares[0].clone()) };
+    assert_eq!(service.submit_share(request).expect_err("unknown ceremony").code(), Code::NotFound);
+    let err = service.reconstruct(ReconstructRequest { ceremony: dealt.ceremony }).expect_err("no shares yet");
+    assert_eq!(err.code(), Code::FailedPrecondition);
+}
+
+#[tokio::test]
+async fn test_ceremony_over_grpc() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.expect("Failed to bind");
+    let addr = listener.local_addr().expect("Failed to read address");
+    tokio::spawn(grpc::serve(listener));
+
+    let mut client = ShamirClient::connect(format!("http://{}", addr)).await.expect("Failed to connect");
+    let dealt = client.deal(DealRequest { secret: 1234, threshold: 3, shares: 5 }).await.expect("Failed to deal");
+    assert_eq!(dealt.shares.len(), 5);
+
+    let request = VerifyShareRequest { share: Some(dealt.shares[1].clone()), bundle: dealt.bundle.clone() };
+    assert!(client.verify_share(request).await.expect("Failed to verify").valid);
+
+    for share in &dealt.shares[2..] {
+        let request = SubmitShareRequest { ceremony: dealt.ceremony.clone(), share: Some(share.clone()) };
+        client.submit_share(request).await.expect("Failed to submit share");
+    }
+    let request = ReconstructRequest { ceremony: dealt.ceremony.clone() };
+    let reconstructed = client.reconstruct(request).await.expect("Failed to reconstruct");
+    assert_eq!(reconstructed.secret, 1234);
+    assert_eq!(reconstructed.accepted, vec![3, 4, 5]);
+}
@@ -0,0 +1,53 @@
+use rand::thread_rng;
+use shamir::algos::hierarchical;
+use shamir::algos::share::Share;
+use shamir::algos::sss::ShamirError;
+
+// At least 2 executives (level 0) and 3 signers in total.
+const THRESHOLDS: [usize; 2] = [2, 3];
+
+#[test]
+fn test_authorized_subsets_reconstruct() {
+    let shares = hierarchical::generate_shares(31337, &THRESHOLDS, &[3, 4], &mut thread_rng())
+        .expect("Failed to generate shares");
+    assert_eq!(shares.len(), 7);
+    assert!(shares.iter().all(|share| share.total == 7 && share.fingerprint == shares[0].fingerprint));
+    assert!(shares[..3].iter().all(|share| share.level == 0));
+    assert!(shares[3..].iter().all(|share| share.level == 1));
+
+    let two_executives = vec![shares[0].clone(), shares[2].clone(), shares[5].clone()];
+    assert_eq!(hierarchical::reconstruct_secret(&two_executives, &THRESHOLDS).expect("Failed to reconstruct"), 31337);
+    assert_eq!(hierarchical::reconstruct_secret(&shares[..3], &THRESHOLDS).expect("Failed to reconstruct"), 31337);
+    assert_eq!(hierarchical::reconstruct_secret(&shares, &THRESHOLDS).expect("Failed to reconstruct"), 31337);
+}
+
+#[test]
+fn test_unauthorized_subset_is_rejected() {
+    let shares = hierarchical::generate_shares(7, &THRESHOLDS, &[3, 4], &mut thread_rng())
+        .expect("Failed to generate shares");
+    // One executive is not enough, however many lower-level signers join.
+    let one_executive = vec![shares[0].clone(), shares[3].clone(), shares[4].clone(), shares[5].clone()];
+    assert!(!hierarchical::is_authorized(&one_executive, &THRESHOLDS));
+    assert!(matches!(
+        hierarchical::reconstruct_secret(&one_executive, &THRESHOLDS),
+        Err(ShamirError::InsufficientShares { .. })
+    ));
+}
+
+#[test]
+fn test_level_survives_text_round_trip() {
+    let shares = hierarchical::generate_shares(42, &THRESHOLDS, &[2, 2], &mut thread_rng())
+        .expect("Failed to generate shares");
+    let encoded = shares[3].to_string();
+    assert!(encoded.contains("-4@1-"));
+    let decoded: Share = encoded.parse().expect("Failed to parse share");
+    assert_eq!(decoded, shares[3]);
+}
+
+#[test]
+fn test_unreachable_level_is_rejected() {
+    assert!(matches!(
+        hierarchical::generate_shares(1, &THRESHOLDS, &[1, 5], &mut thread_rng()),
+        Err(ShamirError::InvalidShareCount)
+    ));
+}
@@ -0,0 +1,77 @@
+#![cfg(feature = "keyring")]
+
+use std::any::Any;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use keyring::credential::{Credential, CredentialApi, CredentialBuilder, CredentialBuilderApi};
+use rand::thread_rng;
+use shamir::algos::keychain::KeychainStore;
+use shamir::algos::sss;
+use shamir::algos::store::ShareStore;
+
+// keyring's own mock keeps each entry's secret to itself; this one shares a map between entries
+// for the same account, as a real credential store does.
+type Secrets = Arc<Mutex<HashMap<(String, String), Vec<u8>>>>;
+
+struct Shared {
+    secrets: Secrets,
+    key: (String, String),
+}
+
+impl CredentialApi for Shared {
+    fn set_secret(&self, secret: &[u8]) -> keyring::Result<()> {
+        self.secrets.lock().expect("poisoned").insert(self.key.clone(), secret.to_vec());
+        Ok(())
+    }
+
+    fn get_secret(&self) -> keyring::Result<Vec<u8>> {
+        self.secrets.lock().expect("poisoned").get(&self.key).cloned().ok_or(keyring::Error::NoEntry)
+    }
+
+    fn delete_credential(&self) -> keyring::Result<()> {
+        self.secrets.lock().expect("poisoned").remove(&self.key).map(|_| ()).ok_or(keyring::Error::NoEntry)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+struct SharedBuilder(Secrets);
+
+impl CredentialBuilderApi for SharedBuilder {
+    fn build(&self, _target: Option<&str>, service: &str, user: &str) -> keyring::Result<Box<Credential>> {
+        Ok(Box::new(Shared { secrets: self.0.clone(), key: (service.into(), user.into()) }))
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+#[test]
+fn test_keychain_round_trip() {
+    let secrets = Secrets::default();
+    keyring::set_default_credential_builder(Box::new(SharedBuilder(secrets.clone())) as Box<CredentialBuilder>);
+
+    let shares = sss::split_bytes(b"in the keychain", 2, 3, &mut thread_rng()).expect("Failed to split");
+    let dealing = shares[0].scheme_id;
+    let mut store = KeychainStore::new("shamir-test");
+    for share in &shares {
+        store.put(share).expect("Failed to put");
+    }
+    assert_eq!(ShareStore::<Vec<u8>>::list(&store, dealing).expect("Failed to list"), vec![1, 2, 3]);
+    // Three shares and the dealing's index.
+    assert_eq!(secrets.lock().expect("poisoned").len(), 4);
+
+    assert!(ShareStore::<Vec<u8>>::delete(&mut store, dealing, 2).expect("Failed to delete"));
+    let loaded: Vec<_> = store.load(dealing).expect("Failed to load");
+    assert_eq!(loaded.iter().map(|share| share.index).collect::<Vec<_>>(), vec![1, 3]);
+    assert_eq!(&sss::combine_bytes(&loaded).expect("Failed to combine")[..], b"in the keychain");
+
+    for index in [1, 3] {
+        assert!(ShareStore::<Vec<u8>>::delete(&mut store, dealing, index).expect("Failed to delete"));
+    }
+    assert!(secrets.lock().expect("poisoned").is_empty());
+}
@@ -0,0 +1,41 @@
+use rand::{thread_rng, Rng};
+use shamir::algos::sss::ShamirError;
+use shamir::algos::{ida, krawczyk};
+
+#[test]
+fn test_krawczyk_roundtrip_with_small_shares() {
+    let mut rng = thread_rng();
+    let mut secret = vec![0u8; 100_000];
+    rng.fill(&mut secret[..]);
+    let shares = krawczyk::split(&secret, 4, 7, &mut rng).expect("Failed to split secret");
+    assert_eq!(shares.len(), 7);
+    // Each holder keeps a quarter of the ciphertext plus a 32-byte key share.
+    assert!(shares.iter().all(|share| share.fragment.value.len() < secret.len() / 4 + 16));
+
+    let subset = vec![shares[6].clone(), shares[0].clone(), shares[3].clone(), shares[2].clone()];
+    let combined = krawczyk::combine(&subset).expect("Failed to combine shares");
+    assert_eq!(*combined, secret);
+}
+
+#[test]
+fn test_krawczyk_detects_tampering() {
+    let mut shares = krawczyk::split(b"launch codes", 2, 3, &mut thread_rng()).expect("Failed to split secret");
+    assert!(matches!(krawczyk::combine(&shares[..1]), Err(ShamirError::InsufficientShares { .. })));
+
+    shares[1].fragment.value[5] ^= 1;
+    assert!(matches!(krawczyk::combine(&shares[..2]), Err(ShamirError::DigestMismatch)));
+
+    let other = krawczyk::split(b"launch codes", 2, 3, &mut thread_rng()).expect("Failed to split secret");
+    let mixed = vec![shares[0].clone(), other[2].clone()];
+    assert!(matches!(krawczyk::combine(&mixed), Err(ShamirError::InconsistentShares)));
+}
+
+#[test]
+fn test_ida_roundtrip() {
+    let data = b"information dispersal".to_vec();
+    let fragments = ida::split(&data, 3, 5, &mut thread_rng()).expect("Failed to split data");
+    assert!(fragments.iter().all(|fragment| fragment.total == 5));
+    let subset = vec![fragments[4].clone(), fragments[1].clone(), fragments[2].clone()];
+    assert_eq!(*ida::combine(&subset).expect("Failed to combine fragments"), data);
+    assert!(matches!(ida::combine(&fragments[..2]), Err(ShamirError::InsufficientShares { .. })));
+}
@@ -0,0 +1,48 @@
+use rand::thread_rng;
+use shamir::algos::share::Share;
+use shamir::algos::sss::{self, ShamirError};
+use shamir::algos::mnemonic;
+
+#[test]
+fn test_mnemonic_round_trip() {
+    for len in [0, 1, 11, 16, 32, 64] {
+        let secret: Vec<u8> = (0..len).map(|i| (i * 37 + 11) as u8).collect();
+        let shares = sss::split_bytes(&secret, 3, 5, &mut thread_rng()).expect("Failed to split bytes");
+        for share in &shares {
+            let words = mnemonic::to_mnemonic(share).expect("Failed to encode share");
+            let mut decoded = mnemonic::from_mnemonic(&words).expect("Failed to decode share");
+            // The word layout has no room for the dealing size or fingerprint.
+            assert_eq!((decoded.total, decoded.fingerprint), (0, 0));
+            decoded.total = share.total;
+            decoded.fingerprint = share.fingerprint;
+            assert_eq!(&decoded, share);
+        }
+    }
+}
+
+#[test]
+fn test_prefixes_and_case_are_accepted() {
+    let shares = sss::split_bytes(b"paper backup", 2, 3, &mut thread_rng()).expect("Failed to split bytes");
+    let words = mnemonic::to_mnemonic(&shares[1]).expect("Failed to encode share");
+    let typed: Vec<String> = words
+        .split(' ')
+        .map(|w| w.chars().take(4).collect::<String>().to_uppercase())
+        .collect();
+    let mut decoded = mnemonic::from_mnemonic(&typed.join("  ")).expect("Failed to decode share");
+    decoded.total = 3;
+    decoded.fingerprint = shares[1].fingerprint;
+    assert_eq!(decoded, shares[1]);
+}
+
+#[test]
+fn test_typo_is_detected() {
+    let share = Share::new(3, vec![0xde, 0xad, 0xbe, 0xef], 2, 0x1234_5678);
+    let words = mnemonic::to_mnemonic(&share).expect("Failed to encode share");
+    let mut list: Vec<&str> = words.split(' ').collect();
+    list[7] = if list[7] == "abandon" { "ability" } else { "abandon" };
+    assert!(matches!(
+        mnemonic::from_mnemonic(&list.join(" ")),
+        Err(ShamirError::InvalidChecksum)
+    ));
+    assert!(matches!(mnemonic::from_mnemonic("notaword abandon"), Err(ShamirError::InvalidMnemonic)));
+}
@@ -0,0 +1,81 @@
+use num_bigint::{BigUint, RandBigInt};
+use num_traits::One;
+use rand::thread_rng;
+use shamir::algos::montgomery::Montgomery;
+use shamir::algos::{bigint::BigPrimeField, polynomial};
+
+fn moduli() -> Vec<BigUint> {
+    let one = BigUint::one();
+    vec![
+        // One limb, two limbs, a full top limb, and a short top limb.
+        (&one << 61u32) - 1u8,
+        (&one << 127u32) - 1u8,
+        (&one << 256u32) - 189u8,
+        (&one << 521u32) - 1u8,
+    ]
+}
+
+#[test]
+fn test_arithmetic_matches_biguint() {
+    let mut rng = thread_rng();
+    for m in moduli() {
+        let mont = Montgomery::new(&m).expect("Failed to set up Montgomery form");
+        for _ in 0..200 {
+            let (a, b) = (rng.gen_biguint_below(&m), rng.gen_biguint_below(&m));
+            let (ra, rb) = (mont.to_montgomery(&a), mont.to_montgomery(&b));
+            assert_eq!(mont.from_montgomery(&ra), a);
+            assert_eq!(mont.from_montgomery(&mont.mul(&ra, &rb)), &a * &b % &m);
+            assert_eq!(mont.from_montgomery(&mont.add(&ra, &rb)), (&a + &b) % &m);
+            assert_eq!(mont.from_montgomery(&mont.sub(&ra, &rb)), (&a + &m - &b) % &m);
+        }
+        // The extremes of the reductions.
+        let top = &m - 1u8;
+        let rt = mont.to_montgomery(&top);
+        assert_eq!(mont.from_montgomery(&mont.mul(&rt, &rt)), BigUint::one());
+        assert_eq!(mont.from_montgomery(&mont.add(&rt, &rt)), &m - 2u8);
+        assert_eq!(mont.from_montgomery(&mont.inv(&rt).expect("Nonzero element has an inverse")), top);
+        assert_eq!(mont.from_montgomery(&mont.to_montgomery(&(&m + 5u8))), BigUint::from(5u8));
+        assert!(mont.inv(&mont.to_montgomery(&m)).is_none());
+    }
+}
+
+#[test]
+fn test_evaluate_and_interpolate_match_generic() {
+    let mut rng = thread_rng();
+    for m in moduli() {
+        let field = BigPrimeField::new(m.clone()).expect("Invalid modulus");
+        let mont = Montgomery::new(&m).expect("Failed to set up Montgomery form");
+        let coeffs: Vec<BigUint> = (0..5).map(|_| rng.gen_biguint_below(&m)).collect();
+        let residues = mont.to_montgomery_all(&coeffs);
+        let points: Vec<(BigUint, BigUint)> = (1..=5u64)
+            .map(|x| (BigUint::from(x), mont.evaluate(&residues, &BigUint::from(x))))
+            .collect();
+        for (x, y) in &points {
+            assert_eq!(y, &polynomial::evaluate(&field, &coeffs, x));
+        }
+        assert_eq!(mont.interpolate_at_zero(&points), Some(coeffs[0].clone()));
+    }
+}
+
+#[test]
+fn test_rejects_bad_input() {
+    assert!(Montgomery::new(&BigUint::from(1u8 << 7)).is_err());
+    assert!(Montgomery::new(&BigUint::one()).is_err());
+    let mont = Montgomery::new(&BigUint::from(7u8)).expect("Failed to set up Montgomery form");
+    let repeated = vec![(BigUint::from(1u8), BigUint::from(2u8)), (BigUint::from(1u8), BigUint::from(3u8))];
+    assert_eq!(mont.interpolate_at_zero(&repeated), None);
+}
+
+#[test]
+fn test_batch_inv() {
+    let mut rng = thread_rng();
+    let m = (BigUint::one() << 127u32) - 1u8;
+    let mont = Montgomery::new(&m).expect("Failed to set up Montgomery form");
+    let values: Vec<BigUint> = (0..10).map(|_| rng.gen_biguint_range(&BigUint::one(), &m)).collect();
+    let inverses = mont.batch_inv(&mont.to_montgomery_all(&values)).expect("Failed to invert");
+    for (value, inverse) in values.iter().zip(&inverses) {
+        assert_eq!(Some(mont.from_montgomery(inverse)), value.modinv(&m));
+    }
+    let with_zero = mont.to_montgomery_all(&[BigUint::from(3u8), m.clone()]);
+    assert!(mont.batch_inv(&with_zero).is_none());
+}
@@ -0,0 +1,48 @@
+use rand::thread_rng;
+use shamir::algos::multi;
+use shamir::algos::sss::{ShamirError, PRIME};
+
+#[test]
+fn test_multi_secret_roundtrip() {
+    let secrets = [11, 22, 33];
+    let shares = multi::generate_shares(&secrets, 5, 8, &mut thread_rng()).expect("Failed to generate shares");
+    assert_eq!(shares.len(), 8);
+    assert!(shares.iter().all(|share| share.total == 8));
+
+    let recovered = multi::reconstruct_secrets(&shares[3..], secrets.len()).expect("Failed to reconstruct");
+    assert_eq!(*recovered, secrets);
+}
+
+#[test]
+fn test_multi_secret_insufficient_shares() {
+    let shares = multi::generate_shares(&[1, 2], 4, 6, &mut thread_rng()).expect("Failed to generate shares");
+    assert!(matches!(
+        multi::reconstruct_secrets(&shares[..3], 2),
+        Err(ShamirError::InsufficientShares { .. })
+    ));
+}
+
+#[test]
+fn test_multi_secret_threshold_must_exceed_count() {
+    assert!(matches!(
+        multi::generate_shares(&[1, 2, 3], 3, 5, &mut thread_rng()),
+        Err(ShamirError::InvalidThreshold)
+    ));
+}
+
+#[test]
+fn test_multi_secret_checks_every_share() {
+    let mut shares = multi::generate_shares(&[1, 2], 3, 6, &mut thread_rng()).expect("Failed to generate shares");
+    // A bad share past the first threshold is reported rather than ignored.
+    shares[5].value = (shares[5].value + 1) % PRIME;
+    assert!(matches!(
+        multi::reconstruct_secrets(&shares, 2),
+        Err(ShamirError::Outliers(ref indices)) if *indices == [6]
+    ));
+    assert_eq!(*multi::reconstruct_secrets(&shares[..5], 2).expect("Failed to reconstruct"), [1, 2]);
+
+    shares[4].index = shares[0].index;
+    assert!(matches!(multi::reconstruct_secrets(&shares[..5], 2), Err(ShamirError::DuplicateIndex)));
+    shares[4].index = 0;
+    assert!(matches!(multi::reconstruct_secrets(&shares[..5], 2), Err(ShamirError::ZeroIndex)));
+}
@@ -0,0 +1,37 @@
+use rand::{thread_rng, Rng};
+use shamir::algos::multipoint;
+use shamir::algos::sss::{self, PRIME};
+
+fn random_poly(len: usize) -> Vec<u64> {
+    let mut rng = thread_rng();
+    (0..len).map(|_| rng.gen_range(0..PRIME)).collect()
+}
+
+#[test]
+fn test_multiply_matches_schoolbook() {
+    let (a, b) = (random_poly(700), random_poly(300));
+    let fast = multipoint::multiply(&a, &b);
+    let mut slow = vec![0u64; a.len() + b.len() - 1];
+    for (i, &x) in a.iter().enumerate() {
+        for (j, &y) in b.iter().enumerate() {
+            slow[i + j] = (slow[i + j] + x * y) % PRIME;
+        }
+    }
+    assert_eq!(fast, slow);
+}
+
+#[test]
+fn test_evaluate_many_matches_horner() {
+    let coeffs = random_poly(multipoint::MIN_DEGREE + 500);
+    let mut rng = thread_rng();
+    let xs: Vec<u64> = (0..multipoint::MIN_DEGREE + 100).map(|_| rng.gen_range(0..PRIME)).collect();
+    let expected: Vec<u64> = xs.iter().map(|&x| sss::evaluate_polynomial(&coeffs, x)).collect();
+    assert_eq!(multipoint::evaluate_many(&coeffs, &xs), expected);
+}
+
+#[test]
+fn test_large_threshold_shares_reconstruct() {
+    let threshold = multipoint::MIN_DEGREE + 10;
+    let shares = sss::generate_shares(777, threshold, threshold + 100, &mut thread_rng()).expect("Failed to generate shares");
+    assert_eq!(sss::reconstruct_secret(&shares[100..]).expect("Failed to reconstruct"), 777);
+}
@@ -0,0 +1,78 @@
+#![cfg(feature = "net")]
+
+use std::time::Duration;
+
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use shamir::algos::net::{self, Message, NetError};
+use shamir::algos::vss::{self, VssConfig};
+use tokio::net::TcpListener;
+
+const TIMEOUT: Duration = Duration::from_secs(5);
+
+#[test]
+fn test_message_roundtrip() {
+    let config = VssConfig::default();
+    let mut rng = StdRng::seed_from_u64(77);
+    let coeffs = vss::generate_polynomial(5, &config, &mut rng);
+    let share = vss::generate_shares(&coeffs, &config, &mut rng).remove(0);
+    let commitments = vss::generate_commitments(&coeffs, &config);
+    let bundle = vss::CommitmentBundle { commitments, verification_key: None };
+    let deal = Message::Deal { share: share.clone(), bundle };
+    for message in [deal, Message::Ack, Message::Request, Message::Submit(share)] {
+        assert_eq!(Message::decode(&message.encode()).expect("Failed to decode message"), message);
+    }
+    assert!(matches!(Message::decode(&[9]), Err(NetError::Protocol(_))));
+    assert!(matches!(Message::decode(&[1, 0, 0]), Err(NetError::Decode(_))));
+}
+
+#[tokio::test]
+async fn test_frame_limit() {
+    let (mut client, mut server) = tokio::io::duplex(64);
+    tokio::io::AsyncWriteExt::write_all(&mut client, &u32::MAX.to_be_bytes()).await.expect("Failed to write");
+    assert!(matches!(net::read_frame(&mut server).await, Err(NetError::Protocol(_))));
+}
+
+#[tokio::test]
+async fn test_deal_and_gather_over_tcp() {
+    let config = VssConfig::default();
+    let mut listeners = Vec::new();
+    for _ in 0..config.shares() {
+        listeners.push(TcpListener::bind("127.0.0.1:0").await.expect("Failed to bind"));
+    }
+    let addrs: Vec<_> =
+        listeners.iter().map(|listener| listener.local_addr().expect("Failed to read address")).collect();
+
+    // Each participant takes its share from the dealer, then hands it to the coordinator.
+    let participants: Vec<_> = listeners
+        .into_iter()
+        .map(|listener| {
+            tokio::spawn(async move {
+                let (share, _) = net::receive_share(&listener, &config, TIMEOUT).await?;
+                net::serve_share(&listener, &share, TIMEOUT).await
+            })
+        })
+        .collect();
+
+    let mut rng = StdRng::seed_from_u64(77);
+    let bundle = net::deal(1234, &addrs, &config, TIMEOUT, &mut rng).await.expect("Failed to deal");
+    let result = net::gather(&addrs, &bundle.commitments, &config, TIMEOUT).await.expect("Failed to gather");
+    assert_eq!(result.secret, Some(1234));
+    assert!(result.rejected.is_empty());
+    for participant in participants {
+        participant.await.expect("Failed to join participant").expect("Participant failed");
+    }
+}
+
+#[tokio::test]
+async fn test_gather_skips_unreachable_participants() {
+    let config = VssConfig::default();
+    let listener = TcpListener::bind("127.0.0.1:0").await.expect("Failed to bind");
+    let addr = listener.local_addr().expect("Failed to read address");
+    drop(listener);
+    let commitments = vec![0; config.threshold()];
+    let result =
+        net::gather(&[addr], &commitments, &config, Duration::from_millis(200)).await.expect("Failed to gather");
+    assert_eq!(result.secret, None);
+    assert!(result.accepted.is_empty());
+}
@@ -0,0 +1,27 @@
+use rand::thread_rng;
+use shamir::algos::params;
+use shamir::algos::vss::{self, VssError};
+
+#[test]
+fn test_safe_primes_have_requested_size() {
+    let mut rng = thread_rng();
+    for bits in [params::MIN_BITS, 4, 12, 32, params::MAX_BITS] {
+        let prime = params::safe_prime(bits, &mut rng).expect("Failed to generate safe prime");
+        assert_eq!(64 - prime.leading_zeros(), bits);
+        let generator = params::generator(prime, &mut rng);
+        assert_eq!(vss::validate_params(prime as i128, generator as i128, 2, 2), Ok(()));
+    }
+    assert!(matches!(params::safe_prime(2, &mut rng), Err(VssError::InvalidParameters("bits"))));
+    assert!(matches!(params::safe_prime(64, &mut rng), Err(VssError::InvalidParameters("bits"))));
+}
+
+#[test]
+fn test_generated_config_runs_vss() {
+    let mut rng = thread_rng();
+    let config = params::generate(48, 3, 5, &mut rng).expect("Failed to generate parameters");
+    let coeffs = vss::generate_polynomial(123_456_789, &config, &mut rng);
+    let shares = vss::generate_shares(&coeffs, &config, &mut rng);
+    let commitments = vss::generate_commitments(&coeffs, &config);
+    vss::verify_shares(&shares, &commitments, &config).expect("Failed to verify shares");
+    assert_eq!(vss::reconstruct_secret(&shares[2..], &config).expect("Failed to reconstruct secret"), 123_456_789);
+}
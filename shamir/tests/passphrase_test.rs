@@ -0,0 +1,54 @@
+#![cfg(feature = "argon2")]
+
+use rand::thread_rng;
+use shamir::algos::passphrase::{self, KdfParams};
+use shamir::algos::sss::ShamirError;
+
+// Cheap enough for a debug build; real splits use `KdfParams::new`.
+fn test_params() -> KdfParams {
+    KdfParams::with_cost(64, 1, 1, &mut thread_rng())
+}
+
+#[test]
+fn test_split_and_recover_key() {
+    let mut rng = thread_rng();
+    let params = test_params();
+    let shares = passphrase::split_passphrase_with("correct horse", &params, 2, 3, &mut rng).expect("Failed to split");
+    assert!(shares.iter().all(|share| share.kdf == params));
+
+    let key = passphrase::combine_key(&shares[1..]).expect("Failed to combine");
+    assert_eq!(key, passphrase::derive_key("correct horse", &params).expect("Failed to derive"));
+    assert_eq!(key.len(), passphrase::KEY_LEN);
+    assert!(passphrase::check_passphrase("correct horse", &shares[..2]).expect("Failed to check"));
+    assert!(!passphrase::check_passphrase("correct horse!", &shares[..2]).expect("Failed to check"));
+}
+
+#[test]
+fn test_mixed_kdf_params_are_rejected() {
+    let mut rng = thread_rng();
+    let mut shares = passphrase::split_passphrase_with("pw", &test_params(), 2, 2, &mut rng).expect("Failed to split");
+    shares[1].kdf.iterations += 1;
+    assert!(matches!(passphrase::combine_key(&shares), Err(ShamirError::InconsistentShares)));
+
+    let mut bad = test_params();
+    bad.memory_kib = 0;
+    assert!(matches!(passphrase::derive_key("pw", &bad), Err(ShamirError::InvalidParameters("kdf"))));
+}
+
+#[test]
+fn test_sealed_share_round_trip() {
+    let mut rng = thread_rng();
+    let shares = shamir::algos::sss::split_bytes(b"at rest", 2, 2, &mut rng).expect("Failed to split");
+    let sealed =
+        passphrase::seal_share_with(&shares[0], "holder one", test_params(), &mut rng).expect("Failed to seal");
+    assert!(!sealed.to_string().contains(&shares[0].to_string()));
+
+    let parsed: passphrase::SealedShare = sealed.to_string().parse().expect("Failed to parse");
+    assert_eq!(parsed, sealed);
+    assert_eq!(passphrase::open_share(&parsed, "holder one").expect("Failed to open"), shares[0]);
+    assert!(matches!(passphrase::open_share(&parsed, "holder two"), Err(ShamirError::DecryptionFailed)));
+
+    let mut tampered = sealed;
+    tampered.ciphertext[0] ^= 1;
+    assert!(matches!(passphrase::open_share(&tampered, "holder one"), Err(ShamirError::DecryptionFailed)));
+}
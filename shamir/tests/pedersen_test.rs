@@ -0,0 +1,43 @@
+use rand::thread_rng;
+use shamir::algos::group::Commitment;
+use shamir::algos::pedersen::Pedersen;
+use shamir::algos::vss::{VssConfig, VssError};
+
+#[test]
+fn test_deal_verify_and_reconstruct() {
+    let pedersen = Pedersen::from_config(VssConfig::default());
+    let config = *pedersen.config();
+    let (shares, commitments) = pedersen.deal(1234, &mut thread_rng());
+    assert_eq!(shares.len(), config.shares());
+    pedersen.verify_shares(&shares, &commitments).expect("Failed to verify");
+    assert_eq!(pedersen.reconstruct_secret(&shares[2..]).expect("Failed to reconstruct"), 1234);
+
+    // C_0 is not g^secret, so it says nothing about the secret on its own.
+    assert_ne!(commitments[0], config.commit(&1234));
+}
+
+#[test]
+fn test_tampered_share_or_blinding_is_rejected() {
+    let pedersen = Pedersen::from_config(VssConfig::default());
+    let order = pedersen.config().order();
+    let (mut shares, commitments) = pedersen.deal(7, &mut thread_rng());
+    shares[1].share.value = (shares[1].share.value + 1) % order;
+    shares[3].blinding = (shares[3].blinding + 1) % order;
+    assert!(!pedersen.verify_share(&shares[1], &commitments));
+    assert!(!pedersen.verify_share(&shares[3], &commitments));
+    assert!(matches!(pedersen.verify_shares(&shares, &commitments), Err(VssError::VerificationFailed { index: 2 })));
+}
+
+#[cfg(feature = "ristretto")]
+#[test]
+fn test_ristretto_pedersen() {
+    use curve25519_dalek::{RistrettoPoint, Scalar};
+    use sha2::{Digest, Sha512};
+    use shamir::algos::ristretto::Ristretto;
+
+    let digest: [u8; 64] = Sha512::digest(b"shamir/pedersen/h").into();
+    let pedersen = Pedersen::new(Ristretto, RistrettoPoint::from_uniform_bytes(&digest));
+    let (shares, commitments) =
+        pedersen.deal_in(Scalar::from(5u64), 3, 4, &mut thread_rng()).expect("Failed to deal");
+    assert!(shares.iter().all(|share| pedersen.verify_share(share, &commitments)));
+}
@@ -0,0 +1,47 @@
+use rand::thread_rng;
+use shamir::algos::group::Commitment;
+use shamir::algos::pok;
+use shamir::algos::vss::{self, VssConfig};
+
+#[test]
+fn test_prove_and_verify() {
+    let config = VssConfig::default();
+    let proof = pok::prove(777, b"ctx", &config, &mut thread_rng());
+    let y = config.commit(&777);
+    assert!(pok::verify(y, &proof, b"ctx", &config));
+    assert!(!pok::verify(y, &proof, b"other", &config));
+    assert!(!pok::verify(config.commit(&778), &proof, b"ctx", &config));
+    let tampered = pok::SchnorrProof { response: (proof.response + 1) % config.order(), ..proof };
+    assert!(!pok::verify(y, &tampered, b"ctx", &config));
+}
+
+#[test]
+fn test_proven_dealing_verifies() {
+    let config = VssConfig::default();
+    let (shares, bundle) = pok::deal(42, &config, &mut thread_rng());
+    assert!(pok::verify_commitments(&bundle, &config));
+    for share in &shares {
+        assert!(vss::verify_share(share, &bundle.commitments, &config));
+    }
+}
+
+#[test]
+fn test_made_up_commitments_are_rejected() {
+    let config = VssConfig::default();
+    let mut rng = thread_rng();
+    let (_, bundle) = pok::deal(42, &config, &mut rng);
+
+    // Someone copying C_0 into their own dealing can't reuse the proof.
+    let mut copied = bundle.clone();
+    copied.commitments[2] = config.commit(&5);
+    assert!(!pok::verify_commitments(&copied, &config));
+
+    // Nor can a dealer commit to g^x for an x it doesn't know and prove something else.
+    let mut forged = bundle.clone();
+    forged.commitments[0] = config.commit(&43);
+    assert!(!pok::verify_commitments(&forged, &config));
+
+    let mut short = bundle;
+    short.commitments.pop();
+    assert!(!pok::verify_commitments(&short, &config));
+}
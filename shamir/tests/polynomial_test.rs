@@ -0,0 +1,115 @@
+use rand::thread_rng;
+use shamir::algos::bigint::BigPrimeField;
+use shamir::algos::field::{FiniteField, PrimeField64, SmallPrimeField};
+use shamir::algos::polynomial::{self, Polynomial};
+use shamir::algos::share::Share;
+use shamir::algos::sss::{self, PRIME};
+
+fn check_interpolation<F: FiniteField>(field: &F, secret: F::Element) {
+    let coeffs = polynomial::generate(field, secret.clone(), 4, &mut thread_rng());
+    assert_eq!(coeffs.len(), 4);
+    assert!(coeffs[0] == secret);
+    let shares: Vec<Share<F::Element>> = [2u64, 5, 9, 11]
+        .iter()
+        .map(|&x| Share::new(x, polynomial::evaluate(field, &coeffs, &field.element(x)), 4, 7))
+        .collect();
+    assert!(polynomial::interpolate_at_zero(field, &shares).expect("Failed to interpolate") == secret);
+}
+
+#[test]
+fn test_interpolation_in_every_field() {
+    check_interpolation(&sss::FIELD, 123_456);
+    check_interpolation(&SmallPrimeField::new(2003), 1999);
+    let big = BigPrimeField::mersenne_521();
+    check_interpolation(&big, big.element(u64::MAX));
+    #[cfg(feature = "ristretto")]
+    check_interpolation(
+        &shamir::algos::group::ScalarField::<shamir::algos::ristretto::Ristretto>::new(),
+        curve25519_dalek::Scalar::from(42u64),
+    );
+}
+
+#[test]
+fn test_evaluate_and_lagrange() {
+    let field = SmallPrimeField::new(2003);
+    // 3 + 2x + x^2 at x = 10.
+    assert_eq!(polynomial::evaluate(&field, &[3, 2, 1], &10), 123);
+    assert_eq!(polynomial::evaluate(&field, &[], &10), 0);
+
+    let xs = [1, 4, 7];
+    let weights: Vec<i128> = (0..xs.len())
+        .map(|j| polynomial::lagrange_at_zero(&field, j, &xs).expect("Failed to compute weight"))
+        .collect();
+    assert_eq!(weights.iter().fold(0, |acc, w| field.add(&acc, w)), 1);
+    assert_eq!(polynomial::lagrange_at_zero(&field, 0, &[1, 4, 2004]), None);
+}
+
+#[test]
+fn test_interpolate_points() {
+    let field = SmallPrimeField::new(2003);
+    // 7 + 5x + 2x^2 + x^3.
+    let coeffs = [7, 5, 2, 1];
+    let points: Vec<(i128, i128)> = [3, 8, 1, 2000]
+        .iter()
+        .map(|&x| (x, polynomial::evaluate(&field, &coeffs, &x)))
+        .collect();
+    assert_eq!(polynomial::interpolate(&field, &points), Some(coeffs.to_vec()));
+    assert_eq!(polynomial::interpolate_constant(&field, &points), Some(7));
+    // A lower-degree polynomial comes back with zero high coefficients.
+    assert_eq!(polynomial::interpolate(&field, &[(1, 4), (2, 4), (5, 4)]), Some(vec![4, 0, 0]));
+
+    assert_eq!(polynomial::interpolate(&field, &[(1, 4), (2004, 5)]), None);
+    assert_eq!(polynomial::interpolate_constant(&field, &[(1, 4), (2004, 5)]), None);
+    assert_eq!(polynomial::interpolate(&field, &[]), Some(vec![]));
+}
+
+#[test]
+fn test_polynomial_arithmetic() {
+    let field = SmallPrimeField::new(2003);
+    let f = Polynomial::new(field, vec![1, 2]);
+    let g = Polynomial::new(field, vec![3, 0, 2001, 0]);
+    assert_eq!(g.degree(), Some(2));
+    assert_eq!(Polynomial::zero(field).degree(), None);
+
+    assert_eq!(&f + &g, Polynomial::new(field, vec![4, 2, 2001]));
+    assert_eq!(&g - &g, Polynomial::zero(field));
+    // (1 + 2x)(3 - 2x^2) = 3 + 6x - 2x^2 - 4x^3.
+    let product = &f * &g;
+    assert_eq!(product, Polynomial::new(field, vec![3, 6, 2001, 1999]));
+    assert_eq!(product.evaluate(&5), field.mul(&f.evaluate(&5), &g.evaluate(&5)));
+    assert_eq!(f.scale(&3), Polynomial::new(field, vec![3, 6]));
+    assert_eq!(-&f + f.clone(), Polynomial::zero(field));
+}
+
+#[test]
+fn test_refresh_polynomial_keeps_secret() {
+    let field = SmallPrimeField::new(2003);
+    let mut rng = thread_rng();
+    let dealing = Polynomial::random(field, 1234, 3, &mut rng);
+    let refresh = Polynomial::random(field, 0, 3, &mut rng);
+    let refreshed = &dealing + &refresh;
+    assert_eq!(refreshed.constant(), 1234);
+    assert_eq!(refreshed.evaluate(&7), field.add(&dealing.evaluate(&7), &refresh.evaluate(&7)));
+
+    let points: Vec<(i128, i128)> = [1, 2, 3].iter().map(|&x| (x, refreshed.evaluate(&x))).collect();
+    assert_eq!(Polynomial::interpolate(field, &points), Some(refreshed));
+}
+
+#[test]
+fn test_batch_inverse() {
+    let field = PrimeField64::new(PRIME);
+    let values: Vec<u64> = (1..=20).map(|i| field.element(i * 7919)).collect();
+    let inverses = polynomial::batch_inverse(&field, &values).expect("Failed to invert");
+    for (value, inverse) in values.iter().zip(&inverses) {
+        assert_eq!(Some(*inverse), field.inv(value));
+    }
+    assert_eq!(polynomial::batch_inverse(&field, &[]), Some(vec![]));
+    assert_eq!(polynomial::batch_inverse(&field, &[3, 0, 5]), None);
+
+    let xs: Vec<u64> = vec![1, 4, 9, 16];
+    let basis = polynomial::lagrange_basis_at(&field, &xs, &25).expect("Failed to compute basis");
+    for (j, lambda) in basis.iter().enumerate() {
+        assert_eq!(Some(*lambda), polynomial::lagrange_at(&field, j, &xs, &25));
+    }
+    assert_eq!(polynomial::lagrange_basis_at_zero(&field, &[2, 2]), None);
+}
@@ -0,0 +1,21 @@
+use rand::thread_rng;
+use shamir::prelude::*;
+
+#[test]
+fn test_top_level_split_and_combine() {
+    let shares: Vec<Share<Vec<u8>>> = split(b"library secret", 2, 3, &mut thread_rng()).expect("Failed to split secret");
+    let secret = combine(&shares[1..]).expect("Failed to combine shares");
+    assert_eq!(secret.as_slice(), b"library secret");
+    assert!(matches!(combine(&shares[..1]), Err(ShamirError::InsufficientShares { .. })));
+}
+
+#[test]
+fn test_prelude_vss() {
+    let config = VssConfig::default();
+    let mut rng = thread_rng();
+    let coeffs = shamir::vss::generate_polynomial(42, &config, &mut rng);
+    let shares = shamir::vss::generate_shares(&coeffs, &config, &mut rng);
+    let commitment = config.commit(&coeffs[0]);
+    assert_eq!(commitment, shamir::vss::generate_commitments(&coeffs, &config)[0]);
+    assert_eq!(shamir::vss::reconstruct_secret(&shares[..3], &config).expect("Failed to reconstruct secret"), 42);
+}
@@ -0,0 +1,70 @@
+use proptest::prelude::*;
+use proptest::sample::{select, subsequence};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use shamir::algos::share::{Share, ShareValue};
+use shamir::algos::sss::{self, ShamirError, PRIME};
+use shamir::algos::vss::{self, VssConfig};
+
+fn is_prime(n: i128) -> bool {
+    n >= 2 && (2..).take_while(|d| d * d <= n).all(|d| n % d != 0)
+}
+
+fn safe_primes() -> Vec<i128> {
+    (23..20000).filter(|&p| is_prime(p) && is_prime((p - 1) / 2)).collect()
+}
+
+// (threshold, share count, indices of a shuffled quorum of at least threshold shares)
+fn dealing() -> impl Strategy<Value = (usize, usize, Vec<usize>)> {
+    (2usize..=10).prop_flat_map(|n| {
+        (2..=n).prop_flat_map(move |t| (Just(t), Just(n), subsequence((0..n).collect::<Vec<_>>(), t..=n).prop_shuffle()))
+    })
+}
+
+fn pick<V: Clone + ShareValue>(shares: &[Share<V>], quorum: &[usize]) -> Vec<Share<V>> {
+    quorum.iter().map(|&i| shares[i].clone()).collect()
+}
+
+proptest! {
+    #[test]
+    fn prop_any_quorum_reconstructs(secret in 0..PRIME, (t, n, quorum) in dealing(), seed: u64) {
+        let shares = sss::generate_shares(secret, t, n, &mut StdRng::seed_from_u64(seed)).expect("Failed to generate shares");
+        prop_assert_eq!(sss::reconstruct_secret(&pick(&shares, &quorum)).expect("Failed to reconstruct"), secret);
+    }
+
+    #[test]
+    fn prop_fewer_than_threshold_fails(secret in 0..PRIME, (t, n, quorum) in dealing(), seed: u64) {
+        let shares = sss::generate_shares(secret, t, n, &mut StdRng::seed_from_u64(seed)).expect("Failed to generate shares");
+        let short = pick(&shares, &quorum[..t - 1]);
+        let result = sss::reconstruct_secret(&short);
+        prop_assert!(
+            matches!(result, Err(ShamirError::InsufficientShares { provided, required }) if provided == t - 1 && required == t),
+            "short share set was accepted"
+        );
+    }
+
+    #[test]
+    fn prop_byte_quorum_reconstructs(secret in prop::collection::vec(any::<u8>(), 0..64), (t, n, quorum) in dealing(), seed: u64) {
+        let shares = sss::split_bytes(&secret, t, n, &mut StdRng::seed_from_u64(seed)).expect("Failed to split bytes");
+        prop_assert_eq!(&sss::combine_bytes(&pick(&shares, &quorum)).expect("Failed to combine")[..], &secret[..]);
+    }
+
+    #[test]
+    fn prop_vss_invariants(prime in select(safe_primes()), secret: i64, (t, n, quorum) in dealing(), delta in 1i128..1000, seed: u64) {
+        // 4 is a square, so it generates the order-q subgroup of every safe prime above 5.
+        let config = VssConfig::new(prime, 4, t, n).expect("Failed to build VSS config");
+        let mut rng = StdRng::seed_from_u64(seed);
+        let coeffs = vss::generate_polynomial(secret as i128, &config, &mut rng);
+        let shares = vss::generate_shares(&coeffs, &config, &mut rng);
+        let commitments = vss::generate_commitments(&coeffs, &config);
+
+        prop_assert!(vss::verify_shares(&shares, &commitments, &config).is_ok());
+        let recovered = vss::reconstruct_secret(&pick(&shares, &quorum), &config).expect("Failed to reconstruct");
+        prop_assert_eq!(recovered, (secret as i128).rem_euclid(config.order()));
+
+        let mut tampered = shares[quorum[0]].clone();
+        prop_assume!(delta % config.order() != 0);
+        tampered.value += delta;
+        prop_assert!(!vss::verify_share(&tampered, &commitments, &config));
+    }
+}
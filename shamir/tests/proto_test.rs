@@ -0,0 +1,42 @@
+#![cfg(feature = "protobuf")]
+
+use prost::Message;
+use rand::thread_rng;
+use shamir::algos::complaint::Complaint;
+use shamir::algos::refresh::{self, RefreshContribution};
+use shamir::algos::share::Share;
+use shamir::algos::vss::{CommitmentBundle, VssConfig};
+use shamir::algos::wire::DecodeError;
+use shamir::algos::{auth, proto, sss};
+
+#[test]
+fn test_share_round_trip() {
+    let mut share = sss::split_bytes(b"protobuf", 2, 3, &mut thread_rng()).expect("Failed to split bytes").remove(1);
+    share.epoch = 3;
+    let bytes = proto::Share::from(&share).encode_to_vec();
+    let message = proto::Share::decode(bytes.as_slice()).expect("Failed to decode message");
+    assert_eq!(Share::<Vec<u8>>::try_from(message.clone()), Ok(share));
+    assert_eq!(Share::<u64>::try_from(message), Err(DecodeError::FieldMismatch));
+}
+
+#[test]
+fn test_protocol_messages_round_trip() {
+    let config = VssConfig::default();
+    let contribution = refresh::generate_refresh(&config, &mut thread_rng());
+    let message = proto::RefreshMessage::try_from(&contribution).expect("Failed to convert refresh");
+    let decoded = proto::RefreshMessage::decode(message.encode_to_vec().as_slice()).expect("Failed to decode message");
+    assert_eq!(RefreshContribution::try_from(decoded), Ok(contribution.clone()));
+
+    let bundle = CommitmentBundle {
+        commitments: contribution.commitments,
+        verification_key: Some(auth::derive_key(b"dealer", 5)),
+    };
+    let message = proto::CommitmentBundle::try_from(&bundle).expect("Failed to convert bundle");
+    let decoded = proto::CommitmentBundle::decode(message.encode_to_vec().as_slice()).expect("Failed to decode message");
+    assert_eq!(CommitmentBundle::try_from(decoded), Ok(bundle));
+
+    let complaint = Complaint { complainant: 4, dealer: 2 };
+    let decoded = proto::Complaint::decode(proto::Complaint::from(&complaint).encode_to_vec().as_slice())
+        .expect("Failed to decode message");
+    assert_eq!(Complaint::from(decoded), complaint);
+}
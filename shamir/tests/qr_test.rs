@@ -0,0 +1,34 @@
+#![cfg(feature = "qr")]
+
+use rand::thread_rng;
+use shamir::algos::share::Share;
+use shamir::algos::{qr, sss};
+
+#[test]
+fn test_png_round_trip() {
+    let shares = sss::split_bytes(b"print me", 2, 3, &mut thread_rng()).expect("Failed to split bytes");
+    for share in &shares {
+        let png = qr::to_png(share).expect("Failed to render QR code");
+        let decoded: Share<Vec<u8>> = qr::from_image(&png).expect("Failed to scan QR code");
+        assert_eq!(&decoded, share);
+    }
+}
+
+#[test]
+fn test_text_renderings() {
+    let share = Share::new(2, 123456u64, 2, 0xabcdef);
+    let svg = qr::to_svg(&share).expect("Failed to render SVG");
+    assert!(svg.contains("<svg"));
+    let terminal = qr::to_terminal(&share).expect("Failed to render terminal QR code");
+    assert!(terminal.lines().count() > 10);
+}
+
+#[test]
+fn test_image_without_code() {
+    let blank = image::GrayImage::from_pixel(64, 64, image::Luma([255]));
+    let mut png = Vec::new();
+    blank
+        .write_to(&mut std::io::Cursor::new(&mut png), image::ImageFormat::Png)
+        .expect("Failed to encode PNG");
+    assert!(matches!(qr::from_image::<u64>(&png), Err(qr::QrError::NotFound)));
+}
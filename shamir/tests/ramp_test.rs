@@ -0,0 +1,36 @@
+use rand::thread_rng;
+use shamir::algos::ramp;
+use shamir::algos::sss::ShamirError;
+
+#[test]
+fn test_ramp_roundtrip() {
+    let secret = vec![0x5au8; 1000];
+    let shares = ramp::split(&secret, 2, 6, 8, &mut thread_rng()).expect("Failed to split secret");
+    assert_eq!(shares.len(), 8);
+    assert!(shares.iter().all(|share| share.total == 8 && share.fingerprint == shares[0].fingerprint));
+    // Four secret bytes per chunk: each share is about a quarter of the secret.
+    assert_eq!(shares[0].value.len(), 251);
+
+    let combined = ramp::combine(&shares[2..], 2).expect("Failed to combine shares");
+    assert_eq!(*combined, secret);
+}
+
+#[test]
+fn test_ramp_short_and_empty_secrets() {
+    for secret in [&b""[..], b"x", b"hello world"] {
+        let shares = ramp::split(secret, 1, 3, 4, &mut thread_rng()).expect("Failed to split secret");
+        let subset = vec![shares[3].clone(), shares[0].clone(), shares[2].clone()];
+        let combined = ramp::combine(&subset, 1).expect("Failed to combine shares");
+        assert_eq!(&combined[..], secret);
+    }
+}
+
+#[test]
+fn test_ramp_rejects_bad_parameters() {
+    let mut rng = thread_rng();
+    assert!(matches!(ramp::split(b"abc", 3, 3, 5, &mut rng), Err(ShamirError::InvalidThreshold)));
+    assert!(matches!(ramp::split(b"abc", 0, 3, 5, &mut rng), Err(ShamirError::InvalidThreshold)));
+
+    let shares = ramp::split(b"abc", 1, 3, 5, &mut rng).expect("Failed to split secret");
+    assert!(matches!(ramp::combine(&shares[..2], 1), Err(ShamirError::InsufficientShares { .. })));
+}
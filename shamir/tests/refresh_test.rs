@@ -0,0 +1,117 @@
+use rand::thread_rng;
+use shamir::algos::refresh;
+use shamir::algos::share::StalePolicy;
+use shamir::algos::vss::{self, VssConfig, VssError};
+
+#[test]
+fn test_refresh_preserves_secret() {
+    let config = VssConfig::default();
+    let mut rng = thread_rng();
+    let coeffs = vss::generate_polynomial(1500, &config, &mut rng);
+    let shares = vss::generate_shares(&coeffs, &config, &mut rng);
+    let commitments = vss::generate_commitments(&coeffs, &config);
+
+    let contributions: Vec<_> = (0..config.shares())
+        .map(|_| refresh::generate_refresh(&config, &mut rng))
+        .collect();
+    for contribution in &contributions {
+        refresh::verify_refresh(contribution, &config).expect("Refresh contribution failed verification");
+    }
+
+    let refreshed: Vec<_> = shares
+        .iter()
+        .map(|share| refresh::apply_refresh(share, &contributions, &config).expect("Failed to apply refresh"))
+        .collect();
+    let new_commitments = refresh::refresh_commitments(&commitments, &contributions, &config)
+        .expect("Failed to refresh commitments");
+
+    assert_eq!(new_commitments[0], commitments[0]);
+    for (old, new) in shares.iter().zip(&refreshed) {
+        assert_eq!(new.epoch, old.epoch + 1);
+        assert!(vss::verify_share(new, &new_commitments, &config));
+    }
+    assert_eq!(vss::reconstruct_secret(&refreshed[2..], &config).expect("Failed to reconstruct"), 1500);
+}
+
+#[test]
+fn test_mixed_epochs_are_rejected() {
+    let config = VssConfig::default();
+    let mut rng = thread_rng();
+    let coeffs = vss::generate_polynomial(7, &config, &mut rng);
+    let shares = vss::generate_shares(&coeffs, &config, &mut rng);
+    let contributions = vec![refresh::generate_refresh(&config, &mut rng)];
+    let refreshed = refresh::apply_refresh(&shares[0], &contributions, &config).expect("Failed to apply refresh");
+
+    let mixed = vec![refreshed, shares[1].clone(), shares[2].clone()];
+    assert!(matches!(
+        vss::reconstruct_secret(&mixed, &config),
+        Err(VssError::InconsistentShares)
+    ));
+}
+
+#[test]
+fn test_verify_fresh_sets_aside_old_epochs() {
+    let config = VssConfig::default();
+    let mut rng = thread_rng();
+    let coeffs = vss::generate_polynomial(7, &config, &mut rng);
+    let shares = vss::generate_shares(&coeffs, &config, &mut rng);
+    let commitments = vss::generate_commitments(&coeffs, &config);
+    let contributions = vec![refresh::generate_refresh(&config, &mut rng)];
+    let new_commitments = refresh::refresh_commitments(&commitments, &contributions, &config)
+        .expect("Failed to refresh commitments");
+    let mut mixed: Vec<_> = shares[..4]
+        .iter()
+        .map(|share| refresh::apply_refresh(share, &contributions, &config).expect("Failed to apply refresh"))
+        .collect();
+    mixed.push(shares[4].clone());
+
+    assert!(matches!(
+        vss::verify_fresh(&mixed, &new_commitments, &config, None, StalePolicy::Refuse),
+        Err(VssError::StaleShares(indices)) if indices == vec![5]
+    ));
+    let staleness = vss::verify_fresh(&mixed, &new_commitments, &config, None, StalePolicy::Warn)
+        .expect("Failed to verify fresh shares");
+    assert_eq!(staleness.stale, vec![5]);
+
+    mixed[0].valid_until = Some(1_000);
+    assert!(matches!(
+        vss::verify_fresh(&mixed, &new_commitments, &config, Some(2_000), StalePolicy::Refuse),
+        Err(VssError::ExpiredShares(indices)) if indices == vec![1]
+    ));
+    assert!(vss::verify_fresh(&mixed[..4], &new_commitments, &config, Some(500), StalePolicy::Refuse).is_ok());
+}
+
+#[test]
+fn test_nonzero_refresh_is_rejected() {
+    let config = VssConfig::default();
+    let mut rng = thread_rng();
+    let mut contribution = refresh::generate_refresh(&config, &mut rng);
+    let coeffs = vss::generate_polynomial(5, &config, &mut rng);
+    contribution.commitments = vss::generate_commitments(&coeffs, &config);
+    contribution.shares = vss::generate_shares(&coeffs, &config, &mut rng);
+    assert!(matches!(
+        refresh::verify_refresh(&contribution, &config),
+        Err(VssError::VerificationFailed { .. })
+    ));
+}
+
+#[test]
+fn test_refresh_commitments_reduce_inputs() {
+    let config = VssConfig::default();
+    let mut rng = thread_rng();
+    let coeffs = vss::generate_polynomial(42, &config, &mut rng);
+    let commitments = vss::generate_commitments(&coeffs, &config);
+    let contributions = vec![refresh::generate_refresh(&config, &mut rng)];
+    let expected = refresh::refresh_commitments(&commitments, &contributions, &config)
+        .expect("Failed to refresh commitments");
+
+    // The same group elements lifted close to i128::MAX, whose product would overflow.
+    let p = config.prime();
+    let lift = |c: &i128| c + p * (i128::MAX / p - 1);
+    let lifted: Vec<i128> = commitments.iter().map(lift).collect();
+    let mut contribution = contributions[0].clone();
+    contribution.commitments = contribution.commitments.iter().map(lift).collect();
+    let refreshed =
+        refresh::refresh_commitments(&lifted, &[contribution], &config).expect("Failed to refresh commitments");
+    assert_eq!(refreshed, expected);
+}
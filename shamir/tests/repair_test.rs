@@ -0,0 +1,74 @@
+use rand::thread_rng;
+use shamir::algos::repair;
+use shamir::algos::vss::{self, VssConfig, VssError};
+
+fn run_repair(
+    shares: &[shamir::Share<i128>],
+    helpers: &[u64],
+    lost: u64,
+    config: &VssConfig,
+) -> Vec<repair::RepairSum> {
+    let mut rng = thread_rng();
+    let holders: Vec<_> = helpers.iter().map(|&h| shares.iter().find(|s| s.index == h).expect("Failed to find helper")).collect();
+    let deltas: Vec<_> = holders
+        .iter()
+        .flat_map(|share| repair::split_delta(share, helpers, lost, config, &mut rng).expect("Failed to split delta"))
+        .collect();
+    holders
+        .iter()
+        .map(|share| repair::aggregate_deltas(share, &deltas, helpers, lost, config).expect("Failed to aggregate deltas"))
+        .collect()
+}
+
+#[test]
+fn test_recover_lost_share() {
+    let config = VssConfig::default();
+    let mut rng = thread_rng();
+    let coeffs = vss::generate_polynomial(4321, &config, &mut rng);
+    let shares = vss::generate_shares(&coeffs, &config, &mut rng);
+    let commitments = vss::generate_commitments(&coeffs, &config);
+
+    let lost = shares[1].index;
+    let helpers: Vec<u64> = shares.iter().map(|s| s.index).filter(|&i| i != lost).take(config.threshold()).collect();
+    let sums = run_repair(&shares, &helpers, lost, &config);
+    assert_eq!(sums.len(), config.threshold());
+    let recovered = repair::recover_share(lost, &sums, &commitments, &config).expect("Failed to recover share");
+    assert_eq!(recovered, shares[1]);
+}
+
+#[test]
+fn test_tampered_sum_is_caught() {
+    let config = VssConfig::default();
+    let mut rng = thread_rng();
+    let coeffs = vss::generate_polynomial(77, &config, &mut rng);
+    let shares = vss::generate_shares(&coeffs, &config, &mut rng);
+    let commitments = vss::generate_commitments(&coeffs, &config);
+
+    let lost = shares[0].index;
+    let helpers: Vec<u64> = shares[1..=config.threshold()].iter().map(|s| s.index).collect();
+    let mut sums = run_repair(&shares, &helpers, lost, &config);
+    sums[0].value = (sums[0].value + 1) % config.order();
+    assert!(matches!(
+        repair::recover_share(lost, &sums, &commitments, &config),
+        Err(VssError::VerificationFailed { index }) if index == lost
+    ));
+}
+
+#[test]
+fn test_invalid_helper_sets() {
+    let config = VssConfig::default();
+    let mut rng = thread_rng();
+    let coeffs = vss::generate_polynomial(5, &config, &mut rng);
+    let shares = vss::generate_shares(&coeffs, &config, &mut rng);
+
+    let too_few = [shares[1].index];
+    assert!(matches!(
+        repair::split_delta(&shares[1], &too_few, shares[0].index, &config, &mut rng),
+        Err(VssError::InsufficientShares { .. })
+    ));
+    let with_lost: Vec<u64> = shares[..config.threshold()].iter().map(|s| s.index).collect();
+    assert!(matches!(
+        repair::split_delta(&shares[1], &with_lost, shares[0].index, &config, &mut rng),
+        Err(VssError::DuplicateIndex)
+    ));
+}
@@ -0,0 +1,93 @@
+use rand::thread_rng;
+use shamir::algos::reshare::{self, ReshareContribution};
+use shamir::algos::vss::{self, VssConfig, VssError};
+
+fn old_dealing(secret: i128) -> (VssConfig, Vec<i128>, Vec<shamir::algos::share::Share<i128>>) {
+    let config = VssConfig::default();
+    let mut rng = thread_rng();
+    let coeffs = vss::generate_polynomial(secret, &config, &mut rng);
+    (config, vss::generate_commitments(&coeffs, &config), vss::generate_shares(&coeffs, &config, &mut rng))
+}
+
+#[test]
+fn test_reshare_to_new_committee() {
+    let (old_config, old_commitments, old_shares) = old_dealing(1500);
+    let new_config = VssConfig::new(old_config.prime(), old_config.generator(), 2, 4).expect("Failed to build config");
+    let mut rng = thread_rng();
+
+    let contributions: Vec<ReshareContribution> = [&old_shares[4], &old_shares[1], &old_shares[2]]
+        .iter()
+        .map(|share| reshare::generate_reshare(share, &new_config, &mut rng))
+        .collect();
+    for contribution in &contributions {
+        reshare::verify_reshare(contribution, &old_commitments, &old_config, &new_config)
+            .expect("Failed to verify contribution");
+    }
+
+    let new_shares: Vec<_> = (1..=4)
+        .map(|j| reshare::combine_reshares(j, &contributions, &old_commitments, &old_config, &new_config))
+        .collect::<Result<_, _>>()
+        .expect("Failed to combine reshares");
+    let new_commitments =
+        reshare::reshare_commitments(&contributions, &old_config, &new_config).expect("Failed to combine commitments");
+    assert_eq!(new_commitments[0], old_commitments[0]);
+    assert!(vss::verify_shares(&new_shares, &new_commitments, &new_config).is_ok());
+    assert!(new_shares.iter().all(|share| share.epoch == 1 && share.threshold == 2 && share.total == 4));
+    assert!(new_shares.iter().all(|share| share.fingerprint != 0 && share.fingerprint == new_shares[0].fingerprint));
+    assert_ne!(new_shares[0].fingerprint, old_shares[0].fingerprint);
+
+    let recovered = vss::reconstruct_secret(&new_shares[2..], &new_config).expect("Failed to reconstruct secret");
+    assert_eq!(recovered, 1500);
+}
+
+#[test]
+fn test_reshare_rejects_wrong_source_share() {
+    let (old_config, old_commitments, old_shares) = old_dealing(42);
+    let new_config = old_config;
+    let mut forged = old_shares[0].clone();
+    forged.value = (forged.value + 1) % old_config.order();
+    let contribution = reshare::generate_reshare(&forged, &new_config, &mut thread_rng());
+    assert!(matches!(
+        reshare::verify_reshare(&contribution, &old_commitments, &old_config, &new_config),
+        Err(VssError::VerificationFailed { .. })
+    ));
+}
+
+#[test]
+fn test_reshare_needs_old_threshold() {
+    let (old_config, old_commitments, old_shares) = old_dealing(7);
+    let new_config = old_config;
+    let mut rng = thread_rng();
+    let contributions: Vec<_> =
+        old_shares[..2].iter().map(|share| reshare::generate_reshare(share, &new_config, &mut rng)).collect();
+    assert!(matches!(
+        reshare::combine_reshares(1, &contributions, &old_commitments, &old_config, &new_config),
+        Err(VssError::InsufficientShares { .. })
+    ));
+    let duplicated = vec![contributions[0].clone(), contributions[1].clone(), contributions[0].clone()];
+    assert!(matches!(
+        reshare::combine_reshares(1, &duplicated, &old_commitments, &old_config, &new_config),
+        Err(VssError::DuplicateIndex)
+    ));
+}
+
+#[test]
+fn test_reshare_reduces_sub_shares() {
+    let (old_config, old_commitments, old_shares) = old_dealing(77);
+    let mut rng = thread_rng();
+    let mut contributions: Vec<ReshareContribution> = old_shares[..3]
+        .iter()
+        .map(|share| reshare::generate_reshare(share, &old_config, &mut rng))
+        .collect();
+    let expected = reshare::combine_reshares(1, &contributions, &old_commitments, &old_config, &old_config)
+        .expect("Failed to combine reshares");
+
+    // Lifted by a multiple of q the sub-shares still verify, and must not overflow when weighted.
+    let q = old_config.order();
+    for sub_share in contributions[1].shares.iter_mut() {
+        sub_share.value += (i128::MAX / q / 2) * q;
+    }
+    let lifted = reshare::combine_reshares(1, &contributions, &old_commitments, &old_config, &old_config)
+        .expect("Failed to combine reshares");
+    assert_eq!(lifted, expected);
+}
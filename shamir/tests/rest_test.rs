@@ -0,0 +1,67 @@
+#![cfg(feature = "rest")]
+
+use axum::body::{Body, to_bytes};
+use axum::http::{Request, StatusCode};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use shamir::algos::rest::{
+    self, CombineResponse, ErrorResponse, SharesRequest, SplitRequest, SplitResponse, VerifyResponse,
+};
+use tower::ServiceExt;
+
+async fn post<T: DeserializeOwned>(path: &str, body: String) -> (StatusCode, T) {
+    let request = Request::post(path).header("content-type", "application/json").body(Body::from(body));
+    let response = rest::router().oneshot(request.expect("Failed to build request")).await.expect("Failed to serve");
+    let status = response.status();
+    let body = to_bytes(response.into_body(), usize::MAX).await.expect("Failed to read body");
+    (status, serde_json::from_slice(&body).expect("Failed to parse response"))
+}
+
+fn json(value: &impl Serialize) -> String {
+    serde_json::to_string(value).expect("Failed to encode request")
+}
+
+#[tokio::test]
+async fn test_split_verify_combine() {
+    let request = SplitRequest { secret: "68656c6c6f".into(), threshold: 2, shares: 3 };
+    let (status, split): (_, SplitResponse) = post("/split", json(&request)).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(split.shares.len(), 3);
+
+    let quorum = SharesRequest { shares: split.shares[1..].to_vec() };
+    let (status, verified): (_, VerifyResponse) = post("/verify", json(&quorum)).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(verified, VerifyResponse { valid: true, error: None });
+
+    let (status, combined): (_, CombineResponse) = post("/combine", json(&quorum)).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(combined.secret, "68656c6c6f");
+
+    let mut edited = quorum.clone();
+    edited.shares[0].value.replace_range(..2, "00");
+    let (status, verified): (_, VerifyResponse) = post("/verify", json(&edited)).await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(!verified.valid);
+    assert!(verified.error.is_some());
+}
+
+#[tokio::test]
+async fn test_error_status_codes() {
+    let (status, err): (_, ErrorResponse) = post("/split", "{\"secret\": 5}".into()).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert!(!err.error.is_empty());
+
+    let request = SplitRequest { secret: "zz".into(), threshold: 2, shares: 3 };
+    let (status, _): (_, ErrorResponse) = post("/split", json(&request)).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    let request = SplitRequest { secret: "00".into(), threshold: 1, shares: 3 };
+    let (status, _): (_, ErrorResponse) = post("/split", json(&request)).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    let request = SplitRequest { secret: "00".into(), threshold: 3, shares: 3 };
+    let (_, split): (_, SplitResponse) = post("/split", json(&request)).await;
+    let short = SharesRequest { shares: split.shares[..2].to_vec() };
+    let (status, err): (_, ErrorResponse) = post("/combine", json(&short)).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert!(err.error.contains("required"));
+}
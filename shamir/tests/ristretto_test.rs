@@ -0,0 +1,59 @@
+#![cfg(feature = "ristretto")]
+
+use curve25519_dalek::Scalar;
+use rand::thread_rng;
+use shamir::algos::ristretto;
+use shamir::algos::share::Share;
+use shamir::algos::vss::VssError;
+use shamir::algos::wire;
+
+#[test]
+fn test_ristretto_vss_roundtrip() {
+    let mut rng = thread_rng();
+    let secret = Scalar::from(0xdead_beef_u64);
+    let coeffs = ristretto::generate_polynomial(secret, 3, &mut rng).expect("Failed to generate polynomial");
+    let shares = ristretto::generate_shares(&coeffs, 5, &mut rng).expect("Failed to generate shares");
+    let commitments = ristretto::generate_commitments(&coeffs);
+    assert!(shares.iter().all(|share| ristretto::verify_share(share, &commitments)));
+
+    let subset = vec![shares[4].clone(), shares[1].clone(), shares[3].clone()];
+    assert_eq!(ristretto::reconstruct_secret(&subset).expect("Failed to reconstruct secret"), secret);
+    assert!(matches!(ristretto::reconstruct_secret(&shares[..2]), Err(VssError::InsufficientShares { .. })));
+}
+
+#[test]
+fn test_ristretto_rejects_tampered_share() {
+    let mut rng = thread_rng();
+    let coeffs = ristretto::generate_polynomial(Scalar::from(7u64), 2, &mut rng).expect("Failed to generate polynomial");
+    let mut shares = ristretto::generate_shares(&coeffs, 3, &mut rng).expect("Failed to generate shares");
+    let commitments = ristretto::generate_commitments(&coeffs);
+    shares[0].value += Scalar::ONE;
+    assert!(!ristretto::verify_share(&shares[0], &commitments));
+    shares[1].index = 3;
+    assert!(!ristretto::verify_share(&shares[1], &commitments));
+}
+
+#[test]
+fn test_ristretto_share_encodings() {
+    let mut rng = thread_rng();
+    let coeffs = ristretto::generate_polynomial(Scalar::from(99u64), 2, &mut rng).expect("Failed to generate polynomial");
+    let shares = ristretto::generate_shares(&coeffs, 2, &mut rng).expect("Failed to generate shares");
+    let parsed: Share<Scalar> = shares[0].to_string().parse().expect("Failed to parse share");
+    assert_eq!(parsed, shares[0]);
+    let decoded: Share<Scalar> = wire::decode(&wire::encode(&shares[1])).expect("Failed to decode share");
+    assert_eq!(decoded, shares[1]);
+}
+
+#[test]
+fn test_ristretto_rejects_bad_indices() {
+    let mut rng = thread_rng();
+    let coeffs = ristretto::generate_polynomial(Scalar::from(5u8), 2, &mut rng).expect("Failed to generate polynomial");
+    let shares = ristretto::generate_shares(&coeffs, 3, &mut rng).expect("Failed to generate shares");
+    let mut forged = shares[0].clone();
+    forged.index = 0;
+    assert!(matches!(ristretto::reconstruct_secret(&[forged, shares[1].clone()]), Err(VssError::ZeroIndex)));
+    assert!(matches!(
+        ristretto::reconstruct_secret(&[shares[1].clone(), shares[1].clone()]),
+        Err(VssError::DuplicateIndex)
+    ));
+}
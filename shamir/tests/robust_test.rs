@@ -0,0 +1,67 @@
+use rand::thread_rng;
+use shamir::algos::robust;
+use shamir::algos::sss::{self, ShamirError, PRIME};
+
+#[test]
+fn test_no_corruption() {
+    let shares = sss::generate_shares(4321, 3, 7, &mut thread_rng()).expect("Failed to generate shares");
+    let result = robust::reconstruct_robust(&shares).expect("Failed to reconstruct");
+    assert_eq!(result.secret, 4321);
+    assert!(result.corrupted.is_empty());
+}
+
+#[test]
+fn test_corrects_up_to_half_the_redundancy() {
+    // n = 9, t = 3 tolerates (9 - 3) / 2 = 3 corrupted shares.
+    let mut shares = sss::generate_shares(987654, 3, 9, &mut thread_rng()).expect("Failed to generate shares");
+    for i in [1, 4, 8] {
+        shares[i].value = (shares[i].value + 1000 * i as u64) % PRIME;
+    }
+    let result = robust::reconstruct_robust(&shares).expect("Failed to reconstruct");
+    assert_eq!(result.secret, 987654);
+    assert_eq!(result.corrupted, vec![2, 5, 9]);
+}
+
+#[test]
+fn test_too_many_errors() {
+    let mut shares = sss::generate_shares(5, 3, 5, &mut thread_rng()).expect("Failed to generate shares");
+    for share in shares.iter_mut().take(2) {
+        share.value = (share.value + 1) % PRIME;
+    }
+    assert!(matches!(
+        robust::reconstruct_robust(&shares),
+        Err(ShamirError::InconsistentShares)
+    ));
+}
+
+#[test]
+fn test_consensus_outvotes_corrupted_shares() {
+    // Berlekamp-Welch handles (6 - 3) / 2 = 1 error here; the vote still finds the four good shares.
+    let mut shares = sss::generate_shares(2468, 3, 6, &mut thread_rng()).expect("Failed to generate shares");
+    shares[0].value = (shares[0].value + 1) % PRIME;
+    shares[4].value = (shares[4].value + 2) % PRIME;
+    let result = robust::reconstruct_consensus(&shares).expect("Failed to reconstruct");
+    assert_eq!(result.secret, 2468);
+    assert_eq!(result.corrupted, vec![1, 5]);
+}
+
+#[test]
+fn test_consensus_rejects_ties() {
+    // With t + 1 shares and one of them bad, every subset is supported by itself alone.
+    let mut shares = sss::generate_shares(77, 2, 3, &mut thread_rng()).expect("Failed to generate shares");
+    shares[2].value = (shares[2].value + 1) % PRIME;
+    assert!(matches!(robust::reconstruct_consensus(&shares), Err(ShamirError::InconsistentShares)));
+
+    let result = robust::reconstruct_consensus(&shares[..2]).expect("Failed to reconstruct");
+    assert_eq!(result.secret, 77);
+    assert!(result.corrupted.is_empty());
+}
+
+#[test]
+fn test_robust_rejects_bad_indices() {
+    let mut shares = sss::generate_shares(5, 2, 4, &mut thread_rng()).expect("Failed to generate shares");
+    shares[3].index = shares[1].index + PRIME;
+    assert!(matches!(robust::reconstruct_robust(&shares), Err(ShamirError::DuplicateIndex)));
+    shares[3].index = PRIME;
+    assert!(matches!(robust::reconstruct_robust(&shares), Err(ShamirError::ZeroIndex)));
+}
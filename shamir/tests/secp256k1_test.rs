@@ -0,0 +1,47 @@
+use rand::thread_rng;
+use shamir::algos::secp256k1;
+use shamir::algos::sss::ShamirError;
+
+fn hex_key(hex: &str) -> [u8; 32] {
+    let mut key = [0u8; 32];
+    for (i, byte) in key.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&hex[2 * i..2 * i + 2], 16).expect("valid hex");
+    }
+    key
+}
+
+#[test]
+fn test_split_known_keys() {
+    let keys = [
+        // BIP-32 test vector 1 master private key.
+        "e8f32e723decf4051aefac8e2c93c9c5b214313817cdb01a1494b917c8436b35",
+        "0000000000000000000000000000000000000000000000000000000000000001",
+        // n - 1, the largest valid private key.
+        "fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364140",
+    ];
+    for hex in keys {
+        let key = hex_key(hex);
+        let shares = secp256k1::split_key(&key, 3, 5, &mut thread_rng()).expect("Failed to split key");
+        let combined = secp256k1::combine_key(&shares[1..4]).expect("Failed to combine key");
+        assert_eq!(*combined, key);
+    }
+}
+
+#[test]
+fn test_rejects_out_of_range_keys() {
+    let zero = [0u8; 32];
+    let order = hex_key(secp256k1::ORDER_HEX);
+    for key in [zero, order, [0xffu8; 32]] {
+        assert!(matches!(
+            secp256k1::split_key(&key, 2, 3, &mut thread_rng()),
+            Err(ShamirError::SecretOutOfRange)
+        ));
+    }
+}
+
+#[test]
+fn test_scalar_bytes_round_trip() {
+    let key = hex_key("00000000000000000000000000000000000000000000000000000000deadbeef");
+    let scalar = secp256k1::scalar_from_bytes(&key).expect("Valid scalar");
+    assert_eq!(secp256k1::scalar_to_bytes(&scalar).expect("Scalar fits"), key);
+}
@@ -0,0 +1,42 @@
+#![cfg(feature = "serde")]
+
+use rand::thread_rng;
+use shamir::algos::share::Share;
+use shamir::algos::{sss, vss};
+
+#[test]
+fn test_share_json_round_trip() {
+    let shares = sss::generate_shares(4242, 3, 5, &mut thread_rng()).expect("Failed to generate shares");
+    let json = serde_json::to_string(&shares).expect("Failed to serialize shares");
+    let decoded: Vec<Share> = serde_json::from_str(&json).expect("Failed to deserialize shares");
+    assert_eq!(decoded, shares);
+    assert_eq!(sss::reconstruct_secret(&decoded).expect("Failed to reconstruct"), 4242);
+}
+
+#[test]
+fn test_byte_share_json_round_trip() {
+    let shares = sss::split_bytes(b"serde", 2, 3, &mut thread_rng()).expect("Failed to split bytes");
+    let json = serde_json::to_string(&shares[0]).expect("Failed to serialize share");
+    let decoded: Share<Vec<u8>> = serde_json::from_str(&json).expect("Failed to deserialize share");
+    assert_eq!(decoded, shares[0]);
+}
+
+#[test]
+fn test_vss_dealing_json_round_trip() {
+    let config = vss::VssConfig::default();
+    let mut rng = thread_rng();
+    let coeffs = vss::generate_polynomial(99, &config, &mut rng);
+    let shares = vss::generate_shares(&coeffs, &config, &mut rng);
+    let commitments = vss::generate_commitments(&coeffs, &config);
+
+    let json = serde_json::to_string(&(config, &commitments, &shares[0])).expect("Failed to serialize");
+    let (config, commitments, share): (vss::VssConfig, Vec<i128>, Share<i128>) =
+        serde_json::from_str(&json).expect("Failed to deserialize");
+    assert!(vss::verify_share(&share, &commitments, &config));
+}
+
+#[test]
+fn test_invalid_config_is_rejected() {
+    let json = r#"{"prime":4007,"generator":4006,"threshold":3,"shares":5}"#;
+    assert!(serde_json::from_str::<vss::VssConfig>(json).is_err());
+}
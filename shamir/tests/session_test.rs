@@ -0,0 +1,85 @@
+use rand::thread_rng;
+use shamir::algos::complaint::{DisqualifyReason, Dispute, Verdict};
+use shamir::algos::session::{DealerSession, DealerState, Participant, ParticipantState};
+use shamir::algos::vss::{self, VssConfig, VssError};
+
+const DEALER: u64 = 9;
+
+fn participants(config: &VssConfig) -> Vec<Participant> {
+    (1..=config.shares() as u64)
+        .map(|index| Participant::new(index, DEALER, *config).expect("Failed to create participant"))
+        .collect()
+}
+
+#[test]
+fn test_complaint_round_recovers_share() {
+    let config = VssConfig::default();
+    let (mut dealer, dealing) = DealerSession::deal(DEALER, 321, config, &mut thread_rng()).expect("Failed to deal");
+    let mut parties = participants(&config);
+
+    // Participant 2's share is lost in transit.
+    let mut complaints = Vec::new();
+    for (party, share) in parties.iter_mut().zip(&dealing.shares) {
+        let share = (party.index() != 2).then(|| share.clone());
+        if let Some(complaint) = party.receive(share, dealing.commitments.clone()).expect("Failed to receive") {
+            complaints.push(complaint);
+        }
+    }
+    assert_eq!(parties[1].state(), ParticipantState::Complained);
+    assert_eq!(parties[0].state(), ParticipantState::Verified);
+
+    for complaint in &complaints {
+        dealer.file(complaint).expect("Failed to file complaint");
+    }
+    let responses = dealer.answer().expect("Failed to answer");
+    assert_eq!(responses.len(), 1);
+
+    // Every participant reaches the same verdict from the public messages.
+    let mut dispute = Dispute::new(DEALER, dealing.commitments.clone(), config).expect("Failed to open dispute");
+    complaints.iter().try_for_each(|complaint| dispute.file(complaint)).expect("Failed to file");
+    dispute.close().expect("Failed to close");
+    responses.into_iter().try_for_each(|response| dispute.respond(response)).expect("Failed to respond");
+    let verdict = dispute.resolve().expect("Failed to resolve");
+    assert_eq!(dealer.finalize().expect("Failed to finalize dealer"), verdict);
+    assert_eq!(dealer.state(), DealerState::Finalized);
+
+    let shares: Vec<_> = parties
+        .iter_mut()
+        .map(|party| party.finalize(&verdict).expect("Failed to finalize").expect("dealer disqualified"))
+        .collect();
+    assert!(parties.iter().all(|party| party.state() == ParticipantState::Finalized));
+    assert_eq!(vss::reconstruct_secret(&shares[1..4], &config).expect("Failed to reconstruct"), 321);
+}
+
+#[test]
+fn test_too_many_complaints_reveal_nothing() {
+    let config = VssConfig::default();
+    let (mut dealer, dealing) = DealerSession::deal(DEALER, 5, config, &mut thread_rng()).expect("Failed to deal");
+    let mut parties = participants(&config);
+    for party in &mut parties[..config.threshold()] {
+        let complaint = party.receive(None, dealing.commitments.clone()).expect("Failed to receive");
+        dealer.file(&complaint.expect("Failed to complain")).expect("Failed to file complaint");
+    }
+    assert!(dealer.answer().expect("Failed to answer").is_empty());
+    let verdict = dealer.finalize().expect("Failed to finalize");
+    assert_eq!(verdict, Verdict::Disqualified(DisqualifyReason::TooManyComplaints));
+    assert_eq!(parties[0].finalize(&verdict).expect("Failed to finalize"), None);
+}
+
+#[test]
+fn test_out_of_order_transitions() {
+    let config = VssConfig::default();
+    let (mut dealer, dealing) = DealerSession::deal(DEALER, 5, config, &mut thread_rng()).expect("Failed to deal");
+    assert!(matches!(dealer.finalize(), Err(VssError::InvalidState)));
+
+    let mut party = Participant::new(1, DEALER, config).expect("Failed to create participant");
+    let verdict = Verdict::Qualified { revealed: Vec::new() };
+    assert!(matches!(party.finalize(&verdict), Err(VssError::InvalidState)));
+    assert!(matches!(party.receive(None, Vec::new()), Err(VssError::InvalidParameters("commitments"))));
+    party.receive(Some(dealing.shares[0].clone()), dealing.commitments.clone()).expect("Failed to receive");
+    assert!(matches!(party.receive(None, dealing.commitments.clone()), Err(VssError::InvalidState)));
+
+    dealer.answer().expect("Failed to answer");
+    assert!(matches!(dealer.answer(), Err(VssError::InvalidState)));
+    assert!(Participant::new(0, DEALER, config).is_err());
+}
@@ -0,0 +1,43 @@
+#![cfg(feature = "json")]
+
+use std::fs;
+
+use rand::thread_rng;
+use shamir::algos::sharefile;
+use shamir::algos::sss::ShamirError;
+
+#[test]
+fn test_share_files_round_trip() {
+    let dir = std::env::temp_dir().join(format!("sharefile-test-{}", std::process::id()));
+    let shares = shamir::split(b"json files", 3, 5, &mut thread_rng()).expect("Failed to split secret");
+    let paths = sharefile::write_files(&dir, &shares).expect("Failed to write share files");
+    assert_eq!(paths[0].file_name().and_then(|name| name.to_str()), Some("share-01.json"));
+
+    let read = sharefile::read_files(&[&paths[4], &paths[0], &paths[2]]).expect("Failed to read share files");
+    assert_eq!(&shamir::combine(&read).expect("Failed to combine shares")[..], b"json files");
+    fs::remove_dir_all(&dir).expect("Failed to remove temp dir");
+}
+
+#[test]
+fn test_mixed_dealings_rejected() {
+    let mut rng = thread_rng();
+    let first = sharefile::from_shares(&shamir::split(b"one", 2, 3, &mut rng).expect("Failed to split secret"));
+    let second = sharefile::from_shares(&shamir::split(b"two", 2, 3, &mut rng).expect("Failed to split secret"));
+    assert!(matches!(
+        sharefile::to_shares(&[first[0].clone(), second[1].clone()]),
+        Err(ShamirError::InconsistentShares)
+    ));
+}
+
+#[test]
+fn test_edited_value_rejected() {
+    let shares = shamir::split(b"edit", 2, 3, &mut thread_rng()).expect("Failed to split secret");
+    let mut files = sharefile::from_shares(&shares);
+    files[1].value.replace_range(0..2, if shares[1].value[0] == 0 { "01" } else { "00" });
+    assert!(matches!(sharefile::to_shares(&files[..2]), Err(ShamirError::InconsistentShares)));
+
+    let json = serde_json::to_string(&files[0]).expect("Failed to serialize share file");
+    let mut parsed: sharefile::ShareFile = serde_json::from_str(&json).expect("Failed to parse share file");
+    parsed.version = 2;
+    assert!(matches!(parsed.to_share(), Err(ShamirError::InvalidParameters("version"))));
+}
@@ -0,0 +1,47 @@
+use rand::thread_rng;
+use shamir::algos::complaint::{Complaint, DisqualifyReason, Verdict};
+use shamir::algos::sim::{COORDINATOR, DEALER, Fault, FaultKind, Phase, Simulation};
+use shamir::algos::vss::{RejectReason, Rejection, VssConfig};
+
+fn fault(phase: Phase, from: u64, to: u64, kind: FaultKind) -> Fault {
+    Fault { phase, from, to, kind }
+}
+
+#[test]
+fn test_honest_run() {
+    let report = Simulation::new(VssConfig::default()).run(1500, &mut thread_rng()).expect("Failed to simulate");
+    assert!(report.complaints.is_empty());
+    assert_eq!(report.verdict, Verdict::Qualified { revealed: Vec::new() });
+    let reconstruction = report.reconstruction.expect("Failed to reach reconstruction");
+    assert_eq!(reconstruction.secret, Some(1500));
+    assert_eq!(reconstruction.accepted, vec![1, 2, 3, 4, 5]);
+}
+
+#[test]
+fn test_faults_are_contained() {
+    let report = Simulation::new(VssConfig::default())
+        .with_fault(fault(Phase::Deal, DEALER, 2, FaultKind::Drop))
+        .with_fault(fault(Phase::Refresh, 3, 4, FaultKind::Corrupt))
+        .with_fault(fault(Phase::Refresh, 1, 5, FaultKind::Drop))
+        .with_fault(fault(Phase::Reconstruct, 5, COORDINATOR, FaultKind::Corrupt))
+        .run(42, &mut thread_rng())
+        .expect("Failed to simulate");
+
+    assert_eq!(report.complaints, vec![Complaint { complainant: 2, dealer: DEALER }]);
+    assert!(matches!(report.verdict, Verdict::Qualified { ref revealed } if revealed.len() == 1));
+    assert_eq!(report.excluded_refreshes, vec![1, 3]);
+    let reconstruction = report.reconstruction.expect("Failed to reach reconstruction");
+    assert_eq!(reconstruction.secret, Some(42));
+    assert_eq!(reconstruction.rejected, vec![Rejection { index: 5, reason: RejectReason::VerificationFailed }]);
+}
+
+#[test]
+fn test_cheating_dealer_is_caught() {
+    let report = Simulation::new(VssConfig::default())
+        .with_fault(fault(Phase::Deal, DEALER, 2, FaultKind::Corrupt))
+        .with_fault(fault(Phase::Response, DEALER, 2, FaultKind::Corrupt))
+        .run(7, &mut thread_rng())
+        .expect("Failed to simulate");
+    assert_eq!(report.verdict, Verdict::Disqualified(DisqualifyReason::InvalidResponse(2)));
+    assert_eq!(report.reconstruction, None);
+}
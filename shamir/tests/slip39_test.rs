@@ -0,0 +1,82 @@
+use rand::thread_rng;
+use shamir::algos::slip39::{self, Slip39Share};
+use shamir::algos::sss::ShamirError;
+
+fn vectors() -> Vec<(String, Vec<String>, String)> {
+    serde_json::from_str(include_str!("fixtures/slip39_vectors.json")).expect("Valid vector file")
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+#[test]
+fn test_reference_vectors() {
+    for (description, mnemonics, secret) in vectors() {
+        let result = slip39::combine_mnemonics(&mnemonics, b"TREZOR");
+        if secret.is_empty() {
+            assert!(result.is_err(), "{} should fail", description);
+        } else {
+            let recovered = result.unwrap_or_else(|e| panic!("{} failed: {:?}", description, e));
+            assert_eq!(hex(&recovered), secret, "{}", description);
+        }
+    }
+}
+
+#[test]
+fn test_mnemonic_round_trip() {
+    for (_, mnemonics, secret) in vectors() {
+        if secret.is_empty() {
+            continue;
+        }
+        for mnemonic in mnemonics {
+            let share = Slip39Share::from_mnemonic(&mnemonic).expect("Valid mnemonic");
+            assert_eq!(share.to_mnemonic(), mnemonic);
+        }
+    }
+}
+
+#[test]
+fn test_generate_two_level_sharing() {
+    let secret = b"0123456789abcdef0123456789abcdef";
+    let groups = [(1, 1), (2, 3), (3, 5)];
+    let mnemonics = slip39::generate_mnemonics(2, &groups, secret, b"pass", false, 0, &mut thread_rng())
+        .expect("Failed to generate mnemonics");
+    assert_eq!(mnemonics.iter().map(Vec::len).collect::<Vec<_>>(), vec![1, 3, 5]);
+
+    let chosen = vec![
+        mnemonics[0][0].clone(),
+        mnemonics[2][4].clone(),
+        mnemonics[2][0].clone(),
+        mnemonics[2][2].clone(),
+    ];
+    let recovered = slip39::combine_mnemonics(&chosen, b"pass").expect("Failed to combine");
+    assert_eq!(&recovered[..], secret);
+
+    // A different passphrase silently yields a different secret, as the spec intends.
+    let other = slip39::combine_mnemonics(&chosen, b"other").expect("Failed to combine");
+    assert_ne!(&other[..], secret);
+    assert!(matches!(
+        slip39::combine_mnemonics(&chosen[..3], b"pass"),
+        Err(ShamirError::InsufficientShares { .. })
+    ));
+}
+
+#[test]
+fn test_extendable_sharing() {
+    let secret = [0x42u8; 16];
+    let mnemonics = slip39::generate_mnemonics(1, &[(2, 3)], &secret, b"", true, 1, &mut thread_rng())
+        .expect("Failed to generate mnemonics");
+    let share = Slip39Share::from_mnemonic(&mnemonics[0][0]).expect("Valid mnemonic");
+    assert!(share.extendable);
+    let recovered = slip39::combine_mnemonics(&mnemonics[0][1..], b"").expect("Failed to combine");
+    assert_eq!(*recovered, secret);
+}
+
+#[test]
+fn test_rejects_invalid_parameters() {
+    let mut rng = thread_rng();
+    assert!(slip39::generate_mnemonics(1, &[(1, 1)], &[0u8; 15], b"", false, 0, &mut rng).is_err());
+    assert!(slip39::generate_mnemonics(1, &[(1, 2)], &[0u8; 16], b"", false, 0, &mut rng).is_err());
+    assert!(slip39::generate_mnemonics(2, &[(1, 1)], &[0u8; 16], b"", false, 0, &mut rng).is_err());
+}
@@ -1,10 +1,16 @@
+use zeroize::Zeroize;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use rand::thread_rng;
+use shamir::algos::field::PrimeField64;
+use shamir::algos::share::{ParseShareError, Share, StalePolicy};
 use shamir::algos::sss;
 
 #[test]
 fn test_generate_polynomial() {
     let secret = 1234;
     let threshold = 3;
-    let poly = sss::generate_polynomial(secret, threshold)
+    let poly = sss::generate_polynomial(secret, threshold, &mut thread_rng())
         .expect("Failed to generate polynomial");
     // The constant term must equal the secret.
     assert_eq!(poly[0], secret);
@@ -27,7 +33,7 @@ fn test_generate_shares() {
     let secret = 9876;
     let threshold = 3;
     let num_shares = 5;
-    let shares = sss::generate_shares(secret, threshold, num_shares)
+    let shares = sss::generate_shares(secret, threshold, num_shares, &mut thread_rng())
         .expect("Failed to generate shares");
     // The number of generated shares should match `num_shares`.
     assert_eq!(shares.len(), num_shares);
@@ -38,9 +44,306 @@ fn test_reconstruct_secret() {
     let secret = 7777;
     let threshold = 3;
     let num_shares = 5;
-    let shares = sss::generate_shares(secret, threshold, num_shares)
+    let shares = sss::generate_shares(secret, threshold, num_shares, &mut thread_rng())
         .expect("Failed to generate shares");
-    let reconstructed = sss::reconstruct_secret(&shares[..threshold], threshold)
+    let reconstructed = sss::reconstruct_secret(&shares[..threshold])
         .expect("Failed to reconstruct secret");
     assert_eq!(reconstructed, secret);
 }
+
+#[test]
+fn test_split_and_combine_bytes() {
+    for len in [0, 1, 16, 32, 1000] {
+        let secret: Vec<u8> = (0..len).map(|i| (i * 7 + 3) as u8).collect();
+        let shares = sss::split_bytes(&secret, 3, 5, &mut thread_rng()).expect("Failed to split bytes");
+        assert_eq!(shares.len(), 5);
+        let combined = sss::combine_bytes(&shares[2..]).expect("Failed to combine bytes");
+        assert_eq!(*combined, secret);
+    }
+}
+
+#[test]
+fn test_combine_bytes_below_threshold() {
+    let secret = vec![0xa5u8; 64];
+    let shares = sss::split_bytes(&secret, 4, 6, &mut thread_rng()).expect("Failed to split bytes");
+    assert!(matches!(
+        sss::combine_bytes(&shares[..3]),
+        Err(sss::ShamirError::InsufficientShares { .. })
+    ));
+}
+
+#[test]
+fn test_share_metadata() {
+    let shares = sss::generate_shares(31337, 2, 4, &mut thread_rng()).expect("Failed to generate shares");
+    for (i, share) in shares.iter().enumerate() {
+        assert_eq!(share.index, i as u64 + 1);
+        assert_eq!(share.threshold, 2);
+        assert_eq!(share.scheme_id, shares[0].scheme_id);
+        assert_eq!(share.total, 4);
+    }
+    let text = shares[0].to_string();
+    assert!(text.starts_with(&format!("{:08x}-2/4#{:016x}-1-", shares[0].scheme_id, shares[0].fingerprint)));
+    assert_eq!(text.parse::<Share>().expect("Failed to parse share"), shares[0]);
+
+    // Shares written before the total and fingerprint were recorded still parse, with both unknown.
+    let mut old = shares[1].clone();
+    old.total = 0;
+    old.fingerprint = 0;
+    assert!(old.to_string().starts_with(&format!("{:08x}-2-2-", old.scheme_id)));
+    assert_eq!(sss::reconstruct_secret(&[old, shares[2].clone()]).expect("Failed to reconstruct"), 31337);
+}
+
+#[test]
+fn test_reconstruct_rejects_mixed_dealings() {
+    let first = sss::generate_shares(1, 2, 3, &mut thread_rng()).expect("Failed to generate shares");
+    let mut second = sss::generate_shares(2, 2, 3, &mut thread_rng()).expect("Failed to generate shares");
+    second[1].scheme_id = first[0].scheme_id.wrapping_add(1);
+    let mixed = vec![first[0].clone(), second[1].clone()];
+    assert!(matches!(
+        sss::reconstruct_secret(&mixed),
+        Err(sss::ShamirError::InconsistentShares)
+    ));
+
+    // Even when the scheme ids collide, the dealings' fingerprints tell them apart.
+    second[1].scheme_id = first[0].scheme_id;
+    assert_ne!(first[0].fingerprint, second[1].fingerprint);
+    let mixed = vec![first[0].clone(), second[1].clone()];
+    assert!(matches!(sss::reconstruct_secret(&mixed), Err(sss::ShamirError::InconsistentShares)));
+}
+
+#[test]
+fn test_share_string_round_trip() {
+    let shares = sss::split_bytes(b"hunter2", 2, 3, &mut thread_rng()).expect("Failed to split bytes");
+    let parsed: Vec<Share<Vec<u8>>> = shares
+        .iter()
+        .map(|share| share.to_string().parse().expect("Failed to parse share"))
+        .collect();
+    assert_eq!(parsed, shares);
+    assert!("not-a-share".parse::<Share<Vec<u8>>>().is_err());
+    assert!("0000abcd-2-1-abc".parse::<Share<Vec<u8>>>().is_err());
+}
+
+#[test]
+fn test_seeded_rng_is_deterministic() {
+    let mut first = StdRng::seed_from_u64(7);
+    let mut second = StdRng::seed_from_u64(7);
+    let a = sss::generate_shares(555, 3, 5, &mut first).expect("Failed to generate shares");
+    let b = sss::generate_shares(555, 3, 5, &mut second).expect("Failed to generate shares");
+    assert_eq!(a, b);
+    let a = sss::split_bytes(b"seeded", 2, 3, &mut first).expect("Failed to split bytes");
+    let b = sss::split_bytes(b"seeded", 2, 3, &mut second).expect("Failed to split bytes");
+    assert_eq!(a, b);
+}
+
+#[test]
+fn test_share_zeroize() {
+    let mut shares = sss::split_bytes(b"wipe me", 2, 2, &mut thread_rng()).expect("Failed to split bytes");
+    shares[0].zeroize();
+    assert!(shares[0].value.is_empty());
+    assert_eq!(shares[0].index, 0);
+    let mut coeffs = sss::generate_polynomial(99, 3, &mut thread_rng()).expect("Failed to generate polynomial");
+    coeffs.zeroize();
+    assert!(coeffs.is_empty());
+}
+
+#[test]
+fn test_share_string_with_epoch() {
+    let mut share = sss::generate_shares(10, 2, 2, &mut thread_rng()).expect("Failed to generate shares")[0].clone();
+    share.epoch = 3;
+    let text = share.to_string();
+    assert!(text.starts_with(&format!("{:08x}.3-", share.scheme_id)));
+    assert_eq!(text.parse::<Share>().expect("Failed to parse share"), share);
+}
+
+#[test]
+fn test_share_checksum_catches_typos() {
+    let share = sss::split_bytes(b"hunter2", 2, 3, &mut thread_rng()).expect("Failed to split bytes")[1].clone();
+    let text = share.to_string();
+    assert_eq!(text.to_uppercase().parse::<Share<Vec<u8>>>().expect("Failed to parse share"), share);
+
+    // Flip one hex digit of the value (the checksum is the last nine characters).
+    let mut typo = text.clone().into_bytes();
+    let pos = text.len() - 10;
+    typo[pos] = if typo[pos] == b'0' { b'1' } else { b'0' };
+    let typo = String::from_utf8(typo).expect("Failed to build typo");
+    assert_eq!(typo.parse::<Share<Vec<u8>>>(), Err(ParseShareError::Corrupted));
+
+    let (body, _) = text.rsplit_once('-').expect("Failed to split checksum");
+    assert_eq!(body.parse::<Share<Vec<u8>>>(), Err(ParseShareError::Malformed));
+}
+
+#[test]
+fn test_shares_at_custom_indices() {
+    let mut rng = thread_rng();
+    let xs = [sss::index_for(b"alice"), sss::index_for(b"bob"), sss::index_for(b"carol"), 1_000_000];
+    assert_eq!(sss::index_for(b"alice"), xs[0]);
+    let shares = sss::generate_shares_at(2024, 3, &xs, &mut rng).expect("Failed to generate shares");
+    assert_eq!(shares.iter().map(|share| share.index).collect::<Vec<_>>(), xs);
+    assert_eq!(sss::reconstruct_secret(&shares[1..]).expect("Failed to reconstruct secret"), 2024);
+
+    assert!(matches!(sss::generate_shares_at(1, 2, &[5, 0, 7], &mut rng), Err(sss::ShamirError::ZeroIndex)));
+    assert!(matches!(sss::generate_shares_at(1, 2, &[5, 7, 5], &mut rng), Err(sss::ShamirError::DuplicateIndex)));
+    assert!(matches!(sss::generate_shares_at(1, 2, &[5, sss::PRIME], &mut rng), Err(sss::ShamirError::InvalidParameters(_))));
+
+    let shares = sss::split_bytes_at(b"ids", 2, &[200, 17, 99], &mut rng).expect("Failed to split bytes");
+    assert_eq!(shares[0].index, 200);
+    assert_eq!(&sss::combine_bytes(&shares[..2]).expect("Failed to combine bytes")[..], b"ids");
+    assert!(matches!(sss::split_bytes_at(b"ids", 2, &[3, 3], &mut rng), Err(sss::ShamirError::DuplicateIndex)));
+}
+
+#[test]
+fn test_error_display_and_source() {
+    use std::error::Error;
+
+    let err = sss::reconstruct_secret(&[]).expect_err("Failed to reject empty share set");
+    assert_eq!(err.to_string(), "0 shares provided but 1 are required");
+    assert!(err.source().is_none());
+
+    let wrapped: Box<dyn Error> = Box::new(sss::ShamirError::from(shamir::vss::VssError::VerificationFailed { index: 4 }));
+    assert_eq!(
+        wrapped.to_string(),
+        "verifiable secret sharing failed: share 4 does not match the dealer's commitments"
+    );
+    assert!(wrapped.source().is_some_and(|source| source.is::<shamir::vss::VssError>()));
+}
+
+#[test]
+fn test_user_supplied_modulus() {
+    let mut rng = StdRng::seed_from_u64(59);
+    // 2^61 - 1 is a Mersenne prime.
+    let field = PrimeField64::checked((1 << 61) - 1).expect("Failed to accept prime modulus");
+    let secret = (1 << 60) + 12345;
+    let shares = sss::generate_shares_in(&field, secret, 3, 5, &mut rng).expect("Failed to generate shares");
+    assert_eq!(sss::reconstruct_secret_in(&field, &shares[2..]).expect("Failed to reconstruct secret"), secret);
+
+    assert!(matches!(PrimeField64::checked(3215031751), Err(sss::ShamirError::InvalidModulus)));
+    assert!(matches!(
+        sss::generate_shares_in(&field, field.modulus(), 3, 5, &mut rng),
+        Err(sss::ShamirError::SecretOutOfRange)
+    ));
+    assert!(matches!(sss::generate_shares(sss::PRIME, 2, 3, &mut rng), Err(sss::ShamirError::SecretOutOfRange)));
+}
+
+#[test]
+fn test_interpolate_at_matches_other_shares() {
+    let mut rng = StdRng::seed_from_u64(60);
+    let shares = sss::generate_shares(777, 3, 6, &mut rng).expect("Failed to generate shares");
+    for share in &shares[3..] {
+        assert_eq!(sss::interpolate_at(&shares[..3], share.index).expect("Failed to interpolate"), share.value);
+    }
+    assert_eq!(sss::interpolate_at(&shares[2..5], 0).expect("Failed to interpolate"), 777);
+
+    let mut forged = shares[..3].to_vec();
+    forged[1].value ^= 1;
+    assert_ne!(sss::interpolate_at(&forged, shares[4].index).expect("Failed to interpolate"), shares[4].value);
+}
+
+#[test]
+fn test_reconstruct_rejects_bad_indices() {
+    let mut rng = StdRng::seed_from_u64(71);
+    let shares = sss::generate_shares(99, 2, 3, &mut rng).expect("Failed to generate shares");
+    let duplicated = [shares[0].clone(), shares[0].clone()];
+    assert!(matches!(sss::reconstruct_secret(&duplicated), Err(sss::ShamirError::DuplicateIndex)));
+
+    // A share at x = 0 would be the secret; x = PRIME is the same point.
+    let mut zero = shares[1].clone();
+    zero.index = sss::PRIME;
+    assert!(matches!(sss::reconstruct_secret(&[shares[0].clone(), zero]), Err(sss::ShamirError::ZeroIndex)));
+
+    let bytes = sss::split_bytes(b"xy", 2, 3, &mut rng).expect("Failed to split bytes");
+    let mut at_zero = bytes[1].clone();
+    at_zero.index = 0;
+    assert!(matches!(sss::combine_bytes(&[bytes[0].clone(), at_zero]), Err(sss::ShamirError::ZeroIndex)));
+    assert!(matches!(
+        sss::combine_bytes(&[bytes[2].clone(), bytes[2].clone()]),
+        Err(sss::ShamirError::DuplicateIndex)
+    ));
+
+    // Past the threshold too: a share at zero carrying the secret, or a repeat of a quorum share.
+    let quorum = sss::split_bytes(b"secret", 3, 3, &mut rng).expect("Failed to split bytes");
+    let mut secret_share = quorum[0].clone();
+    secret_share.index = 0;
+    secret_share.value = b"secret".to_vec();
+    let mut with_zero = quorum.clone();
+    with_zero.push(secret_share);
+    assert!(matches!(sss::combine_bytes(&with_zero), Err(sss::ShamirError::ZeroIndex)));
+    let mut with_repeat = quorum.clone();
+    with_repeat.push(quorum[0].clone());
+    assert!(matches!(sss::combine_bytes(&with_repeat), Err(sss::ShamirError::DuplicateIndex)));
+}
+
+#[test]
+fn test_reconstruct_names_outliers() {
+    let mut rng = StdRng::seed_from_u64(72);
+    let shares = sss::generate_shares(4321, 3, 6, &mut rng).expect("Failed to generate shares");
+    assert_eq!(sss::reconstruct_secret(&shares).expect("Failed to reconstruct secret"), 4321);
+
+    // Shares past the threshold are checked against the first three, not ignored.
+    let mut tampered = shares.clone();
+    tampered[3].value = (tampered[3].value + 1) % sss::PRIME;
+    tampered[5].value = (tampered[5].value + 7) % sss::PRIME;
+    match sss::reconstruct_secret(&tampered) {
+        Err(sss::ShamirError::Outliers(indices)) => assert_eq!(indices, vec![4, 6]),
+        other => panic!("expected outliers, got {:?}", other),
+    }
+
+    let mut bytes = sss::split_bytes(b"secret", 2, 4, &mut rng).expect("Failed to split bytes");
+    bytes[2].value[0] ^= 1;
+    match sss::combine_bytes(&bytes) {
+        Err(sss::ShamirError::Outliers(indices)) => assert_eq!(indices, vec![3]),
+        other => panic!("expected outliers, got {:?}", other),
+    }
+    assert_eq!(sss::combine_bytes(&bytes[..2]).expect("Failed to combine bytes").as_slice(), b"secret");
+}
+
+#[test]
+fn test_combine_fresh_leaves_out_expired_shares() {
+    let mut shares = sss::split_bytes(b"ceremony", 2, 4, &mut thread_rng()).expect("Failed to split");
+    shares[0].valid_until = Some(100);
+    shares[1].valid_until = Some(300);
+    assert!(matches!(
+        sss::combine_fresh(&shares, Some(200), StalePolicy::Refuse),
+        Err(sss::ShamirError::ExpiredShares(indices)) if indices == vec![1]
+    ));
+    let (secret, staleness) = sss::combine_fresh(&shares, Some(200), StalePolicy::Warn).expect("Failed to combine");
+    assert_eq!(&secret[..], b"ceremony");
+    assert_eq!(staleness.expired, vec![1]);
+    assert!(staleness.stale.is_empty());
+    let (_, staleness) = sss::combine_fresh(&shares, None, StalePolicy::Refuse).expect("Failed to combine");
+    assert!(staleness.is_empty());
+}
+
+#[test]
+fn test_split_from_seed_is_reproducible() {
+    let shares = sss::split_bytes_from_seed(b"seeded", 2, 3, [7; 32]).expect("Failed to split");
+    let text: Vec<String> = shares.iter().map(ToString::to_string).collect();
+    assert_eq!(
+        text,
+        [
+            "789200f4-2/3#db43c145e5bd6407-1-2471c9e87156-d4881dff",
+            "789200f4-2/3#db43c145e5bd6407-2-dd4d20614d00-7a22b397",
+            "789200f4-2/3#db43c145e5bd6407-3-8a598ced5932-b4d7d296",
+        ]
+    );
+    assert_eq!(sss::split_bytes_from_seed(b"seeded", 2, 3, [7; 32]).expect("Failed to split"), shares);
+    assert_ne!(sss::split_bytes_from_seed(b"seeded", 2, 3, [8; 32]).expect("Failed to split"), shares);
+}
+
+#[test]
+fn test_split_str_normalizes_and_pads() {
+    let mut rng = thread_rng();
+    // "café" with a combining acute accent comes back precomposed.
+    let shares = sss::split_str("cafe\u{301} au lait", 2, 3, &mut rng).expect("Failed to split");
+    assert!(shares.iter().all(|share| share.value.len() == sss::STR_BLOCK));
+    let secret = sss::combine_to_string(&shares[1..]).expect("Failed to combine");
+    assert_eq!(secret.as_str(), "caf\u{e9} au lait");
+
+    let long = "x".repeat(sss::STR_BLOCK);
+    let shares = sss::split_str(&long, 2, 2, &mut rng).expect("Failed to split");
+    assert_eq!(shares[0].value.len(), 2 * sss::STR_BLOCK);
+    assert_eq!(*sss::combine_to_string(&shares).expect("Failed to combine"), long);
+
+    // Raw bytes that were never a padded string are refused.
+    let shares = sss::split_bytes(b"\xff\xff", 2, 2, &mut rng).expect("Failed to split");
+    assert!(matches!(sss::combine_to_string(&shares), Err(sss::ShamirError::InvalidString)));
+}
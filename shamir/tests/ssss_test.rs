@@ -0,0 +1,69 @@
+use rand::thread_rng;
+use shamir::algos::ssss::{self, SsssShare};
+
+// The (3,5) example from the ssss-split(1) manual page, made with the default diffusion layer.
+const MANPAGE_SHARES: [&str; 5] = [
+    "1-1c41ef496eccfbeba439714085df8437236298da8dd824",
+    "2-fbc74a03a50e14ab406c225afb5f45c40ae11976d2b665",
+    "3-fa1c3a9c6df8af0779c36de6c33f6e36e989d0e0b91309",
+    "4-468de7d6eb36674c9cf008c8e8fc8c566537ad6301eb9e",
+    "5-4756974923c0dce0a55f4774d09ca7a4865f64f56a4ee0",
+];
+
+#[test]
+fn test_ssss_combines_manpage_shares() {
+    let shares: Vec<SsssShare> = MANPAGE_SHARES.iter().map(|s| s.parse().expect("Failed to parse share")).collect();
+    for quorum in [[0, 1, 2], [4, 2, 0], [1, 3, 4]] {
+        let subset: Vec<SsssShare> = quorum.iter().map(|&i| shares[i].clone()).collect();
+        let secret = ssss::combine(&subset, 3, true).expect("Failed to combine shares");
+        assert_eq!(&secret[..], b"my secret root password");
+    }
+}
+
+#[test]
+fn test_ssss_roundtrip() {
+    let mut rng = thread_rng();
+    // Even and odd lengths above the diffusion cutoff, and short secrets that skip it.
+    for secret in [&b"correct horse battery staple"[..], b"0123456789abcde", b"pin", b"x"] {
+        for diffusion in [true, false] {
+            let shares = ssss::split(secret, 3, 6, diffusion, &mut rng).expect("Failed to split secret");
+            assert!(shares.iter().all(|share| share.value.len() == secret.len()));
+            let subset = vec![shares[5].clone(), shares[1].clone(), shares[3].clone()];
+            let combined = ssss::combine(&subset, 3, diffusion).expect("Failed to combine shares");
+            assert_eq!(&combined[..], secret);
+        }
+    }
+}
+
+#[test]
+fn test_ssss_share_format() {
+    let mut shares = ssss::split(b"hello", 2, 12, true, &mut thread_rng()).expect("Failed to split secret");
+    let lines = ssss::format_shares(&shares);
+    assert!(lines[0].starts_with("01-"));
+    assert!(lines[11].starts_with("12-"));
+    assert_eq!(lines[0].len(), 3 + 10);
+
+    shares[0].token = Some("backup".into());
+    let line = ssss::format_shares(&shares[..1]).remove(0);
+    assert!(line.starts_with("backup-1-"));
+    let parsed: SsssShare = line.parse().expect("Failed to parse share");
+    assert_eq!(parsed, shares[0]);
+
+    assert!("1-".parse::<SsssShare>().is_err());
+    assert!("0-abcd".parse::<SsssShare>().is_err());
+    assert!("x-abcd".parse::<SsssShare>().is_err());
+}
+
+#[test]
+fn test_ssss_rejects_bad_input() {
+    let mut rng = thread_rng();
+    assert!(ssss::split(b"", 2, 3, true, &mut rng).is_err());
+    assert!(ssss::split(&[0u8; 129], 2, 3, true, &mut rng).is_err());
+    // GF(2^8) only has 255 nonzero x-coordinates.
+    assert!(ssss::split(b"x", 2, 256, true, &mut rng).is_err());
+
+    let shares = ssss::split(b"secret", 3, 5, true, &mut rng).expect("Failed to split secret");
+    assert!(ssss::combine(&shares[..2], 3, true).is_err());
+    let duplicated = vec![shares[0].clone(), shares[0].clone(), shares[1].clone()];
+    assert!(ssss::combine(&duplicated, 3, true).is_err());
+}
@@ -0,0 +1,53 @@
+use rand::thread_rng;
+use shamir::algos::sss;
+use shamir::algos::store::{MemoryStore, ShareStore};
+
+// Returns the dealing whose shares 2 and 3 are left in the store.
+fn exercise<S: ShareStore<Vec<u8>>>(store: &mut S) -> u32
+where
+    S::Error: std::fmt::Debug,
+{
+    let mut rng = thread_rng();
+    let shares = sss::split_bytes(b"stored", 2, 3, &mut rng).expect("Failed to split");
+    let other = sss::split_bytes(b"other", 2, 2, &mut rng).expect("Failed to split");
+    let dealing = shares[0].scheme_id;
+    for share in shares.iter().chain(&other) {
+        store.put(share).expect("Failed to put");
+    }
+
+    assert_eq!(store.list(dealing).expect("Failed to list"), vec![1, 2, 3]);
+    assert_eq!(store.get(dealing, 2).expect("Failed to get").as_ref(), Some(&shares[1]));
+    assert_eq!(store.get(dealing, 4).expect("Failed to get"), None);
+    assert!(store.delete(dealing, 1).expect("Failed to delete"));
+    assert!(!store.delete(dealing, 1).expect("Failed to delete"));
+
+    let loaded = store.load(dealing).expect("Failed to load");
+    assert_eq!(&sss::combine_bytes(&loaded).expect("Failed to combine")[..], b"stored");
+    assert_eq!(store.load(other[0].scheme_id).expect("Failed to load"), other);
+    dealing
+}
+
+#[test]
+fn test_memory_store() {
+    exercise(&mut MemoryStore::new());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_file_store() {
+    use shamir::algos::store::FileStore;
+
+    let root = std::env::temp_dir().join(format!("shamir-store-{}", std::process::id()));
+    let mut store = FileStore::new(&root);
+    let dealing = exercise(&mut store);
+    assert_eq!(ShareStore::<Vec<u8>>::list(&store, 0).expect("Failed to list"), Vec::<u64>::new());
+
+    // A share edited on disk fails its checksum instead of coming back altered.
+    let path = root.join(format!("{:08x}", dealing)).join("2.share");
+    let text = std::fs::read_to_string(&path).expect("Failed to read share");
+    let (body, sum) = text.trim().rsplit_once('-').expect("no checksum");
+    let edited = format!("{}{}-{}", &body[..body.len() - 1], if body.ends_with('0') { '1' } else { '0' }, sum);
+    std::fs::write(&path, edited).expect("Failed to edit share");
+    assert!(ShareStore::<Vec<u8>>::get(&store, dealing, 2).is_err());
+    std::fs::remove_dir_all(&root).expect("Failed to clean up");
+}
@@ -0,0 +1,61 @@
+use std::io::{self, Read, Write};
+
+use rand::{thread_rng, RngCore};
+use shamir::algos::stream::{CombineReader, SplitWriter, CHUNK_SIZE};
+
+fn split(secret: &[u8], threshold: usize, count: usize) -> Vec<Vec<u8>> {
+    let mut writer = SplitWriter::new(vec![Vec::new(); count], threshold, thread_rng()).expect("Failed to create writer");
+    io::copy(&mut &secret[..], &mut writer).expect("Failed to stream secret");
+    writer.finish().expect("Failed to finish streams")
+}
+
+#[test]
+fn test_stream_round_trip_across_chunks() {
+    let mut secret = vec![0u8; 3 * CHUNK_SIZE + 17];
+    thread_rng().fill_bytes(&mut secret);
+    let outputs = split(&secret, 3, 5);
+    assert!(outputs.iter().all(|output| output.len() == secret.len() + 11));
+
+    let inputs = vec![&outputs[4][..], &outputs[1][..], &outputs[2][..]];
+    let mut recovered = Vec::new();
+    CombineReader::new(inputs).expect("Failed to open share streams").read_to_end(&mut recovered).expect("Failed to combine");
+    assert_eq!(recovered, secret);
+}
+
+#[test]
+fn test_empty_secret() {
+    let outputs = split(b"", 2, 2);
+    let mut recovered = Vec::new();
+    CombineReader::new(vec![&outputs[0][..], &outputs[1][..]])
+        .expect("Failed to open share streams")
+        .read_to_end(&mut recovered)
+        .expect("Failed to combine");
+    assert!(recovered.is_empty());
+}
+
+#[test]
+fn test_insufficient_and_mismatched_streams() {
+    let outputs = split(b"streaming", 3, 4);
+    assert!(CombineReader::new(vec![&outputs[0][..], &outputs[1][..]]).is_err());
+
+    let other = split(b"streaming", 3, 4);
+    assert!(CombineReader::new(vec![&outputs[0][..], &outputs[1][..], &other[2][..]]).is_err());
+
+    let truncated = &outputs[2][..outputs[2].len() - 1];
+    let mut reader = CombineReader::new(vec![&outputs[0][..], &outputs[1][..], truncated]).expect("Failed to open share streams");
+    assert!(reader.read_to_end(&mut Vec::new()).is_err());
+}
+
+#[test]
+fn test_writer_flushes_partial_writes() {
+    let mut writer = SplitWriter::new(vec![Vec::new(); 3], 2, thread_rng()).expect("Failed to create writer");
+    writer.write_all(b"ab").expect("Failed to write");
+    writer.write_all(b"cd").expect("Failed to write");
+    let outputs = writer.finish().expect("Failed to finish streams");
+    let mut recovered = String::new();
+    CombineReader::new(vec![&outputs[2][..], &outputs[0][..]])
+        .expect("Failed to open share streams")
+        .read_to_string(&mut recovered)
+        .expect("Failed to combine");
+    assert_eq!(recovered, "abcd");
+}
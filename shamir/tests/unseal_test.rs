@@ -0,0 +1,41 @@
+#![cfg(feature = "json")]
+
+use rand::thread_rng;
+use shamir::algos::sharefile;
+use shamir::algos::sss::{self, ShamirError};
+use shamir::algos::unseal::{Progress, Unseal};
+
+#[test]
+fn test_unseals_at_threshold() {
+    let shares = sss::split_bytes(b"root token", 3, 5, &mut thread_rng()).expect("Failed to split");
+    let mut unseal = Unseal::new();
+    assert_eq!(unseal.required(), None);
+    for (i, share) in shares[..2].iter().enumerate() {
+        let progress = unseal.submit(share.clone()).expect("Failed to submit");
+        assert!(matches!(progress, Progress::NeedMore { provided, required: 3 } if provided == i + 1));
+    }
+    // Handing in the same share twice doesn't count towards the threshold.
+    assert!(matches!(unseal.submit(shares[1].clone()), Err(ShamirError::DuplicateIndex)));
+    assert_eq!(unseal.provided(), 2);
+    match unseal.submit(shares[4].clone()).expect("Failed to submit") {
+        Progress::Unsealed(secret) => assert_eq!(&secret[..], b"root token"),
+        progress => panic!("still sealed: {:?}", progress),
+    }
+}
+
+#[test]
+fn test_refuses_shares_from_elsewhere() {
+    let mut rng = thread_rng();
+    let shares = sss::split_bytes(b"root token", 2, 3, &mut rng).expect("Failed to split");
+    let other = sss::split_bytes(b"root token", 2, 3, &mut rng).expect("Failed to split");
+    let commitments = sharefile::from_shares(&shares)[0].commitments.clone();
+
+    let mut unseal = Unseal::with_commitments(commitments);
+    assert!(matches!(unseal.submit(other[0].clone()), Err(ShamirError::InconsistentShares)));
+    assert!(matches!(unseal.submit(shares[0].clone()), Ok(Progress::NeedMore { provided: 1, required: 2 })));
+
+    let mut unseal = Unseal::new();
+    unseal.submit(shares[0].clone()).expect("Failed to submit");
+    assert!(matches!(unseal.submit(other[1].clone()), Err(ShamirError::InconsistentShares)));
+    assert!(matches!(unseal.submit(shares[2].clone()), Ok(Progress::Unsealed(_))));
+}
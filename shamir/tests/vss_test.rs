@@ -1,17 +1,19 @@
-use rand::thread_rng;
+use rand::rngs::StdRng;
+use rand::{thread_rng, Rng, SeedableRng};
 use shamir::algos::vss;
 
 #[test]
 fn test_verify_shares() {
     let secret = 1234;
+    let config = vss::VssConfig::default();
     let mut rng = thread_rng();
-    let coeffs = vss::generate_polynomial(secret, vss::THRESHOLD, &mut rng);
-    let shares = vss::generate_shares(&coeffs);
-    let commitments = vss::generate_commitments(&coeffs);
+    let coeffs = vss::generate_polynomial(secret, &config, &mut rng);
+    let shares = vss::generate_shares(&coeffs, &config, &mut rng);
+    let commitments = vss::generate_commitments(&coeffs, &config);
     for share in shares {
         assert!(
-            vss::verify_share(share, &commitments),
-            "Share {:?} failed verification",
+            vss::verify_share(&share, &commitments, &config),
+            "Share {} failed verification",
             share
         );
     }
@@ -20,9 +22,275 @@ fn test_verify_shares() {
 #[test]
 fn test_reconstruct_secret() {
     let secret = 1234;
+    let config = vss::VssConfig::default();
     let mut rng = thread_rng();
-    let coeffs = vss::generate_polynomial(secret, vss::THRESHOLD, &mut rng);
-    let shares = vss::generate_shares(&coeffs);
-    let recovered = vss::reconstruct_secret(&shares[0..vss::THRESHOLD]);
+    let coeffs = vss::generate_polynomial(secret, &config, &mut rng);
+    let shares = vss::generate_shares(&coeffs, &config, &mut rng);
+    let recovered = vss::reconstruct_secret(&shares[0..vss::THRESHOLD], &config)
+        .expect("Failed to reconstruct secret");
     assert_eq!(recovered, secret, "Reconstructed secret did not match original");
 }
+
+#[test]
+fn test_generate_custom_share_count() {
+    let secret = 42;
+    let config = vss::VssConfig::new(vss::P, vss::G, 4, 7).expect("Invalid config");
+    let mut rng = thread_rng();
+    let coeffs = vss::generate_polynomial(secret, &config, &mut rng);
+    let shares = vss::generate_shares(&coeffs, &config, &mut rng);
+    assert_eq!(shares.len(), 7);
+    let commitments = vss::generate_commitments(&coeffs, &config);
+    let mut share = shares[2].clone();
+    assert!(vss::verify_share(&share, &commitments, &config));
+    share.value = (share.value + 1) % vss::Q;
+    assert!(!vss::verify_share(&share, &commitments, &config));
+    assert_eq!(vss::reconstruct_secret(&shares[3..7], &config).expect("Failed to reconstruct"), secret);
+}
+
+#[test]
+fn test_custom_field() {
+    // p = 2 * 1019 + 1 is a safe prime and 4 = 2^2 lies in the order-1019 subgroup.
+    let config = vss::VssConfig::new(2039, 4, 2, 3).expect("Invalid config");
+    assert_eq!(config.order(), 1019);
+    let mut rng = thread_rng();
+    let coeffs = vss::generate_polynomial(777, &config, &mut rng);
+    let shares = vss::generate_shares(&coeffs, &config, &mut rng);
+    let commitments = vss::generate_commitments(&coeffs, &config);
+    assert!(shares.iter().all(|s| vss::verify_share(s, &commitments, &config)));
+    assert_eq!(vss::reconstruct_secret(&shares[1..3], &config).expect("Failed to reconstruct"), 777);
+}
+
+#[test]
+fn test_invalid_config() {
+    assert!(vss::VssConfig::new(vss::P, vss::G, 1, 5).is_err());
+    assert!(vss::VssConfig::new(vss::P, vss::G, 4, 3).is_err());
+    assert!(vss::VssConfig::new(4006, vss::G, 3, 5).is_err());
+    // -1 has order 2, not q.
+    assert!(matches!(
+        vss::VssConfig::new(vss::P, vss::P - 1, 3, 5),
+        Err(vss::VssError::InvalidParameters("generator"))
+    ));
+}
+
+#[test]
+fn test_reconstruct_errors() {
+    let config = vss::VssConfig::default();
+    let mut rng = thread_rng();
+    let coeffs = vss::generate_polynomial(5, &config, &mut rng);
+    let shares = vss::generate_shares(&coeffs, &config, &mut rng);
+    assert!(matches!(
+        vss::reconstruct_secret(&shares[..2], &config),
+        Err(vss::VssError::InsufficientShares { provided: 2, required: 3 })
+    ));
+    let duplicated = vec![shares[0].clone(), shares[0].clone(), shares[1].clone()];
+    assert!(matches!(
+        vss::reconstruct_secret(&duplicated, &config),
+        Err(vss::VssError::DuplicateIndex)
+    ));
+    // x = q is zero in the scalar field.
+    let mut zero = shares[2].clone();
+    zero.index = config.order() as u64;
+    assert!(matches!(
+        vss::reconstruct_secret(&[shares[0].clone(), shares[1].clone(), zero], &config),
+        Err(vss::VssError::ZeroIndex)
+    ));
+}
+
+#[test]
+fn test_verify_shares_reports_failure() {
+    let config = vss::VssConfig::default();
+    let mut rng = thread_rng();
+    let coeffs = vss::generate_polynomial(5, &config, &mut rng);
+    let mut shares = vss::generate_shares(&coeffs, &config, &mut rng);
+    let commitments = vss::generate_commitments(&coeffs, &config);
+    assert!(vss::verify_shares(&shares, &commitments, &config).is_ok());
+    shares[4].value += 1;
+    assert!(matches!(
+        vss::verify_shares(&shares, &commitments, &config),
+        Err(vss::VssError::VerificationFailed { index: 5 })
+    ));
+}
+
+#[test]
+fn test_shares_at_custom_indices() {
+    let config = vss::VssConfig::default();
+    let mut rng = thread_rng();
+    let coeffs = vss::generate_polynomial(77, &config, &mut rng);
+    let shares = vss::generate_shares_at(&coeffs, &config, &[11, 500, 1999, 42], &mut rng)
+        .expect("Failed to generate shares");
+    let commitments = vss::generate_commitments(&coeffs, &config);
+    assert!(shares.iter().all(|share| vss::verify_share(share, &commitments, &config)));
+    let recovered = vss::reconstruct_secret(&shares[1..], &config).expect("Failed to reconstruct secret");
+    assert_eq!(recovered, 77);
+
+    assert!(matches!(vss::generate_shares_at(&coeffs, &config, &[1, 2, 0], &mut rng), Err(vss::VssError::ZeroIndex)));
+    assert!(matches!(
+        vss::generate_shares_at(&coeffs, &config, &[1, 2, 2], &mut rng),
+        Err(vss::VssError::DuplicateIndex)
+    ));
+}
+
+#[test]
+fn test_reconstruct_verified_names_cheaters() {
+    let config = vss::VssConfig::default();
+    let mut rng = thread_rng();
+    let coeffs = vss::generate_polynomial(321, &config, &mut rng);
+    let mut shares = vss::generate_shares(&coeffs, &config, &mut rng);
+    let commitments = vss::generate_commitments(&coeffs, &config);
+    shares[1].value = (shares[1].value + 5) % config.order();
+    shares.push(shares[3].clone());
+
+    let result = vss::reconstruct_verified(&shares, &commitments, &config).expect("Failed to reconstruct secret");
+    assert_eq!(result.secret, Some(321));
+    assert_eq!(result.accepted, [1, 3, 4, 5]);
+    assert_eq!(
+        result.rejected,
+        [
+            vss::Rejection { index: 2, reason: vss::RejectReason::VerificationFailed },
+            vss::Rejection { index: 4, reason: vss::RejectReason::DuplicateIndex },
+        ]
+    );
+
+    let result = vss::reconstruct_verified(&shares[..3], &commitments, &config).expect("Failed to reconstruct secret");
+    assert_eq!(result.secret, None);
+    assert_eq!(result.accepted, [1, 3]);
+
+    // q + 1 is index 1 again in the exponent field.
+    let mut alias = shares[0].clone();
+    alias.index = config.order() as u64 + 1;
+    let aliased = [shares[0].clone(), alias.clone(), shares[2].clone(), shares[3].clone()];
+    let result = vss::reconstruct_verified(&aliased, &commitments, &config).expect("Failed to reconstruct secret");
+    assert_eq!(result.secret, Some(321));
+    assert_eq!(result.rejected, [vss::Rejection { index: alias.index, reason: vss::RejectReason::DuplicateIndex }]);
+}
+
+#[test]
+fn test_validate_params_reports_every_failure() {
+    assert_eq!(vss::validate_params(vss::P, vss::G, 3, 5), Ok(()));
+    assert_eq!(vss::validate_params(2039, 4, 2, 3), Ok(()));
+    // 2003 is prime but 1001 is not, so Z_2003* has subgroups of order 7, 11, 13, ...
+    assert_eq!(vss::validate_params(2003, 3, 3, 5), Err(vec![vss::ParamFailure::NotSafePrime]));
+    assert_eq!(vss::validate_params(4009, 2, 3, 5), Err(vec![vss::ParamFailure::PrimeComposite]));
+    // 5 is a non-residue mod 4007, so it has order 2q.
+    assert_eq!(
+        vss::validate_params(vss::P, 5, 1, 0),
+        Err(vec![
+            vss::ParamFailure::WrongGeneratorOrder,
+            vss::ParamFailure::ThresholdTooSmall,
+            vss::ParamFailure::TooFewShares
+        ])
+    );
+    assert_eq!(vss::validate_params(vss::P, 1, 3, 5), Err(vec![vss::ParamFailure::SmallOrderGenerator]));
+    assert_eq!(vss::validate_params(vss::P, vss::G, 3, 2003), Err(vec![vss::ParamFailure::TooManyShares]));
+    assert!(matches!(vss::VssConfig::new(2003, 3, 3, 5), Err(vss::VssError::InvalidParameters("prime"))));
+}
+
+#[test]
+fn test_interpolate_at() {
+    let config = vss::VssConfig::default();
+    let mut rng = thread_rng();
+    let coeffs = vss::generate_polynomial(99, &config, &mut rng);
+    let shares = vss::generate_shares(&coeffs, &config, &mut rng);
+    let last = &shares[shares.len() - 1];
+    let value = vss::interpolate_at(&shares[..config.threshold()], last.index, &config).expect("Failed to interpolate");
+    assert_eq!(value, last.value);
+    assert_eq!(vss::interpolate_at(&shares[1..], 0, &config).expect("Failed to interpolate"), 99);
+}
+
+#[test]
+fn test_verify_shares_batch() {
+    // p = 2 * 1019 + 1 again, now with enough shares that batching matters. A fixed seed keeps
+    // the 1/q chance of the tampered batch passing out of the test.
+    let config = vss::VssConfig::new(2039, 4, 5, 400).expect("Invalid config");
+    let mut rng = StdRng::seed_from_u64(62);
+    let coeffs = vss::generate_polynomial(31, &config, &mut rng);
+    let mut shares = vss::generate_shares(&coeffs, &config, &mut rng);
+    let commitments = vss::generate_commitments(&coeffs, &config);
+    vss::verify_shares_batch(&shares, &commitments, &config, &mut rng).expect("Failed to verify batch");
+
+    shares[250].value = (shares[250].value + 1) % config.order();
+    assert!(matches!(
+        vss::verify_shares_batch(&shares, &commitments, &config, &mut rng),
+        Err(vss::VssError::VerificationFailed { index }) if index == shares[250].index
+    ));
+    assert!(vss::verify_shares_batch(&shares, &commitments[1..], &config, &mut rng).is_err());
+}
+
+#[test]
+fn test_reconstruct_names_outliers() {
+    let config = vss::VssConfig::default();
+    let mut rng = StdRng::seed_from_u64(72);
+    let coeffs = vss::generate_polynomial(55, &config, &mut rng);
+    let mut shares = vss::generate_shares(&coeffs, &config, &mut rng);
+    assert_eq!(vss::reconstruct_secret(&shares, &config).expect("Failed to reconstruct secret"), 55);
+
+    let last = shares.len() - 1;
+    shares[last].value = (shares[last].value + 1) % config.order();
+    let index = shares[last].index;
+    match vss::reconstruct_secret(&shares, &config) {
+        Err(vss::VssError::Outliers(indices)) => assert_eq!(indices, vec![index]),
+        other => panic!("expected outliers, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_deal_from_seed_is_reproducible() {
+    let config = vss::VssConfig::default();
+    let (shares, commitments) = vss::deal_from_seed(1234, &config, [7; 32]);
+    assert_eq!(shares.iter().map(|share| share.value).collect::<Vec<_>>(), [1908, 167, 17, 1458, 484]);
+    assert_eq!(commitments, [3762, 2148, 2179]);
+    vss::verify_shares(&shares, &commitments, &config).expect("Seeded shares failed verification");
+    assert_eq!(vss::reconstruct_secret(&shares[..3], &config).expect("Failed to reconstruct"), 1234);
+}
+
+#[test]
+fn test_fixed_base_table_matches_exponentiation() {
+    use shamir::algos::group::Commitment;
+    // The largest safe prime below 2^63 as well as the toy one, for the full table width.
+    let wide = vss::VssConfig::new(9_223_372_036_854_771_239, 4, 3, 5).expect("Invalid config");
+    let configs = [vss::VssConfig::default(), wide];
+    let mut rng = thread_rng();
+    for config in configs {
+        let table = vss::FixedBaseTable::new(config.generator(), &config);
+        for exp in [0, 1, 15, 16, config.order() - 1, config.order(), -1]
+            .into_iter()
+            .chain((0..50).map(|_| rng.gen_range(0..config.order())))
+        {
+            assert_eq!(table.pow(exp), config.commit(&exp));
+        }
+    }
+}
+
+#[test]
+fn test_verification_tables() {
+    let config = vss::VssConfig::default();
+    let mut rng = thread_rng();
+    let coeffs = vss::generate_polynomial(77, &config, &mut rng);
+    let mut shares = vss::generate_shares(&coeffs, &config, &mut rng);
+    let commitments = vss::generate_commitments(&coeffs, &config);
+    let tables = vss::VerificationTables::new(&commitments, &config);
+    assert!(shares.iter().all(|share| tables.verify_share(share)));
+
+    shares[2].value = (shares[2].value + 1) % config.order();
+    assert!(!tables.verify_share(&shares[2]));
+    assert!(!vss::VerificationTables::new(&commitments[..2], &config).verify_share(&shares[0]));
+}
+
+#[test]
+fn test_reconstruct_checked_against_c0() {
+    let config = vss::VssConfig::default();
+    let mut rng = thread_rng();
+    let coeffs = vss::generate_polynomial(321, &config, &mut rng);
+    let shares = vss::generate_shares(&coeffs, &config, &mut rng);
+    let commitments = vss::generate_commitments(&coeffs, &config);
+    assert_eq!(vss::reconstruct_checked(&shares[1..4], &commitments, &config).expect("Failed to reconstruct"), 321);
+
+    // Shares of another dealing are consistent among themselves but not with these commitments.
+    let other = vss::generate_polynomial(322, &config, &mut rng);
+    let other_shares = vss::generate_shares(&other, &config, &mut rng);
+    assert!(matches!(
+        vss::reconstruct_checked(&other_shares[..3], &commitments, &config),
+        Err(vss::VssError::ReconstructionMismatch)
+    ));
+    assert!(matches!(vss::check_secret(321, &[], &config), Err(vss::VssError::InvalidParameters("commitments"))));
+}
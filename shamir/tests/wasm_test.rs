@@ -0,0 +1,12 @@
+#![cfg(feature = "wasm")]
+
+use shamir::algos::wasm;
+
+// The exports are plain Rust functions on native targets, so the happy path runs here too.
+#[test]
+fn test_split_and_combine_strings() {
+    let shares = wasm::split(b"browser secret", 2, 3).unwrap_or_else(|_| panic!("Failed to split secret"));
+    assert_eq!(shares.len(), 3);
+    let secret = wasm::combine(shares[1..].to_vec()).unwrap_or_else(|_| panic!("Failed to combine shares"));
+    assert_eq!(secret, b"browser secret");
+}
@@ -0,0 +1,34 @@
+use rand::thread_rng;
+use shamir::algos::sss::ShamirError;
+use shamir::algos::weighted;
+
+#[test]
+fn test_weighted_reconstruction() {
+    let shares = weighted::generate_shares(2024, 5, &[3, 2, 1, 1], &mut thread_rng()).expect("Failed to generate shares");
+    let weights: Vec<usize> = shares.iter().map(|share| share.weight()).collect();
+    assert_eq!(weights, vec![3, 2, 1, 1]);
+
+    assert_eq!(weighted::reconstruct_secret(&shares[..2]).expect("Failed to reconstruct"), 2024);
+    let subset = vec![shares[0].clone(), shares[2].clone(), shares[3].clone()];
+    assert_eq!(weighted::reconstruct_secret(&subset).expect("Failed to reconstruct"), 2024);
+}
+
+#[test]
+fn test_insufficient_weight() {
+    let shares = weighted::generate_shares(9, 5, &[3, 2, 1, 1], &mut thread_rng()).expect("Failed to generate shares");
+    let subset = vec![shares[0].clone(), shares[2].clone()];
+    assert!(matches!(weighted::reconstruct_secret(&subset), Err(ShamirError::InsufficientShares { .. })));
+}
+
+#[test]
+fn test_duplicate_participant_is_rejected() {
+    let shares = weighted::generate_shares(9, 4, &[2, 2], &mut thread_rng()).expect("Failed to generate shares");
+    let subset = vec![shares[0].clone(), shares[0].clone()];
+    assert!(matches!(weighted::reconstruct_secret(&subset), Err(ShamirError::InconsistentShares)));
+}
+
+#[test]
+fn test_unreachable_threshold() {
+    assert!(matches!(weighted::validate_weights(&[1, 1, 1], 4), Err(ShamirError::InvalidShareCount)));
+    assert!(matches!(weighted::validate_weights(&[2, 0, 2], 3), Err(ShamirError::InvalidParameters(_))));
+}
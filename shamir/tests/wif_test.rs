@@ -0,0 +1,50 @@
+use rand::thread_rng;
+use shamir::algos::sss::ShamirError;
+use shamir::algos::wif::{self, Network, WifKey, WifShare};
+
+// One key in the four encodings (the bitcoin wiki's WIF example).
+const KEY_HEX: &str = "0c28fca386c7a227600b2fe50b7cae11ec86d3bf1fbe471be89827e19d72aa1d";
+const MAINNET: &str = "5HueCGU8rMjxEXxiPuD5BDku4MkFqeZyd4dZ1jvhTVqvbTLvyTJ";
+const MAINNET_COMPRESSED: &str = "KwdMAjGmerYanjeui5SHS7JkmpZvVipYvB2LJGU1ZxJwYvP98617";
+const TESTNET: &str = "91gGn1HgSap6CbU12F6z3pJri26xzp7Ay1VW6NHCoEayNXwRpu2";
+const TESTNET_COMPRESSED: &str = "cMzLdeGd5vEqxB8B6VFQoRopQ3sLAAvEzDAoQgvX54xwofSWj1fx";
+
+#[test]
+fn test_known_vectors() {
+    let cases = [
+        (MAINNET, Network::Mainnet, false),
+        (MAINNET_COMPRESSED, Network::Mainnet, true),
+        (TESTNET, Network::Testnet, false),
+        (TESTNET_COMPRESSED, Network::Testnet, true),
+    ];
+    for (encoded, network, compressed) in cases {
+        let key = WifKey::decode(encoded).expect("Failed to decode");
+        let hex: String = key.key.iter().map(|byte| format!("{:02x}", byte)).collect();
+        assert_eq!(hex, KEY_HEX);
+        assert_eq!((key.network, key.compressed), (network, compressed));
+        assert_eq!(key.encode().as_str(), encoded);
+    }
+    let typo = MAINNET_COMPRESSED.replace("Kwd", "Kwe");
+    assert!(matches!(WifKey::decode(&typo), Err(ShamirError::InvalidChecksum)));
+}
+
+#[test]
+fn test_split_and_combine_keeps_prefix() {
+    let mut rng = thread_rng();
+    for encoded in [MAINNET, TESTNET_COMPRESSED] {
+        let shares = wif::split_wif(encoded, 2, 3, &mut rng).expect("Failed to split");
+        let parsed: Vec<WifShare> =
+            shares.iter().map(|share| share.to_string().parse().expect("Failed to parse share")).collect();
+        assert_eq!(parsed, shares);
+        assert_eq!(wif::combine_wif(&parsed[1..]).expect("Failed to combine").as_str(), encoded);
+    }
+}
+
+#[test]
+fn test_mixed_networks_are_rejected() {
+    let mut rng = thread_rng();
+    let mut shares = wif::split_wif(MAINNET_COMPRESSED, 2, 2, &mut rng).expect("Failed to split");
+    shares[1].network = Network::Testnet;
+    assert!(matches!(wif::combine_wif(&shares), Err(ShamirError::InconsistentShares)));
+    assert!("regtest:compressed:00".parse::<WifShare>().is_err());
+}
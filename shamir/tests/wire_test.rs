@@ -0,0 +1,70 @@
+use rand::thread_rng;
+use shamir::algos::share::Share;
+use shamir::algos::sss;
+use shamir::algos::wire::{self, DecodeError};
+
+fn from_hex(s: &str) -> Vec<u8> {
+    (0..s.len()).step_by(2).map(|i| u8::from_str_radix(&s[i..i + 2], 16).expect("Invalid hex")).collect()
+}
+
+// Shares written by version 1 must decode unchanged in every later release.
+const GOLDEN_U64: &str = "53484d520101deadbeef000000000000000000000002000000000000000300000008000000000012d6872e80a3cc";
+const GOLDEN_BYTES: &str = "53484d520102010203040000000000000000000000020000000000000001000000026869ed429ca4";
+// Version 2: the same u64 share from a dealing of five with fingerprint 0x0123456789abcdef.
+const GOLDEN_V2: &str =
+    "53484d520201deadbeef000000000000000000000002000000050123456789abcdef000000000000000300000008000000000012d687f91e0beb";
+
+#[test]
+fn test_golden_vectors() {
+    let mut share = Share::new(3, 1234567u64, 2, 0xdeadbeef);
+    assert_eq!(wire::decode::<u64>(&from_hex(GOLDEN_U64)), Ok(share.clone()));
+    share.total = 5;
+    share.fingerprint = 0x0123456789abcdef;
+    assert_eq!(wire::encode(&share), from_hex(GOLDEN_V2));
+    assert_eq!(wire::decode::<u64>(&from_hex(GOLDEN_V2)), Ok(share));
+
+    let share = Share::new(1, b"hi".to_vec(), 2, 0x01020304);
+    assert_eq!(wire::decode::<Vec<u8>>(&from_hex(GOLDEN_BYTES)), Ok(share.clone()));
+    assert_eq!(wire::decode::<Vec<u8>>(&wire::encode(&share)), Ok(share));
+}
+
+#[test]
+fn test_header_describes_dealing() {
+    let shares = sss::split_bytes(b"header", 3, 5, &mut thread_rng()).expect("Failed to split bytes");
+    let header = wire::header(&wire::encode(&shares[2])).expect("Failed to read header");
+    assert_eq!((header.version, header.threshold, header.total, header.index), (2, 3, 5, 3));
+    assert_eq!(header.scheme_id, shares[0].scheme_id);
+    assert_ne!(header.fingerprint, 0);
+    for share in &shares {
+        assert_eq!(wire::header(&wire::encode(share)).expect("Failed to read header").fingerprint, header.fingerprint);
+    }
+    let v1 = wire::header(&from_hex(GOLDEN_U64)).expect("Failed to read header");
+    assert_eq!((v1.total, v1.fingerprint), (0, 0));
+
+    // Another split of the same secret carries its own scheme id and fingerprint.
+    let others = sss::split_bytes(b"header", 3, 5, &mut thread_rng()).expect("Failed to split bytes");
+    let other = wire::header(&wire::encode(&others[2])).expect("Failed to read header");
+    assert_ne!(header.scheme_id, other.scheme_id);
+    assert_ne!(header.fingerprint, other.fingerprint);
+}
+
+#[test]
+fn test_round_trip_preserves_metadata() {
+    let mut shares = sss::split_bytes(b"wire format", 2, 3, &mut thread_rng()).expect("Failed to split bytes");
+    shares[1].epoch = 4;
+    shares[1].level = 2;
+    let decoded = wire::decode::<Vec<u8>>(&wire::encode(&shares[1])).expect("Failed to decode share");
+    assert_eq!(decoded, shares[1]);
+}
+
+#[test]
+fn test_rejects_corruption() {
+    let mut bytes = from_hex(GOLDEN_U64);
+    bytes[20] ^= 1;
+    assert_eq!(wire::decode::<u64>(&bytes), Err(DecodeError::BadChecksum));
+    assert_eq!(wire::decode::<u64>(&bytes[..10]), Err(DecodeError::Truncated));
+    assert_eq!(wire::decode::<u64>(b"NOPE\x01"), Err(DecodeError::BadMagic));
+    assert_eq!(wire::decode::<u64>(b"SHMR\x09"), Err(DecodeError::UnsupportedVersion(9)));
+    assert_eq!(wire::decode::<Vec<u8>>(&from_hex(GOLDEN_U64)), Err(DecodeError::FieldMismatch));
+}
+